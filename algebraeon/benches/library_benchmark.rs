@@ -1,7 +1,19 @@
+use algebraeon::rings::algebraic_number_field::{
+    AlgebraicIntegerRingSignature, AlgebraicNumberFieldPolynomialQuotientStructure,
+    AlgebraicNumberFieldSignature, OrderIdeal, OrderWithBasis,
+};
 use algebraeon::rings::matrix::{Matrix, RealInnerProduct, StandardInnerProduct};
+use algebraeon::rings::num_theory::modulo::const_naive::Modulo;
 use algebraeon::rings::parsing::{parse_integer_polynomial, parse_rational_polynomial};
-use algebraeon::rings::polynomial::Polynomial;
-use algebraeon::rings::structure::MetaFactoringMonoid;
+use algebraeon::rings::polynomial::{
+    Polynomial, gcd_modular, gcd_pseudo_remainder, gcd_subresultant, mul_ntt,
+};
+use algebraeon::rings::structure::{
+    AdditiveGroupSignature, IdealsArithmeticSignature, MetaAdditionSignature,
+    MetaAdditiveMonoidSignature, MetaFactoringMonoid, MetaMultiplicationSignature,
+    MetaMultiplicativeMonoidSignature, MetaZeroSignature, MultiplicationSignature, OneSignature,
+    RingSignature, RingToIdealsSignature,
+};
 use algebraeon_structures::*;
 use gungraun::{library_benchmark, library_benchmark_group, main};
 use std::hint::black_box;
@@ -38,6 +50,19 @@ library_benchmark_group!(
     benchmarks = bench_factor_integer_polynomial
 );
 
+#[library_benchmark]
+#[bench::deg20_cyclotomic(parse_rational_polynomial("x^20 - 1", "x").unwrap())]
+#[bench::deg20_two_irreducibles(parse_rational_polynomial("(x^10 - 3/2*x^7 + x^2 - 5) * (1/3*x^10 + x^9 - 2*x^4 + 7)", "x").unwrap())]
+#[bench::deg20_irreducible(parse_rational_polynomial("x^20 + 2/5*x^13 - x^6 + 3*x - 1", "x").unwrap())]
+fn bench_factor_rational_polynomial(polynomial: Polynomial<Rational>) {
+    black_box(polynomial.factor());
+}
+
+library_benchmark_group!(
+    name = bench_factor_rational_polynomial_group;
+    benchmarks = bench_factor_rational_polynomial
+);
+
 #[library_benchmark]
 #[bench::poly1(parse_rational_polynomial("x^5 - x + 1", "x").unwrap())]
 fn bench_count_real_polynomial_roots(polynomial: Polynomial<Rational>) {
@@ -91,9 +116,279 @@ library_benchmark_group!(
         bench_lll_integral_dim6,
 );
 
+fn setup_ntt_polys(deg: usize) -> (Polynomial<Modulo<998244353>>, Polynomial<Modulo<998244353>>) {
+    let poly = |seed: usize| {
+        Polynomial::from_coeffs(
+            (0..=deg)
+                .map(|i| Modulo::<998244353>::from(i * i * seed + 7 * i + seed))
+                .collect(),
+        )
+    };
+    (poly(3), poly(5))
+}
+
+#[library_benchmark]
+#[bench::deg512(setup = setup_ntt_polys, args = (512))]
+fn bench_mul_ntt(polys: (Polynomial<Modulo<998244353>>, Polynomial<Modulo<998244353>>)) {
+    black_box(mul_ntt(&polys.0, &polys.1));
+}
+
+#[library_benchmark]
+#[bench::deg512(setup = setup_ntt_polys, args = (512))]
+fn bench_mul_schoolbook(polys: (Polynomial<Modulo<998244353>>, Polynomial<Modulo<998244353>>)) {
+    black_box(Polynomial::mul(&polys.0, &polys.1));
+}
+
+library_benchmark_group!(
+    name = polynomial_multiplication;
+    benchmarks =
+        bench_mul_ntt,
+        bench_mul_schoolbook,
+);
+
+fn setup_rationals(n: usize) -> Vec<Rational> {
+    (1..=n)
+        .map(|i| Rational::from_integers(Integer::from(i), Integer::from(i * i + 1)))
+        .collect()
+}
+
+#[library_benchmark]
+#[bench::n200(setup = setup_rationals, args = (200))]
+fn bench_sum_iter(vals: Vec<Rational>) {
+    black_box(Rational::sum_iter(vals.into_iter()));
+}
+
+#[library_benchmark]
+#[bench::n200(setup = setup_rationals, args = (200))]
+fn bench_sum_loop(vals: Vec<Rational>) {
+    let mut sum = Rational::zero();
+    for val in vals {
+        sum = Rational::add(&sum, &val);
+    }
+    black_box(sum);
+}
+
+#[library_benchmark]
+#[bench::n200(setup = setup_rationals, args = (200))]
+fn bench_product_iter(vals: Vec<Rational>) {
+    black_box(Rational::product_iter(vals.into_iter()));
+}
+
+library_benchmark_group!(
+    name = sums_and_products;
+    benchmarks =
+        bench_sum_iter,
+        bench_sum_loop,
+        bench_product_iter,
+);
+
+// polynomials of degree 2 * deg sharing a factor of degree deg, with coefficients of around 2 * digits digits
+fn setup_gcd_polys(deg: usize, digits: usize) -> (Polynomial<Integer>, Polynomial<Integer>) {
+    let modulus = Integer::from(10).nat_pow(&Natural::from(digits));
+    let mut seed = Integer::from(1234567890123456789u64);
+    let mut random_poly = |deg: usize| {
+        Polynomial::from_coeffs(
+            (0..=deg)
+                .map(|_| {
+                    seed = (&seed * Integer::from(6364136223846793005u64)
+                        + Integer::from(1442695040888963407u64))
+                        % &modulus;
+                    &seed - &modulus / Integer::from(2)
+                })
+                .collect(),
+        )
+    };
+    let a = random_poly(deg);
+    let f = Polynomial::mul(&a, &random_poly(deg));
+    let g = Polynomial::mul(&a, &random_poly(deg - 1));
+    (f, g)
+}
+
+#[library_benchmark]
+#[bench::small(setup = setup_gcd_polys, args = (6, 5))]
+fn bench_gcd_pseudo_remainder(polys: (Polynomial<Integer>, Polynomial<Integer>)) {
+    black_box(gcd_pseudo_remainder(&polys.0, &polys.1));
+}
+
+#[library_benchmark]
+#[bench::small(setup = setup_gcd_polys, args = (6, 5))]
+#[bench::large(setup = setup_gcd_polys, args = (30, 25))]
+#[bench::deg50(setup = setup_gcd_polys, args = (25, 10))]
+fn bench_gcd_subresultant(polys: (Polynomial<Integer>, Polynomial<Integer>)) {
+    black_box(gcd_subresultant(&polys.0, &polys.1));
+}
+
+#[library_benchmark]
+#[bench::small(setup = setup_gcd_polys, args = (6, 5))]
+#[bench::large(setup = setup_gcd_polys, args = (30, 25))]
+#[bench::deg50(setup = setup_gcd_polys, args = (25, 10))]
+fn bench_gcd_modular(polys: (Polynomial<Integer>, Polynomial<Integer>)) {
+    black_box(gcd_modular(&polys.0, &polys.1));
+}
+
+library_benchmark_group!(
+    name = integer_polynomial_gcd;
+    benchmarks =
+        bench_gcd_pseudo_remainder,
+        bench_gcd_subresultant,
+        bench_gcd_modular,
+);
+
+// an n by n matrix with entries of around digits digits
+fn setup_integer_matrix(n: usize, digits: usize) -> Matrix<Integer> {
+    let modulus = Integer::from(10).nat_pow(&Natural::from(digits));
+    let mut seed = Integer::from(987654321u64);
+    let mut next = || {
+        seed = (&seed * Integer::from(6364136223846793005u64)
+            + Integer::from(1442695040888963407u64))
+            % &modulus;
+        &seed - &modulus / Integer::from(2)
+    };
+    Matrix::from_rows(
+        (0..n)
+            .map(|_| (0..n).map(|_| next()).collect())
+            .collect::<Vec<Vec<Integer>>>(),
+    )
+}
+
+#[library_benchmark]
+#[bench::dim10(setup = setup_integer_matrix, args = (10, 3))]
+fn bench_smith_normal_form(mat: Matrix<Integer>) {
+    black_box(mat.smith_algorithm());
+}
+
+library_benchmark_group!(
+    name = smith_normal_form;
+    benchmarks = bench_smith_normal_form
+);
+
+#[library_benchmark]
+#[bench::cyclotomic7(parse_rational_polynomial("x^6 + x^5 + x^4 + x^3 + x^2 + x + 1", "x").unwrap().algebraic_number_field().unwrap())]
+#[bench::pure_sextic(parse_rational_polynomial("x^6 + 108", "x").unwrap().algebraic_number_field().unwrap())]
+fn bench_integral_basis(anf: AlgebraicNumberFieldPolynomialQuotientStructure) {
+    black_box(anf.compute_integral_basis_and_discriminant());
+}
+
+library_benchmark_group!(
+    name = integral_basis;
+    benchmarks = bench_integral_basis
+);
+
+type RingOfIntegers = OrderWithBasis<
+    AlgebraicNumberFieldPolynomialQuotientStructure,
+    AlgebraicNumberFieldPolynomialQuotientStructure,
+    true,
+>;
+
+// powers of the prime ideals above 2 and 3 in the ring of integers Z[(1 + sqrt(-23)) / 2] of Q(sqrt(-23)), which has class number 3
+fn setup_sqrt_minus_23_ideals(
+    two_power: usize,
+    three_power: usize,
+) -> (RingOfIntegers, OrderIdeal, OrderIdeal) {
+    let roi = parse_rational_polynomial("x^2 + 23", "x")
+        .unwrap()
+        .algebraic_number_field()
+        .unwrap()
+        .into_ring_of_integers();
+    let ideals = roi.ideals();
+    // w = (1 + sqrt(-23)) / 2 has minimal polynomial x^2 - x + 6
+    let w = roi
+        .try_from_anf(&parse_rational_polynomial("1/2 + 1/2*x", "x").unwrap())
+        .unwrap();
+    let prime_power = |p: i64, k: usize| {
+        let prime = ideals.generated_ideal(vec![roi.from_int(p), w.clone()]);
+        (0..k).fold(ideals.principal_ideal(&roi.one()), |acc, _| {
+            ideals.mul(&acc, &prime)
+        })
+    };
+    let a = prime_power(2, two_power);
+    let b = prime_power(3, three_power);
+    (roi, a, b)
+}
+
+#[library_benchmark]
+#[bench::primes(setup = setup_sqrt_minus_23_ideals, args = (1, 1))]
+#[bench::prime_powers(setup = setup_sqrt_minus_23_ideals, args = (7, 5))]
+fn bench_ideal_mul_sqrt_minus_23(input: (RingOfIntegers, OrderIdeal, OrderIdeal)) {
+    let (roi, a, b) = input;
+    black_box(roi.ideals().mul(&a, &b));
+}
+
+// the first n of the prime ideals (p, i - a) of Z[i] with a^2 = -1 mod p
+fn setup_gaussian_prime_ideals(n: usize) -> (RingOfIntegers, Vec<OrderIdeal>) {
+    let roi = parse_rational_polynomial("x^2 + 1", "x")
+        .unwrap()
+        .algebraic_number_field()
+        .unwrap()
+        .into_ring_of_integers();
+    let ideals = roi.ideals();
+    let i = roi
+        .try_from_anf(&parse_rational_polynomial("x", "x").unwrap())
+        .unwrap();
+    let primes = [
+        (5, 2),
+        (13, 5),
+        (17, 4),
+        (29, 12),
+        (37, 6),
+        (41, 9),
+        (53, 23),
+        (61, 11),
+        (73, 27),
+        (89, 34),
+        (97, 22),
+        (101, 10),
+        (109, 33),
+        (113, 15),
+        (137, 37),
+        (149, 44),
+    ];
+    let prime_ideals = primes[..n]
+        .iter()
+        .map(|(p, a)| {
+            ideals.generated_ideal(vec![roi.from_int(*p), roi.sub(&i, &roi.from_int(*a))])
+        })
+        .collect();
+    (roi, prime_ideals)
+}
+
+#[library_benchmark]
+#[bench::n8(setup = setup_gaussian_prime_ideals, args = (8))]
+#[bench::n16(setup = setup_gaussian_prime_ideals, args = (16))]
+fn bench_ideal_product_balanced(input: (RingOfIntegers, Vec<OrderIdeal>)) {
+    let (roi, ideals) = input;
+    black_box(roi.ideals().ideal_product(ideals));
+}
+
+#[library_benchmark]
+#[bench::n8(setup = setup_gaussian_prime_ideals, args = (8))]
+#[bench::n16(setup = setup_gaussian_prime_ideals, args = (16))]
+fn bench_ideal_product_sequential(input: (RingOfIntegers, Vec<OrderIdeal>)) {
+    let (roi, ideals) = input;
+    let ideals_structure = roi.ideals();
+    black_box(ideals.iter().fold(ideals_structure.one(), |acc, ideal| {
+        ideals_structure.mul(&acc, ideal)
+    }));
+}
+
+library_benchmark_group!(
+    name = ideal_arithmetic;
+    benchmarks =
+        bench_ideal_mul_sqrt_minus_23,
+        bench_ideal_product_balanced,
+        bench_ideal_product_sequential,
+);
+
 main!(
     library_benchmark_groups = bench_factor_natural_group,
     bench_factor_integer_polynomial_group,
     bench_count_polynomial_roots,
-    lll
+    lll,
+    polynomial_multiplication,
+    sums_and_products,
+    integer_polynomial_gcd,
+    bench_factor_rational_polynomial_group,
+    smith_normal_form,
+    integral_basis,
+    ideal_arithmetic
 );
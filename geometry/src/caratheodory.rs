@@ -0,0 +1,154 @@
+use crate::{convex_hull::ConvexHull, integer_program::Tableau, vector::Vector};
+
+use super::*;
+use algebraeon_rings::structure::*;
+
+/// Write `point` as a convex combination of at most `d + 1` of the defining points of `hull`, where `d` is the dimension of the ambient space.
+///
+/// Returns pairs `(vertex, coefficient)` with positive coefficients summing to `1`, or `None` if `point` is not in `hull`.
+///
+/// The coefficients are a basic feasible solution of the `d + 1` linear equations `sum c_i v_i = point` and `sum c_i = 1` in non-negative `c_i`,
+/// found with the first phase of the simplex method, so at most `d + 1` of them are nonzero as in Carathéodory's theorem.
+pub fn caratheodory_decompose<'f, FS: OrderedRingSignature + FieldSignature>(
+    hull: &ConvexHull<'f, FS>,
+    point: &Vector<'f, FS>,
+) -> Option<Vec<(Vector<'f, FS>, FS::Elem)>>
+where
+    FS::Elem: Hash,
+{
+    let space = hull.ambient_space();
+    assert_eq!(point.ambient_space(), space);
+    let field = space.field();
+    let d = space.linear_dimension()?;
+    let vertices = hull.defining_points().into_iter().collect::<Vec<_>>();
+    if vertices.is_empty() {
+        return None;
+    }
+    let k = vertices.len();
+
+    let mut rows = (0..d)
+        .map(|i| {
+            vertices
+                .iter()
+                .map(|v| v.coordinate(i).clone())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    rows.push(vec![field.one(); k]);
+    let mut rhs = (0..d)
+        .map(|i| point.coordinate(i).clone())
+        .collect::<Vec<_>>();
+    rhs.push(field.one());
+
+    let mut tableau = Tableau::with_artificial_basis(field, rows, rhs);
+    if !tableau.eliminate_artificial(k) {
+        return None;
+    }
+    Some(
+        vertices
+            .into_iter()
+            .zip(tableau.basic_solution(k))
+            .filter(|(_, c)| !field.is_zero(c))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ambient_space::AffineSpace;
+    use algebraeon_structures::*;
+
+    fn check_decomposition<'f>(
+        hull: &ConvexHull<'f, RationalCanonicalStructure>,
+        point: &Vector<'f, RationalCanonicalStructure>,
+    ) {
+        let space = hull.ambient_space();
+        let d = space.linear_dimension().unwrap();
+        let decomposition = caratheodory_decompose(hull, point).unwrap();
+        assert!(!decomposition.is_empty());
+        assert!(decomposition.len() <= d + 1);
+        let mut total = Rational::ZERO;
+        let mut combination = Vector::zero(space);
+        for (vertex, c) in &decomposition {
+            assert!(c > &Rational::ZERO);
+            assert!(hull.defining_points().contains(vertex));
+            total += c;
+            combination += &vertex.scalar_mul(c);
+        }
+        assert_eq!(total, Rational::ONE);
+        assert_eq!(&combination, point);
+    }
+
+    #[test]
+    fn decompose_in_square() {
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 2);
+        let square = space.convex_hull(vec![
+            space.vector([0, 0]),
+            space.vector([2, 0]),
+            space.vector([0, 2]),
+            space.vector([2, 2]),
+        ]);
+        for pt in [[1, 1], [0, 0], [2, 1], [0, 2], [1, 0]] {
+            check_decomposition(&square, &space.vector(pt));
+        }
+        check_decomposition(
+            &square,
+            &space.vector([Rational::from_integers(1, 3), Rational::from_integers(7, 5)]),
+        );
+        // vertices need only themselves
+        assert_eq!(
+            caratheodory_decompose(&square, &space.vector([2, 2]))
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(caratheodory_decompose(&square, &space.vector([3, 1])).is_none());
+        assert!(
+            caratheodory_decompose(
+                &square,
+                &space.vector([Rational::from_integers(-1, 100), Rational::ONE])
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn decompose_in_octahedron() {
+        // the octahedron has 6 vertices but every point needs at most 4 of them
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 3);
+        let octahedron = space.convex_hull(vec![
+            space.vector([1, 0, 0]),
+            space.vector([-1, 0, 0]),
+            space.vector([0, 1, 0]),
+            space.vector([0, -1, 0]),
+            space.vector([0, 0, 1]),
+            space.vector([0, 0, -1]),
+        ]);
+        let q = |n: i64, d: i64| Rational::from_integers(n, d);
+        for pt in [
+            [q(0, 1), q(0, 1), q(0, 1)],
+            [q(1, 4), q(1, 4), q(1, 4)],
+            [q(-1, 3), q(1, 5), q(-1, 7)],
+            [q(1, 2), q(0, 1), q(-1, 2)],
+        ] {
+            check_decomposition(&octahedron, &space.vector(pt));
+        }
+        assert!(
+            caratheodory_decompose(&octahedron, &space.vector([q(1, 2), q(1, 2), q(1, 2)]))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn decompose_in_lower_dimensional_hull() {
+        // a segment in the plane
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 2);
+        let segment = space.convex_hull(vec![space.vector([0, 0]), space.vector([4, 2])]);
+        check_decomposition(&segment, &space.vector([2, 1]));
+        assert!(caratheodory_decompose(&segment, &space.vector([2, 2])).is_none());
+
+        let empty = space.convex_hull(vec![]);
+        assert!(caratheodory_decompose(&empty, &space.vector([0, 0])).is_none());
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     affine_subspace::EmbeddedAffineSubspace,
     ambient_space::AffineSpace,
+    half_space_representation::HalfSpaceRepresentation,
     oriented_simplex::{
         OrientationSide, OrientedHyperplane, OrientedHyperplaneIntersectLineSegmentResult,
         OrientedSimplex,
@@ -12,6 +13,7 @@ use crate::{
 };
 
 use super::*;
+use algebraeon_rings::matrix::{Matrix, MatrixStructure};
 use std::collections::{HashMap, HashSet};
 
 #[derive(Clone)]
@@ -254,6 +256,18 @@ where
         }
     }
 
+    /// Whether `pt` lies in the convex hull, including on its boundary.
+    pub fn contains_point(&self, pt: &Vector<'f, FS>) -> bool {
+        assert_eq!(pt.ambient_space(), self.ambient_space);
+        match self.subspace.unembed_point(pt) {
+            Some(subsp_pt) => self
+                .facets
+                .iter()
+                .all(|facet| facet.classify_point(&subsp_pt) != OrientationSide::Negative),
+            None => false,
+        }
+    }
+
     pub fn extend_by_point(&mut self, pt: Vector<'f, FS>) {
         assert_eq!(pt.ambient_space(), self.ambient_space);
         #[cfg(debug_assertions)]
@@ -765,6 +779,62 @@ where
         self_mut.intersect_mut(other);
         self_mut
     }
+
+    /// The half-spaces bounding this convex hull, one for each oriented facet.
+    ///
+    /// When the convex hull does not span the ambient space each facet is extended orthogonally to a hyperplane,
+    /// and the affine span is cut out by pairs of opposite half-spaces.
+    ///
+    /// # Panics
+    /// If the convex hull is empty.
+    pub fn to_halfspace_representation(&self) -> HalfSpaceRepresentation<'f, FS> {
+        let ambient_space = self.ambient_space();
+        let field = ambient_space.field();
+        let n = ambient_space.linear_dimension().unwrap();
+        let (_, span) = self
+            .subspace
+            .get_root_and_span()
+            .expect("the convex hull should be non-empty");
+        // a basis for the orthogonal complement of the affine span
+        let complement = MatrixStructure::<FS, &FS>::new(field)
+            .col_kernel(Matrix::construct(span.len(), n, |r, c| {
+                span[r].coordinate(c).clone()
+            }))
+            .basis()
+            .into_iter()
+            .map(|v| ambient_space.vector(v))
+            .collect::<Vec<_>>();
+
+        let mut half_spaces = vec![];
+        for facet in &self.facets {
+            if let Some(positive_point) = facet.positive_point() {
+                let mut points = facet
+                    .simplex()
+                    .points()
+                    .iter()
+                    .map(|pt| self.subspace.embed_point(pt))
+                    .collect::<Vec<_>>();
+                let root = points[0].clone();
+                points.extend(complement.iter().map(|v| &root + v));
+                half_spaces.push(
+                    OrientedSimplex::new_with_positive_point(
+                        ambient_space,
+                        points,
+                        &self.subspace.embed_point(&positive_point),
+                    )
+                    .unwrap(),
+                );
+            }
+        }
+        for hyperplane in self.subspace.to_oriented_hyperplane_intersection().unwrap() {
+            let half_space = hyperplane.into_oriented_simplex();
+            let mut opposite = half_space.clone();
+            opposite.flip();
+            half_spaces.push(half_space);
+            half_spaces.push(opposite);
+        }
+        HalfSpaceRepresentation::new(ambient_space, half_spaces)
+    }
 }
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,186 @@
+use crate::{
+    ambient_space::AffineSpace, convex_hull::ConvexHull, oriented_simplex::OrientationSide,
+    oriented_simplex::OrientedSimplex, vector::Vector,
+};
+use algebraeon_rings::matrix::{Matrix, MatrixStructure};
+use itertools::Itertools;
+
+use super::*;
+
+/// A convex polytope given as the intersection of finitely many closed half-spaces.
+///
+/// Each half-space is the set of points on the positive or neutral side of an oriented hyperplane.
+#[derive(Debug, Clone)]
+pub struct HalfSpaceRepresentation<'f, FS: OrderedRingSignature + FieldSignature> {
+    ambient_space: AffineSpace<'f, FS>,
+    half_spaces: Vec<OrientedSimplex<'f, FS>>,
+}
+
+impl<'f, FS: OrderedRingSignature + FieldSignature> HalfSpaceRepresentation<'f, FS> {
+    pub fn new(
+        ambient_space: AffineSpace<'f, FS>,
+        half_spaces: Vec<OrientedSimplex<'f, FS>>,
+    ) -> Self {
+        for half_space in &half_spaces {
+            assert_eq!(half_space.ambient_space(), ambient_space);
+        }
+        Self {
+            ambient_space,
+            half_spaces,
+        }
+    }
+
+    pub fn ambient_space(&self) -> AffineSpace<'f, FS> {
+        self.ambient_space
+    }
+
+    pub fn half_spaces(&self) -> &Vec<OrientedSimplex<'f, FS>> {
+        &self.half_spaces
+    }
+
+    /// Whether `point` lies in every half-space.
+    pub fn contains_point(&self, point: &Vector<'f, FS>) -> bool {
+        self.half_spaces
+            .iter()
+            .all(|half_space| half_space.classify_point(point) != OrientationSide::Negative)
+    }
+}
+
+impl<'f, FS: OrderedRingSignature + FieldSignature> HalfSpaceRepresentation<'f, FS>
+where
+    FS::Elem: Hash,
+{
+    /// The convex hull of the vertices of the polytope.
+    ///
+    /// The vertices are found by intersecting each set of hyperplanes whose normals form a basis and keeping the intersection points which lie in every half-space.
+    /// This recovers the polytope only when it is bounded.
+    pub fn to_vertex_representation(&self) -> ConvexHull<'f, FS> {
+        let space = self.ambient_space;
+        let field = space.field();
+        let n = space.linear_dimension().unwrap();
+        let origin = space.origin().unwrap();
+        // write each half-space as a.x + b >= 0
+        let equations = self
+            .half_spaces
+            .iter()
+            .map(|half_space| {
+                let b = half_space.classify_point_quantitatively(&origin);
+                let a = (0..n)
+                    .map(|i| {
+                        let e_i = Vector::construct(space, |j| {
+                            if i == j { field.one() } else { field.zero() }
+                        });
+                        field.sub(&half_space.classify_point_quantitatively(&e_i), &b)
+                    })
+                    .collect::<Vec<_>>();
+                (a, b)
+            })
+            .collect::<Vec<_>>();
+
+        let mat_structure = MatrixStructure::<FS, &FS>::new(field);
+        let mut vertices = vec![];
+        for subset in (0..equations.len()).combinations(n) {
+            let mat = Matrix::construct(n, n, |r, c| equations[subset[r]].0[c].clone());
+            if mat_structure.rank(mat.clone()) != n {
+                continue;
+            }
+            let y = subset
+                .iter()
+                .map(|i| field.neg(&equations[*i].1))
+                .collect::<Vec<_>>();
+            let vertex = space.vector(mat_structure.col_solve(mat, &y).unwrap());
+            if self.contains_point(&vertex) {
+                vertices.push(vertex);
+            }
+        }
+        space.convex_hull(vertices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algebraeon_structures::Rational;
+    use std::collections::HashSet;
+
+    #[test]
+    fn cube_round_trip() {
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 3);
+        let mut corners = vec![];
+        for x in [0, 1] {
+            for y in [0, 1] {
+                for z in [0, 1] {
+                    corners.push(space.vector([x, y, z]));
+                }
+            }
+        }
+        let cube = space.convex_hull(corners.clone());
+        let half_spaces = cube.to_halfspace_representation();
+        assert_eq!(
+            half_spaces.to_vertex_representation().defining_points(),
+            corners.into_iter().collect::<HashSet<_>>()
+        );
+
+        let half = Rational::from(1) / Rational::from(2);
+        let quarter = Rational::from(1) / Rational::from(4);
+        assert!(half_spaces.contains_point(&space.vector([
+            half.clone(),
+            quarter.clone(),
+            half.clone()
+        ])));
+        assert!(half_spaces.contains_point(&space.vector([1, 0, 1])));
+        assert!(!half_spaces.contains_point(&space.vector([
+            half.clone(),
+            quarter,
+            Rational::from(2)
+        ])));
+        assert!(!half_spaces.contains_point(&space.vector([-1, 0, 0])));
+    }
+
+    #[test]
+    fn tetrahedron_round_trip() {
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 3);
+        let corners = vec![
+            space.vector([0, 0, 0]),
+            space.vector([2, 0, 0]),
+            space.vector([0, 3, 0]),
+            space.vector([1, 1, 5]),
+        ];
+        let tetrahedron = space.convex_hull(corners.clone());
+        let half_spaces = tetrahedron.to_halfspace_representation();
+        assert_eq!(half_spaces.half_spaces().len(), 4);
+        assert_eq!(
+            half_spaces.to_vertex_representation().defining_points(),
+            corners.into_iter().collect::<HashSet<_>>()
+        );
+        assert!(half_spaces.contains_point(&space.vector([1, 1, 1])));
+        assert!(!half_spaces.contains_point(&space.vector([1, 1, 6])));
+    }
+
+    #[test]
+    fn lower_dimensional_round_trip() {
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 3);
+        let corners = vec![
+            space.vector([0, 0, 1]),
+            space.vector([1, 0, 1]),
+            space.vector([0, 1, 1]),
+        ];
+        let triangle = space.convex_hull(corners.clone());
+        let half_spaces = triangle.to_halfspace_representation();
+        assert_eq!(
+            half_spaces.to_vertex_representation().defining_points(),
+            corners.into_iter().collect::<HashSet<_>>()
+        );
+        let third = Rational::from(1) / Rational::from(3);
+        assert!(half_spaces.contains_point(&space.vector([
+            third.clone(),
+            third.clone(),
+            Rational::from(1)
+        ])));
+        assert!(!half_spaces.contains_point(&space.vector([
+            third.clone(),
+            third,
+            Rational::from(0)
+        ])));
+    }
+}
@@ -0,0 +1,565 @@
+use algebraeon_rings::matrix::Matrix;
+use algebraeon_rings::structure::*;
+use algebraeon_structures::*;
+
+/// The outcome of maximizing a linear objective over a polyhedron.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinearProgramSolution {
+    Infeasible,
+    Unbounded,
+    Optimal {
+        point: Vec<Rational>,
+        value: Rational,
+    },
+}
+
+/// The outcome of maximizing a linear objective over the integer points of a polyhedron.
+///
+/// An unbounded program has no optimal point but is not infeasible, so the outcomes are kept apart rather than both being `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntegerProgramSolution {
+    Infeasible,
+    Unbounded,
+    Optimal { point: Vec<Integer>, value: Integer },
+}
+
+/// A simplex tableau for the equations `rows * x = rhs` together with the index of the basic variable of each row.
+pub(crate) struct Tableau<'f, FS: OrderedRingSignature + FieldSignature> {
+    pub(crate) field: &'f FS,
+    pub(crate) rows: Vec<Vec<FS::Elem>>,
+    pub(crate) rhs: Vec<FS::Elem>,
+    pub(crate) basis: Vec<usize>,
+}
+
+impl<'f, FS: OrderedRingSignature + FieldSignature> Tableau<'f, FS> {
+    pub(crate) fn pivot(&mut self, r: usize, c: usize) {
+        let field = self.field;
+        let p = self.rows[r][c].clone();
+        for x in &mut self.rows[r] {
+            *x = field.try_divide(x, &p).unwrap();
+        }
+        self.rhs[r] = field.try_divide(&self.rhs[r], &p).unwrap();
+        for i in 0..self.rows.len() {
+            if i != r && !field.is_zero(&self.rows[i][c]) {
+                let f = self.rows[i][c].clone();
+                for j in 0..self.rows[i].len() {
+                    let d = field.mul(&f, &self.rows[r][j]);
+                    field.sub_mut(&mut self.rows[i][j], &d);
+                }
+                let d = field.mul(&f, &self.rhs[r]);
+                field.sub_mut(&mut self.rhs[i], &d);
+            }
+        }
+        self.basis[r] = c;
+    }
+
+    /// Maximize `cost . x` over the columns `0..num_cols` starting from the current feasible basis, using Bland's rule to avoid cycling.
+    ///
+    /// Returns `false` if the objective is unbounded.
+    pub(crate) fn maximize(&mut self, cost: &[FS::Elem], num_cols: usize) -> bool {
+        let field = self.field;
+        loop {
+            let reduced_cost = |j: usize| {
+                let mut d = cost[j].clone();
+                for (i, b) in self.basis.iter().enumerate() {
+                    field.sub_mut(&mut d, &field.mul(&cost[*b], &self.rows[i][j]));
+                }
+                d
+            };
+            let Some(c) =
+                (0..num_cols).find(|j| field.cmp(&reduced_cost(*j), &field.zero()).is_gt())
+            else {
+                return true;
+            };
+            let mut leaving: Option<(usize, FS::Elem)> = None;
+            for i in 0..self.rows.len() {
+                if field.cmp(&self.rows[i][c], &field.zero()).is_gt() {
+                    let ratio = field.try_divide(&self.rhs[i], &self.rows[i][c]).unwrap();
+                    let better = match &leaving {
+                        None => true,
+                        Some((r, best)) => match field.cmp(&ratio, best) {
+                            std::cmp::Ordering::Less => true,
+                            std::cmp::Ordering::Equal => self.basis[i] < self.basis[*r],
+                            std::cmp::Ordering::Greater => false,
+                        },
+                    };
+                    if better {
+                        leaving = Some((i, ratio));
+                    }
+                }
+            }
+            match leaving {
+                Some((r, _)) => self.pivot(r, c),
+                None => return false,
+            }
+        }
+    }
+
+    /// A tableau for `rows * x = rhs` and `x >= 0` with an artificial variable added for each row, which form the initial basis.
+    ///
+    /// The artificial variables are the last `rows.len()` columns.
+    pub(crate) fn with_artificial_basis(
+        field: &'f FS,
+        rows: Vec<Vec<FS::Elem>>,
+        rhs: Vec<FS::Elem>,
+    ) -> Self {
+        let m = rows.len();
+        let mut tableau = Self {
+            field,
+            rows: vec![],
+            rhs: vec![],
+            basis: vec![],
+        };
+        for (i, (mut row, mut b)) in rows.into_iter().zip(rhs).enumerate() {
+            if field.cmp(&b, &field.zero()).is_lt() {
+                for x in &mut row {
+                    *x = field.neg(x);
+                }
+                b = field.neg(&b);
+            }
+            let n = row.len();
+            row.extend((0..m).map(|k| if k == i { field.one() } else { field.zero() }));
+            tableau.rows.push(row);
+            tableau.rhs.push(b);
+            tableau.basis.push(n + i);
+        }
+        tableau
+    }
+
+    /// Drive the artificial variables, the columns from `first_artificial` onwards, to zero.
+    ///
+    /// Returns `false` if there is no feasible solution.
+    /// Otherwise the artificial variables are pivoted out of the basis where possible,
+    /// and those which remain are in redundant rows and stay at zero.
+    pub(crate) fn eliminate_artificial(&mut self, first_artificial: usize) -> bool {
+        let field = self.field;
+        let num_cols = self.rows.first().map_or(0, |row| row.len());
+        let cost = (0..num_cols)
+            .map(|j| {
+                if j >= first_artificial {
+                    field.neg(&field.one())
+                } else {
+                    field.zero()
+                }
+            })
+            .collect::<Vec<_>>();
+        self.maximize(&cost, num_cols);
+        if (0..self.rows.len())
+            .any(|i| self.basis[i] >= first_artificial && !field.is_zero(&self.rhs[i]))
+        {
+            return false;
+        }
+        for i in 0..self.rows.len() {
+            if self.basis[i] >= first_artificial
+                && let Some(j) = (0..first_artificial).find(|j| !field.is_zero(&self.rows[i][*j]))
+            {
+                self.pivot(i, j);
+            }
+        }
+        true
+    }
+
+    /// The values of the first `n` variables at the current basic solution.
+    pub(crate) fn basic_solution(&self, n: usize) -> Vec<FS::Elem> {
+        let mut point = vec![self.field.zero(); n];
+        for (i, b) in self.basis.iter().enumerate() {
+            if *b < n {
+                point[*b] = self.rhs[i].clone();
+            }
+        }
+        point
+    }
+}
+
+/// Maximize `objective . x` subject to `constraints * x <= rhs` and `x >= 0` over the rationals, using the two phase simplex method.
+pub fn linear_program_solve(
+    objective: &[Rational],
+    constraints: &Matrix<Rational>,
+    rhs: &[Rational],
+) -> LinearProgramSolution {
+    let m = constraints.rows();
+    let n = constraints.cols();
+    assert_eq!(objective.len(), n);
+    assert_eq!(rhs.len(), m);
+
+    // columns are the n variables, then a slack variable for each row, then an artificial variable for each row
+    let rows = (0..m)
+        .map(|i| {
+            let mut row = constraints.get_row(i);
+            row.extend((0..m).map(|k| {
+                if k == i {
+                    Rational::ONE
+                } else {
+                    Rational::ZERO
+                }
+            }));
+            row
+        })
+        .collect::<Vec<_>>();
+    let mut tableau = Tableau::with_artificial_basis(Rational::structure_ref(), rows, rhs.to_vec());
+    // start from the slack basis where it is feasible
+    for (i, b) in rhs.iter().enumerate() {
+        if b >= &Rational::ZERO {
+            tableau.pivot(i, n + i);
+        }
+    }
+    if !tableau.eliminate_artificial(n + m) {
+        return LinearProgramSolution::Infeasible;
+    }
+
+    // optimize the objective without using the artificial variables
+    let cost = (0..n + 2 * m)
+        .map(|j| {
+            if j < n {
+                objective[j].clone()
+            } else {
+                Rational::ZERO
+            }
+        })
+        .collect::<Vec<_>>();
+    if !tableau.maximize(&cost, n + m) {
+        return LinearProgramSolution::Unbounded;
+    }
+    let point = tableau.basic_solution(n);
+    let value = objective
+        .iter()
+        .zip(&point)
+        .map(|(c, x)| c * x)
+        .fold(Rational::ZERO, |a, b| a + b);
+    LinearProgramSolution::Optimal { point, value }
+}
+
+/// Whether the integer points of `{x : constraints * x <= rhs, x >= 0}` can satisfy the equalities which hold on the whole polyhedron.
+///
+/// The polyhedron must be nonempty. The equalities are found by minimizing each constraint over the polyhedron, and their integer solvability is decided by a hermite normal form.
+fn implicit_equalities_have_integer_solution(
+    constraints: &Matrix<Integer>,
+    rhs: &[Integer],
+) -> bool {
+    let m = constraints.rows();
+    let n = constraints.cols();
+    let rational_constraints = constraints.apply_map(|x| Rational::from(x));
+    let rational_rhs = rhs.iter().map(Rational::from).collect::<Vec<_>>();
+    // the rows a . x <= b and the rows -x_j <= 0
+    let inequalities = (0..m)
+        .map(|r| (constraints.get_row(r), rhs[r].clone()))
+        .chain((0..n).map(|j| {
+            let mut row = vec![Integer::ZERO; n];
+            row[j] = -Integer::ONE;
+            (row, Integer::ZERO)
+        }));
+    let mut equality_rows = vec![];
+    let mut equality_rhs = vec![];
+    for (row, b) in inequalities {
+        let negated = row.iter().map(|a| -Rational::from(a)).collect::<Vec<_>>();
+        if let LinearProgramSolution::Optimal { value, .. } =
+            linear_program_solve(&negated, &rational_constraints, &rational_rhs)
+            && value == -Rational::from(&b)
+        {
+            equality_rows.push(row);
+            equality_rhs.push(b);
+        }
+    }
+    equality_rows.is_empty()
+        || Matrix::construct(equality_rows.len(), n, |r, c| equality_rows[r][c].clone())
+            .col_solve(&equality_rhs)
+            .is_some()
+}
+
+/// Maximize `objective . x` subject to `constraints * x <= rhs` and `x >= 0` over the integers, by branch and bound on the rational linear programming relaxation.
+///
+/// Let `n` be the number of variables and `a` bound the absolute values of the entries of `constraints` and `rhs`.
+/// Every integer point of the polyhedron is an integer point with coordinates at most `(n + 1) (n a)^n` plus a non-negative integer combination of extreme rays,
+/// by Cramer's rule and Hadamard's inequality applied to the vertices and rays.
+/// If the objective is bounded on the integer points then it is non-positive on the rays, so some optimum lies in that box.
+/// Branch and bound is run inside the box so that it terminates, and the integer program is unbounded exactly when it is feasible and its relaxation is unbounded.
+///
+/// Before branching, the equalities which hold on the whole relaxation are checked to have an integer solution.
+pub fn integer_program_solve(
+    objective: &[Integer],
+    constraints: &Matrix<Integer>,
+    rhs: &[Integer],
+) -> IntegerProgramSolution {
+    let m = constraints.rows();
+    let n = constraints.cols();
+    assert_eq!(objective.len(), n);
+    assert_eq!(rhs.len(), m);
+    let rational_objective = objective.iter().map(Rational::from).collect::<Vec<_>>();
+    let rational_constraints = constraints.apply_map(|x| Rational::from(x));
+    let rational_rhs = rhs.iter().map(Rational::from).collect::<Vec<_>>();
+
+    let relaxation =
+        linear_program_solve(&rational_objective, &rational_constraints, &rational_rhs);
+    if relaxation == LinearProgramSolution::Infeasible
+        || !implicit_equalities_have_integer_solution(constraints, rhs)
+    {
+        return IntegerProgramSolution::Infeasible;
+    }
+    let unbounded = relaxation == LinearProgramSolution::Unbounded;
+
+    let a = constraints
+        .entries_list()
+        .into_iter()
+        .chain(rhs)
+        .map(Abs::abs)
+        .fold(Natural::ONE, std::cmp::max);
+    let bound =
+        Integer::from(Natural::from(n + 1) * (Natural::from(n) * a).nat_pow(&Natural::from(n)));
+
+    // when the relaxation is unbounded it only remains to find any integer point
+    let objective = if unbounded {
+        vec![Integer::ZERO; n]
+    } else {
+        objective.to_vec()
+    };
+    let rational_objective = objective.iter().map(Rational::from).collect::<Vec<_>>();
+    let mut best: Option<(Vec<Integer>, Integer)> = None;
+    // subproblems are given by lower and upper bounds on each variable
+    let mut stack = vec![(vec![Integer::ZERO; n], vec![bound; n])];
+    while let Some((lower, upper)) = stack.pop() {
+        if lower.iter().zip(&upper).any(|(l, u)| l > u) {
+            continue;
+        }
+        let mut rows = constraints
+            .rows_iter()
+            .map(|row| row.map(Rational::from).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let mut all_rhs = rational_rhs.clone();
+        for j in 0..n {
+            let mut unit = vec![Rational::ZERO; n];
+            unit[j] = Rational::ONE;
+            rows.push(unit.iter().map(|x| -x).collect());
+            all_rhs.push(-Rational::from(&lower[j]));
+            rows.push(unit);
+            all_rhs.push(Rational::from(&upper[j]));
+        }
+        let sub_constraints = Matrix::construct(rows.len(), n, |r, c| rows[r][c].clone());
+        match linear_program_solve(&rational_objective, &sub_constraints, &all_rhs) {
+            LinearProgramSolution::Infeasible => {}
+            LinearProgramSolution::Unbounded => {
+                unreachable!("the subproblems are bounded");
+            }
+            LinearProgramSolution::Optimal { point, value } => {
+                // the objective takes integer values at integer points
+                if let Some((_, best_value)) = &best
+                    && Floor::floor(&value) <= *best_value
+                {
+                    continue;
+                }
+                match point.iter().position(|x| x.denominator() != Natural::ONE) {
+                    None => {
+                        let point = point.iter().map(Floor::floor).collect::<Vec<_>>();
+                        best = Some((point, Floor::floor(&value)));
+                    }
+                    Some(j) => {
+                        // branch on x_j <= floor(v) and x_j >= ceil(v)
+                        let mut down_upper = upper.clone();
+                        down_upper[j] = Floor::floor(&point[j]);
+                        let mut up_lower = lower.clone();
+                        up_lower[j] = Ceil::ceil(&point[j]);
+                        stack.push((up_lower, upper));
+                        stack.push((lower, down_upper));
+                    }
+                }
+            }
+        }
+    }
+    match best {
+        None => IntegerProgramSolution::Infeasible,
+        Some(_) if unbounded => IntegerProgramSolution::Unbounded,
+        Some((point, value)) => IntegerProgramSolution::Optimal { point, value },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ints(v: Vec<i64>) -> Vec<Integer> {
+        v.into_iter().map(Integer::from).collect()
+    }
+
+    fn integer_matrix(rows: Vec<Vec<i64>>) -> Matrix<Integer> {
+        Matrix::from_rows(rows)
+    }
+
+    fn rational_matrix(rows: Vec<Vec<i64>>) -> Matrix<Rational> {
+        Matrix::from_rows(rows)
+    }
+
+    fn is_feasible(constraints: &Matrix<Integer>, rhs: &[Integer], x: &[Integer]) -> bool {
+        x.iter().all(|x| x >= &Integer::ZERO)
+            && (0..constraints.rows()).all(|r| {
+                constraints
+                    .row_iter(r)
+                    .zip(x)
+                    .map(|(a, x)| a * x)
+                    .fold(Integer::ZERO, |a, b| a + b)
+                    <= rhs[r]
+            })
+    }
+
+    fn value(objective: &[Integer], x: &[Integer]) -> Integer {
+        objective
+            .iter()
+            .zip(x)
+            .map(|(c, x)| c * x)
+            .fold(Integer::ZERO, |a, b| a + b)
+    }
+
+    fn optimal_point(solution: IntegerProgramSolution) -> Vec<Integer> {
+        match solution {
+            IntegerProgramSolution::Optimal { point, .. } => point,
+            _ => panic!("expected an optimal solution"),
+        }
+    }
+
+    #[test]
+    fn linear_programs() {
+        // max x + y subject to x + 2y <= 4, 3x + y <= 6 has optimum at (8/5, 6/5)
+        let constraints = rational_matrix(vec![vec![1, 2], vec![3, 1]]);
+        assert_eq!(
+            linear_program_solve(
+                &[Rational::ONE, Rational::ONE],
+                &constraints,
+                &[Rational::from(4), Rational::from(6)]
+            ),
+            LinearProgramSolution::Optimal {
+                point: vec![Rational::from_integers(8, 5), Rational::from_integers(6, 5)],
+                value: Rational::from_integers(14, 5),
+            }
+        );
+
+        // max x subject to x >= 1 is unbounded
+        let constraints = rational_matrix(vec![vec![-1]]);
+        assert_eq!(
+            linear_program_solve(&[Rational::ONE], &constraints, &[Rational::from(-1)]),
+            LinearProgramSolution::Unbounded
+        );
+        // x >= 2 is incompatible with x <= 1
+        let constraints = rational_matrix(vec![vec![-1], vec![1]]);
+        assert_eq!(
+            linear_program_solve(
+                &[Rational::ONE],
+                &constraints,
+                &[Rational::from(-2), Rational::from(1)]
+            ),
+            LinearProgramSolution::Infeasible
+        );
+    }
+
+    #[test]
+    fn integer_program_on_triangle() {
+        // max x1 + x2 subject to x1 + x2 <= 3
+        let constraints = integer_matrix(vec![vec![1, 1]]);
+        let rhs = ints(vec![3]);
+        let objective = ints(vec![1, 1]);
+        let x = optimal_point(integer_program_solve(&objective, &constraints, &rhs));
+        assert!(is_feasible(&constraints, &rhs, &x));
+        assert_eq!(value(&objective, &x), Integer::from(3));
+    }
+
+    #[test]
+    fn integer_program_with_fractional_relaxation() {
+        // max x + y subject to x + 2y <= 4, 3x + y <= 6 has fractional relaxation optimum (8/5, 6/5)
+        let constraints = integer_matrix(vec![vec![1, 2], vec![3, 1]]);
+        let rhs = ints(vec![4, 6]);
+        let objective = ints(vec![1, 1]);
+        let x = optimal_point(integer_program_solve(&objective, &constraints, &rhs));
+        assert!(is_feasible(&constraints, &rhs, &x));
+        assert_eq!(value(&objective, &x), Integer::from(2));
+
+        // a knapsack problem: max 5a + 4b + 3c subject to 2a + 3b + c <= 5, 4a + b + 2c <= 11, 3a + 4b + 2c <= 8
+        let constraints = integer_matrix(vec![vec![2, 3, 1], vec![4, 1, 2], vec![3, 4, 2]]);
+        let rhs = ints(vec![5, 11, 8]);
+        let objective = ints(vec![5, 4, 3]);
+        let x = optimal_point(integer_program_solve(&objective, &constraints, &rhs));
+        assert!(is_feasible(&constraints, &rhs, &x));
+        // compare with brute force over the bounded region
+        let mut best = None;
+        for a in 0..=5 {
+            for b in 0..=5 {
+                for c in 0..=5 {
+                    let y = ints(vec![a, b, c]);
+                    if is_feasible(&constraints, &rhs, &y) {
+                        let v = value(&objective, &y);
+                        if best.as_ref().is_none_or(|best| &v > best) {
+                            best = Some(v);
+                        }
+                    }
+                }
+            }
+        }
+        assert_eq!(Some(value(&objective, &x)), best);
+    }
+
+    #[test]
+    fn infeasible_integer_program() {
+        // 2x = 1 has rational but no integer solutions
+        let constraints = integer_matrix(vec![vec![2], vec![-2]]);
+        let rhs = ints(vec![1, -1]);
+        assert_eq!(
+            integer_program_solve(&ints(vec![1]), &constraints, &rhs),
+            IntegerProgramSolution::Infeasible
+        );
+
+        // x >= 2 and x <= 1
+        let constraints = integer_matrix(vec![vec![-1], vec![1]]);
+        let rhs = ints(vec![-2, 1]);
+        assert_eq!(
+            integer_program_solve(&ints(vec![1]), &constraints, &rhs),
+            IntegerProgramSolution::Infeasible
+        );
+
+        // 2x - 2y = 1 has rational but no integer solutions, and branch and bound alone would move along the line forever
+        let constraints = integer_matrix(vec![vec![2, -2], vec![-2, 2]]);
+        let rhs = ints(vec![1, -1]);
+        assert_eq!(
+            integer_program_solve(&ints(vec![0, 0]), &constraints, &rhs),
+            IntegerProgramSolution::Infeasible
+        );
+
+        // the strip 1 <= 10x <= 2 has an unbounded relaxation but no integer points
+        let constraints = integer_matrix(vec![vec![-10, 0], vec![10, 0]]);
+        let rhs = ints(vec![-1, 2]);
+        assert_eq!(
+            integer_program_solve(&ints(vec![0, 1]), &constraints, &rhs),
+            IntegerProgramSolution::Infeasible
+        );
+
+        // 2x + 3y = 1 has integer solutions but none with x, y >= 0
+        let constraints = integer_matrix(vec![vec![2, 3], vec![-2, -3]]);
+        let rhs = ints(vec![1, -1]);
+        assert_eq!(
+            integer_program_solve(&ints(vec![1, 0]), &constraints, &rhs),
+            IntegerProgramSolution::Infeasible
+        );
+    }
+
+    #[test]
+    fn unbounded_integer_program() {
+        // max x subject to x >= 1
+        let constraints = integer_matrix(vec![vec![-1]]);
+        assert_eq!(
+            integer_program_solve(&ints(vec![1]), &constraints, &ints(vec![-1])),
+            IntegerProgramSolution::Unbounded
+        );
+
+        // max x + y on the line x - y = 1
+        let constraints = integer_matrix(vec![vec![1, -1], vec![-1, 1]]);
+        assert_eq!(
+            integer_program_solve(&ints(vec![1, 1]), &constraints, &ints(vec![1, -1])),
+            IntegerProgramSolution::Unbounded
+        );
+
+        // max -x on the line 2x - 2y = 2 is bounded even though the line is not
+        let constraints = integer_matrix(vec![vec![2, -2], vec![-2, 2]]);
+        assert_eq!(
+            integer_program_solve(&ints(vec![-1, 0]), &constraints, &ints(vec![2, -2])),
+            IntegerProgramSolution::Optimal {
+                point: ints(vec![1, 0]),
+                value: Integer::from(-1),
+            }
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use crate::convex_hull::ConvexHull;
+use algebraeon_structures::*;
+
+/// All points with integer coordinates in `hull`, including those on its boundary.
+///
+/// Each coordinate is bounded using the extent of the defining points of `hull` and every integer point in the resulting box is tested with `contains_point`,
+/// so this is only practical in small dimensions.
+pub fn lattice_points<'a, 'f>(
+    hull: &'a ConvexHull<'f, RationalCanonicalStructure>,
+) -> impl Iterator<Item = Vec<Integer>> + 'a {
+    let space = hull.ambient_space();
+    let n = space.linear_dimension().unwrap();
+    let points = hull.defining_points();
+    // the range of integer values taken by each coordinate in the bounding box
+    let bounds = (0..n)
+        .map(|i| {
+            let min = points
+                .iter()
+                .map(|pt| pt.coordinate(i))
+                .min()
+                .map(Floor::floor);
+            let max = points
+                .iter()
+                .map(|pt| pt.coordinate(i))
+                .max()
+                .map(Ceil::ceil);
+            (min, max)
+        })
+        .collect::<Vec<_>>();
+    let mut next = if points.is_empty() {
+        None
+    } else {
+        Some(
+            bounds
+                .iter()
+                .map(|(min, _)| min.clone().unwrap())
+                .collect::<Vec<_>>(),
+        )
+    };
+    std::iter::from_fn(move || {
+        // step through the box in lexicographic order
+        let current = next.take()?;
+        let mut successor = current.clone();
+        for i in (0..n).rev() {
+            let (min, max) = &bounds[i];
+            if &successor[i] < max.as_ref().unwrap() {
+                successor[i] += Integer::ONE;
+                next = Some(successor);
+                break;
+            }
+            successor[i] = min.clone().unwrap();
+        }
+        Some(current)
+    })
+    .filter(move |pt| {
+        hull.contains_point(&space.vector(pt.iter().map(Rational::from).collect::<Vec<_>>()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ambient_space::AffineSpace;
+    use algebraeon_rings::matrix::Matrix;
+
+    #[test]
+    fn lattice_points_in_square() {
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 2);
+        for n in 0..5 {
+            let square = space.convex_hull(vec![
+                space.vector([0, 0]),
+                space.vector([n, 0]),
+                space.vector([0, n]),
+                space.vector([n, n]),
+            ]);
+            assert_eq!(
+                lattice_points(&square).count(),
+                ((n + 1) * (n + 1)) as usize
+            );
+        }
+
+        // a triangle with no lattice points in its interior
+        let triangle = space.convex_hull(vec![
+            space.vector([Rational::from(1) / Rational::from(2), Rational::from(0)]),
+            space.vector([Rational::from(3) / Rational::from(2), Rational::from(0)]),
+            space.vector([Rational::from(1), Rational::from(1) / Rational::from(2)]),
+        ]);
+        assert_eq!(
+            lattice_points(&triangle).collect::<Vec<_>>(),
+            vec![vec![Integer::from(1), Integer::from(0)]]
+        );
+
+        assert_eq!(lattice_points(&space.convex_hull(vec![])).count(), 0);
+    }
+
+    #[test]
+    fn ehrhart_polynomial_of_standard_simplex() {
+        // the t-th dilate of the standard d-simplex contains binomial(t + d, d) lattice points
+        for d in 1..4 {
+            let space = AffineSpace::new_linear(Rational::structure_ref(), d);
+            let dilate = |t: i32| {
+                let mut points = vec![space.origin().unwrap()];
+                for i in 0..d {
+                    points.push(
+                        space.vector(
+                            (0..d)
+                                .map(|j| Rational::from(if i == j { t } else { 0 }))
+                                .collect::<Vec<_>>(),
+                        ),
+                    );
+                }
+                space.convex_hull(points)
+            };
+            for t in 0..4 {
+                let expected = (1..=d as i32).fold(1, |acc, k| acc * (t + k) / k);
+                assert_eq!(lattice_points(&dilate(t)).count(), expected as usize);
+            }
+        }
+
+        // interpolate the Ehrhart polynomial of the standard triangle, (t+1)(t+2)/2 = 1 + 3t/2 + t^2/2
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 2);
+        let counts = (0..3)
+            .map(|t| {
+                Rational::from(
+                    lattice_points(&space.convex_hull(vec![
+                        space.vector([0, 0]),
+                        space.vector([t, 0]),
+                        space.vector([0, t]),
+                    ]))
+                    .count(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let vandermonde = Matrix::construct(3, 3, |t, k| Rational::from(t.pow(k as u32)));
+        assert_eq!(
+            vandermonde.col_solve(&counts).unwrap(),
+            vec![
+                Rational::ONE,
+                Rational::from(3) / Rational::from(2),
+                Rational::from(1) / Rational::from(2)
+            ]
+        );
+    }
+}
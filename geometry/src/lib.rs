@@ -7,14 +7,21 @@ use std::hash::Hash;
 pub mod affine_subspace;
 pub mod ambient_space;
 pub mod boolean_operations;
+pub mod caratheodory;
 pub mod convex_hull;
+pub mod half_space_representation;
+pub mod integer_program;
+pub mod lattice_points;
 pub mod minkowski_sum;
 pub mod oriented_simplex;
 pub mod parse;
 pub mod partial_simplicial_complex;
+pub mod projective_space;
 pub mod simplex;
 pub mod simplex_collection;
 pub mod simplex_overlap;
 pub mod simplicial_complex;
 pub mod simplicial_disjoint_union;
+pub mod simplicial_map;
 pub mod vector;
+pub mod winding_number;
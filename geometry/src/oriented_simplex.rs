@@ -7,6 +7,7 @@ use algebraeon_rings::{
     matrix::{Matrix, MatrixStructure},
     structure::{FieldSignature, OrderedRingSignature},
 };
+use algebraeon_structures::Natural;
 
 #[derive(Clone)]
 pub struct OrientedSimplex<'f, FS: OrderedRingSignature + FieldSignature> {
@@ -145,7 +146,7 @@ impl<'f, FS: OrderedRingSignature + FieldSignature> OrientedSimplex<'f, FS> {
         }
     }
 
-    fn classify_point_quantitatively(&self, point: &Vector<'f, FS>) -> FS::Elem {
+    pub(crate) fn classify_point_quantitatively(&self, point: &Vector<'f, FS>) -> FS::Elem {
         match &self.orientation {
             Some(OrientedSimplexOrientation {
                 flip,
@@ -162,6 +163,31 @@ impl<'f, FS: OrderedRingSignature + FieldSignature> OrientedSimplex<'f, FS> {
         }
     }
 
+    /// The signed volume of the simplex joining this oriented simplex to the origin, positive when the origin is on the positive side.
+    ///
+    /// Summing this over the facets of a polytope, oriented with their positive sides inwards, gives the volume of the polytope.
+    pub fn signed_volume(&self) -> FS::Elem {
+        let space = self.ambient_space();
+        let field = space.field();
+        let origin = space.origin().unwrap();
+        let det = space.determinant(self.simplex.points().iter().collect());
+        let volume = field
+            .try_divide(
+                &det,
+                &field.from_nat(Natural::from(self.simplex.n()).factorial()),
+            )
+            .unwrap();
+        let volume = match field.cmp(&volume, &field.zero()) {
+            std::cmp::Ordering::Less => field.neg(&volume),
+            _ => volume,
+        };
+        match self.classify_point(&origin) {
+            OrientationSide::Positive => volume,
+            OrientationSide::Neutral => field.zero(),
+            OrientationSide::Negative => field.neg(&volume),
+        }
+    }
+
     pub fn classify_point(&self, point: &Vector<'f, FS>) -> OrientationSide {
         let space = self.ambient_space();
         let field = space.field();
@@ -236,6 +262,10 @@ impl<'f, FS: OrderedRingSignature + FieldSignature> OrientedHyperplane<'f, FS> {
         self.oriented_simplex.classify_point(point)
     }
 
+    pub fn into_oriented_simplex(self) -> OrientedSimplex<'f, FS> {
+        self.oriented_simplex
+    }
+
     pub fn intersect_line(
         &self,
         a: &Vector<'f, FS>,
@@ -337,4 +367,26 @@ mod tests {
             s_neg.orientation.unwrap().flip
         );
     }
+
+    #[test]
+    fn oriented_simplex_signed_volume() {
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 2);
+        let mut s = OrientedSimplex::new_with_positive_point(
+            space,
+            vec![space.vector([1, 0]), space.vector([0, 1])],
+            &space.vector([2, 3]),
+        )
+        .unwrap();
+        assert_eq!(s.signed_volume(), -Rational::ONE / Rational::TWO);
+        s.flip();
+        assert_eq!(s.signed_volume(), Rational::ONE / Rational::TWO);
+
+        let s = OrientedSimplex::new_with_positive_point(
+            space,
+            vec![space.vector([1, 1]), space.vector([2, 2])],
+            &space.vector([0, 3]),
+        )
+        .unwrap();
+        assert_eq!(s.signed_volume(), Rational::ZERO);
+    }
 }
@@ -0,0 +1,377 @@
+use crate::{ambient_space::AffineSpace, vector::Vector};
+
+use super::*;
+use algebraeon_rings::{
+    matrix::{Matrix, MatrixStructure},
+    structure::*,
+};
+
+/// The projective space `P^n` over a field, as the projective closure of an affine space of linear dimension `n`.
+///
+/// A point is a nonzero vector `(x_0, ..., x_n)` up to scaling. The affine space is the chart `x_n != 0`,
+/// where the affine point `(x_0, ..., x_{n-1})` corresponds to `(x_0, ..., x_{n-1}, 1)`, and the points with `x_n = 0` are at infinity.
+#[derive(Debug, Clone)]
+pub struct ProjectiveSpace<'f, FS: FieldSignature> {
+    affine_chart: AffineSpace<'f, FS>,
+}
+
+impl<'f, FS: FieldSignature> Copy for ProjectiveSpace<'f, FS> {}
+
+impl<'f, FS: FieldSignature> PartialEq for ProjectiveSpace<'f, FS> {
+    fn eq(&self, other: &Self) -> bool {
+        self.affine_chart == other.affine_chart
+    }
+}
+
+impl<'f, FS: FieldSignature> Eq for ProjectiveSpace<'f, FS> {}
+
+impl<'f, FS: FieldSignature + Hash> Hash for ProjectiveSpace<'f, FS> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.affine_chart.hash(state);
+    }
+}
+
+/// A point of a [`ProjectiveSpace`] in homogeneous coordinates, scaled so that the last nonzero coordinate is `1`.
+#[derive(Clone)]
+pub struct HomogeneousVector<'f, FS: FieldSignature> {
+    space: ProjectiveSpace<'f, FS>,
+    coordinates: Vec<FS::Elem>,
+}
+
+/// The hyperplane `a_0 x_0 + ... + a_n x_n = 0` of a [`ProjectiveSpace`], with coefficients scaled so that the last nonzero one is `1`.
+#[derive(Clone)]
+pub struct ProjectiveHyperplane<'f, FS: FieldSignature> {
+    space: ProjectiveSpace<'f, FS>,
+    coefficients: Vec<FS::Elem>,
+}
+
+impl<'f, FS: FieldSignature> std::fmt::Debug for HomogeneousVector<'f, FS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HomogeneousVector")
+            .field("coordinates", &self.coordinates)
+            .finish()
+    }
+}
+
+impl<'f, FS: FieldSignature> std::fmt::Debug for ProjectiveHyperplane<'f, FS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProjectiveHyperplane")
+            .field("coefficients", &self.coefficients)
+            .finish()
+    }
+}
+
+impl<'f, FS: FieldSignature> PartialEq for HomogeneousVector<'f, FS> {
+    fn eq(&self, other: &Self) -> bool {
+        self.space == other.space
+            && self
+                .coordinates
+                .iter()
+                .zip(&other.coordinates)
+                .all(|(a, b)| self.space.field().equal(a, b))
+    }
+}
+
+impl<'f, FS: FieldSignature> Eq for HomogeneousVector<'f, FS> {}
+
+impl<'f, FS: FieldSignature> PartialEq for ProjectiveHyperplane<'f, FS> {
+    fn eq(&self, other: &Self) -> bool {
+        self.space == other.space
+            && self
+                .coefficients
+                .iter()
+                .zip(&other.coefficients)
+                .all(|(a, b)| self.space.field().equal(a, b))
+    }
+}
+
+impl<'f, FS: FieldSignature> Eq for ProjectiveHyperplane<'f, FS> {}
+
+impl<'f, FS: FieldSignature> ProjectiveSpace<'f, FS> {
+    pub fn new(field: &'f FS, projective_dimension: usize) -> Self {
+        Self::projective_closure(AffineSpace::new_linear(field, projective_dimension))
+    }
+
+    /// The projective space containing `affine_space` as the chart `x_n != 0`.
+    ///
+    /// Panics if `affine_space` is empty.
+    pub fn projective_closure(affine_space: AffineSpace<'f, FS>) -> Self {
+        assert!(affine_space.linear_dimension().is_some());
+        Self {
+            affine_chart: affine_space,
+        }
+    }
+
+    pub fn field(&self) -> &'f FS {
+        self.affine_chart.field()
+    }
+
+    pub fn affine_chart(&self) -> AffineSpace<'f, FS> {
+        self.affine_chart
+    }
+
+    pub fn projective_dimension(&self) -> usize {
+        self.affine_chart.linear_dimension().unwrap()
+    }
+
+    // scale so that the last nonzero entry is 1, or return None if all entries are zero
+    fn normalize(&self, mut coordinates: Vec<FS::Elem>) -> Option<Vec<FS::Elem>> {
+        assert_eq!(coordinates.len(), self.projective_dimension() + 1);
+        let field = self.field();
+        let last = coordinates.iter().rev().find(|c| !field.is_zero(c))?;
+        let last_inv = field.try_reciprocal(last).unwrap();
+        for c in &mut coordinates {
+            field.mul_mut(c, &last_inv);
+        }
+        Some(coordinates)
+    }
+
+    /// The point with the given homogeneous coordinates, or `None` if they are all zero.
+    pub fn point(
+        self,
+        coordinates: impl IntoIterator<Item = impl Into<FS::Elem>>,
+    ) -> Option<HomogeneousVector<'f, FS>> {
+        let coordinates = self.normalize(coordinates.into_iter().map(|c| c.into()).collect())?;
+        Some(HomogeneousVector {
+            space: self,
+            coordinates,
+        })
+    }
+
+    /// The hyperplane with the given coefficients, or `None` if they are all zero.
+    pub fn hyperplane(
+        self,
+        coefficients: impl IntoIterator<Item = impl Into<FS::Elem>>,
+    ) -> Option<ProjectiveHyperplane<'f, FS>> {
+        let coefficients = self.normalize(coefficients.into_iter().map(|c| c.into()).collect())?;
+        Some(ProjectiveHyperplane {
+            space: self,
+            coefficients,
+        })
+    }
+
+    /// The hyperplane `x_n = 0` of points at infinity.
+    pub fn hyperplane_at_infinity(self) -> ProjectiveHyperplane<'f, FS> {
+        let n = self.projective_dimension();
+        self.hyperplane((0..=n).map(|i| {
+            if i == n {
+                self.field().one()
+            } else {
+                self.field().zero()
+            }
+        }))
+        .unwrap()
+    }
+
+    fn rows(&self, rows: Vec<&Vec<FS::Elem>>) -> Matrix<FS::Elem> {
+        Matrix::construct(rows.len(), self.projective_dimension() + 1, |r, c| {
+            rows[r][c].clone()
+        })
+    }
+
+    /// Whether all of `points` lie on a common projective line.
+    pub fn are_collinear_projective(&self, points: Vec<&HomogeneousVector<'f, FS>>) -> bool {
+        for point in &points {
+            assert_eq!(*self, point.space);
+        }
+        MatrixStructure::new(self.field().clone())
+            .rank(self.rows(points.iter().map(|p| &p.coordinates).collect()))
+            <= 2
+    }
+
+    /// The unique hyperplane containing `points`, or `None` if `points` do not span a hyperplane.
+    pub fn projective_hyperplane(
+        &self,
+        points: Vec<&HomogeneousVector<'f, FS>>,
+    ) -> Option<ProjectiveHyperplane<'f, FS>> {
+        for point in &points {
+            assert_eq!(*self, point.space);
+        }
+        let kernel = MatrixStructure::new(self.field().clone())
+            .col_kernel(self.rows(points.iter().map(|p| &p.coordinates).collect()))
+            .basis();
+        if kernel.len() != 1 {
+            return None;
+        }
+        self.hyperplane(kernel.into_iter().next().unwrap())
+    }
+
+    /// The unique point lying on all of `hyperplanes`, or `None` if their intersection is not a single point.
+    pub fn intersect_hyperplanes(
+        &self,
+        hyperplanes: Vec<&ProjectiveHyperplane<'f, FS>>,
+    ) -> Option<HomogeneousVector<'f, FS>> {
+        for hyperplane in &hyperplanes {
+            assert_eq!(*self, hyperplane.space);
+        }
+        let kernel = MatrixStructure::new(self.field().clone())
+            .col_kernel(self.rows(hyperplanes.iter().map(|h| &h.coefficients).collect()))
+            .basis();
+        if kernel.len() != 1 {
+            return None;
+        }
+        self.point(kernel.into_iter().next().unwrap())
+    }
+}
+
+impl<'f, FS: FieldSignature> HomogeneousVector<'f, FS> {
+    pub fn projective_space(&self) -> ProjectiveSpace<'f, FS> {
+        self.space
+    }
+
+    pub fn coordinates(&self) -> &Vec<FS::Elem> {
+        &self.coordinates
+    }
+
+    /// The point `(x_0, ..., x_{n-1}, 1)` of the projective closure of the ambient space of `pt`.
+    pub fn from_affine(pt: &Vector<'f, FS>) -> Self {
+        let space = ProjectiveSpace::projective_closure(pt.ambient_space());
+        let mut coordinates = pt.clone().into_coordinates();
+        coordinates.push(space.field().one());
+        Self { space, coordinates }
+    }
+
+    /// The corresponding point of the affine chart, or `None` for a point at infinity.
+    pub fn to_affine(&self) -> Option<Vector<'f, FS>> {
+        let n = self.space.projective_dimension();
+        if self.is_at_infinity() {
+            return None;
+        }
+        // the last coordinate is 1 after normalizing
+        Some(
+            self.space
+                .affine_chart()
+                .vector(self.coordinates[..n].iter().cloned()),
+        )
+    }
+
+    pub fn is_at_infinity(&self) -> bool {
+        let n = self.space.projective_dimension();
+        self.space.field().is_zero(&self.coordinates[n])
+    }
+}
+
+impl<'f, FS: FieldSignature> ProjectiveHyperplane<'f, FS> {
+    pub fn projective_space(&self) -> ProjectiveSpace<'f, FS> {
+        self.space
+    }
+
+    pub fn coefficients(&self) -> &Vec<FS::Elem> {
+        &self.coefficients
+    }
+
+    pub fn contains(&self, point: &HomogeneousVector<'f, FS>) -> bool {
+        assert_eq!(self.space, point.space);
+        let field = self.space.field();
+        let mut total = field.zero();
+        for (a, x) in self.coefficients.iter().zip(&point.coordinates) {
+            field.add_mut(&mut total, &field.mul(a, x));
+        }
+        field.is_zero(&total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algebraeon_structures::*;
+
+    #[test]
+    fn affine_chart_round_trip() {
+        let plane = AffineSpace::new_linear(Rational::structure_ref(), 2);
+        let proj = ProjectiveSpace::projective_closure(plane);
+        assert_eq!(proj.projective_dimension(), 2);
+        let pt = plane.vector([Rational::from_integers(1, 2), Rational::from(-3)]);
+        let hpt = HomogeneousVector::from_affine(&pt);
+        assert_eq!(hpt.projective_space(), proj);
+        assert!(!hpt.is_at_infinity());
+        assert_eq!(hpt.to_affine().unwrap(), pt);
+        // scaling does not change the point
+        assert_eq!(proj.point([-2, 12, -4]).unwrap(), hpt);
+        assert!(proj.point([0, 0, 0]).is_none());
+
+        let infinite = proj.point([3, 6, 0]).unwrap();
+        assert!(infinite.is_at_infinity());
+        assert!(infinite.to_affine().is_none());
+        assert!(proj.hyperplane_at_infinity().contains(&infinite));
+        assert!(!proj.hyperplane_at_infinity().contains(&hpt));
+    }
+
+    #[test]
+    fn projective_closure_of_line_has_no_boundary() {
+        // the affine line through p in direction d is closed up by the single point at infinity [d : 0]
+        let plane = AffineSpace::new_linear(Rational::structure_ref(), 2);
+        let proj = ProjectiveSpace::projective_closure(plane);
+        let p = plane.vector([1, 2]);
+        let d = plane.vector([3, -1]);
+        let line = proj
+            .projective_hyperplane(vec![
+                &HomogeneousVector::from_affine(&p),
+                &HomogeneousVector::from_affine(&(&p + &d)),
+            ])
+            .unwrap();
+        // x + 3y - 7 = 0
+        assert_eq!(line, proj.hyperplane([1, 3, -7]).unwrap());
+        for t in -5..5 {
+            let q = &p + &d.scalar_mul(&Rational::from(t));
+            assert!(line.contains(&HomogeneousVector::from_affine(&q)));
+        }
+
+        let at_infinity = proj
+            .intersect_hyperplanes(vec![&line, &proj.hyperplane_at_infinity()])
+            .unwrap();
+        assert_eq!(at_infinity, proj.point([3, -1, 0]).unwrap());
+        // both ends of the affine line approach the same point, so the closure is a circle rather than a segment with two endpoints
+        assert_eq!(at_infinity, proj.point([-3, 1, 0]).unwrap());
+    }
+
+    #[test]
+    fn two_lines_in_the_projective_plane_intersect() {
+        let proj = ProjectiveSpace::new(Rational::structure_ref(), 2);
+        let lines = [
+            [1, 0, 0],
+            [0, 1, 0],
+            [0, 0, 1],
+            [1, 1, -2],
+            [2, 2, 5],
+            [1, -1, 0],
+            [3, 0, 7],
+        ]
+        .into_iter()
+        .map(|a| proj.hyperplane(a).unwrap())
+        .collect::<Vec<_>>();
+        for l1 in &lines {
+            for l2 in &lines {
+                let meet = proj.intersect_hyperplanes(vec![l1, l2]);
+                if l1 == l2 {
+                    assert!(meet.is_none());
+                } else {
+                    let meet = meet.unwrap();
+                    assert!(l1.contains(&meet));
+                    assert!(l2.contains(&meet));
+                }
+            }
+        }
+        // the parallel lines x + y = 2 and 2x + 2y = -5 meet at infinity
+        let meet = proj
+            .intersect_hyperplanes(vec![&lines[3], &lines[4]])
+            .unwrap();
+        assert!(meet.is_at_infinity());
+        assert_eq!(meet, proj.point([1, -1, 0]).unwrap());
+    }
+
+    #[test]
+    fn collinearity() {
+        let proj = ProjectiveSpace::new(Rational::structure_ref(), 2);
+        let a = proj.point([0, 0, 1]).unwrap();
+        let b = proj.point([1, 1, 1]).unwrap();
+        let c = proj.point([1, 1, 0]).unwrap();
+        let d = proj.point([1, 0, 0]).unwrap();
+        assert!(proj.are_collinear_projective(vec![&a, &b, &c]));
+        assert!(!proj.are_collinear_projective(vec![&a, &b, &d]));
+        assert!(proj.are_collinear_projective(vec![&a, &d]));
+        assert!(proj.projective_hyperplane(vec![&a, &b, &c]).is_some());
+        assert!(proj.projective_hyperplane(vec![&a, &b, &d]).is_none());
+        assert!(proj.projective_hyperplane(vec![&a]).is_none());
+    }
+}
@@ -3,6 +3,7 @@ use crate::{
     affine_subspace::EmbeddedAffineSubspace, ambient_space::AffineSpace,
     oriented_simplex::OrientedSimplex, vector::Vector,
 };
+use algebraeon_structures::Natural;
 use itertools::Itertools;
 
 #[derive(Clone)]
@@ -195,11 +196,38 @@ where
         EmbeddedAffineSubspace::new_affine_independent_span(self.ambient_space, self.into_points())
             .unwrap()
     }
+
+    /// The volume of this simplex, the absolute value of the determinant of `[v1-v0, ..., vn-v0]` divided by `n!` where `v0, ..., vn` are its points.
+    ///
+    /// The points of a simplex are stored in sorted order, so they carry no orientation.
+    /// See [`OrientedSimplex::signed_volume`] for a signed version.
+    ///
+    /// # Panics
+    /// If this simplex does not span its ambient space.
+    pub fn volume(&self) -> FS::Elem {
+        let space = self.ambient_space;
+        assert_eq!(space.affine_dimension(), self.n());
+        let field = space.field();
+        let vecs = (1..self.n())
+            .map(|i| &self.points[i] - &self.points[0])
+            .collect::<Vec<_>>();
+        let volume = field
+            .try_divide(
+                &space.determinant(vecs.iter().collect()),
+                &field.from_nat(Natural::from(self.n() - 1).factorial()),
+            )
+            .unwrap();
+        match field.cmp(&volume, &field.zero()) {
+            std::cmp::Ordering::Less => field.neg(&volume),
+            _ => volume,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::simplex_collection::LabelledSimplexCollection;
     use algebraeon_structures::Rational;
 
     #[test]
@@ -235,4 +263,83 @@ mod tests {
         assert_eq!(s.faces().len(), 1);
         assert_eq!(s.skeleton(4).len(), 0);
     }
+
+    #[test]
+    fn simplex_volume() {
+        // the standard n-simplex has volume 1/n!
+        for n in 1..5 {
+            let space = AffineSpace::new_linear(Rational::structure_ref(), n);
+            let mut points = vec![space.origin().unwrap()];
+            for i in 0..n {
+                points.push(space.vector((0..n).map(|j| if i == j { 1 } else { 0 })));
+            }
+            let volume = space.simplex(points).unwrap().volume();
+            assert_eq!(
+                volume,
+                Rational::ONE / Rational::from(Natural::from(n).factorial())
+            );
+        }
+
+        // the volume does not depend on the order in which the points are given
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 2);
+        let s = space
+            .simplex(vec![
+                space.vector([0, 0]),
+                space.vector([1, 0]),
+                space.vector([0, 1]),
+            ])
+            .unwrap();
+        assert_eq!(s.volume(), Rational::ONE / Rational::TWO);
+        let t = space
+            .simplex(vec![
+                space.vector([0, 1]),
+                space.vector([1, 0]),
+                space.vector([0, 0]),
+            ])
+            .unwrap();
+        assert_eq!(t.volume(), Rational::ONE / Rational::TWO);
+        let t = space
+            .simplex(vec![
+                space.vector([0, 0]),
+                space.vector([-1, 0]),
+                space.vector([0, 1]),
+            ])
+            .unwrap();
+        assert_eq!(t.volume(), Rational::ONE / Rational::TWO);
+    }
+
+    #[test]
+    fn volume_of_triangulated_polytope() {
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 3);
+        let hull = space.convex_hull(vec![
+            space.vector([1, 1, 1]),
+            space.vector([3, 1, 1]),
+            space.vector([1, 4, 1]),
+            space.vector([3, 4, 1]),
+            space.vector([1, 1, 2]),
+            space.vector([3, 1, 2]),
+            space.vector([1, 4, 2]),
+            space.vector([3, 4, 2]),
+            space.vector([2, 2, 5]),
+        ]);
+        // a 2x3x1 box with a pyramid of height 3 on top
+        let volume = Rational::from(6) + Rational::from(6);
+
+        // the full dimensional simplexes of a triangulation
+        let total = hull
+            .to_simplicial_complex()
+            .simplexes()
+            .into_iter()
+            .filter(|spx| spx.n() == 4)
+            .fold(Rational::ZERO, |acc, spx| acc + spx.volume());
+        assert_eq!(total, volume);
+
+        // cones from the origin over the oriented boundary
+        let total = hull
+            .to_halfspace_representation()
+            .half_spaces()
+            .iter()
+            .fold(Rational::ZERO, |acc, facet| acc + facet.signed_volume());
+        assert_eq!(total, volume);
+    }
 }
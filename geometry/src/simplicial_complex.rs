@@ -11,6 +11,8 @@ use crate::{
     simplicial_disjoint_union::LabelledSimplicialDisjointUnion,
     vector::Vector,
 };
+use algebraeon_groups::abelian::FinitelyGeneratedAbelianGroup;
+use algebraeon_structures::{Integer, Rational, RationalCanonicalStructure};
 use std::collections::{HashMap, HashSet};
 
 #[derive(Clone)]
@@ -191,6 +193,117 @@ where
     }
 }
 
+impl<'f, FS: OrderedRingSignature + FieldSignature, T: Eq + Clone + Send + Sync>
+    LabelledSimplicialComplex<'f, FS, T>
+where
+    FS::Elem: Hash,
+{
+    /// The dimension of the largest simplex, or `None` for the empty complex.
+    pub fn dimension(&self) -> Option<usize> {
+        self.simplexes.keys().map(|spx| spx.n() - 1).max()
+    }
+
+    /// The integral homology groups `H_0, ..., H_n` where `n` is the dimension.
+    pub fn homology(&self) -> Vec<FinitelyGeneratedAbelianGroup> {
+        let Some(n) = self.dimension() else {
+            return vec![];
+        };
+        // the k-cycles are a direct summand of the k-chains, so C_k / im(d_(k+1)) is the direct sum of H_k and the free group im(d_k)
+        let cokernels = (0..=n)
+            .map(|k| self.boundary_matrix(k + 1).cokernel_abelian_group())
+            .collect::<Vec<_>>();
+        (0..=n)
+            .map(|k| {
+                let boundary_rank = if k == 0 {
+                    0
+                } else {
+                    self.simplexes_by_vertex_indices(k - 1).len() - cokernels[k - 1].free_rank()
+                };
+                FinitelyGeneratedAbelianGroup::new(
+                    cokernels[k].free_rank() - boundary_rank,
+                    cokernels[k].torsion().clone(),
+                )
+                .unwrap()
+            })
+            .collect()
+    }
+
+    /// The link of `vertex`, made of the simplexes which do not contain `vertex` but are faces of simplexes which do.
+    ///
+    /// Panics if `vertex` is not a vertex of the complex.
+    pub fn vertex_link(&self, vertex: &Vector<'f, FS>) -> SimplicialComplex<'f, FS> {
+        let vertex_spx = self.ambient_space.simplex(vec![vertex.clone()]).unwrap();
+        let info = self
+            .simplexes
+            .get(&vertex_spx)
+            .expect("not a vertex of the simplicial complex");
+        let mut link = HashSet::new();
+        for spx in &info.inv_bdry {
+            let i = (0..spx.n()).find(|i| spx.point(*i) == vertex).unwrap();
+            link.extend(spx.facet(i).sub_simplices_not_null());
+        }
+        SimplicialComplex::new_unchecked(self.ambient_space, link)
+    }
+
+    /// For each `k`-simplex the number of `(k+1)`-simplexes having it as a face, in increasing order.
+    pub fn degree_sequence(&self, k: usize) -> Vec<usize> {
+        let mut degrees = self
+            .simplexes
+            .iter()
+            .filter(|(spx, _)| spx.n() == k + 1)
+            .map(|(_, info)| {
+                info.inv_bdry
+                    .iter()
+                    .filter(|coface| coface.n() == k + 2)
+                    .count()
+            })
+            .collect::<Vec<_>>();
+        degrees.sort_unstable();
+        degrees
+    }
+
+    fn is_manifold_of_dimension(&self, n: usize) -> bool {
+        if n == 0 {
+            return self.simplexes.keys().all(|spx| spx.n() == 1);
+        }
+        self.simplexes
+            .keys()
+            .filter(|spx| spx.n() == 1)
+            .all(|v| self.vertex_link(v.point(0)).is_sphere_of_dimension(n - 1))
+    }
+
+    fn is_sphere_of_dimension(&self, n: usize) -> bool {
+        if n == 0 {
+            return self.simplexes.len() == 2 && self.simplexes.keys().all(|spx| spx.n() == 1);
+        }
+        if self.dimension() != Some(n) || !self.is_manifold_of_dimension(n) {
+            return false;
+        }
+        self.homology().into_iter().enumerate().all(|(k, group)| {
+            group == FinitelyGeneratedAbelianGroup::free(usize::from(k == 0 || k == n))
+        })
+    }
+
+    /// Whether the complex is a closed manifold, meaning that it is nonempty and the link of every vertex is a simplicial sphere of one dimension less.
+    pub fn is_simplicial_manifold(&self) -> bool {
+        match self.dimension() {
+            Some(n) => self.is_manifold_of_dimension(n),
+            None => false,
+        }
+    }
+
+    /// Whether the complex is a closed manifold with the integral homology of a sphere, checked recursively on vertex links.
+    ///
+    /// This decides whether the complex triangulates a sphere in dimensions up to 2.
+    /// In higher dimensions a manifold can have the homology of a sphere without being one, such as the Poincaré homology sphere in dimension 3.
+    pub fn is_simplicial_sphere(&self) -> bool {
+        match self.dimension() {
+            Some(n) => self.is_sphere_of_dimension(n),
+            None => false,
+        }
+    }
+}
+
 impl<'f, FS: OrderedRingSignature + FieldSignature> SimplicialComplex<'f, FS>
 where
     FS::Elem: Hash,
@@ -862,3 +975,665 @@ where
         self.check();
     }
 }
+
+// all chains (0, 0) = (i_0, j_0) < ... < (i_k, j_k) = (m, n) where each step increases i or j or both by one
+fn staircase_chains(m: usize, n: usize) -> Vec<Vec<(usize, usize)>> {
+    if m == 0 && n == 0 {
+        return vec![vec![(0, 0)]];
+    }
+    let mut chains = vec![];
+    for (di, dj) in [(1, 0), (0, 1), (1, 1)] {
+        if di <= m && dj <= n {
+            for mut chain in staircase_chains(m - di, n - dj) {
+                chain.push((m, n));
+                chains.push(chain);
+            }
+        }
+    }
+    chains
+}
+
+impl<'f, FS: OrderedRingSignature + FieldSignature, T: Eq + Clone + Send + Sync>
+    LabelledSimplicialComplex<'f, FS, T>
+where
+    FS::Elem: Hash,
+{
+    /// The product of `self` and `other` in the product of their ambient spaces.
+    ///
+    /// If `p_0 < ... < p_m` and `q_0 < ... < q_n` are the ordered points of simplexes of `self` and `other` then `p x q` is triangulated by the simplexes with points `(p_{i_0}, q_{j_0}), ..., (p_{i_k}, q_{j_k})` where `(i_0, j_0) = (0, 0)`, `(i_k, j_k) = (m, n)` and each step increases `i` or `j` or both by one.
+    /// The points of every simplex are ordered in the same way, so these triangulations agree on common faces.
+    /// Each simplex of the product is labelled by the labels of the simplexes of `self` and `other` which it projects onto.
+    pub fn product<S: Eq + Clone + Send + Sync>(
+        &self,
+        other: &LabelledSimplicialComplex<'f, FS, S>,
+    ) -> LabelledSimplicialComplex<'f, FS, (T, S)> {
+        let field = self.ambient_space().field();
+        debug_assert_eq!(field, other.ambient_space().field());
+        let (Some(dim_a), Some(dim_b)) = (
+            self.ambient_space().linear_dimension(),
+            other.ambient_space().linear_dimension(),
+        ) else {
+            return LabelledSimplicialComplex::new_labelled_unchecked(
+                AffineSpace::new_empty(field),
+                HashMap::new(),
+            );
+        };
+        let space = AffineSpace::new_linear(field, dim_a + dim_b);
+
+        let mut simplexes = HashMap::new();
+        for (spx_a, label_a) in self.labelled_simplexes() {
+            for (spx_b, label_b) in other.labelled_simplexes() {
+                for chain in staircase_chains(spx_a.n() - 1, spx_b.n() - 1) {
+                    let points = chain
+                        .into_iter()
+                        .map(|(i, j)| {
+                            Vector::construct(space, |k| {
+                                if k < dim_a {
+                                    spx_a.point(i).coordinate(k).clone()
+                                } else {
+                                    spx_b.point(j).coordinate(k - dim_a).clone()
+                                }
+                            })
+                        })
+                        .collect();
+                    simplexes.insert(
+                        space.simplex(points).unwrap(),
+                        (label_a.clone(), label_b.clone()),
+                    );
+                }
+            }
+        }
+        LabelledSimplicialComplex::new_labelled_unchecked(space, simplexes)
+    }
+}
+
+impl<'f, FS: OrderedRingSignature + FieldSignature, T: Eq + Clone + Send + Sync>
+    LabelledSimplicialComplex<'f, FS, T>
+where
+    FS::Elem: Hash,
+{
+    /// The join of `self` and `other`, containing the simplexes of both together with a simplex spanned by `p` and `q` for every pair of simplexes `p` of `self` and `q` of `other`.
+    ///
+    /// `self` is embedded as `(x, 0, 0)` and `other` as `(0, y, 1)` in a space of dimension one more than the sum of their dimensions, so these simplexes do not overlap.
+    /// Each simplex is labelled by the labels of the simplexes of `self` and `other` which it contains as a face, if any.
+    pub fn join<S: Eq + Clone + Send + Sync>(
+        &self,
+        other: &LabelledSimplicialComplex<'f, FS, S>,
+    ) -> LabelledSimplicialComplex<'f, FS, (Option<T>, Option<S>)> {
+        let field = self.ambient_space().field();
+        debug_assert_eq!(field, other.ambient_space().field());
+        // a complex in the empty space has no simplexes so its dimension doesn't matter
+        let dim_a = self.ambient_space().linear_dimension().unwrap_or(0);
+        let dim_b = other.ambient_space().linear_dimension().unwrap_or(0);
+        let space = AffineSpace::new_linear(field, dim_a + dim_b + 1);
+
+        let embed_a = |spx: &Simplex<'f, FS>| {
+            spx.points()
+                .iter()
+                .map(|pt| {
+                    Vector::construct(space, |k| {
+                        if k < dim_a {
+                            pt.coordinate(k).clone()
+                        } else {
+                            field.zero()
+                        }
+                    })
+                })
+                .collect::<Vec<_>>()
+        };
+        let embed_b = |spx: &Simplex<'f, FS>| {
+            spx.points()
+                .iter()
+                .map(|pt| {
+                    Vector::construct(space, |k| {
+                        if k < dim_a {
+                            field.zero()
+                        } else if k < dim_a + dim_b {
+                            pt.coordinate(k - dim_a).clone()
+                        } else {
+                            field.one()
+                        }
+                    })
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut simplexes = HashMap::new();
+        for (spx_a, label_a) in self.labelled_simplexes() {
+            simplexes.insert(
+                space.simplex(embed_a(spx_a)).unwrap(),
+                (Some(label_a.clone()), None),
+            );
+        }
+        for (spx_b, label_b) in other.labelled_simplexes() {
+            simplexes.insert(
+                space.simplex(embed_b(spx_b)).unwrap(),
+                (None, Some(label_b.clone())),
+            );
+        }
+        for (spx_a, label_a) in self.labelled_simplexes() {
+            for (spx_b, label_b) in other.labelled_simplexes() {
+                let mut points = embed_a(spx_a);
+                points.extend(embed_b(spx_b));
+                simplexes.insert(
+                    space.simplex(points).unwrap(),
+                    (Some(label_a.clone()), Some(label_b.clone())),
+                );
+            }
+        }
+        LabelledSimplicialComplex::new_labelled_unchecked(space, simplexes)
+    }
+
+    /// The suspension of `self`, which is its join with two points.
+    ///
+    /// The two new points are labelled `None` and every other simplex is labelled by the label of the simplex of `self` it contains.
+    pub fn suspension(&self) -> LabelledSimplicialComplex<'f, FS, Option<T>> {
+        let field = self.ambient_space().field();
+        let space = AffineSpace::new_linear(field, 1);
+        let poles = LabelledSimplicialComplex::<'f, FS, ()>::new_unchecked(
+            space,
+            [
+                space.simplex(vec![space.vector([field.neg(&field.one())])]),
+                space.simplex(vec![space.vector([field.one()])]),
+            ]
+            .into_iter()
+            .map(|spx| spx.unwrap())
+            .collect(),
+        );
+        self.join(&poles)
+            .into_apply_label_function(|(label, _)| label)
+    }
+}
+
+impl<'f> SimplicialComplex<'f, RationalCanonicalStructure> {
+    // the vertex 0 and the standard basis vectors e_1, ..., e_n of `space`
+    fn standard_simplex(
+        n: usize,
+        space: AffineSpace<'f, RationalCanonicalStructure>,
+    ) -> Simplex<'f, RationalCanonicalStructure> {
+        let dim = space.linear_dimension().unwrap();
+        assert!(
+            n <= dim,
+            "an {}-simplex does not fit in a space of dimension {}",
+            n,
+            dim
+        );
+        space
+            .simplex(
+                (0..=n)
+                    .map(|i| space.vector((0..dim).map(|j| if i == j + 1 { 1 } else { 0 })))
+                    .collect(),
+            )
+            .unwrap()
+    }
+
+    /// The triangulation of the `n`-sphere as the boundary of the standard `(n+1)`-simplex in `space`.
+    ///
+    /// Panics if `space` has dimension less than `n+1`.
+    pub fn sphere(n: usize, space: AffineSpace<'f, RationalCanonicalStructure>) -> Self {
+        Self::new_unchecked(
+            space,
+            Self::standard_simplex(n + 1, space)
+                .proper_sub_simplices_not_null()
+                .into_iter()
+                .collect(),
+        )
+    }
+
+    /// The triangulation of the `n`-disk as the standard `n`-simplex in `space`.
+    ///
+    /// Panics if `space` has dimension less than `n`.
+    pub fn disk(n: usize, space: AffineSpace<'f, RationalCanonicalStructure>) -> Self {
+        Self::new_unchecked(
+            space,
+            Self::standard_simplex(n, space)
+                .sub_simplices_not_null()
+                .into_iter()
+                .collect(),
+        )
+    }
+
+    /// Realize an abstract 2-dimensional simplicial complex, given by its triangles, with vertex `i` at the point `(t, t^2, ..., t^5)` for `t = i + 1` on the moment curve.
+    ///
+    /// Any 6 points on the moment curve in 5 dimensions are affinely independent, so triangles which are disjoint in the abstract complex are disjoint in the realization.
+    fn from_abstract_triangles(
+        triangles: &[[usize; 3]],
+        space: AffineSpace<'f, RationalCanonicalStructure>,
+    ) -> Self {
+        let dim = space.linear_dimension().unwrap();
+        assert!(
+            dim >= 5,
+            "a surface need not embed in a space of dimension {}",
+            dim
+        );
+        let vertex = |i: usize| {
+            let t = Integer::from(i + 1);
+            let mut power = Rational::ONE;
+            space.vector((0..dim).map(|k| {
+                if k < 5 {
+                    power *= Rational::from(&t);
+                    power.clone()
+                } else {
+                    Rational::ZERO
+                }
+            }))
+        };
+        let mut simplexes = HashSet::new();
+        for triangle in triangles {
+            let spx = space
+                .simplex(triangle.iter().map(|i| vertex(*i)).collect())
+                .unwrap();
+            simplexes.extend(spx.sub_simplices_not_null());
+        }
+        Self::new_unchecked(space, simplexes)
+    }
+
+    // the triangles of the 3 by 3 grid on the unit square, with the vertex (i, j) for i, j in Z/3 numbered 3i + j
+    // if `twist` the top edge is glued to the bottom edge in the opposite direction
+    fn grid_surface_triangles(twist: bool) -> Vec<[usize; 3]> {
+        let vertex = |i: usize, j: usize| {
+            if j == 3 && twist {
+                3 * ((3 - i) % 3)
+            } else {
+                3 * (i % 3) + j % 3
+            }
+        };
+        let mut triangles = vec![];
+        for i in 0..3 {
+            for j in 0..3 {
+                triangles.push([vertex(i, j), vertex(i + 1, j), vertex(i + 1, j + 1)]);
+                triangles.push([vertex(i, j), vertex(i, j + 1), vertex(i + 1, j + 1)]);
+            }
+        }
+        triangles
+    }
+
+    /// A triangulation of the torus with 9 vertices and 18 triangles, obtained from a 3 by 3 grid on a square with opposite edges glued.
+    ///
+    /// Panics if `space` has dimension less than 5.
+    pub fn torus_triangulation(space: AffineSpace<'f, RationalCanonicalStructure>) -> Self {
+        Self::from_abstract_triangles(&Self::grid_surface_triangles(false), space)
+    }
+
+    /// A triangulation of the Klein bottle with 9 vertices and 18 triangles, obtained from a 3 by 3 grid on a square with one pair of opposite edges glued with a twist.
+    ///
+    /// Panics if `space` has dimension less than 5.
+    pub fn klein_bottle_triangulation(space: AffineSpace<'f, RationalCanonicalStructure>) -> Self {
+        Self::from_abstract_triangles(&Self::grid_surface_triangles(true), space)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algebraeon_structures::Natural;
+
+    fn simplex_counts<T: Eq + Clone + Send + Sync>(
+        complex: &LabelledSimplicialComplex<'_, RationalCanonicalStructure, T>,
+    ) -> Vec<usize> {
+        let mut counts = vec![];
+        for spx in complex.simplexes() {
+            let k = spx.n() - 1;
+            if counts.len() <= k {
+                counts.resize(k + 1, 0);
+            }
+            counts[k] += 1;
+        }
+        counts
+    }
+
+    fn triangle(
+        space: AffineSpace<'_, RationalCanonicalStructure>,
+    ) -> Simplex<'_, RationalCanonicalStructure> {
+        space
+            .simplex(vec![
+                space.vector([0, 0]),
+                space.vector([1, 0]),
+                space.vector([0, 1]),
+            ])
+            .unwrap()
+    }
+
+    #[test]
+    fn product_with_point() {
+        let point_space = AffineSpace::new_linear(Rational::structure_ref(), 0);
+        let point = SimplicialComplex::new_unchecked(
+            point_space,
+            [point_space
+                .simplex(vec![point_space.vector(Vec::<Rational>::new())])
+                .unwrap()]
+            .into_iter()
+            .collect(),
+        );
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 2);
+        let tri = SimplicialComplex::new_unchecked(
+            space,
+            triangle(space)
+                .sub_simplices_not_null()
+                .into_iter()
+                .collect(),
+        );
+
+        let prod = point.product(&tri);
+        prod.check();
+        assert_eq!(prod.ambient_space().linear_dimension(), Some(2));
+        assert_eq!(simplex_counts(&prod), vec![3, 3, 1]);
+        let mut vertices = prod
+            .simplexes()
+            .into_iter()
+            .filter(|spx| spx.n() == 1)
+            .map(|spx| spx.point(0).clone().into_coordinates())
+            .collect::<Vec<_>>();
+        vertices.sort();
+        assert_eq!(
+            vertices,
+            vec![
+                vec![Rational::from(0), Rational::from(0)],
+                vec![Rational::from(0), Rational::from(1)],
+                vec![Rational::from(1), Rational::from(0)],
+            ]
+        );
+
+        let prod = tri.product(&point);
+        prod.check();
+        assert_eq!(simplex_counts(&prod), vec![3, 3, 1]);
+    }
+
+    #[test]
+    fn product_of_intervals() {
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 1);
+        let interval = LabelledSimplicialComplex::new_labelled_unchecked(
+            space,
+            [
+                (space.simplex(vec![space.vector([0])]).unwrap(), 'a'),
+                (space.simplex(vec![space.vector([1])]).unwrap(), 'b'),
+                (
+                    space
+                        .simplex(vec![space.vector([0]), space.vector([1])])
+                        .unwrap(),
+                    'e',
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        let square = interval.product(&interval);
+        square.check();
+        assert_eq!(simplex_counts(&square), vec![4, 5, 2]);
+        // the interior of the square is labelled by the edge in both factors
+        assert_eq!(
+            square
+                .labelled_simplexes()
+                .into_iter()
+                .filter(|(_, label)| **label == ('e', 'e'))
+                .map(|(spx, _)| spx.n())
+                .collect::<Vec<_>>()
+                .len(),
+            3
+        );
+        assert_eq!(
+            square.homology(),
+            vec![
+                FinitelyGeneratedAbelianGroup::free(1),
+                FinitelyGeneratedAbelianGroup::trivial(),
+                FinitelyGeneratedAbelianGroup::trivial()
+            ]
+        );
+    }
+
+    #[test]
+    fn product_of_circles() {
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 2);
+        let circle = SimplicialComplex::new_unchecked(
+            space,
+            triangle(space)
+                .proper_sub_simplices_not_null()
+                .into_iter()
+                .collect(),
+        );
+        assert_eq!(
+            circle.homology(),
+            vec![
+                FinitelyGeneratedAbelianGroup::free(1),
+                FinitelyGeneratedAbelianGroup::free(1)
+            ]
+        );
+
+        let torus = circle.product(&circle);
+        assert_eq!(torus.ambient_space().linear_dimension(), Some(4));
+        assert_eq!(simplex_counts(&torus), vec![9, 27, 18]);
+        assert_eq!(
+            torus.homology(),
+            vec![
+                FinitelyGeneratedAbelianGroup::free(1),
+                FinitelyGeneratedAbelianGroup::free(2),
+                FinitelyGeneratedAbelianGroup::free(1)
+            ]
+        );
+    }
+
+    fn point(
+        space: AffineSpace<'_, RationalCanonicalStructure>,
+    ) -> SimplicialComplex<'_, RationalCanonicalStructure> {
+        SimplicialComplex::new_unchecked(
+            space,
+            [space.simplex(vec![space.origin().unwrap()]).unwrap()]
+                .into_iter()
+                .collect(),
+        )
+    }
+
+    // the homology of the n-sphere, which for n = 0 is a pair of points
+    fn sphere_homology(n: usize) -> Vec<FinitelyGeneratedAbelianGroup> {
+        (0..=n)
+            .map(|k| FinitelyGeneratedAbelianGroup::free(usize::from(k == 0) + usize::from(k == n)))
+            .collect()
+    }
+
+    #[test]
+    fn suspension_of_spheres() {
+        for n in 1..4 {
+            let space = AffineSpace::new_linear(Rational::structure_ref(), n);
+            let sphere = SimplicialComplex::sphere(n - 1, space);
+            assert_eq!(sphere.homology(), sphere_homology(n - 1));
+
+            let suspension = sphere.suspension();
+            if n < 3 {
+                // checking that no simplexes overlap is slow in higher dimensions
+                suspension.check();
+            }
+            assert_eq!(suspension.homology(), sphere_homology(n));
+            assert_eq!(
+                suspension
+                    .labelled_simplexes()
+                    .into_values()
+                    .filter(|label| label.is_none())
+                    .count(),
+                2
+            );
+        }
+    }
+
+    #[test]
+    fn join_with_point() {
+        let point_space = AffineSpace::new_linear(Rational::structure_ref(), 0);
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 2);
+        let tri = SimplicialComplex::new_unchecked(
+            space,
+            triangle(space)
+                .sub_simplices_not_null()
+                .into_iter()
+                .collect(),
+        );
+        // the cone on a triangle is a tetrahedron
+        let cone = point(point_space).join(&tri);
+        cone.check();
+        assert_eq!(cone.ambient_space().linear_dimension(), Some(3));
+        assert_eq!(simplex_counts(&cone), vec![4, 6, 4, 1]);
+        assert_eq!(
+            cone.homology(),
+            vec![
+                FinitelyGeneratedAbelianGroup::free(1),
+                FinitelyGeneratedAbelianGroup::trivial(),
+                FinitelyGeneratedAbelianGroup::trivial(),
+                FinitelyGeneratedAbelianGroup::trivial()
+            ]
+        );
+        assert_eq!(
+            cone.labelled_simplexes()
+                .into_values()
+                .filter(|label| **label == (Some(()), Some(())))
+                .count(),
+            7
+        );
+    }
+
+    #[test]
+    fn join_of_zero_spheres() {
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 1);
+        let s0 = SimplicialComplex::sphere(0, space);
+        assert_eq!(simplex_counts(&s0), vec![2]);
+        let s1 = s0.join(&s0);
+        s1.check();
+        assert_eq!(simplex_counts(&s1), vec![4, 4]);
+        assert_eq!(
+            s1.homology(),
+            vec![
+                FinitelyGeneratedAbelianGroup::free(1),
+                FinitelyGeneratedAbelianGroup::free(1)
+            ]
+        );
+    }
+
+    #[test]
+    fn spheres_and_disks() {
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 4);
+        for n in 0..4 {
+            let sphere = SimplicialComplex::sphere(n, space);
+            assert_eq!(sphere.homology(), sphere_homology(n));
+
+            let disk = SimplicialComplex::disk(n, space);
+            assert_eq!(
+                disk.homology(),
+                (0..=n)
+                    .map(|k| FinitelyGeneratedAbelianGroup::free(usize::from(k == 0)))
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(sphere.simplexes().len(), (1 << (n + 2)) - 2);
+            assert_eq!(disk.simplexes().len(), (1 << (n + 1)) - 1);
+        }
+    }
+
+    #[test]
+    fn torus_and_klein_bottle() {
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 5);
+        let torus = SimplicialComplex::torus_triangulation(space);
+        assert_eq!(simplex_counts(&torus), vec![9, 27, 18]);
+        assert_eq!(
+            torus.homology(),
+            vec![
+                FinitelyGeneratedAbelianGroup::free(1),
+                FinitelyGeneratedAbelianGroup::free(2),
+                FinitelyGeneratedAbelianGroup::free(1)
+            ]
+        );
+
+        let klein_bottle = SimplicialComplex::klein_bottle_triangulation(space);
+        assert_eq!(simplex_counts(&klein_bottle), vec![9, 27, 18]);
+        assert_eq!(
+            klein_bottle.homology(),
+            vec![
+                FinitelyGeneratedAbelianGroup::free(1),
+                FinitelyGeneratedAbelianGroup::new(1, vec![Natural::TWO]).unwrap(),
+                FinitelyGeneratedAbelianGroup::trivial()
+            ]
+        );
+    }
+
+    #[test]
+    fn spheres_and_manifolds() {
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 4);
+        for n in 0..4 {
+            let sphere = SimplicialComplex::sphere(n, space);
+            assert!(sphere.is_simplicial_manifold());
+            assert!(sphere.is_simplicial_sphere());
+            // the boundary of the (n+1)-simplex has every vertex link the boundary of an n-simplex
+            let vertex = sphere
+                .simplexes()
+                .into_iter()
+                .find(|spx| spx.n() == 1)
+                .unwrap();
+            let link = sphere.vertex_link(vertex.point(0));
+            assert_eq!(link.dimension(), n.checked_sub(1));
+            assert_eq!(link.simplexes().len(), (1 << (n + 1)) - 2);
+
+            let disk = SimplicialComplex::disk(n, space);
+            assert_eq!(disk.is_simplicial_manifold(), n == 0);
+            assert!(!disk.is_simplicial_sphere());
+        }
+
+        // the boundary of a tetrahedron
+        let tetrahedron = SimplicialComplex::sphere(2, space);
+        assert_eq!(tetrahedron.degree_sequence(0), vec![3; 4]);
+        assert_eq!(tetrahedron.degree_sequence(1), vec![2; 6]);
+        assert_eq!(tetrahedron.degree_sequence(2), vec![0; 4]);
+
+        // a triangulated square with an interior vertex is a disk
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 2);
+        let corners = [[0, 0], [2, 0], [2, 2], [0, 2]];
+        let mut simplexes = HashSet::new();
+        for i in 0..4 {
+            let spx = space
+                .simplex(vec![
+                    space.vector([1, 1]),
+                    space.vector(corners[i]),
+                    space.vector(corners[(i + 1) % 4]),
+                ])
+                .unwrap();
+            simplexes.extend(spx.sub_simplices_not_null());
+        }
+        let square = SimplicialComplex::new_unchecked(space, simplexes);
+        assert!(!square.is_simplicial_manifold());
+        assert!(!square.is_simplicial_sphere());
+        assert_eq!(square.degree_sequence(1), vec![1, 1, 1, 1, 2, 2, 2, 2]);
+        assert!(
+            square
+                .vertex_link(&space.vector([1, 1]))
+                .is_simplicial_sphere()
+        );
+
+        // closed surfaces other than the sphere
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 5);
+        for surface in [
+            SimplicialComplex::torus_triangulation(space),
+            SimplicialComplex::klein_bottle_triangulation(space),
+        ] {
+            assert!(surface.is_simplicial_manifold());
+            assert!(!surface.is_simplicial_sphere());
+            assert_eq!(surface.degree_sequence(0), vec![6; 9]);
+        }
+    }
+
+    #[test]
+    fn icosahedron() {
+        // the top vertex 0, the upper ring 1 to 5, the lower ring 6 to 10 and the bottom vertex 11
+        let upper = |i: usize| 1 + i % 5;
+        let lower = |i: usize| 6 + i % 5;
+        let mut triangles = vec![];
+        for i in 0..5 {
+            triangles.push([0, upper(i), upper(i + 1)]);
+            triangles.push([upper(i), upper(i + 1), lower(i)]);
+            triangles.push([upper(i + 1), lower(i), lower(i + 1)]);
+            triangles.push([11, lower(i), lower(i + 1)]);
+        }
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 5);
+        let icosahedron = SimplicialComplex::from_abstract_triangles(&triangles, space);
+        assert_eq!(simplex_counts(&icosahedron), vec![12, 30, 20]);
+        assert_eq!(icosahedron.degree_sequence(0), vec![5; 12]);
+        assert_eq!(icosahedron.degree_sequence(1), vec![2; 30]);
+        assert_eq!(icosahedron.degree_sequence(2), vec![0; 20]);
+        assert!(icosahedron.degree_sequence(3).is_empty());
+        assert!(icosahedron.is_simplicial_manifold());
+        assert!(icosahedron.is_simplicial_sphere());
+    }
+}
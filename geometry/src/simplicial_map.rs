@@ -0,0 +1,402 @@
+use super::*;
+use crate::{
+    simplex_collection::LabelledSimplexCollection, simplicial_complex::LabelledSimplicialComplex,
+    vector::Vector,
+};
+use algebraeon_rings::matrix::Matrix;
+use algebraeon_structures::*;
+use std::collections::{HashMap, HashSet};
+
+impl<'f, FS: OrderedRingSignature + FieldSignature, T: Eq + Clone + Send + Sync>
+    LabelledSimplicialComplex<'f, FS, T>
+where
+    FS::Elem: Hash,
+{
+    /// The points of the `0`-simplexes in increasing order.
+    ///
+    /// The vertices of a simplicial complex are referred to by their index in this list.
+    pub fn vertices(&self) -> Vec<Vector<'f, FS>> {
+        let mut vertices = self
+            .simplexes()
+            .into_iter()
+            .filter(|spx| spx.n() == 1)
+            .map(|spx| spx.point(0).clone())
+            .collect::<Vec<_>>();
+        vertices.sort_unstable();
+        vertices
+    }
+
+    /// The `k`-simplexes, each given by the increasing list of the indices of its vertices, in lexicographic order.
+    pub fn simplexes_by_vertex_indices(&self, k: usize) -> Vec<Vec<usize>> {
+        let vertices = self.vertices();
+        let mut simplexes = self
+            .simplexes()
+            .into_iter()
+            .filter(|spx| spx.n() == k + 1)
+            .map(|spx| {
+                spx.points()
+                    .iter()
+                    .map(|pt| vertices.binary_search(pt).unwrap())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        simplexes.sort_unstable();
+        simplexes
+    }
+
+    /// The boundary map from `k`-chains to `(k-1)`-chains with a row for each `k`-simplex and a column for each `(k-1)`-simplex, both ordered as in [`Self::simplexes_by_vertex_indices`].
+    ///
+    /// The boundary of the simplex with vertices `v_0 < ... < v_k` is the sum of `(-1)^i` times its face without `v_i`.
+    pub fn boundary_matrix(&self, k: usize) -> Matrix<Integer> {
+        let rows = self.simplexes_by_vertex_indices(k);
+        if k == 0 {
+            return Matrix::zero(rows.len(), 0);
+        }
+        let cols = self.simplexes_by_vertex_indices(k - 1);
+        let mut mat = Matrix::zero(rows.len(), cols.len());
+        for (r, spx) in rows.iter().enumerate() {
+            for i in 0..=k {
+                let mut face = spx.clone();
+                face.remove(i);
+                let c = cols.binary_search(&face).unwrap();
+                *mat.at_mut(r, c).unwrap() = if i % 2 == 0 {
+                    Integer::ONE
+                } else {
+                    -Integer::ONE
+                };
+            }
+        }
+        mat
+    }
+
+    // the k-cycles together with a change of basis which puts the k-boundaries in smith normal form
+    fn free_homology(&self, k: usize) -> FreeHomology {
+        let num_chains = self.simplexes_by_vertex_indices(k).len();
+        // a basis of the k-cycles
+        let cycles = if k == 0 {
+            Matrix::ident(num_chains)
+        } else {
+            self.boundary_matrix(k).row_kernel().into_row_basis_matrix()
+        };
+        let num_cycles = cycles.rows();
+        // the k-boundaries in terms of the basis of k-cycles
+        let boundaries = self.boundary_matrix(k + 1);
+        let relations = (0..boundaries.rows())
+            .map(|r| cycles.clone().row_solve(&boundaries.get_row(r)).unwrap())
+            .collect::<Vec<_>>();
+        let (_u, _s, v, rank) =
+            Matrix::construct(relations.len(), num_cycles, |r, c| relations[r][c].clone())
+                .smith_algorithm();
+        FreeHomology {
+            cycles,
+            inv_v: v.inv().unwrap(),
+            v,
+            rank,
+        }
+    }
+}
+
+struct FreeHomology {
+    // the rows are a basis of the cycles
+    cycles: Matrix<Integer>,
+    // writing a cycle as x * cycles, the coordinates of x * v past the first `rank` give its image in the free part of homology
+    v: Matrix<Integer>,
+    inv_v: Matrix<Integer>,
+    rank: usize,
+}
+
+impl FreeHomology {
+    fn betti_number(&self) -> usize {
+        self.cycles.rows() - self.rank
+    }
+
+    // a cycle representing the ith generator of the free part of homology
+    fn generator(&self, i: usize) -> Vec<Integer> {
+        Matrix::mul(&self.inv_v.get_row_submatrix(self.rank + i), &self.cycles)
+            .unwrap()
+            .get_row(0)
+    }
+
+    // the coordinates of the image of the cycle `chain` in the free part of homology
+    fn coordinates(&self, chain: &Vec<Integer>) -> Vec<Integer> {
+        let x = self.cycles.clone().row_solve(chain).unwrap();
+        Matrix::mul(&Matrix::from_rows(vec![x]), &self.v)
+            .unwrap()
+            .get_row(0)
+            .into_iter()
+            .skip(self.rank)
+            .collect()
+    }
+}
+
+/// A map between simplicial complexes given by where it sends each vertex.
+///
+/// Vertices are referred to by their index in [`LabelledSimplicialComplex::vertices`].
+pub struct SimplicialMap<
+    'a,
+    'f,
+    FS: OrderedRingSignature + FieldSignature,
+    T: Eq + Clone + Send + Sync,
+    S: Eq + Clone + Send + Sync,
+> {
+    source: &'a LabelledSimplicialComplex<'f, FS, T>,
+    target: &'a LabelledSimplicialComplex<'f, FS, S>,
+    vertex_map: HashMap<usize, usize>,
+}
+
+impl<
+    'a,
+    'f,
+    FS: OrderedRingSignature + FieldSignature,
+    T: Eq + Clone + Send + Sync,
+    S: Eq + Clone + Send + Sync,
+> SimplicialMap<'a, 'f, FS, T, S>
+where
+    FS::Elem: Hash,
+{
+    pub fn new(
+        source: &'a LabelledSimplicialComplex<'f, FS, T>,
+        target: &'a LabelledSimplicialComplex<'f, FS, S>,
+        vertex_map: HashMap<usize, usize>,
+    ) -> Self {
+        Self {
+            source,
+            target,
+            vertex_map,
+        }
+    }
+
+    pub fn source(&self) -> &'a LabelledSimplicialComplex<'f, FS, T> {
+        self.source
+    }
+
+    pub fn target(&self) -> &'a LabelledSimplicialComplex<'f, FS, S> {
+        self.target
+    }
+
+    pub fn vertex_map(&self) -> &HashMap<usize, usize> {
+        &self.vertex_map
+    }
+
+    /// Whether every vertex of the source is sent to a vertex of the target and the vertices of every simplex of the source are sent to the vertices of a simplex of the target.
+    pub fn is_simplicial(&self) -> bool {
+        let num_source_vertices = self.source.vertices().len();
+        let num_target_vertices = self.target.vertices().len();
+        if !(0..num_source_vertices).all(|v| {
+            self.vertex_map
+                .get(&v)
+                .is_some_and(|w| *w < num_target_vertices)
+        }) {
+            return false;
+        }
+        let dim = self
+            .source
+            .simplexes()
+            .into_iter()
+            .map(|spx| spx.n())
+            .max()
+            .unwrap_or(0);
+        let target_simplexes = (0..dim)
+            .flat_map(|k| self.target.simplexes_by_vertex_indices(k))
+            .collect::<HashSet<_>>();
+        (0..dim).all(|k| {
+            self.source
+                .simplexes_by_vertex_indices(k)
+                .into_iter()
+                .all(|spx| {
+                    let mut image = spx.iter().map(|v| self.vertex_map[v]).collect::<Vec<_>>();
+                    image.sort_unstable();
+                    image.dedup();
+                    target_simplexes.contains(&image)
+                })
+        })
+    }
+
+    // the image of a k-chain of the source as a k-chain of the target
+    fn chain_map(&self, k: usize, chain: &[Integer]) -> Vec<Integer> {
+        let target_simplexes = self.target.simplexes_by_vertex_indices(k);
+        let mut image = vec![Integer::ZERO; target_simplexes.len()];
+        for (spx, coeff) in self
+            .source
+            .simplexes_by_vertex_indices(k)
+            .into_iter()
+            .zip(chain)
+        {
+            let mut spx_image = spx.iter().map(|v| self.vertex_map[v]).collect::<Vec<_>>();
+            // sort the image and keep track of the sign of the permutation
+            let mut sign = true;
+            for i in 0..spx_image.len() {
+                for j in (i + 1)..spx_image.len() {
+                    if spx_image[i] > spx_image[j] {
+                        sign = !sign;
+                    }
+                }
+            }
+            spx_image.sort_unstable();
+            if spx_image.windows(2).any(|w| w[0] == w[1]) {
+                // degenerate simplexes map to zero
+                continue;
+            }
+            let idx = target_simplexes.binary_search(&spx_image).unwrap();
+            if sign {
+                image[idx] += coeff;
+            } else {
+                image[idx] -= coeff;
+            }
+        }
+        image
+    }
+
+    /// The map induced on the free part of the `k`th integral homology groups.
+    ///
+    /// The `j`th column is the image of the `j`th generator of the free part of `H_k` of the source in terms of the generators of the free part of `H_k` of the target, so composition of maps corresponds to multiplication of matrices.
+    /// The map must be simplicial.
+    pub fn induced_map_on_homology(&self, k: usize) -> Matrix<Integer> {
+        debug_assert!(self.is_simplicial());
+        let source_homology = self.source.free_homology(k);
+        let target_homology = self.target.free_homology(k);
+        let images = (0..source_homology.betti_number())
+            .map(|j| target_homology.coordinates(&self.chain_map(k, &source_homology.generator(j))))
+            .collect::<Vec<_>>();
+        Matrix::construct(
+            target_homology.betti_number(),
+            source_homology.betti_number(),
+            |i, j| images[j][i].clone(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ambient_space::AffineSpace;
+    use crate::simplicial_complex::SimplicialComplex;
+    use algebraeon_structures::Rational;
+
+    fn circle(
+        space: AffineSpace<'_, algebraeon_structures::RationalCanonicalStructure>,
+    ) -> SimplicialComplex<'_, algebraeon_structures::RationalCanonicalStructure> {
+        SimplicialComplex::new_unchecked(
+            space,
+            space
+                .simplex(vec![
+                    space.vector([0, 0]),
+                    space.vector([1, 0]),
+                    space.vector([0, 1]),
+                ])
+                .unwrap()
+                .proper_sub_simplices_not_null()
+                .into_iter()
+                .collect(),
+        )
+    }
+
+    fn identity_map(n: usize) -> HashMap<usize, usize> {
+        (0..n).map(|v| (v, v)).collect()
+    }
+
+    #[test]
+    fn boundary_matrices() {
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 2);
+        let circle = circle(space);
+        assert_eq!(circle.vertices().len(), 3);
+        assert_eq!(
+            circle.simplexes_by_vertex_indices(1),
+            vec![vec![0, 1], vec![0, 2], vec![1, 2]]
+        );
+        assert_eq!(
+            circle.boundary_matrix(1),
+            Matrix::from_rows(vec![vec![-1, 1, 0], vec![-1, 0, 1], vec![0, -1, 1]])
+        );
+        assert_eq!(circle.boundary_matrix(0), Matrix::zero(3, 0));
+        assert_eq!(circle.boundary_matrix(2), Matrix::zero(0, 3));
+    }
+
+    #[test]
+    fn contract_circle_to_point() {
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 2);
+        let circle = circle(space);
+        let point_space = AffineSpace::new_linear(Rational::structure_ref(), 0);
+        let point = SimplicialComplex::new_unchecked(
+            point_space,
+            [point_space
+                .simplex(vec![point_space.origin().unwrap()])
+                .unwrap()]
+            .into_iter()
+            .collect(),
+        );
+        let f = SimplicialMap::new(&circle, &point, (0..3).map(|v| (v, 0)).collect());
+        assert!(f.is_simplicial());
+        assert_eq!(
+            f.induced_map_on_homology(0),
+            Matrix::from_rows(vec![vec![1]])
+        );
+        // H_1 of a point is zero
+        let h1 = f.induced_map_on_homology(1);
+        assert_eq!((h1.rows(), h1.cols()), (0, 1));
+    }
+
+    #[test]
+    fn identity_and_reflection_of_circle() {
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 2);
+        let circle = circle(space);
+        let id = SimplicialMap::new(&circle, &circle, identity_map(3));
+        assert!(id.is_simplicial());
+        assert_eq!(id.induced_map_on_homology(0), Matrix::ident(1));
+        assert_eq!(id.induced_map_on_homology(1), Matrix::ident(1));
+
+        // swapping two vertices reverses the orientation of the circle
+        let reflection =
+            SimplicialMap::new(&circle, &circle, HashMap::from([(0, 1), (1, 0), (2, 2)]));
+        assert!(reflection.is_simplicial());
+        assert_eq!(
+            reflection.induced_map_on_homology(1),
+            Matrix::from_rows(vec![vec![-1]])
+        );
+
+        // a rotation preserves it
+        let rotation =
+            SimplicialMap::new(&circle, &circle, HashMap::from([(0, 1), (1, 2), (2, 0)]));
+        assert_eq!(rotation.induced_map_on_homology(1), Matrix::ident(1));
+    }
+
+    #[test]
+    fn identity_of_torus() {
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 2);
+        let circle = circle(space);
+        let torus = circle.product(&circle);
+        let id = SimplicialMap::new(&torus, &torus, identity_map(9));
+        assert!(id.is_simplicial());
+        assert_eq!(id.induced_map_on_homology(0), Matrix::ident(1));
+        assert_eq!(id.induced_map_on_homology(1), Matrix::ident(2));
+        assert_eq!(id.induced_map_on_homology(2), Matrix::ident(1));
+    }
+
+    #[test]
+    fn non_simplicial_maps() {
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 2);
+        let circle = circle(space);
+        let disk = SimplicialComplex::new_unchecked(
+            space,
+            space
+                .simplex(vec![
+                    space.vector([0, 0]),
+                    space.vector([1, 0]),
+                    space.vector([0, 1]),
+                ])
+                .unwrap()
+                .sub_simplices_not_null()
+                .into_iter()
+                .collect(),
+        );
+        // the circle includes into the disk but the disk can't be mapped onto the circle
+        assert!(SimplicialMap::new(&circle, &disk, identity_map(3)).is_simplicial());
+        assert!(!SimplicialMap::new(&disk, &circle, identity_map(3)).is_simplicial());
+        // every vertex must be mapped
+        assert!(!SimplicialMap::new(&circle, &circle, HashMap::from([(0, 0)])).is_simplicial());
+        assert!(
+            !SimplicialMap::new(&circle, &circle, HashMap::from([(0, 0), (1, 1), (2, 3)]))
+                .is_simplicial()
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use crate::vector::Vector;
+use algebraeon_structures::*;
+
+/// The number of times the closed polygon with vertices `polygon` winds anticlockwise around `point` in the plane.
+///
+/// The polygon is closed by the edge from its last vertex back to its first and may intersect itself.
+/// Rather than summing angles, each edge crossing the horizontal ray to the right of `point` contributes `+1` if it passes upwards with `point` to its left and `-1` if it passes downwards with `point` to its right, so only exact cross products are needed.
+/// The result is `0` for points outside the polygon and is not meaningful for points on the polygon itself.
+pub fn winding_number(
+    polygon: &[Vector<'_, RationalCanonicalStructure>],
+    point: &Vector<'_, RationalCanonicalStructure>,
+) -> i64 {
+    assert_eq!(point.ambient_space().linear_dimension(), Some(2));
+    let (px, py) = (point.coordinate(0), point.coordinate(1));
+    let mut winding = 0;
+    for i in 0..polygon.len() {
+        let a = &polygon[i];
+        let b = &polygon[(i + 1) % polygon.len()];
+        assert_eq!(a.ambient_space(), point.ambient_space());
+        let (ax, ay) = (a.coordinate(0), a.coordinate(1));
+        let (bx, by) = (b.coordinate(0), b.coordinate(1));
+        // positive when point is to the left of the directed edge a -> b
+        let cross = (bx - ax) * (py - ay) - (by - ay) * (px - ax);
+        if ay <= py && py < by && cross > Rational::ZERO {
+            winding += 1;
+        } else if by <= py && py < ay && cross < Rational::ZERO {
+            winding -= 1;
+        }
+    }
+    winding
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ambient_space::AffineSpace;
+
+    #[test]
+    fn winding_number_of_triangle() {
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 2);
+        let ccw = vec![
+            space.vector([0, 0]),
+            space.vector([4, 0]),
+            space.vector([0, 4]),
+        ];
+        let cw = ccw.iter().rev().cloned().collect::<Vec<_>>();
+        let inside = space.vector([1, 1]);
+        let outside = space.vector([3, 3]);
+        assert_eq!(winding_number(&ccw, &inside), 1);
+        assert_eq!(winding_number(&cw, &inside), -1);
+        assert_eq!(winding_number(&ccw, &outside), 0);
+        assert_eq!(winding_number(&cw, &outside), 0);
+        // level with a vertex
+        assert_eq!(winding_number(&ccw, &space.vector([-1, 0])), 0);
+        assert_eq!(winding_number(&ccw, &space.vector([-1, 4])), 0);
+    }
+
+    #[test]
+    fn winding_number_of_self_intersecting_polygons() {
+        let space = AffineSpace::new_linear(Rational::structure_ref(), 2);
+
+        // a pentagram winds twice around its centre and once around each of its points
+        let pentagon = [
+            space.vector([0, 3]),
+            space.vector([-3, 1]),
+            space.vector([-2, -2]),
+            space.vector([2, -2]),
+            space.vector([3, 1]),
+        ];
+        let pentagram = [0, 2, 4, 1, 3]
+            .into_iter()
+            .map(|i| pentagon[i].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(winding_number(&pentagram, &space.vector([0, 0])), 2);
+        assert_eq!(winding_number(&pentagram, &space.vector([0, 2])), 1);
+        assert_eq!(winding_number(&pentagram, &space.vector([0, 4])), 0);
+
+        // a square traced twice in the same direction
+        let square = [
+            space.vector([0, 0]),
+            space.vector([2, 0]),
+            space.vector([2, 2]),
+            space.vector([0, 2]),
+        ];
+        let twice = square
+            .iter()
+            .chain(square.iter())
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(winding_number(&twice, &space.vector([1, 1])), 2);
+
+        // the two lobes of a figure-eight are traced in opposite directions
+        let figure_eight = vec![
+            space.vector([0, 0]),
+            space.vector([2, 2]),
+            space.vector([2, 0]),
+            space.vector([0, 2]),
+        ];
+        let left = space.vector([Rational::from(1) / Rational::from(2), Rational::from(1)]);
+        let right = space.vector([Rational::from(3) / Rational::from(2), Rational::from(1)]);
+        assert_eq!(
+            winding_number(&figure_eight, &left) * winding_number(&figure_eight, &right),
+            -1
+        );
+    }
+}
@@ -0,0 +1,255 @@
+use crate::composition_table::group::{
+    FiniteGroupMultiplicationTable, direct_product_structure,
+    examples::{cyclic_group_structure, trivial_group_structure},
+};
+use algebraeon_structures::*;
+
+/// A finitely generated abelian group `Z^r + Z/n_1Z + ... + Z/n_kZ` where each `n_i > 1` and `n_1 | n_2 | ... | n_k`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FinitelyGeneratedAbelianGroup {
+    free_rank: usize,
+    torsion: Vec<Natural>,
+}
+
+impl FinitelyGeneratedAbelianGroup {
+    /// Construct from the free rank and the invariant factors.
+    ///
+    /// Returns `None` unless each invariant factor is greater than `1` and divides the next.
+    pub fn new(free_rank: usize, torsion: Vec<Natural>) -> Option<Self> {
+        if torsion.iter().any(|n| *n <= Natural::ONE) {
+            return None;
+        }
+        for i in 1..torsion.len() {
+            if &torsion[i] % &torsion[i - 1] != Natural::ZERO {
+                return None;
+            }
+        }
+        Some(Self { free_rank, torsion })
+    }
+
+    /// The group `Z/n_1Z + ... + Z/n_kZ` for any `n_i`, where `Z/0Z = Z`.
+    pub fn from_cyclic_orders(orders: Vec<Natural>) -> Self {
+        let mut free_rank = 0;
+        let mut torsion: Vec<Natural> = vec![];
+        for n in orders {
+            if n == Natural::ZERO {
+                free_rank += 1;
+                continue;
+            }
+            // Z/aZ + Z/bZ = Z/gcd(a,b)Z + Z/lcm(a,b)Z
+            // so adding Z/nZ to Z/t_1Z + ... + Z/t_kZ pushes n up the divisibility chain
+            let mut carry = n;
+            for t in torsion.iter_mut().rev() {
+                let g = Natural::gcd(t, &carry);
+                let l = Natural::lcm(t, &carry);
+                *t = l;
+                carry = g;
+            }
+            torsion.insert(0, carry);
+            torsion.retain(|t| *t != Natural::ONE);
+        }
+        Self { free_rank, torsion }
+    }
+
+    /// The trivial group.
+    pub fn trivial() -> Self {
+        Self {
+            free_rank: 0,
+            torsion: vec![],
+        }
+    }
+
+    /// The free abelian group `Z^r`.
+    pub fn free(free_rank: usize) -> Self {
+        Self {
+            free_rank,
+            torsion: vec![],
+        }
+    }
+
+    /// The cyclic group `Z/nZ`, which is `Z` when `n = 0`.
+    pub fn cyclic(n: Natural) -> Self {
+        Self::from_cyclic_orders(vec![n])
+    }
+
+    pub fn free_rank(&self) -> usize {
+        self.free_rank
+    }
+
+    /// The invariant factors `n_1 | n_2 | ... | n_k` of the torsion subgroup.
+    pub fn torsion(&self) -> &Vec<Natural> {
+        &self.torsion
+    }
+
+    /// The minimal number of generators.
+    pub fn rank(&self) -> usize {
+        self.free_rank + self.torsion.len()
+    }
+
+    pub fn is_trivial(&self) -> bool {
+        self.free_rank == 0 && self.torsion.is_empty()
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.free_rank == 0
+    }
+
+    pub fn is_cyclic(&self) -> bool {
+        self.rank() <= 1
+    }
+
+    /// The number of elements, or `None` if the group is infinite.
+    pub fn order(&self) -> Option<Natural> {
+        if self.is_finite() {
+            Some(self.torsion.iter().fold(Natural::ONE, |acc, n| acc * n))
+        } else {
+            None
+        }
+    }
+
+    /// The direct sum of `self` and `other`.
+    pub fn direct_sum(&self, other: &Self) -> Self {
+        let mut g = Self::from_cyclic_orders(
+            self.torsion
+                .iter()
+                .chain(other.torsion.iter())
+                .cloned()
+                .collect(),
+        );
+        g.free_rank = self.free_rank + other.free_rank;
+        g
+    }
+
+    /// The multiplication table of this group, or `None` if it is infinite.
+    pub fn to_finite_group(&self) -> Option<FiniteGroupMultiplicationTable> {
+        if !self.is_finite() {
+            return None;
+        }
+        let mut grp = trivial_group_structure();
+        for n in &self.torsion {
+            grp = direct_product_structure(&grp, &cyclic_group_structure(n.try_into().unwrap()));
+        }
+        Some(grp)
+    }
+}
+
+impl std::fmt::Display for FinitelyGeneratedAbelianGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = vec![];
+        match self.free_rank {
+            0 => {}
+            1 => parts.push("Z".to_string()),
+            r => parts.push(format!("Z^{}", r)),
+        }
+        for n in &self.torsion {
+            parts.push(format!("Z/{}Z", n));
+        }
+        if parts.is_empty() {
+            write!(f, "0")
+        } else {
+            write!(f, "{}", parts.join(" + "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invariant_factors() {
+        assert_eq!(
+            FinitelyGeneratedAbelianGroup::new(1, vec![Natural::from(2u32), Natural::from(6u32)]),
+            Some(FinitelyGeneratedAbelianGroup {
+                free_rank: 1,
+                torsion: vec![Natural::from(2u32), Natural::from(6u32)]
+            })
+        );
+        assert_eq!(
+            FinitelyGeneratedAbelianGroup::new(0, vec![Natural::from(2u32), Natural::from(3u32)]),
+            None
+        );
+        assert_eq!(
+            FinitelyGeneratedAbelianGroup::new(0, vec![Natural::ONE]),
+            None
+        );
+
+        // Z/4 + Z/6 + Z + Z/1 = Z + Z/2 + Z/12
+        let g = FinitelyGeneratedAbelianGroup::from_cyclic_orders(vec![
+            Natural::from(4u32),
+            Natural::from(6u32),
+            Natural::ZERO,
+            Natural::ONE,
+        ]);
+        assert_eq!(g.free_rank(), 1);
+        assert_eq!(
+            g.torsion(),
+            &vec![Natural::from(2u32), Natural::from(12u32)]
+        );
+        assert_eq!(g.order(), None);
+        assert_eq!(g.to_string(), "Z + Z/2Z + Z/12Z");
+
+        // Z/2 + Z/3 = Z/6
+        let g = FinitelyGeneratedAbelianGroup::from_cyclic_orders(vec![
+            Natural::from(2u32),
+            Natural::from(3u32),
+        ]);
+        assert!(g.is_cyclic());
+        assert_eq!(
+            g,
+            FinitelyGeneratedAbelianGroup::cyclic(Natural::from(6u32))
+        );
+        assert_eq!(g.order(), Some(Natural::from(6u32)));
+
+        assert_eq!(FinitelyGeneratedAbelianGroup::trivial().to_string(), "0");
+        assert_eq!(FinitelyGeneratedAbelianGroup::free(3).to_string(), "Z^3");
+        assert_eq!(
+            FinitelyGeneratedAbelianGroup::from_cyclic_orders(vec![
+                Natural::from(2u32),
+                Natural::from(2u32),
+                Natural::from(4u32),
+                Natural::from(3u32),
+            ])
+            .direct_sum(&FinitelyGeneratedAbelianGroup::from_cyclic_orders(vec![
+                Natural::ZERO,
+                Natural::from(8u32),
+            ])),
+            FinitelyGeneratedAbelianGroup::new(
+                1,
+                vec![
+                    Natural::from(2u32),
+                    Natural::from(2u32),
+                    Natural::from(4u32),
+                    Natural::from(24u32)
+                ]
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn to_finite_group() {
+        assert!(
+            FinitelyGeneratedAbelianGroup::free(1)
+                .to_finite_group()
+                .is_none()
+        );
+        let grp = FinitelyGeneratedAbelianGroup::from_cyclic_orders(vec![
+            Natural::from(2u32),
+            Natural::from(4u32),
+        ])
+        .to_finite_group()
+        .unwrap();
+        grp.check_state().unwrap();
+        assert_eq!(grp.size(), 8);
+        assert!(grp.is_abelian());
+        assert_eq!(grp.elems().map(|x| grp.order(x).unwrap()).max(), Some(4));
+        assert_eq!(
+            FinitelyGeneratedAbelianGroup::trivial()
+                .to_finite_group()
+                .unwrap()
+                .size(),
+            1
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use super::group::FiniteGroupMultiplicationTable;
+use super::subgroup::Subgroup;
+use algebraeon_sets::sets::FinitelySupportedPermutation;
+use algebraeon_structures::*;
+
+/// For each element `g` of `group`, the permutation `x -> gx` of the elements of `group`.
+///
+/// The `i`th permutation is the one induced by the element `i`. This is Cayley's embedding of `group` into the symmetric group on its elements.
+pub fn regular_representation(
+    group: &FiniteGroupMultiplicationTable,
+) -> Vec<FinitelySupportedPermutation<usize>> {
+    group
+        .elems()
+        .map(|g| {
+            FinitelySupportedPermutation::<usize>::new_perm(
+                group
+                    .elems()
+                    .map(|x| (x, group.mul(g, x)))
+                    .filter(|(x, y)| x != y)
+                    .collect(),
+            )
+            .unwrap()
+        })
+        .collect()
+}
+
+/// For each element `g` of the group containing `subgroup`, the permutation `xH -> gxH` of the left cosets of `subgroup`.
+///
+/// The cosets are labelled `0, 1, ..., k-1` in the order of `subgroup.left_cosets()` and the `i`th permutation is the one induced by the element `i`.
+/// The kernel of this action is the core of `subgroup`, the largest normal subgroup it contains.
+pub fn action_on_cosets(subgroup: &Subgroup) -> Vec<FinitelySupportedPermutation<usize>> {
+    let group = subgroup.subset.group();
+    let cosets = subgroup.left_cosets();
+    let representatives = (0..cosets.size())
+        .map(|c| *cosets.partition.get_class(c).iter().next().unwrap())
+        .collect::<Vec<_>>();
+    group
+        .elems()
+        .map(|g| {
+            FinitelySupportedPermutation::<usize>::new_perm(
+                representatives
+                    .iter()
+                    .enumerate()
+                    .map(|(c, x)| (c, cosets.partition.project(group.mul(g, *x))))
+                    .filter(|(c, d)| c != d)
+                    .collect(),
+            )
+            .unwrap()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod action_tests {
+    use super::super::group::examples;
+    use super::*;
+
+    fn is_homomorphism(
+        group: &FiniteGroupMultiplicationTable,
+        perms: &[FinitelySupportedPermutation<usize>],
+        n: usize,
+    ) -> bool {
+        group.elems().all(|g| {
+            group.elems().all(|h| {
+                (0..n).all(|x| {
+                    perms[group.mul(g, h)].image(&x) == perms[g].image(&perms[h].image(&x))
+                })
+            })
+        })
+    }
+
+    fn image_size(perms: &[FinitelySupportedPermutation<usize>]) -> usize {
+        let mut image: Vec<&FinitelySupportedPermutation<usize>> = vec![];
+        for perm in perms {
+            if !image.iter().any(|other| other.equal(perm)) {
+                image.push(perm);
+            }
+        }
+        image.len()
+    }
+
+    #[test]
+    fn test_regular_representation() {
+        let grp = examples::cyclic_group_structure(3);
+        let perms = regular_representation(&grp);
+        assert_eq!(perms.len(), 3);
+        assert!(is_homomorphism(&grp, &perms, 3));
+        for g in grp.elems() {
+            let cycle_lengths = perms[g]
+                .disjoint_cycles()
+                .into_iter()
+                .map(|cycle| cycle.len())
+                .filter(|len| *len > 1)
+                .collect::<Vec<_>>();
+            if g == grp.ident() {
+                assert!(cycle_lengths.is_empty());
+            } else {
+                assert_eq!(cycle_lengths, vec![3]);
+            }
+        }
+
+        let grp = examples::symmetric_group_structure(3);
+        let perms = regular_representation(&grp);
+        assert!(is_homomorphism(&grp, &perms, 6));
+        assert_eq!(image_size(&perms), 6);
+    }
+
+    #[test]
+    fn test_action_on_cosets() {
+        let grp = examples::symmetric_group_structure(4);
+        let subgroups = grp.subgroups();
+        let subgroup_of_size = |n: usize| {
+            subgroups
+                .iter()
+                .map(|(subgroup, _)| subgroup)
+                .find(|subgroup| subgroup.size() == n)
+                .unwrap()
+        };
+
+        // a point stabilizer S3 has index 4 and trivial core, so S4 embeds in S4
+        let perms = action_on_cosets(subgroup_of_size(6));
+        assert!(is_homomorphism(&grp, &perms, 4));
+        assert_eq!(image_size(&perms), 24);
+
+        // a Sylow 3-subgroup has index 8 and trivial core, so S4 embeds in S8
+        let perms = action_on_cosets(subgroup_of_size(3));
+        assert!(is_homomorphism(&grp, &perms, 8));
+        assert_eq!(image_size(&perms), 24);
+
+        // a Sylow 2-subgroup has index 3 and its core is the Klein four group, giving S4 -> S3
+        let perms = action_on_cosets(subgroup_of_size(8));
+        assert!(is_homomorphism(&grp, &perms, 3));
+        assert_eq!(image_size(&perms), 6);
+    }
+}
@@ -2,6 +2,9 @@ use super::normal_subgroup::NormalSubgroup;
 use super::partition::GroupPartition;
 use super::subgroup::Subgroup;
 use super::subset::Subset;
+use crate::free_group::todd_coxeter::{
+    finite_group_from_generator_action, try_enumerate_cosets_impl,
+};
 use algebraeon_macros::signature_meta_trait;
 use algebraeon_sets::combinatorics::Partition;
 use algebraeon_structures::*;
@@ -11,6 +14,9 @@ use std::collections::HashSet;
 use std::fmt::Debug;
 use std::hash::Hash;
 
+/// The number of cosets after which `FiniteGroupMultiplicationTable::from_generators_and_relations` gives up.
+pub const TODD_COXETER_MAX_COSETS: usize = 1 << 20;
+
 #[derive(Debug)]
 pub struct FiniteGroupMultiplicationTable {
     n: usize,
@@ -132,6 +138,38 @@ impl FiniteGroupMultiplicationTable {
         }
     }
 
+    /// The finite group with `num_generators` generators subject to `relations`, found by Todd-Coxeter coset enumeration.
+    ///
+    /// Each relation is a word, equal to the identity, of pairs `(i, inverse)` representing the `i`th generator if `inverse` is `false` and its inverse if `inverse` is `true`.
+    /// Return `None` if the enumeration defines more than `TODD_COXETER_MAX_COSETS` cosets, for example when the group is infinite.
+    pub fn from_generators_and_relations(
+        num_generators: usize,
+        relations: &[Vec<(usize, bool)>],
+    ) -> Option<Self> {
+        let relations = relations
+            .iter()
+            .map(|word| {
+                word.iter()
+                    .map(|(i, inverse)| {
+                        assert!(*i < num_generators);
+                        2 * i + usize::from(*inverse)
+                    })
+                    .collect()
+            })
+            .collect();
+        let (n, gen_perms) = try_enumerate_cosets_impl(
+            num_generators,
+            relations,
+            vec![],
+            Some(TODD_COXETER_MAX_COSETS),
+        )?;
+        Some(finite_group_from_generator_action(
+            num_generators,
+            n,
+            &gen_perms,
+        ))
+    }
+
     pub fn mul(&self, x: usize, y: usize) -> usize {
         self.mul[x][y]
     }
@@ -601,6 +639,61 @@ mod group_tests {
         }
     }
 
+    #[test]
+    fn test_from_generators_and_relations() {
+        use crate::composition_table::homomorphism::find_isomorphism;
+
+        // <a | a^n = 1> is C_n
+        for n in [1, 2, 5, 12] {
+            let grp = FiniteGroupMultiplicationTable::from_generators_and_relations(
+                1,
+                &[vec![(0, false); n]],
+            )
+            .unwrap();
+            grp.check_state().unwrap();
+            assert!(find_isomorphism(&grp, &examples::cyclic_group_structure(n)).is_some());
+        }
+
+        // <a, b | a^n = b^2 = (ab)^2 = 1> is D_n
+        for n in [3, 4, 7] {
+            let grp = FiniteGroupMultiplicationTable::from_generators_and_relations(
+                2,
+                &[
+                    vec![(0, false); n],
+                    vec![(1, false); 2],
+                    vec![(0, false), (1, false), (0, false), (1, false)],
+                ],
+            )
+            .unwrap();
+            grp.check_state().unwrap();
+            assert_eq!(grp.size(), 2 * n);
+            assert!(find_isomorphism(&grp, &examples::dihedral_group_structure(n)).is_some());
+        }
+
+        // <a, b | a^4 = b^2 = 1, bab = a^-1> is D_4
+        let grp = FiniteGroupMultiplicationTable::from_generators_and_relations(
+            2,
+            &[
+                vec![(0, false); 4],
+                vec![(1, false); 2],
+                vec![(1, false), (0, false), (1, false), (0, false)],
+            ],
+        )
+        .unwrap();
+        grp.check_state().unwrap();
+        assert!(find_isomorphism(&grp, &examples::dihedral_group_structure(4)).is_some());
+        assert!(find_isomorphism(&grp, &examples::quaternion_group_structure()).is_none());
+
+        // <a, b | ab = ba> is infinite
+        assert!(
+            FiniteGroupMultiplicationTable::from_generators_and_relations(
+                2,
+                &[vec![(0, false), (1, false), (0, true), (1, true)]],
+            )
+            .is_none()
+        );
+    }
+
     #[test]
     fn test_quaternion() {
         let mut grp = examples::quaternion_group_structure();
@@ -1,3 +1,4 @@
+pub mod action;
 pub mod generating_set;
 pub mod group;
 pub mod homomorphism;
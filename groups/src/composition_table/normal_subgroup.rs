@@ -49,6 +49,15 @@ impl<'a> NormalSubgroup<'a> {
     }
 }
 
+/// The quotient group `G/N` of the group `G` containing `normal_subgroup` by `N = normal_subgroup`.
+///
+/// The elements of the quotient are the cosets of `normal_subgroup`, multiplied by multiplying representatives.
+pub fn quotient_group_structure(
+    normal_subgroup: &NormalSubgroup,
+) -> FiniteGroupMultiplicationTable {
+    normal_subgroup.quotient_group()
+}
+
 #[cfg(test)]
 mod normal_subgroup_tests {
     use super::super::subset::*;
@@ -141,4 +150,38 @@ mod normal_subgroup_tests {
             assert_eq!(qgrp.size() * nsg.size(), grp.size());
         }
     }
+
+    #[test]
+    fn quotient_group_structures() {
+        use crate::composition_table::homomorphism::find_isomorphism;
+
+        // S3 / A3 = C2
+        let grp = examples::symmetric_group_structure(3);
+        let nsgs = grp.normal_subgroups();
+        let (a3, _gens) = nsgs.iter().find(|(nsg, _gens)| nsg.size() == 3).unwrap();
+        let qgrp = quotient_group_structure(a3);
+        qgrp.check_state().unwrap();
+        assert!(find_isomorphism(&qgrp, &examples::cyclic_group_structure(2)).is_some());
+
+        // A4 / V4 = C3
+        let grp = examples::alternating_group_structure(4);
+        let nsgs = grp.normal_subgroups();
+        let (v4, _gens) = nsgs.iter().find(|(nsg, _gens)| nsg.size() == 4).unwrap();
+        let qgrp = quotient_group_structure(v4);
+        qgrp.check_state().unwrap();
+        assert!(find_isomorphism(&qgrp, &examples::cyclic_group_structure(3)).is_some());
+
+        // |G / N| = |G| / |N|
+        for grp in [
+            examples::symmetric_group_structure(4),
+            examples::dihedral_group_structure(6),
+            examples::quaternion_group_structure(),
+        ] {
+            for (nsg, _gens) in grp.normal_subgroups() {
+                let qgrp = quotient_group_structure(&nsg);
+                qgrp.check_state().unwrap();
+                assert_eq!(qgrp.size(), grp.size() / nsg.size());
+            }
+        }
+    }
 }
@@ -146,6 +146,20 @@ impl<'a> Subgroup<'a> {
     }
 }
 
+/// The left cosets `gH` of `subgroup`, each sorted and listed in order of their smallest element.
+pub fn cosets(subgroup: &Subgroup) -> Vec<Vec<usize>> {
+    let partition = subgroup.left_cosets().partition;
+    let mut cosets = (0..partition.num_classes())
+        .map(|i| {
+            let mut coset = partition.get_class(i).iter().copied().collect::<Vec<_>>();
+            coset.sort_unstable();
+            coset
+        })
+        .collect::<Vec<_>>();
+    cosets.sort_unstable();
+    cosets
+}
+
 #[cfg(test)]
 mod subgroup_tests {
     use super::*;
@@ -235,6 +249,15 @@ mod subgroup_tests {
                 )
                 .unwrap()
         );
+
+        let coset_lists = super::cosets(&sg);
+        assert_eq!(coset_lists.len(), 3);
+        assert!(coset_lists.iter().all(|coset| coset.len() == 2));
+        assert_eq!(
+            coset_lists.concat().into_iter().collect::<HashSet<_>>(),
+            grp.elems().collect()
+        );
+        assert!(coset_lists[0].contains(&grp.ident()));
     }
 
     #[test]
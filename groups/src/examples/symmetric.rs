@@ -142,6 +142,48 @@ impl<const N: usize> Permutation<N> {
         shape
     }
 
+    /// The disjoint cycle decomposition with 1-cycles omitted.
+    /// Each cycle starts at its smallest element and the cycles are sorted by their first element.
+    pub fn to_cycle_notation(&self) -> Vec<Vec<usize>> {
+        self.disjoint_cycles()
+            .into_iter()
+            .filter(|cycle| cycle.len() != 1)
+            .map(|cycle| cycle.cyc)
+            .collect()
+    }
+
+    /// Inverse of `to_cycle_notation`.
+    /// Error if any cycle is invalid or if the cycles are not disjoint.
+    pub fn from_cycle_notation(cycles: &[Vec<usize>]) -> Result<Self, &'static str> {
+        let mut present = [false; N];
+        for cycle in cycles {
+            for i in cycle {
+                if *i >= N {
+                    return Err("Permutation value out of range");
+                }
+                if present[*i] {
+                    return Err("Cycles are not disjoint");
+                }
+                present[*i] = true;
+            }
+        }
+        Self::new_from_cycles(cycles.to_vec())
+    }
+
+    /// The order of the permutation, the lcm of its cycle lengths.
+    pub fn order(&self) -> usize {
+        fn gcd(mut a: usize, mut b: usize) -> usize {
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            a
+        }
+        self.disjoint_cycles()
+            .iter()
+            .map(Cycle::len)
+            .fold(1, |acc, k| acc / gcd(acc, k) * k)
+    }
+
     pub fn all_permutations() -> impl Iterator<Item = Self> {
         (0..N).permutations(N).map(|perm| Self {
             perm: perm.try_into().unwrap(),
@@ -315,6 +357,41 @@ mod tests {
         assert_eq!(c.sign(), C2::Flip);
     }
 
+    #[test]
+    fn test_cycle_notation() {
+        let cycles = vec![vec![0, 3], vec![1, 4, 2]];
+        let a = Permutation::<6>::from_cycle_notation(&cycles).unwrap();
+        assert_eq!(a.to_cycle_notation(), cycles);
+        assert_eq!(a, Permutation::new([3, 4, 1, 0, 2, 5]).unwrap());
+
+        let e = Permutation::<4>::from_cycle_notation(&[]).unwrap();
+        assert_eq!(e, Permutation::identity());
+        assert_eq!(e.to_cycle_notation(), Vec::<Vec<usize>>::new());
+
+        assert!(Permutation::<4>::from_cycle_notation(&[vec![0, 1], vec![1, 2]]).is_err());
+        assert!(Permutation::<4>::from_cycle_notation(&[vec![0, 4]]).is_err());
+    }
+
+    #[test]
+    fn test_order() {
+        // (12)(345)
+        let a = Permutation::<6>::from_cycle_notation(&[vec![1, 2], vec![3, 4, 5]]).unwrap();
+        assert_eq!(a.order(), 6);
+        assert_eq!(Permutation::<6>::identity().order(), 1);
+        for p in Permutation::<4>::all_permutations() {
+            assert_eq!(
+                Permutation::compose(&p, &p.inverse()),
+                Permutation::identity()
+            );
+            let mut q = p.clone();
+            for _ in 1..p.order() {
+                assert_ne!(q, Permutation::identity());
+                q = Permutation::compose(&q, &p);
+            }
+            assert_eq!(q, Permutation::identity());
+        }
+    }
+
     #[test]
     fn test_all_permutations() {
         assert_eq!(Permutation::<0>::all_permutations().collect_vec().len(), 1);
@@ -104,6 +104,21 @@ fn enumerate_cosets_impl(
     rels: Vec<Vec<usize>>,
     subgens: Vec<Vec<usize>>,
 ) -> (usize, Vec<FinitelySupportedPermutation<usize>>) {
+    try_enumerate_cosets_impl(num_gens, rels, subgens, None).unwrap()
+}
+
+/*
+As enumerate_cosets_impl, but give up and return None
+once more than max_cosets cosets have been defined.
+ */
+pub(crate) fn try_enumerate_cosets_impl(
+    num_gens: usize,
+    rels: Vec<Vec<usize>>,
+    subgens: Vec<Vec<usize>>,
+    max_cosets: Option<usize>,
+) -> Option<(usize, Vec<FinitelySupportedPermutation<usize>>)> {
+    let exceeded = |scg: &SchreierGraph| max_cosets.is_some_and(|m| scg.idents.len() > m);
+
     //impose inverse relations
     let mut full_rels = rels.clone();
     for i in 0..num_gens {
@@ -130,6 +145,9 @@ fn enumerate_cosets_impl(
     for subgen in subgens {
         let end = scg.follow_path(start, &subgen);
         scg.unify(end, start);
+        if exceeded(&scg) {
+            return None;
+        }
     }
 
     let mut to_visit = 0;
@@ -139,6 +157,9 @@ fn enumerate_cosets_impl(
             for rel in &rels {
                 let b = scg.follow_path(c, rel);
                 scg.unify(b, c);
+                if exceeded(&scg) {
+                    return None;
+                }
             }
         }
         to_visit += 1;
@@ -184,7 +205,7 @@ fn enumerate_cosets_impl(
         );
     }
 
-    (cosets.len(), perms)
+    Some((cosets.len(), perms))
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -344,86 +365,97 @@ the list of generators for this finitely generated group"
     pub fn into_finite_group(
         &self,
     ) -> super::super::composition_table::group::FiniteGroupMultiplicationTable {
-        let num_gens = self.generators.len();
         let (n, gen_perms) = self.enumerate_elements();
-        let inv_gen_perms = gen_perms
-            .iter()
-            .map(|perm| perm.clone().inverse())
-            .collect::<Vec<FinitelySupportedPermutation<usize>>>();
-
-        //write each element as a word in the n generators
-        let mut paths: Vec<(bool, Vec<usize>)> = vec![];
-        for i in 0..n {
-            paths.push((i == 0, vec![]));
-        }
+        finite_group_from_generator_action(self.generators.len(), n, &gen_perms)
+    }
+}
 
-        let mut boundary = vec![0];
-        let mut new_boundary = vec![];
-        while !boundary.is_empty() {
-            for b_idx in boundary {
-                let (b_done, b_path) = paths[b_idx].clone();
-                debug_assert!(b_done);
-                #[allow(clippy::needless_range_loop)]
-                for g in 0..num_gens {
-                    let c = gen_perms[g].image(&b_idx);
-                    if !paths[c].0 {
-                        let mut c_path = b_path.clone();
-                        c_path.push(g);
-                        paths[c] = (true, c_path);
-                        new_boundary.push(c);
-                    }
+/*
+Build the multiplication table of a finite group from the action of its generators
+on its n elements by right multiplication, where the identity element is labelled by 0.
+ */
+pub(crate) fn finite_group_from_generator_action(
+    num_gens: usize,
+    n: usize,
+    gen_perms: &[FinitelySupportedPermutation<usize>],
+) -> super::super::composition_table::group::FiniteGroupMultiplicationTable {
+    let inv_gen_perms = gen_perms
+        .iter()
+        .map(|perm| perm.clone().inverse())
+        .collect::<Vec<FinitelySupportedPermutation<usize>>>();
+
+    //write each element as a word in the n generators
+    let mut paths: Vec<(bool, Vec<usize>)> = vec![];
+    for i in 0..n {
+        paths.push((i == 0, vec![]));
+    }
+
+    let mut boundary = vec![0];
+    let mut new_boundary = vec![];
+    while !boundary.is_empty() {
+        for b_idx in boundary {
+            let (b_done, b_path) = paths[b_idx].clone();
+            debug_assert!(b_done);
+            #[allow(clippy::needless_range_loop)]
+            for g in 0..num_gens {
+                let c = gen_perms[g].image(&b_idx);
+                if !paths[c].0 {
+                    let mut c_path = b_path.clone();
+                    c_path.push(g);
+                    paths[c] = (true, c_path);
+                    new_boundary.push(c);
                 }
             }
-            boundary = new_boundary;
-            new_boundary = vec![];
         }
+        boundary = new_boundary;
+        new_boundary = vec![];
+    }
 
-        //remove the done flags from paths and check that the values make sense
-        for p in &paths {
-            assert!(p.0);
-        }
-        let paths = paths
-            .into_iter()
-            .map(|(_done, path)| path)
-            .collect::<Vec<Vec<usize>>>();
-        for p in &paths {
-            for g in p {
-                debug_assert!(*g < num_gens);
-            }
+    //remove the done flags from paths and check that the values make sense
+    for p in &paths {
+        assert!(p.0);
+    }
+    let paths = paths
+        .into_iter()
+        .map(|(_done, path)| path)
+        .collect::<Vec<Vec<usize>>>();
+    for p in &paths {
+        for g in p {
+            debug_assert!(*g < num_gens);
         }
-
-        super::super::composition_table::group::FiniteGroupMultiplicationTable::new_unchecked(
-            n,
-            0,
-            (0..n)
-                .map(|x| {
-                    let mut y = 0;
-                    for g in paths[x].iter().rev() {
-                        y = inv_gen_perms[*g].image(&y);
-                    }
-                    y
-                })
-                .collect(),
-            (0..n)
-                .map(|x| {
-                    (0..n)
-                        .map(|y| {
-                            let mut z = 0;
-                            for g in &paths[x] {
-                                z = gen_perms[*g].image(&z);
-                            }
-                            for g in &paths[y] {
-                                z = gen_perms[*g].image(&z);
-                            }
-                            z
-                        })
-                        .collect()
-                })
-                .collect(),
-            None,
-            None,
-        )
     }
+
+    super::super::composition_table::group::FiniteGroupMultiplicationTable::new_unchecked(
+        n,
+        0,
+        (0..n)
+            .map(|x| {
+                let mut y = 0;
+                for g in paths[x].iter().rev() {
+                    y = inv_gen_perms[*g].image(&y);
+                }
+                y
+            })
+            .collect(),
+        (0..n)
+            .map(|x| {
+                (0..n)
+                    .map(|y| {
+                        let mut z = 0;
+                        for g in &paths[x] {
+                            z = gen_perms[*g].image(&z);
+                        }
+                        for g in &paths[y] {
+                            z = gen_perms[*g].image(&z);
+                        }
+                        z
+                    })
+                    .collect()
+            })
+            .collect(),
+        None,
+        None,
+    )
 }
 
 /// A struct used to help enumerate cosets of a subgroup in a finitely generated group.
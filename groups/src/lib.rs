@@ -14,6 +14,7 @@
     clippy::needless_range_loop
 )]
 
+pub mod abelian;
 pub mod composition_table;
 pub mod examples;
 pub mod free_group;
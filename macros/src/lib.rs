@@ -122,22 +122,61 @@ fn has_option(attrs: &[Attribute], option_name: &str) -> bool {
 ///     }
 /// }
 /// ```
+///
+/// # Generic types
+/// Generic types such as `struct MyWrapper<T>(T)` are supported. The generated `MyWrapperCanonicalStructure<T>` carries a
+/// `PhantomData` marker for the type and lifetime parameters and the generated implementations repeat the bounds of the
+/// original type. They additionally require `MyWrapper<T>: Clone + Debug + Send + Sync`. Since a `static` cannot depend on
+/// generic parameters, `structure_ref` is only generated for non-generic types.
 #[proc_macro_derive(CanonicalStructure, attributes(canonical_structure))]
 pub fn derive_newtype(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    TokenStream::from(canonical_structure(&input).expanded)
+}
+
+/// The output of `#[derive(CanonicalStructure)]` together with what is needed to implement further signatures for the generated structure.
+struct CanonicalStructureExpansion {
+    expanded: proc_macro2::TokenStream,
+    newtype_name: Ident,
+    elem: proc_macro2::TokenStream,
+    impl_generics: proc_macro2::TokenStream,
+    ty_generics: proc_macro2::TokenStream,
+    where_clause: syn::WhereClause,
+}
 
-    let name = input.ident;
-    let vis = input.vis;
+fn canonical_structure(input: &DeriveInput) -> CanonicalStructureExpansion {
+    let name = &input.ident;
+    let vis = &input.vis;
     let newtype_name = Ident::new(&format!("{name}CanonicalStructure"), name.span());
 
+    let is_generic = !input.generics.params.is_empty();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let elem = quote! { #name #ty_generics };
+    let mut where_clause = where_clause.cloned().unwrap_or_else(|| syn::WhereClause {
+        where_token: Default::default(),
+        predicates: Punctuated::new(),
+    });
+    if is_generic {
+        where_clause
+            .predicates
+            .push(syn::parse_quote! { #elem: Clone + std::fmt::Debug + Send + Sync });
+    }
+
+    let where_clause_with = |predicate: syn::WherePredicate| {
+        let mut where_clause = where_clause.clone();
+        where_clause.predicates.push(predicate);
+        where_clause
+    };
+
     let has_eq = has_option(&input.attrs, "eq");
     let has_partial_ord = has_option(&input.attrs, "partial_ord");
     let has_ord = has_option(&input.attrs, "ord");
 
     let impl_eq_signature = if has_eq {
+        let where_clause = where_clause_with(syn::parse_quote! { #elem: Eq });
         quote! {
-            impl EqSignature for #newtype_name
-                where #name: Eq
+            impl #impl_generics EqSignature for #newtype_name #ty_generics
+                #where_clause
             {
                 fn equal(&self, a: &Self::Elem, b: &Self::Elem) -> bool {
                     a == b
@@ -149,9 +188,10 @@ pub fn derive_newtype(input: TokenStream) -> TokenStream {
     };
 
     let impl_partial_ord_signature = if has_partial_ord {
+        let where_clause = where_clause_with(syn::parse_quote! { #elem: Ord });
         quote! {
-            impl PartialOrdSignature for #newtype_name
-                where #name: Ord
+            impl #impl_generics PartialOrdSignature for #newtype_name #ty_generics
+                #where_clause
             {
                 fn partial_cmp(&self, a: &Self::Elem, b: &Self::Elem) -> Option<std::cmp::Ordering> {
                     Some(Ord::cmp(a, b))
@@ -163,9 +203,10 @@ pub fn derive_newtype(input: TokenStream) -> TokenStream {
     };
 
     let impl_ord_signature = if has_ord {
+        let where_clause = where_clause_with(syn::parse_quote! { #elem: Ord });
         quote! {
-            impl OrdSignature for #newtype_name
-                where #name: Ord
+            impl #impl_generics OrdSignature for #newtype_name #ty_generics
+                #where_clause
             {
                 fn cmp(&self, a: &Self::Elem, b: &Self::Elem) -> std::cmp::Ordering {
                     Ord::cmp(a, b)
@@ -181,20 +222,91 @@ pub fn derive_newtype(input: TokenStream) -> TokenStream {
         quote! {}
     };
 
-    let expanded = quote! {
-        #[derive(Debug, Clone, PartialEq, Eq)]
-        #vis struct #newtype_name {}
+    let structure_definition = if is_generic {
+        // `fn() -> _` keeps the structure `Send + Sync` and covariant whatever the parameters are
+        let markers = input
+            .generics
+            .params
+            .iter()
+            .filter_map(|param| match param {
+                GenericParam::Type(type_param) => {
+                    let ident = &type_param.ident;
+                    Some(quote! { #ident })
+                }
+                GenericParam::Lifetime(lifetime_param) => {
+                    let lifetime = &lifetime_param.lifetime;
+                    Some(quote! { &#lifetime () })
+                }
+                GenericParam::Const(_) => None,
+            });
+        let generic_params = &input.generics.params;
+        let where_clause = &input.generics.where_clause;
+        quote! {
+            #vis struct #newtype_name <#generic_params> #where_clause {
+                _marker: std::marker::PhantomData<fn() -> (#(#markers,)*)>,
+            }
+
+            impl #impl_generics #newtype_name #ty_generics #where_clause {
+                fn new() -> Self {
+                    Self {
+                        _marker: std::marker::PhantomData,
+                    }
+                }
+            }
+
+            impl #impl_generics std::fmt::Debug for #newtype_name #ty_generics #where_clause {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str(stringify!(#newtype_name))
+                }
+            }
+
+            impl #impl_generics Clone for #newtype_name #ty_generics #where_clause {
+                fn clone(&self) -> Self {
+                    Self::new()
+                }
+            }
 
-        impl #newtype_name {
-            fn new() -> Self {
-                Self {}
+            impl #impl_generics PartialEq for #newtype_name #ty_generics #where_clause {
+                fn eq(&self, _other: &Self) -> bool {
+                    true
+                }
             }
+
+            impl #impl_generics Eq for #newtype_name #ty_generics #where_clause {}
         }
+    } else {
+        quote! {
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #vis struct #newtype_name {}
 
-        impl Signature for #newtype_name {}
+            impl #newtype_name {
+                fn new() -> Self {
+                    Self {}
+                }
+            }
+        }
+    };
 
-        impl SetSignature for #newtype_name {
-            type Elem = #name;
+    let impl_structure_ref = if is_generic {
+        quote! {}
+    } else {
+        quote! {
+            impl #name {
+                pub fn structure_ref() -> &'static #newtype_name{
+                    static CELL: std::sync::OnceLock<#newtype_name> = std::sync::OnceLock::new();
+                    CELL.get_or_init(|| #newtype_name::new())
+                }
+            }
+        }
+    };
+
+    let expanded = quote! {
+        #structure_definition
+
+        impl #impl_generics Signature for #newtype_name #ty_generics #where_clause {}
+
+        impl #impl_generics SetSignature for #newtype_name #ty_generics #where_clause {
+            type Elem = #elem;
 
             fn validate_element(&self, _x : &Self::Elem) -> Result<(), String> {
                 Ok(())
@@ -205,23 +317,150 @@ pub fn derive_newtype(input: TokenStream) -> TokenStream {
         #impl_partial_ord_signature
         #impl_ord_signature
 
-        impl MetaType for #name {
-            type Signature = #newtype_name;
+        impl #impl_generics MetaType for #elem #where_clause {
+            type Signature = #newtype_name #ty_generics;
 
             fn structure() -> Self::Signature {
                 #newtype_name::new()
             }
         }
 
-        impl #name {
-            pub fn structure_ref() -> &'static #newtype_name{
-                static CELL: std::sync::OnceLock<#newtype_name> = std::sync::OnceLock::new();
-                CELL.get_or_init(|| #newtype_name::new())
+        #impl_structure_ref
+    };
+
+    CanonicalStructureExpansion {
+        expanded,
+        newtype_name,
+        elem,
+        impl_generics: quote! { #impl_generics },
+        ty_generics: quote! { #ty_generics },
+        where_clause,
+    }
+}
+
+/// Generate a canonical commutative ring structure for a type `T` by decorating it with `#[derive(CanonicalRingStructure)]`.
+///
+/// This generates everything `#[derive(CanonicalStructure)]` does, including the optional structure requested by `#[canonical_structure(...)]`,
+/// together with an implementation of `RingSignature` using the arithmetic operators of `T`.
+/// It requires `T: Add<Output = T> + Mul<Output = T> + Neg<Output = T> + From<u8>`, where `0` and `1` are obtained from `From<u8>`.
+/// The operators must make `T` into a commutative ring.
+/// Requires the ring signature traits which `RingSignature` depends on to be in scope.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(Debug, Clone, PartialEq, Eq, CanonicalRingStructure)]
+/// #[canonical_structure(eq)]
+/// struct Wrapper<T>(T);
+///
+/// // impl Add, Mul, Neg and From<u8> for Wrapper<T>
+/// ```
+/// `#[derive(CanonicalRingStructure)]` additionally generates the following
+/// ```rust,ignore
+/// impl<T> ZeroSignature for WrapperCanonicalStructure<T>
+/// where
+///     Wrapper<T>: Add<Output = Wrapper<T>> + Mul<Output = Wrapper<T>> + Neg<Output = Wrapper<T>> + From<u8>,
+/// {
+///     fn zero(&self) -> Self::Elem {
+///         From::from(0u8)
+///     }
+/// }
+///
+/// impl<T> AdditionSignature for WrapperCanonicalStructure<T>
+/// where
+///     Wrapper<T>: Add<Output = Wrapper<T>> + Mul<Output = Wrapper<T>> + Neg<Output = Wrapper<T>> + From<u8>,
+/// {
+///     fn add(&self, a: &Self::Elem, b: &Self::Elem) -> Self::Elem {
+///         a.clone() + b.clone()
+///     }
+/// }
+///
+/// // and so on for each of the signatures which make up `RingSignature`
+/// ```
+#[proc_macro_derive(CanonicalRingStructure, attributes(canonical_structure))]
+pub fn derive_canonical_ring_structure(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let CanonicalStructureExpansion {
+        expanded,
+        newtype_name,
+        elem,
+        impl_generics,
+        ty_generics,
+        mut where_clause,
+    } = canonical_structure(&input);
+    where_clause.predicates.push(syn::parse_quote! {
+        #elem: std::ops::Add<Output = #elem>
+            + std::ops::Mul<Output = #elem>
+            + std::ops::Neg<Output = #elem>
+            + From<u8>
+    });
+
+    let marker_signatures = [
+        quote! { RinglikeSpecializationSignature },
+        quote! { AdditiveMonoidSignature },
+        quote! { CommutativeMultiplicationSignature },
+        quote! { MultiplicativeMonoidSignature },
+        quote! { MultiplicativeAbsorptionMonoidSignature },
+        quote! { LeftDistributiveMultiplicationOverAddition },
+        quote! { RightDistributiveMultiplicationOverAddition },
+        quote! { SemiRingSignature },
+        quote! { RingSignature },
+    ]
+    .into_iter()
+    .map(|signature| {
+        quote! {
+            impl #impl_generics #signature for #newtype_name #ty_generics #where_clause {}
+        }
+    });
+
+    quote! {
+        #expanded
+
+        #(#marker_signatures)*
+
+        impl #impl_generics ZeroSignature for #newtype_name #ty_generics #where_clause {
+            fn zero(&self) -> Self::Elem {
+                From::from(0u8)
+            }
+        }
+
+        impl #impl_generics OneSignature for #newtype_name #ty_generics #where_clause {
+            fn one(&self) -> Self::Elem {
+                From::from(1u8)
+            }
+        }
+
+        impl #impl_generics AdditionSignature for #newtype_name #ty_generics #where_clause {
+            fn add(&self, a: &Self::Elem, b: &Self::Elem) -> Self::Elem {
+                a.clone() + b.clone()
+            }
+        }
+
+        impl #impl_generics CancellativeAdditionSignature for #newtype_name #ty_generics #where_clause {
+            fn try_sub(&self, a: &Self::Elem, b: &Self::Elem) -> Option<Self::Elem> {
+                Some(a.clone() + -b.clone())
+            }
+        }
+
+        impl #impl_generics TryNegateSignature for #newtype_name #ty_generics #where_clause {
+            fn try_neg(&self, a: &Self::Elem) -> Option<Self::Elem> {
+                Some(-a.clone())
+            }
+        }
+
+        impl #impl_generics AdditiveGroupSignature for #newtype_name #ty_generics #where_clause {
+            fn neg(&self, a: &Self::Elem) -> Self::Elem {
+                -a.clone()
             }
         }
-    };
 
-    TokenStream::from(expanded)
+        impl #impl_generics MultiplicationSignature for #newtype_name #ty_generics #where_clause {
+            fn mul(&self, a: &Self::Elem, b: &Self::Elem) -> Self::Elem {
+                a.clone() * b.clone()
+            }
+        }
+    }
+    .into()
 }
 
 /// In a structure trait decorated with `#[proc_macro_attribute]`, decorate a method with `#[skip_meta]` to exclude it from the auto-generated a meta structure trait.
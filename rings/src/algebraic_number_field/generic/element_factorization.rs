@@ -0,0 +1,348 @@
+use crate::{
+    algebraic_number_field::{
+        AlgebraicIntegerRingSignature, AlgebraicNumberFieldSignature, OrderIdeal,
+        OrderIdealsStructure, OrderWithBasis, RingOfIntegersIntegralExtensionWithIdeals,
+        RingOfIntegersToAlgebraicNumberFieldInclusion,
+    },
+    num_theory::integer_ideal::IntegerIdealsStructure,
+    structure::*,
+};
+use algebraeon_structures::*;
+use itertools::Itertools;
+
+/// The number of candidate elements examined when searching for a generator of an ideal in a field with infinitely many units.
+const GENERATOR_SEARCH_BUDGET: usize = 10000;
+
+/// The outcome of searching for a generator of a nonzero ideal.
+#[derive(Debug, Clone)]
+pub enum PrincipalIdealTestResult {
+    /// The ideal is principal and generated by this element.
+    Principal(Vec<Integer>),
+    /// The ideal is certainly not principal.
+    NonPrincipal,
+    /// No generator was found, but the search was not exhaustive.
+    Unknown,
+}
+
+/// The reason a nonzero element could not be factored into prime elements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ElementFactorizationError {
+    /// The element is zero.
+    Zero,
+    /// Some prime ideal factor is certainly not principal.
+    NonPrincipalFactor,
+    /// No generator was found for some prime ideal factor, but it may still be principal.
+    Unknown,
+}
+
+/// A factorization `unit * p_1^k_1 * ... * p_r^k_r` of a nonzero element of a ring of integers into a unit and pairwise non-associate prime elements.
+#[derive(Debug, Clone)]
+pub struct ElementFactorization {
+    unit: Vec<Integer>,
+    powers: Vec<(Vec<Integer>, Natural)>,
+}
+
+impl ElementFactorization {
+    pub fn unit(&self) -> &Vec<Integer> {
+        &self.unit
+    }
+
+    /// The prime elements and their multiplicities.
+    pub fn powers(&self) -> &Vec<(Vec<Integer>, Natural)> {
+        &self.powers
+    }
+
+    pub fn into_unit_and_powers(self) -> (Vec<Integer>, Vec<(Vec<Integer>, Natural)>) {
+        (self.unit, self.powers)
+    }
+}
+
+impl<K: AlgebraicNumberFieldSignature, KB: BorrowedStructure<K>> OrderWithBasis<K, KB, true>
+where
+    for<'a> RingOfIntegersIntegralExtensionWithIdeals<
+        K,
+        OrderWithBasis<K, KB, true>,
+        &'a OrderWithBasis<K, KB, true>,
+        RingOfIntegersToAlgebraicNumberFieldInclusion<
+            K,
+            OrderWithBasis<K, KB, true>,
+            &'a OrderWithBasis<K, KB, true>,
+        >,
+        IntegerIdealsStructure<IntegerCanonicalStructure>,
+        &'a OrderWithBasis<K, KB, true>,
+        OrderIdealsStructure<K, KB, true, &'a OrderWithBasis<K, KB, true>>,
+    >: IntegralClosureExtension<Z = IntegerCanonicalStructure, R = OrderWithBasis<K, KB, true>>
+        + DedekindDomainExtension<
+            IntegerCanonicalStructure,
+            &'a OrderWithBasis<K, KB, true>,
+            IdealsZ = IntegerIdealsStructure<IntegerCanonicalStructure>,
+            IdealsR = OrderIdealsStructure<K, KB, true, &'a OrderWithBasis<K, KB, true>>,
+        >,
+{
+    /// Search for a generator of a nonzero ideal.
+    ///
+    /// An element of the ideal generates it exactly when the absolute value of its norm equals the norm of the ideal.
+    /// When the ring of integers has finitely many units, which is the case for `Q` and imaginary quadratic fields, the elements of the ideal with that norm form a finite set which is searched exhaustively, so the answer is always certified.
+    /// Otherwise a bounded number of small integer combinations of the basis of the ideal are tried and [`PrincipalIdealTestResult::Unknown`] is returned if none of them is a generator.
+    ///
+    /// # Panics
+    /// If `ideal` is the zero ideal.
+    pub fn find_ideal_generator(&self, ideal: &OrderIdeal) -> PrincipalIdealTestResult {
+        let n = self.n();
+        let basis = ideal.basis().expect("ideal must be nonzero");
+        let norm = Rational::from(self.ideals().norm(ideal));
+        let anf = AlgebraicIntegerRingSignature::anf(self);
+        let anf_over_q = anf.inbound_finite_dimensional_rational_extension();
+        let combination = |coeffs: &[Integer]| {
+            (0..n)
+                .map(|i| {
+                    coeffs
+                        .iter()
+                        .zip(basis.iter())
+                        .map(|(c, v)| c * &v[i])
+                        .fold(Integer::ZERO, |acc, t| acc + t)
+                })
+                .collect::<Vec<_>>()
+        };
+        let is_generator = |x: &Vec<Integer>| anf_over_q.norm(&self.to_anf(x)).abs() == norm;
+
+        if n == 1 {
+            return PrincipalIdealTestResult::Principal(basis[0].clone());
+        }
+        if n == 2 && anf.discriminant() < Integer::ZERO {
+            // N(c_0 v_0 + c_1 v_1) = a c_0^2 + b c_0 c_1 + c c_1^2 is positive definite with discriminant -d < 0
+            // so on N = norm of the ideal we have |c_0| <= sqrt(4 c N / d) and |c_1| <= sqrt(4 a N / d)
+            let form_norm = |coeffs: [i64; 2]| {
+                anf_over_q.norm(&self.to_anf(&combination(&coeffs.map(Integer::from))))
+            };
+            let a = form_norm([1, 0]);
+            let c = form_norm([0, 1]);
+            let b = form_norm([1, 1]) - &a - &c;
+            let d = Rational::from(4) * &a * &c - &b * &b;
+            let bound = |x: &Rational| {
+                let r: Integer = (Rational::from(4) * x * &norm / &d).floor();
+                Integer::from(Abs::abs(&r).sqrt_floor())
+            };
+            let (bound_0, bound_1) = (bound(&c), bound(&a));
+            let mut c_0 = -bound_0.clone();
+            while c_0 <= bound_0 {
+                let mut c_1 = -bound_1.clone();
+                while c_1 <= bound_1 {
+                    let x = combination(&[c_0.clone(), c_1.clone()]);
+                    if is_generator(&x) {
+                        return PrincipalIdealTestResult::Principal(x);
+                    }
+                    c_1 += Integer::ONE;
+                }
+                c_0 += Integer::ONE;
+            }
+            return PrincipalIdealTestResult::NonPrincipal;
+        }
+
+        // search boxes of increasing size, only visiting the new elements on the boundary of each box
+        let mut examined = 0;
+        for b in 1i64.. {
+            for coeffs in (0..n).map(|_| -b..=b).multi_cartesian_product() {
+                if coeffs.iter().all(|c| c.abs() < b) {
+                    continue;
+                }
+                if examined == GENERATOR_SEARCH_BUDGET {
+                    return PrincipalIdealTestResult::Unknown;
+                }
+                examined += 1;
+                let x = combination(&coeffs.into_iter().map(Integer::from).collect::<Vec<_>>());
+                if is_generator(&x) {
+                    return PrincipalIdealTestResult::Principal(x);
+                }
+            }
+        }
+        unreachable!()
+    }
+
+    /// Factor a nonzero element into a unit and powers of prime elements.
+    ///
+    /// The principal ideal generated by `alpha` is factored into prime ideals and a generator is found for each of them using [`Self::find_ideal_generator`].
+    /// In particular, this only succeeds for every nonzero element when the ring of integers is a unique factorization domain.
+    pub fn factorize_element(
+        &self,
+        alpha: &Vec<Integer>,
+    ) -> Result<ElementFactorization, ElementFactorizationError> {
+        let ideals = self.ideals();
+        let prime_powers = ideals
+            .factor(&ideals.principal_ideal(alpha))
+            .into_powers()
+            .ok_or(ElementFactorizationError::Zero)?;
+        let mut powers = vec![];
+        for (prime_ideal, k) in prime_powers {
+            match self.find_ideal_generator(&prime_ideal) {
+                PrincipalIdealTestResult::Principal(p) => powers.push((p, k)),
+                PrincipalIdealTestResult::NonPrincipal => {
+                    return Err(ElementFactorizationError::NonPrincipalFactor);
+                }
+                PrincipalIdealTestResult::Unknown => {
+                    return Err(ElementFactorizationError::Unknown);
+                }
+            }
+        }
+        let anf = AlgebraicIntegerRingSignature::anf(self);
+        let product = powers.iter().fold(self.one(), |acc, (p, k)| {
+            self.mul(&acc, &self.nat_pow(p, k))
+        });
+        let unit = self
+            .try_from_anf(
+                &anf.try_divide(&self.to_anf(alpha), &self.to_anf(&product))
+                    .unwrap(),
+            )
+            .unwrap();
+        debug_assert!(self.is_unit(&unit));
+        Ok(ElementFactorization { unit, powers })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{polynomial::Polynomial, structure::IntoErgonomic};
+
+    #[test]
+    fn factorize_in_gaussian_integers() {
+        let x = Polynomial::<Rational>::var().into_ergonomic();
+        let anf = (x.pow(2) + 1)
+            .into_verbose()
+            .algebraic_number_field()
+            .unwrap();
+        let roi = anf.ring_of_integers();
+        let i = roi.try_from_anf(&x.clone().into_verbose()).unwrap();
+        let one_plus_i = roi.try_from_anf(&(1 + &x).into_verbose()).unwrap();
+        let minus_i = roi.neg(&i);
+
+        // 2 = -i * (1+i)^2
+        assert!(roi.equal(
+            &roi.from_int(2),
+            &roi.mul(&minus_i, &roi.nat_pow(&one_plus_i, &Natural::TWO))
+        ));
+
+        let (unit, powers) = roi
+            .factorize_element(&roi.from_int(2))
+            .unwrap()
+            .into_unit_and_powers();
+        assert_eq!(powers.len(), 1);
+        let (p, k) = &powers[0];
+        assert_eq!(*k, Natural::TWO);
+        // the prime is an associate of 1+i
+        assert!(
+            roi.is_unit(
+                &roi.try_from_anf(
+                    &anf.try_divide(&roi.to_anf(p), &roi.to_anf(&one_plus_i))
+                        .unwrap()
+                )
+                .unwrap()
+            )
+        );
+        assert!(roi.is_unit(&unit));
+        assert!(roi.equal(&roi.from_int(2), &roi.mul(&unit, &roi.nat_pow(p, k))));
+
+        // 15 + 5i = (1+i)(2+i)(2-i)^2 up to units
+        let alpha = roi.try_from_anf(&(15 + 5 * &x).into_verbose()).unwrap();
+        let f = roi.factorize_element(&alpha).unwrap();
+        let mut ks = f
+            .powers()
+            .iter()
+            .map(|(_, k)| k.clone())
+            .collect::<Vec<_>>();
+        ks.sort();
+        assert_eq!(ks, vec![Natural::ONE, Natural::ONE, Natural::TWO]);
+        assert!(roi.equal(
+            &alpha,
+            &f.powers().iter().fold(f.unit().clone(), |acc, (p, k)| {
+                roi.mul(&acc, &roi.nat_pow(p, k))
+            })
+        ));
+
+        // units factor trivially
+        let f = roi.factorize_element(&i).unwrap();
+        assert!(f.powers().is_empty());
+        assert!(roi.equal(f.unit(), &i));
+
+        assert_eq!(
+            roi.factorize_element(&roi.zero()).unwrap_err(),
+            ElementFactorizationError::Zero
+        );
+    }
+
+    #[test]
+    fn factorize_in_non_ufd() {
+        let x = Polynomial::<Rational>::var().into_ergonomic();
+        let anf = (x.pow(2) + 5)
+            .into_verbose()
+            .algebraic_number_field()
+            .unwrap();
+        let roi = anf.ring_of_integers();
+
+        // (3) = P Q with neither P nor Q principal
+        assert_eq!(
+            roi.factorize_element(&roi.from_int(3)).unwrap_err(),
+            ElementFactorizationError::NonPrincipalFactor
+        );
+        // (11) is prime
+        let f = roi.factorize_element(&roi.from_int(11)).unwrap();
+        assert_eq!(f.powers().len(), 1);
+        assert_eq!(f.powers()[0].1, Natural::ONE);
+        // 1 + sqrt(-5) has norm 6 but (2) is not principal
+        let alpha = roi.try_from_anf(&(1 + &x).into_verbose()).unwrap();
+        assert_eq!(
+            roi.factorize_element(&alpha).unwrap_err(),
+            ElementFactorizationError::NonPrincipalFactor
+        );
+    }
+
+    #[test]
+    fn find_ideal_generator() {
+        // Z[sqrt(-5)] has class number 2
+        let x = Polynomial::<Rational>::var().into_ergonomic();
+        let anf = (x.pow(2) + 5)
+            .into_verbose()
+            .algebraic_number_field()
+            .unwrap();
+        let roi = anf.ring_of_integers();
+        let ideals = roi.ideals();
+        let sqrt_minus_5 = roi.try_from_anf(&x.clone().into_verbose()).unwrap();
+        let p2 = ideals.generated_ideal(vec![roi.from_int(2), roi.add(&roi.one(), &sqrt_minus_5)]);
+        assert!(matches!(
+            roi.find_ideal_generator(&p2),
+            PrincipalIdealTestResult::NonPrincipal
+        ));
+        // P^2 = (2)
+        match roi.find_ideal_generator(&ideals.mul(&p2, &p2)) {
+            PrincipalIdealTestResult::Principal(g) => {
+                assert!(
+                    roi.is_unit(
+                        &roi.try_from_anf(
+                            &anf.try_divide(&roi.to_anf(&g), &anf.from_int(2)).unwrap()
+                        )
+                        .unwrap()
+                    )
+                );
+            }
+            _ => panic!(),
+        }
+
+        // Z[sqrt(2)] has infinitely many units and 7 = (3 + sqrt(2))(3 - sqrt(2))
+        let anf = (x.pow(2) - 2)
+            .into_verbose()
+            .algebraic_number_field()
+            .unwrap();
+        let roi = anf.ring_of_integers();
+        let f = roi.factorize_element(&roi.from_int(7)).unwrap();
+        assert_eq!(f.powers().len(), 2);
+        for (p, k) in f.powers() {
+            assert_eq!(*k, Natural::ONE);
+            assert_eq!(
+                anf.inbound_finite_dimensional_rational_extension()
+                    .norm(&roi.to_anf(p))
+                    .abs(),
+                Rational::from(7)
+            );
+        }
+    }
+}
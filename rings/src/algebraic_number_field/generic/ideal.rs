@@ -1,6 +1,6 @@
 use crate::algebraic_number_field::{
     FullRankIntegerSubmoduleWithBasisSignature, RingOfIntegersIntegralExtensionWithIdeals,
-    RingOfIntegersToAlgebraicNumberFieldInclusion,
+    RingOfIntegersToAlgebraicNumberFieldInclusion, SplittingType,
 };
 use crate::{
     algebraic_number_field::{
@@ -418,6 +418,21 @@ impl<
             OrderIdeal::NonZero(i) => Cow::Borrowed(i),
         }
     }
+
+    /// The product of `ideals`, computed by multiplying the products of the two halves recursively.
+    ///
+    /// Multiplying left to right makes one factor grow with every step while the other stays small,
+    /// whereas splitting in half keeps the two factors of each multiplication of similar size.
+    pub fn ideal_product(&self, mut ideals: Vec<OrderIdeal>) -> OrderIdeal {
+        match ideals.len() {
+            0 => self.one(),
+            1 => ideals.pop().unwrap(),
+            n => {
+                let right = ideals.split_off(n / 2);
+                self.mul(&self.ideal_product(ideals), &self.ideal_product(right))
+            }
+        }
+    }
 }
 
 impl<
@@ -764,6 +779,83 @@ impl<
     }
 }
 
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> OrderIdealsStructure<K, KB, MAXIMAL, OB>
+{
+    /// Given ideals I and J, find x in I and y in J such that x + y = 1.
+    /// Returns None if I and J are not coprime, i.e. if I + J is not the unit ideal.
+    pub fn coprime_ideals_bezout(
+        &self,
+        a: &OrderIdeal,
+        b: &OrderIdeal,
+    ) -> Option<(Vec<Integer>, Vec<Integer>)> {
+        debug_assert!(self.validate_element(a).is_ok());
+        debug_assert!(self.validate_element(b).is_ok());
+        let mut rows = vec![];
+        let a_basis = a.basis().unwrap_or_default();
+        let b_basis = b.basis().unwrap_or_default();
+        rows.extend(a_basis.iter().cloned());
+        rows.extend(b_basis.iter().cloned());
+        if rows.is_empty() {
+            return None;
+        }
+        let coeffs = Matrix::from_rows(rows).row_solve(&self.order().one())?;
+        let x = self.order().sum(
+            &a_basis
+                .iter()
+                .zip(coeffs.iter())
+                .map(|(v, c)| self.order().mul(&self.order().from_int(c), v))
+                .collect::<Vec<_>>(),
+        );
+        let y = self.order().sub(&self.order().one(), &x);
+        debug_assert!(self.contains_element(a, &x));
+        debug_assert!(self.contains_element(b, &y));
+        Some((x, y))
+    }
+
+    /// Chinese remainder theorem for ideals.
+    ///
+    /// Given pairs `(a_i, I_i)` where the ideals `I_i` are pairwise coprime, return an element `x` such that `x = a_i mod I_i` for all `i`.
+    /// The returned element is reduced modulo the product of the `I_i`.
+    /// Returns None if the ideals are not pairwise coprime.
+    pub fn ideal_crt(&self, pairs: &[(Vec<Integer>, OrderIdeal)]) -> Option<Vec<Integer>> {
+        let mut x = self.order().zero();
+        for (i, (a, ideal)) in pairs.iter().enumerate() {
+            debug_assert!(self.order().validate_element(a).is_ok());
+            let others = self.product(
+                &pairs
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| *j != i)
+                    .map(|(_, (_, other))| other)
+                    .collect::<Vec<_>>(),
+            );
+            // e = 0 mod I_j for j != i and e = 1 mod I_i
+            let (_, e) = self.coprime_ideals_bezout(ideal, &others)?;
+            self.order().add_mut(&mut x, &self.order().mul(a, &e));
+        }
+        let x = match self.product(&pairs.iter().map(|(_, ideal)| ideal).collect::<Vec<_>>()) {
+            OrderIdeal::Zero => x,
+            OrderIdeal::NonZero(integer_submodule) => {
+                self.order()
+                    .free_integer_submodule_restructure()
+                    .submodules()
+                    .reduce_element(&integer_submodule, &x)
+                    .1
+            }
+        };
+        #[cfg(debug_assertions)]
+        for (a, ideal) in pairs {
+            debug_assert!(self.contains_element(ideal, &self.order().sub(&x, a)));
+        }
+        Some(x)
+    }
+}
+
 // only maximal orders are Dedekind
 impl<
     K: AlgebraicNumberFieldSignature,
@@ -936,21 +1028,58 @@ where
             IdealsR = OrderIdealsStructure<K, KB, true, &'a OrderWithBasis<K, KB, true>>,
         >,
 {
+    /// The order of the multiplicative group of the quotient modulo `prime_ideal` raised to the power `exponent`, that is `N(P)^(e-1) (N(P) - 1)`.
+    ///
+    /// `prime_ideal` must be a nonzero prime ideal and `exponent` must be at least `1`.
+    pub fn euler_phi_formula(&self, prime_ideal: &OrderIdeal, exponent: &Natural) -> Natural {
+        assert!(exponent >= &Natural::ONE);
+        let norm = self.norm(prime_ideal);
+        (&norm - Natural::ONE) * norm.pow(&(exponent - Natural::ONE))
+    }
+
     /// The order of the multiplicative group of the quotient modulo the ideal.
+    ///
+    /// This is the product of `euler_phi_formula` over the prime power factors of the ideal,
+    /// generalizing Euler's totient function from the integers to rings of integers.
+    /// Returns `None` for the zero ideal.
     pub fn euler_phi(&self, ideal: &OrderIdeal) -> Option<Natural> {
         Some(
             self.factor(ideal)
                 .into_powers()?
                 .iter()
-                .map(|(prime_ideal, exponent)| {
-                    let norm = self.norm(prime_ideal);
-                    let e_minus_1 = exponent - Natural::ONE;
-                    (&norm - Natural::ONE) * norm.pow(&e_minus_1)
-                })
+                .map(|(prime_ideal, exponent)| self.euler_phi_formula(prime_ideal, exponent))
                 .fold(Natural::ONE, |acc, x| acc * x),
         )
     }
 
+    /// The order of the unit group of the quotient modulo the ideal. The same as `euler_phi`.
+    pub fn unit_group_order(&self, ideal: &OrderIdeal) -> Option<Natural> {
+        self.euler_phi(ideal)
+    }
+
+    /// How the prime `p` factors into prime ideals when the order is the ring of integers of a quadratic number field.
+    ///
+    /// Returns `None` if the number field does not have degree `2`.
+    pub fn prime_splitting_type(&self, p: &Natural) -> Option<SplittingType> {
+        if AlgebraicIntegerRingSignature::n(self.order()) != 2 {
+            return None;
+        }
+        let roi_to_anf = RingOfIntegersToAlgebraicNumberFieldInclusion::from_ring_of_integers(
+            self.order().clone(),
+        );
+        let sq = roi_to_anf.zq_extension().into_with_ideals();
+        let factors = sq.factor_prime_ideal(p.clone()).into_factors();
+        match factors.as_slice() {
+            [_, _] => Some(SplittingType::Split),
+            [f] if f.power == Natural::TWO => Some(SplittingType::Ramified),
+            [f] => {
+                debug_assert_eq!(f.residue_class_degree, 2);
+                Some(SplittingType::Inert)
+            }
+            _ => unreachable!(),
+        }
+    }
+
     /// generate all ideals of norm equal to n
     pub fn all_ideals_norm_eq<'a>(
         &'a self,
@@ -975,23 +1104,83 @@ where
                                 .collect(),
                         )
                         .map(|idxs| {
-                            self.product(
-                                &idxs
-                                    .into_iter()
+                            self.ideal_product(
+                                idxs.into_iter()
                                     .map(|i| primes_over_p[i].prime_ideal.clone())
-                                    .collect::<Vec<_>>(),
+                                    .collect(),
                             )
                         })
                         .collect::<Vec<OrderIdeal>>()
                     })
                     .multi_cartesian_product()
-                    .map(|ideals| self.product(&ideals)),
+                    .map(|ideals| self.ideal_product(ideals)),
             )
         } else {
             Box::new(vec![self.zero()].into_iter())
         }
     }
 
+    /// All ideals of norm equal to `n`.
+    ///
+    /// `n` is factored once and each prime `p | n` is split once. The ideals are then built up one prime at a time so that partial products are shared rather than recomputed for every combination.
+    pub fn ideals_of_norm_exactly(&self, n: &Natural) -> Vec<OrderIdeal> {
+        let Some(n) = Integer::structure().ideals().factor(n).into_powers() else {
+            return vec![self.zero()];
+        };
+        let roi_to_anf = RingOfIntegersToAlgebraicNumberFieldInclusion::from_ring_of_integers(
+            self.order().clone(),
+        );
+        let sq = roi_to_anf.zq_extension().into_with_ideals();
+
+        let mut ideals = vec![self.one()];
+        for (p, k) in n {
+            let k: usize = k.try_into().unwrap();
+            let primes_over_p = sq.factor_prime_ideal(p).into_factors();
+            // the ideals of norm p^k are the products of primes over p whose residue class degrees sum to k
+            let p_part_ideals = num_partitions_part_pool(
+                k,
+                primes_over_p
+                    .iter()
+                    .map(|f| f.residue_class_degree)
+                    .collect(),
+            )
+            .map(|idxs| {
+                self.ideal_product(
+                    idxs.into_iter()
+                        .map(|i| primes_over_p[i].prime_ideal.clone())
+                        .collect(),
+                )
+            })
+            .collect::<Vec<_>>();
+            ideals = ideals
+                .iter()
+                .flat_map(|a| p_part_ideals.iter().map(|b| self.mul(a, b)))
+                .collect();
+        }
+        ideals
+    }
+
+    /// All prime ideals of norm at most `b`, ordered by norm.
+    pub fn prime_ideals_of_norm_at_most(&self, b: &Natural) -> Vec<OrderIdeal> {
+        let roi_to_anf = RingOfIntegersToAlgebraicNumberFieldInclusion::from_ring_of_integers(
+            self.order().clone(),
+        );
+        let sq = roi_to_anf.zq_extension().into_with_ideals();
+
+        // a prime ideal over p with residue class degree f has norm p^f
+        let mut prime_ideals = vec![];
+        for p in primes().map(Natural::from).take_while(|p| p <= b) {
+            for f in sq.factor_prime_ideal(p.clone()).into_factors() {
+                let norm = p.pow(&Natural::from(f.residue_class_degree));
+                if norm <= *b {
+                    prime_ideals.push((norm, f.prime_ideal));
+                }
+            }
+        }
+        prime_ideals.sort_by(|(a, _), (b, _)| a.cmp(b));
+        prime_ideals.into_iter().map(|(_, i)| i).collect()
+    }
+
     /// generate all non-zero ideals of norm at most n
     pub fn all_nonzero_ideals_norm_le<'a>(
         &'a self,
@@ -1276,7 +1465,9 @@ impl<
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{parsing::parse_rational_polynomial, polynomial::*};
+    use crate::{
+        algebraic_number_field::splitting_type, parsing::parse_rational_polynomial, polynomial::*,
+    };
 
     #[test]
     fn ring_of_integers_ideals() {
@@ -1449,6 +1640,122 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_prime_ideals_of_norm_at_most() {
+        let x = &Polynomial::<Rational>::var().into_ergonomic();
+        let b = Natural::from(20u32);
+
+        // in Z[i] the prime ideals of norm at most 20 are
+        // (1 + i) of norm 2, two each over 5, 13 and 17, and (3) of norm 9
+        let anf = (x.pow(2) + 1)
+            .into_verbose()
+            .algebraic_number_field()
+            .unwrap();
+        let roi = anf.into_ring_of_integers();
+        let roi_ideals = roi.ideals();
+        let primes = roi_ideals.prime_ideals_of_norm_at_most(&b);
+        assert_eq!(primes.len(), 8);
+        assert_eq!(
+            primes
+                .iter()
+                .map(|p| roi_ideals.norm(p))
+                .collect::<Vec<_>>(),
+            [2u32, 5, 5, 9, 13, 13, 17, 17].map(Natural::from).to_vec()
+        );
+        for p in &primes {
+            assert!(roi_ideals.is_irreducible(p));
+        }
+
+        // in Z[sqrt(-5)] 2 and 5 ramify, 3 and 7 split and 11, 13, 17 and 19 are inert
+        let anf = (x.pow(2) + 5)
+            .into_verbose()
+            .algebraic_number_field()
+            .unwrap();
+        let roi = anf.into_ring_of_integers();
+        let roi_ideals = roi.ideals();
+        let primes = roi_ideals.prime_ideals_of_norm_at_most(&b);
+        assert_eq!(
+            primes
+                .iter()
+                .map(|p| roi_ideals.norm(p))
+                .collect::<Vec<_>>(),
+            [2u32, 3, 3, 5, 7, 7].map(Natural::from).to_vec()
+        );
+
+        // the prime ideals are exactly the prime ideals among all ideals of norm at most 20
+        assert_eq!(
+            roi_ideals
+                .all_nonzero_ideals_norm_le(&b)
+                .filter(|i| roi_ideals.is_irreducible(i))
+                .count(),
+            primes.len()
+        );
+        assert_eq!(
+            roi_ideals
+                .ideals_of_norm_exactly(&Natural::from(6u32))
+                .len(),
+            2
+        );
+        assert_eq!(
+            roi_ideals
+                .ideals_of_norm_exactly(&Natural::from(11u32))
+                .len(),
+            0
+        );
+        for n in 0..50u32 {
+            assert_eq!(
+                roi_ideals.ideals_of_norm_exactly(&Natural::from(n)).len(),
+                roi_ideals.all_ideals_norm_eq(&Natural::from(n)).count()
+            );
+        }
+        assert!(
+            roi_ideals
+                .prime_ideals_of_norm_at_most(&Natural::ONE)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_ideal_product() {
+        let x = &Polynomial::<Rational>::var().into_ergonomic();
+        let anf = (x.pow(2) + 1)
+            .into_verbose()
+            .algebraic_number_field()
+            .unwrap();
+        let roi = anf.into_ring_of_integers();
+        let roi_ideals = roi.ideals();
+        let primes = roi_ideals.prime_ideals_of_norm_at_most(&Natural::from(100u32));
+        let ideals = (0..20)
+            .map(|i| primes[(7 * i) % primes.len()].clone())
+            .collect::<Vec<_>>();
+
+        let product = roi_ideals.ideal_product(ideals.clone());
+        assert!(roi_ideals.equal(&product, &roi_ideals.product(&ideals)));
+        assert_eq!(
+            roi_ideals.norm(&product),
+            ideals
+                .iter()
+                .map(|i| roi_ideals.norm(i))
+                .fold(Natural::ONE, |a, b| a * b)
+        );
+        // the order of the factors does not matter
+        let mut reordered = ideals.clone();
+        reordered.reverse();
+        assert!(roi_ideals.equal(&roi_ideals.ideal_product(reordered.clone()), &product));
+        reordered.rotate_left(7);
+        assert!(roi_ideals.equal(&roi_ideals.ideal_product(reordered), &product));
+
+        assert!(roi_ideals.equal(&roi_ideals.ideal_product(vec![]), &roi_ideals.one()));
+        assert!(roi_ideals.equal(
+            &roi_ideals.ideal_product(vec![primes[3].clone()]),
+            &primes[3]
+        ));
+        assert!(roi_ideals.equal(
+            &roi_ideals.ideal_product(vec![primes[0].clone(), roi_ideals.zero()]),
+            &roi_ideals.zero()
+        ));
+    }
+
     #[test]
     fn test_euler_phi_of_principal_ideal() {
         let x = Polynomial::<Rational>::var().into_ergonomic();
@@ -1468,6 +1775,57 @@ mod tests {
         assert_eq!(phi, Natural::from(16u32));
     }
 
+    #[test]
+    fn test_euler_phi_against_quotient_ring() {
+        // Z[i]
+        let anf = parse_rational_polynomial("x^2+1", "x")
+            .unwrap()
+            .algebraic_number_field()
+            .unwrap();
+        let roi = anf.ring_of_integers();
+        let roi_ideals = roi.ideals();
+
+        assert_eq!(roi_ideals.euler_phi(&roi_ideals.zero()), None);
+
+        for n in 1u32..=13 {
+            for ideal in roi_ideals.all_ideals_norm_eq(&Natural::from(n)) {
+                // N O is contained in the ideal, so each residue class modulo the ideal
+                // appears n times among the n^2 representatives of O / N O
+                let n = n as i64;
+                let mut unit_count = 0;
+                for a in 0..n {
+                    for b in 0..n {
+                        let x =
+                            roi_ideals.principal_ideal(&vec![Integer::from(a), Integer::from(b)]);
+                        if roi_ideals.equal(&roi_ideals.add(&ideal, &x), &roi_ideals.one()) {
+                            unit_count += 1;
+                        }
+                    }
+                }
+                assert_eq!(unit_count % n, 0);
+                let phi = roi_ideals.euler_phi(&ideal).unwrap();
+                assert_eq!(phi, Natural::from((unit_count / n) as u64));
+                assert_eq!(roi_ideals.unit_group_order(&ideal).unwrap(), phi);
+            }
+        }
+
+        // (3) is prime of norm 9 and (1 + i) is prime of norm 2
+        let three = roi_ideals.principal_ideal(&roi.from_int(3));
+        assert_eq!(
+            roi_ideals.euler_phi_formula(&three, &Natural::from(2u32)),
+            Natural::from(72u32)
+        );
+        let one_plus_i = roi_ideals.principal_ideal(&vec![Integer::ONE, Integer::ONE]);
+        assert_eq!(
+            roi_ideals.euler_phi_formula(&one_plus_i, &Natural::ONE),
+            Natural::ONE
+        );
+        assert_eq!(
+            roi_ideals.euler_phi_formula(&one_plus_i, &Natural::from(5u32)),
+            Natural::from(16u32)
+        );
+    }
+
     #[test]
     fn test_is_square_ideal() {
         let x = Polynomial::<Rational>::var().into_ergonomic();
@@ -1513,6 +1871,127 @@ mod tests {
         assert!(!roi_ideals.is_squarefree(&gaussian_prime_square));
     }
 
+    #[test]
+    fn test_ideal_crt() {
+        // Z[sqrt(-5)]
+        let anf = parse_rational_polynomial("x^2+5", "x")
+            .unwrap()
+            .algebraic_number_field()
+            .unwrap();
+        let roi = anf.ring_of_integers();
+        let roi_ideals = roi.ideals();
+        let elem = |s: &str| {
+            roi.try_from_anf(&parse_rational_polynomial(s, "a").unwrap())
+                .unwrap()
+        };
+
+        // the non-principal prime ideals above 2 and 3 and the principal ideal (7)
+        let p2 = roi_ideals.generated_ideal(vec![elem("2"), elem("1+a")]);
+        let p3 = roi_ideals.generated_ideal(vec![elem("3"), elem("1+a")]);
+        let q7 = roi_ideals.principal_ideal(&elem("7"));
+
+        let pairs = vec![
+            (elem("1"), p2.clone()),
+            (elem("a"), p3.clone()),
+            (elem("2-3*a"), q7.clone()),
+        ];
+        let x = roi_ideals.ideal_crt(&pairs).unwrap();
+        for (a, ideal) in &pairs {
+            assert!(roi_ideals.contains_element(ideal, &roi.sub(&x, a)));
+        }
+
+        // the solution is only determined modulo the product so try some more
+        for (a, b) in [("0", "1"), ("1", "0"), ("5+a", "-2*a")] {
+            let pairs = vec![(elem(a), p3.clone()), (elem(b), q7.clone())];
+            let x = roi_ideals.ideal_crt(&pairs).unwrap();
+            for (a, ideal) in &pairs {
+                assert!(roi_ideals.contains_element(ideal, &roi.sub(&x, a)));
+            }
+        }
+
+        // (2) and p2 are not coprime
+        assert!(
+            roi_ideals
+                .ideal_crt(&[
+                    (elem("1"), p2.clone()),
+                    (elem("0"), roi_ideals.principal_ideal(&elem("2")))
+                ])
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_ideal_lcm_gcd_coprime() {
+        // Z[i]
+        let anf = parse_rational_polynomial("x^2+1", "x")
+            .unwrap()
+            .algebraic_number_field()
+            .unwrap();
+        let roi = anf.ring_of_integers();
+        let roi_ideals = roi.ideals();
+        let ideal = |s: &str| {
+            roi_ideals.principal_ideal(
+                &roi.try_from_anf(&parse_rational_polynomial(s, "i").unwrap())
+                    .unwrap(),
+            )
+        };
+
+        // (2) = (1+i)^2
+        assert!(roi_ideals.equal(
+            &roi_ideals.ideal_lcm(&ideal("2"), &ideal("1+i")),
+            &ideal("2")
+        ));
+        assert!(roi_ideals.equal(
+            &roi_ideals.ideal_gcd(&ideal("2"), &ideal("1+i")),
+            &ideal("1+i")
+        ));
+        assert!(!roi_ideals.are_coprime_ideals(&ideal("2"), &ideal("1+i")));
+        // (5) = (1+2i)(1-2i)
+        assert!(roi_ideals.equal(
+            &roi_ideals.ideal_lcm(&ideal("1+2*i"), &ideal("1-2*i")),
+            &ideal("5")
+        ));
+        assert!(roi_ideals.are_coprime_ideals(&ideal("1+2*i"), &ideal("1-2*i")));
+        assert!(roi_ideals.equal(&roi_ideals.ideal_lcm(&ideal("2"), &ideal("3")), &ideal("6")));
+        assert!(roi_ideals.are_coprime_ideals(&ideal("2"), &ideal("3")));
+        assert!(roi_ideals.equal(&roi_ideals.ideal_gcd(&ideal("6"), &ideal("4")), &ideal("2")));
+        assert!(roi_ideals.equal(
+            &roi_ideals.ideal_lcm(&ideal("6"), &ideal("4")),
+            &ideal("12")
+        ));
+
+        // Z[sqrt(-5)]
+        let anf = parse_rational_polynomial("x^2+5", "x")
+            .unwrap()
+            .algebraic_number_field()
+            .unwrap();
+        let roi = anf.ring_of_integers();
+        let roi_ideals = roi.ideals();
+        let elem = |s: &str| {
+            roi.try_from_anf(&parse_rational_polynomial(s, "a").unwrap())
+                .unwrap()
+        };
+
+        // (2) = p2^2, (3) = p3 p3' and (1+a) = p2 p3
+        let p2 = roi_ideals.generated_ideal(vec![elem("2"), elem("1+a")]);
+        let p3 = roi_ideals.generated_ideal(vec![elem("3"), elem("1+a")]);
+        let two = roi_ideals.principal_ideal(&elem("2"));
+        let three = roi_ideals.principal_ideal(&elem("3"));
+        let one_plus_a = roi_ideals.principal_ideal(&elem("1+a"));
+
+        assert!(roi_ideals.equal(&roi_ideals.ideal_gcd(&two, &one_plus_a), &p2));
+        assert!(roi_ideals.equal(
+            &roi_ideals.ideal_lcm(&two, &one_plus_a),
+            &roi_ideals.mul(&two, &p3)
+        ));
+        assert!(!roi_ideals.are_coprime_ideals(&two, &one_plus_a));
+        assert!(roi_ideals.equal(&roi_ideals.ideal_gcd(&three, &one_plus_a), &p3));
+        assert!(roi_ideals.are_coprime_ideals(&p2, &p3));
+        assert!(roi_ideals.equal(&roi_ideals.ideal_lcm(&p2, &p3), &one_plus_a));
+        assert!(roi_ideals.are_coprime_ideals(&two, &three));
+        assert!(!roi_ideals.are_coprime_ideals(&p2, &p2));
+    }
+
     #[test]
     fn test_two_generators_for_unit_ideal() {
         // there was a bug in `ideal_two_generators` where it would panic if and only if the input ideal was the unit ideal
@@ -1534,4 +2013,40 @@ mod tests {
             &roi.ideals().generated_ideal(vec![a, b])
         ));
     }
+
+    #[test]
+    fn test_prime_splitting_type() {
+        let x = &Polynomial::<Rational>::var().into_ergonomic();
+        let anf = (x.pow(2) + 5)
+            .into_verbose()
+            .algebraic_number_field()
+            .unwrap();
+        let roi = anf.ring_of_integers();
+        let roi_ideals = roi.ideals();
+        for p in [2u32, 3, 5, 7, 11, 13, 17, 19, 23, 29] {
+            let p = Natural::from(p);
+            assert_eq!(
+                roi_ideals.prime_splitting_type(&p),
+                Some(splitting_type(&Integer::from(-5), &p))
+            );
+        }
+        assert_eq!(
+            roi_ideals.prime_splitting_type(&Natural::from(2u32)),
+            Some(SplittingType::Ramified)
+        );
+        assert_eq!(
+            roi_ideals.prime_splitting_type(&Natural::from(3u32)),
+            Some(SplittingType::Split)
+        );
+
+        let anf = (x.pow(3) - 2)
+            .into_verbose()
+            .algebraic_number_field()
+            .unwrap();
+        let roi = anf.ring_of_integers();
+        assert_eq!(
+            roi.ideals().prime_splitting_type(&Natural::from(5u32)),
+            None
+        );
+    }
 }
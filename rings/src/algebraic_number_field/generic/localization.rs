@@ -0,0 +1,233 @@
+use crate::{
+    algebraic_number_field::{
+        AlgebraicIntegerRingSignature, AlgebraicNumberFieldSignature, OrderIdeal,
+        OrderQuotientRing, RingOfIntegersWithIntegralBasis,
+    },
+    structure::*,
+    valuation::Valuation,
+};
+use algebraeon_structures::*;
+use std::marker::PhantomData;
+
+/// The localization `O_P` of a ring of integers `O` at a non-zero prime ideal `P`.
+///
+/// Its elements are the `a / b` in the number field with `a, b` in `O` and `b` not in `P`, which are exactly the elements of non-negative `P`-adic valuation.
+/// It is a discrete valuation ring whose maximal ideal is generated by a uniformizer and whose residue field is `O / P`.
+#[derive(Debug, Clone)]
+pub struct LocalizedRingOfIntegers<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    OB: BorrowedStructure<RingOfIntegersWithIntegralBasis<K, KB>>,
+> {
+    _k: PhantomData<K>,
+    _kb: PhantomData<KB>,
+    roi: OB,
+    prime: OrderIdeal,
+    uniformizer: Vec<Integer>,
+}
+
+impl<K: AlgebraicNumberFieldSignature, KB: BorrowedStructure<K>>
+    RingOfIntegersWithIntegralBasis<K, KB>
+{
+    /// The localization at a non-zero prime ideal.
+    ///
+    /// Panics if `prime` is the zero ideal.
+    pub fn localize_at_prime(&self, prime: &OrderIdeal) -> LocalizedRingOfIntegers<K, KB, &Self> {
+        LocalizedRingOfIntegers::new(self, prime)
+    }
+
+    /// The localization at a non-zero prime ideal.
+    ///
+    /// Panics if `prime` is the zero ideal.
+    pub fn into_localize_at_prime(
+        self,
+        prime: &OrderIdeal,
+    ) -> LocalizedRingOfIntegers<K, KB, Self> {
+        LocalizedRingOfIntegers::new(self, prime)
+    }
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    OB: BorrowedStructure<RingOfIntegersWithIntegralBasis<K, KB>>,
+> LocalizedRingOfIntegers<K, KB, OB>
+{
+    fn new(roi: OB, prime: &OrderIdeal) -> Self {
+        let ideals = roi.borrow().ideals();
+        debug_assert!(ideals.validate_element(prime).is_ok());
+        assert!(!ideals.is_zero(prime), "cannot localize at the zero ideal");
+        debug_assert!(!ideals.equal(prime, &ideals.one()));
+        // if P = (a, b) then a and b can't both lie in P^2, since otherwise P = P^2
+        let prime_squared = ideals.mul(prime, prime);
+        let (a, b) = ideals.ideal_two_generators(prime);
+        let uniformizer = if ideals.contains_element(&prime_squared, &a) {
+            b
+        } else {
+            a
+        };
+        debug_assert!(!ideals.contains_element(&prime_squared, &uniformizer));
+        Self {
+            _k: PhantomData,
+            _kb: PhantomData,
+            roi,
+            prime: prime.clone(),
+            uniformizer,
+        }
+    }
+
+    pub fn ring_of_integers(&self) -> &RingOfIntegersWithIntegralBasis<K, KB> {
+        self.roi.borrow()
+    }
+
+    pub fn prime(&self) -> &OrderIdeal {
+        &self.prime
+    }
+
+    /// An element of `O` generating the maximal ideal of `O_P`, so it has valuation `1`.
+    pub fn uniformizer(&self) -> &Vec<Integer> {
+        &self.uniformizer
+    }
+
+    // the largest k such that P^k contains the element x of O, for x non-zero
+    fn roi_valuation(&self, x: &Vec<Integer>) -> Natural {
+        let ideals = self.ring_of_integers().ideals();
+        let mut k = Natural::ZERO;
+        let mut prime_to_the_k_plus_one = self.prime.clone();
+        while ideals.contains_element(&prime_to_the_k_plus_one, x) {
+            k += Natural::ONE;
+            prime_to_the_k_plus_one = ideals.mul(&prime_to_the_k_plus_one, &self.prime);
+        }
+        k
+    }
+
+    /// The `P`-adic valuation of an element of the number field.
+    pub fn valuation(&self, x: &K::Elem) -> Valuation {
+        let roi = self.ring_of_integers();
+        let anf = roi.anf();
+        if anf.is_zero(x) {
+            return Valuation::Infinity;
+        }
+        // x = m / d with m in O and d a non-zero integer
+        let d = if anf.is_algebraic_integer(x) {
+            Integer::ONE
+        } else {
+            anf.min_poly_denominator_lcm(x)
+        };
+        let m = roi
+            .try_from_anf(&anf.mul(x, &anf.from_int(d.clone())))
+            .unwrap();
+        Valuation::Finite(
+            Integer::from(self.roi_valuation(&m))
+                - Integer::from(self.roi_valuation(&roi.from_int(d))),
+        )
+    }
+
+    /// Whether `x` lies in `O_P`, meaning its valuation is non-negative.
+    pub fn contains(&self, x: &K::Elem) -> bool {
+        self.valuation(x) >= Valuation::Finite(Integer::ZERO)
+    }
+
+    /// Whether `x` is a unit of `O_P`, meaning its valuation is zero.
+    pub fn is_unit(&self, x: &K::Elem) -> bool {
+        self.valuation(x) == Valuation::Finite(Integer::ZERO)
+    }
+
+    /// The residue field `O_P / P O_P`, which is isomorphic to `O / P`.
+    pub fn residue_field(
+        &self,
+    ) -> OrderQuotientRing<K, KB, true, &RingOfIntegersWithIntegralBasis<K, KB>> {
+        self.ring_of_integers()
+            .quotient_ring_by_ideal(&self.prime)
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parsing::parse_rational_polynomial, polynomial::Polynomial};
+
+    #[test]
+    fn test_localize_gaussian_integers() {
+        // Z[i]
+        let anf = parse_rational_polynomial("x^2+1", "x")
+            .unwrap()
+            .algebraic_number_field()
+            .unwrap();
+        let roi = anf.ring_of_integers();
+        let roi_ideals = roi.ideals();
+        let elem = |a: i64, b: i64| roi.to_anf(&vec![Integer::from(a), Integer::from(b)]);
+        let fin = |v: i64| Valuation::Finite(Integer::from(v));
+
+        // (1 + i) is the prime above 2 and 2 = -i (1 + i)^2
+        let one_plus_i = roi_ideals.principal_ideal(&vec![Integer::ONE, Integer::ONE]);
+        let local = roi.localize_at_prime(&one_plus_i);
+        assert_eq!(local.valuation(&elem(1, 1)), fin(1));
+        assert_eq!(local.valuation(&elem(2, 0)), fin(2));
+        assert_eq!(local.valuation(&elem(3, 0)), fin(0));
+        assert_eq!(local.valuation(&elem(0, 1)), fin(0));
+        assert_eq!(local.valuation(&elem(0, 0)), Valuation::Infinity);
+        assert_eq!(local.valuation(&roi.to_anf(local.uniformizer())), fin(1));
+        let half = anf.try_reciprocal(&elem(2, 0)).unwrap();
+        assert_eq!(local.valuation(&half), fin(-2));
+        assert!(!local.contains(&half));
+        // (1 + i) / 3 lies in the maximal ideal, 3 / (1 - i) is not in the local ring, and 3 (1 + i) / (1 - i) is a unit
+        let a = anf.mul(&elem(1, 1), &anf.try_reciprocal(&elem(3, 0)).unwrap());
+        assert_eq!(local.valuation(&a), fin(1));
+        assert!(local.contains(&a) && !local.is_unit(&a));
+        let b = anf.mul(&elem(3, 0), &anf.try_reciprocal(&elem(1, -1)).unwrap());
+        assert_eq!(local.valuation(&b), fin(-1));
+        let b = anf.mul(&b, &elem(1, 1));
+        assert!(local.is_unit(&b));
+        assert_eq!(local.residue_field().size(), Natural::from(2u32));
+
+        // (3) is inert with residue field of order 9
+        let three = roi_ideals.principal_ideal(&roi.from_int(3));
+        let local = roi.localize_at_prime(&three);
+        assert_eq!(local.valuation(&elem(3, 0)), fin(1));
+        assert_eq!(local.valuation(&elem(9, 9)), fin(2));
+        assert_eq!(local.valuation(&elem(1, 1)), fin(0));
+        assert_eq!(local.valuation(&roi.to_anf(local.uniformizer())), fin(1));
+        let residue_field = local.residue_field();
+        assert_eq!(residue_field.size(), Natural::from(9u32));
+        for x in residue_field.list_all_elements() {
+            assert_eq!(
+                residue_field.try_reciprocal(&x).is_some(),
+                !residue_field.is_zero(&x)
+            );
+        }
+
+        // 5 = (2 + i)(2 - i) splits
+        let two_plus_i = roi_ideals.principal_ideal(&vec![Integer::from(2), Integer::ONE]);
+        let local = roi.localize_at_prime(&two_plus_i);
+        assert_eq!(local.valuation(&elem(5, 0)), fin(1));
+        assert_eq!(local.valuation(&elem(2, -1)), fin(0));
+        assert_eq!(local.valuation(&elem(3, 4)), fin(2));
+        assert_eq!(local.residue_field().size(), Natural::from(5u32));
+    }
+
+    #[test]
+    fn test_localize_non_monogenic_generator() {
+        // Q(sqrt(5)) has ring of integers Z[(1 + sqrt(5)) / 2] and 5 ramifies as (sqrt(5))^2
+        let anf = parse_rational_polynomial("x^2-5", "x")
+            .unwrap()
+            .algebraic_number_field()
+            .unwrap();
+        let roi = anf.ring_of_integers();
+        let roi_ideals = roi.ideals();
+        let sqrt5 = Polynomial::<Rational>::var();
+        let prime = roi_ideals.principal_ideal(&roi.try_from_anf(&sqrt5).unwrap());
+        let local = roi.localize_at_prime(&prime);
+        let fin = |v: i64| Valuation::Finite(Integer::from(v));
+        assert_eq!(local.valuation(&sqrt5), fin(1));
+        assert_eq!(local.valuation(&anf.from_int(5)), fin(2));
+        // the golden ratio is an algebraic integer with norm -1
+        let phi = anf.mul(
+            &anf.add(&anf.one(), &sqrt5),
+            &anf.try_reciprocal(&anf.from_int(2)).unwrap(),
+        );
+        assert!(local.is_unit(&phi));
+        assert_eq!(local.residue_field().size(), Natural::from(5u32));
+    }
+}
@@ -1,11 +1,17 @@
+mod element_factorization;
 mod ideal;
 mod integer_submodule;
+mod localization;
 mod order;
+mod quotient;
 mod ring_of_integer_extension;
 mod structure;
 
+pub use element_factorization::*;
 pub use ideal::*;
 pub use integer_submodule::*;
+pub use localization::*;
 pub use order::*;
+pub use quotient::*;
 pub use ring_of_integer_extension::*;
 pub use structure::*;
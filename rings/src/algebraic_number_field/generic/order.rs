@@ -12,13 +12,13 @@ use crate::{
         AdditionSignature, AdditiveGroupSignature, AdditiveMonoidSignature,
         CancellativeAdditionSignature, CancellativeMultiplicationSignature, CharZeroRingSignature,
         CharacteristicSignature, CommutativeMultiplicationSignature, DedekindDomainSignature,
-        FinitelyFreeModuleSignature, IntegralDomainSignature,
-        LeftDistributiveMultiplicationOverAddition, MultiplicationSignature,
-        MultiplicativeAbsorptionMonoidSignature, MultiplicativeIntegralMonoidSignature,
-        MultiplicativeMonoidSignature, OneSignature, RightDistributiveMultiplicationOverAddition,
-        RingSignature, RingToIdealsSignature, RinglikeSpecializationSignature, SemiModuleSignature,
-        SemiRingSignature, TryNegateSignature, TryReciprocalSignature, ZeroEqSignature,
-        ZeroSignature,
+        FiniteDimensionalFieldExtension, FinitelyFreeModuleSignature, IdealsArithmeticSignature,
+        IntegralDomainSignature, LeftDistributiveMultiplicationOverAddition,
+        MultiplicationSignature, MultiplicativeAbsorptionMonoidSignature,
+        MultiplicativeIntegralMonoidSignature, MultiplicativeMonoidSignature, OneSignature,
+        RightDistributiveMultiplicationOverAddition, RingSignature, RingToIdealsSignature,
+        RinglikeSpecializationSignature, SemiModuleSignature, SemiRingSignature,
+        TryNegateSignature, TryReciprocalSignature, ZeroEqSignature, ZeroSignature,
     },
 };
 use algebraeon_structures::*;
@@ -547,6 +547,57 @@ impl<K: AlgebraicNumberFieldSignature, KB: BorrowedStructure<K>> OrderWithBasis<
 
         ideal
     }
+
+    /// The codifferent `{x in K : Tr(xy) in Z for all y in O_K}`.
+    ///
+    /// This is the fractional ideal dual to the ring of integers with respect to the trace form.
+    /// The returned basis is the dual of the integral basis, so `Tr(b_i c_j) = δ_ij`.
+    pub fn codifferent(&self) -> FullRankIntegerSubmoduleWithBasis<K, K> {
+        let anf = AlgebraicIntegerRingSignature::anf(self);
+        let basis = FullRankIntegerSubmoduleWithBasisSignature::basis(self);
+        let n = anf.n();
+        let trace_form_inv = anf
+            .inbound_finite_dimensional_rational_extension()
+            .trace_form_matrix(basis)
+            .inv()
+            .unwrap();
+        let dual_basis = (0..n)
+            .map(|j| {
+                anf.sum(
+                    &(0..n)
+                        .map(|i| {
+                            anf.mul(&anf.from_rat(trace_form_inv.at(i, j).unwrap()), &basis[i])
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+        FullRankIntegerSubmoduleWithBasis::new_unchecked(anf.clone(), dual_basis)
+    }
+
+    /// The different ideal, the inverse of the codifferent.
+    ///
+    /// Its norm is the absolute value of the discriminant and the prime ideals dividing it are exactly the ramified primes.
+    pub fn different(&self) -> OrderIdeal {
+        let ideals = self.ideals();
+        let d = AlgebraicIntegerRingSignature::anf(self).discriminant();
+        // O_K has index |d| in the codifferent so d times the codifferent is an integral ideal
+        let d_codifferent = ideals.from_integer_span(
+            self.codifferent()
+                .basis()
+                .iter()
+                .map(|c| {
+                    self.try_from_anf(
+                        &AlgebraicIntegerRingSignature::anf(self)
+                            .mul(&AlgebraicIntegerRingSignature::anf(self).from_int(&d), c),
+                    )
+                    .unwrap()
+                })
+                .collect(),
+        );
+        // different = (d) / (d * codifferent)
+        ideals.quotient(&ideals.principal_ideal(&self.from_int(d)), &d_codifferent)
+    }
 }
 
 #[cfg(test)]
@@ -723,6 +774,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn different_and_codifferent() {
+        // Q[i]
+        let anf = parse_rational_polynomial("x^2+1", "x")
+            .unwrap()
+            .algebraic_number_field()
+            .unwrap();
+        let roi = anf.ring_of_integers();
+        // the codifferent of Z[i] is (1/2)
+        let codifferent = roi.codifferent();
+        assert!(codifferent.contains_element(&parse_rational_polynomial("1/2", "x").unwrap()));
+        assert!(codifferent.contains_element(&parse_rational_polynomial("1/2*x", "x").unwrap()));
+        assert!(!codifferent.contains_element(&parse_rational_polynomial("1/4", "x").unwrap()));
+        // the different of Z[i] is (2i) = (2)
+        let different = roi.different();
+        assert!(
+            roi.ideals().equal(
+                &different,
+                &roi.ideals().principal_ideal(
+                    &roi.try_from_anf(&parse_rational_polynomial("2*x", "x").unwrap())
+                        .unwrap()
+                )
+            )
+        );
+        assert!(
+            roi.ideals()
+                .equal(&different, &roi.ideals().principal_ideal(&roi.from_int(2)))
+        );
+
+        // the norm of the different is the absolute value of the discriminant
+        for f in [
+            "x^2+1", "x^2-2", "x^2+3", "x^2-5", "x^3-x-1", "x^3-2", "x^4+1",
+        ] {
+            let anf = parse_rational_polynomial(f, "x")
+                .unwrap()
+                .algebraic_number_field()
+                .unwrap();
+            let roi = anf.ring_of_integers();
+            assert_eq!(
+                Integer::from(roi.ideals().norm(&roi.different())),
+                anf.discriminant().abs()
+            );
+        }
+    }
+
     #[test]
     fn order_conductor() {
         // Q[sqrt(-3)]
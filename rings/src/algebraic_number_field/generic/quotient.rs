@@ -0,0 +1,541 @@
+use crate::{
+    algebraic_number_field::{
+        AlgebraicNumberFieldSignature, FullRankIntegerSubmoduleWithBasisSignature, OrderIdeal,
+        OrderWithBasis,
+    },
+    linear::finitely_free_submodule::FinitelyFreeSubmodule,
+    structure::*,
+};
+use algebraeon_structures::*;
+use itertools::Itertools;
+use std::marker::PhantomData;
+
+/// The finite quotient ring `O/I` of an order `O` by a non-zero ideal `I`.
+///
+/// Elements are represented by canonical coset representatives with respect to the hermite normal form of `I`.
+/// With respect to the integral basis of `O` the `i`th coordinate of a representative lies in `[0, h_i)` where `h_i` is the `i`th diagonal entry of the hermite normal form of `I`.
+#[derive(Debug, Clone)]
+pub struct OrderQuotientRing<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> {
+    _k: PhantomData<K>,
+    _kb: PhantomData<KB>,
+    order: OB,
+    ideal: FinitelyFreeSubmodule<Integer>,
+}
+
+impl<K: AlgebraicNumberFieldSignature, KB: BorrowedStructure<K>, const MAXIMAL: bool>
+    OrderWithBasis<K, KB, MAXIMAL>
+{
+    /// The finite ring `O/I`, or `None` if `I` is the zero ideal.
+    pub fn quotient_ring_by_ideal(
+        &self,
+        ideal: &OrderIdeal,
+    ) -> Option<OrderQuotientRing<K, KB, MAXIMAL, &Self>> {
+        OrderQuotientRing::new(self, ideal)
+    }
+
+    /// The finite ring `O/I`, or `None` if `I` is the zero ideal.
+    pub fn into_quotient_ring_by_ideal(
+        self,
+        ideal: &OrderIdeal,
+    ) -> Option<OrderQuotientRing<K, KB, MAXIMAL, Self>> {
+        OrderQuotientRing::new(self, ideal)
+    }
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+    fn new(order: OB, ideal: &OrderIdeal) -> Option<Self> {
+        debug_assert!(order.borrow().ideals().validate_element(ideal).is_ok());
+        match ideal {
+            OrderIdeal::Zero => None,
+            OrderIdeal::NonZero(integer_submodule) => Some(Self {
+                _k: PhantomData,
+                _kb: PhantomData,
+                order,
+                ideal: integer_submodule.clone(),
+            }),
+        }
+    }
+
+    pub fn order(&self) -> &OrderWithBasis<K, KB, MAXIMAL> {
+        self.order.borrow()
+    }
+
+    /// The ideal `I` being quotiented by.
+    pub fn ideal(&self) -> OrderIdeal {
+        OrderIdeal::NonZero(self.ideal.clone())
+    }
+
+    /// The canonical representative of the coset `x + I`.
+    pub fn reduce(&self, x: &Vec<Integer>) -> Vec<Integer> {
+        debug_assert!(self.order().validate_element(x).is_ok());
+        self.order()
+            .free_integer_submodule_restructure()
+            .submodules()
+            .reduce_element(&self.ideal, x)
+            .1
+    }
+
+    /// The image of an element of the order in the quotient ring.
+    pub fn from_element(&self, x: &Vec<Integer>) -> Vec<Integer> {
+        self.reduce(x)
+    }
+
+    /// The canonical representative in the order of an element of the quotient ring.
+    pub fn to_element(&self, x: &Vec<Integer>) -> Vec<Integer> {
+        debug_assert!(self.validate_element(x).is_ok());
+        x.clone()
+    }
+
+    /// The diagonal entries of the hermite normal form of the ideal.
+    /// Their product is the norm of the ideal.
+    fn box_sizes(&self) -> Vec<Integer> {
+        let basis = self.ideal.basis();
+        debug_assert_eq!(basis.len(), self.order().n());
+        basis
+            .iter()
+            .enumerate()
+            .map(|(i, row)| row[i].clone())
+            .collect()
+    }
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> PartialEq for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.order == other.order
+            && self
+                .order()
+                .free_integer_submodule_restructure()
+                .submodules()
+                .equal(&self.ideal, &other.ideal)
+    }
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> Eq for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> Signature for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> SetSignature for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+    type Elem = Vec<Integer>;
+
+    fn validate_element(&self, x: &Self::Elem) -> Result<(), String> {
+        self.order().validate_element(x)?;
+        if *x != self.reduce(x) {
+            return Err("not a reduced coset representative".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> QuotientSetSignature<OrderWithBasis<K, KB, MAXIMAL>> for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+    fn pre_quotient_set(&self) -> &OrderWithBasis<K, KB, MAXIMAL> {
+        self.order()
+    }
+
+    fn project(&self, x: Vec<Integer>) -> Self::Elem {
+        self.reduce(&x)
+    }
+
+    fn project_ref(&self, x: &Vec<Integer>) -> Self::Elem {
+        self.reduce(x)
+    }
+
+    fn unproject(&self, x: Self::Elem) -> Vec<Integer> {
+        x
+    }
+
+    fn unproject_ref(&self, x: &Self::Elem) -> Vec<Integer> {
+        x.clone()
+    }
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> QuotientRingSignature<OrderWithBasis<K, KB, MAXIMAL>> for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> EqSignature for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+    fn equal(&self, a: &Self::Elem, b: &Self::Elem) -> bool {
+        debug_assert!(self.validate_element(a).is_ok());
+        debug_assert!(self.validate_element(b).is_ok());
+        a == b
+    }
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> RinglikeSpecializationSignature for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+    fn try_ring_restructure(&self) -> Option<impl EqSignature<Elem = Self::Elem> + RingSignature> {
+        Some(self.clone())
+    }
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> ZeroSignature for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+    fn zero(&self) -> Self::Elem {
+        self.order().zero()
+    }
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> AdditionSignature for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+    fn add(&self, a: &Self::Elem, b: &Self::Elem) -> Self::Elem {
+        self.reduce(&self.order().add(a, b))
+    }
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> CancellativeAdditionSignature for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+    fn try_sub(&self, a: &Self::Elem, b: &Self::Elem) -> Option<Self::Elem> {
+        Some(self.sub(a, b))
+    }
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> TryNegateSignature for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+    fn try_neg(&self, a: &Self::Elem) -> Option<Self::Elem> {
+        Some(self.neg(a))
+    }
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> AdditiveMonoidSignature for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> AdditiveGroupSignature for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+    fn neg(&self, a: &Self::Elem) -> Self::Elem {
+        self.reduce(&self.order().neg(a))
+    }
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> OneSignature for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+    fn one(&self) -> Self::Elem {
+        self.reduce(&self.order().one())
+    }
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> MultiplicationSignature for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+    fn mul(&self, a: &Self::Elem, b: &Self::Elem) -> Self::Elem {
+        self.reduce(&self.order().mul(a, b))
+    }
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> CommutativeMultiplicationSignature for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> MultiplicativeMonoidSignature for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> MultiplicativeAbsorptionMonoidSignature for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> LeftDistributiveMultiplicationOverAddition for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> RightDistributiveMultiplicationOverAddition for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> SemiRingSignature for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> RingSignature for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> CharacteristicSignature for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+    fn characteristic(&self) -> Natural {
+        // the characteristic is the positive generator of the integer ideal I ∩ Z
+        let submodules = self
+            .order()
+            .free_integer_submodule_restructure()
+            .into_submodules();
+        let one = self.order().one();
+        let integers = submodules.span(vec![&one]);
+        let intersection = submodules.intersect(self.ideal.clone(), integers);
+        debug_assert_eq!(intersection.rank(), 1);
+        let m = &intersection.basis()[0];
+        let i = one.iter().position(|c| *c != Integer::ZERO).unwrap();
+        (&m[i] / &one[i]).abs()
+    }
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> TryReciprocalSignature for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+    fn try_reciprocal(&self, a: &Self::Elem) -> Option<Self::Elem> {
+        // a is a unit iff (a) + I = (1) in which case ab + c = 1 for some b and some c in I
+        let ideals = self.order().ideals();
+        let (ab, _) = ideals.coprime_ideals_bezout(&ideals.principal_ideal(a), &self.ideal())?;
+        let inclusion = self.order().outbound_order_to_anf_inclusion();
+        let b = inclusion
+            .try_preimage(
+                &self
+                    .order()
+                    .anf()
+                    .try_divide(&inclusion.image(&ab), &inclusion.image(a))
+                    .unwrap(),
+            )
+            .unwrap();
+        Some(self.reduce(&b))
+    }
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> CountableSetSignature for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+    fn into_generate_all_elements(self) -> impl Iterator<Item = Self::Elem> {
+        self.box_sizes()
+            .into_iter()
+            .map(|h| {
+                (0usize..(&h.abs()).try_into().unwrap())
+                    .map(Integer::from)
+                    .collect::<Vec<_>>()
+            })
+            .multi_cartesian_product()
+    }
+
+    fn generate_all_elements(&self) -> impl Iterator<Item = Self::Elem> {
+        self.clone().into_generate_all_elements()
+    }
+}
+
+impl<
+    K: AlgebraicNumberFieldSignature,
+    KB: BorrowedStructure<K>,
+    const MAXIMAL: bool,
+    OB: BorrowedStructure<OrderWithBasis<K, KB, MAXIMAL>>,
+> FiniteSetSignature for OrderQuotientRing<K, KB, MAXIMAL, OB>
+{
+    fn size(&self) -> Natural {
+        self.box_sizes()
+            .into_iter()
+            .fold(Natural::ONE, |acc, h| acc * h.abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algebraic_number_field::AlgebraicIntegerRingSignature, parsing::parse_rational_polynomial,
+    };
+
+    #[test]
+    fn test_gaussian_integer_quotients() {
+        let anf = parse_rational_polynomial("x^2+1", "x")
+            .unwrap()
+            .algebraic_number_field()
+            .unwrap();
+        let roi = anf.ring_of_integers();
+        let roi_ideals = roi.ideals();
+        let elem = |s: &str| {
+            roi.try_from_anf(&parse_rational_polynomial(s, "a").unwrap())
+                .unwrap()
+        };
+
+        assert!(roi.quotient_ring_by_ideal(&roi_ideals.zero()).is_none());
+
+        // Z[i]/(1+i) is the field with 2 elements
+        let one_plus_i = roi_ideals.principal_ideal(&elem("1+a"));
+        let q = roi.quotient_ring_by_ideal(&one_plus_i).unwrap();
+        assert_eq!(q.size(), Natural::from(2u32));
+        assert_eq!(q.list_all_elements().len(), 2);
+        assert_eq!(q.characteristic(), Natural::from(2u32));
+
+        // (1+i)^2 = (2i) = (2) so Z[i]/(1+i)^2 has 4 elements
+        let one_plus_i_sq = roi_ideals.nat_pow(&one_plus_i, &Natural::from(2u32));
+        let q = roi.quotient_ring_by_ideal(&one_plus_i_sq).unwrap();
+        assert_eq!(q.size(), Natural::from(4u32));
+        assert_eq!(q.list_all_elements().len(), 4);
+        assert_eq!(q.characteristic(), Natural::from(2u32));
+        // 1+i is nilpotent
+        let x = q.from_element(&elem("1+a"));
+        assert!(!q.is_zero(&x));
+        assert!(q.is_zero(&q.mul(&x, &x)));
+
+        // Z[i]/(5) has 25 elements
+        let five = roi_ideals.principal_ideal(&elem("5"));
+        let q = roi.quotient_ring_by_ideal(&five).unwrap();
+        let elems = q.list_all_elements();
+        assert_eq!(q.size(), Natural::from(25u32));
+        assert_eq!(elems.len(), 25);
+        assert_eq!(q.characteristic(), Natural::from(5u32));
+        for x in &elems {
+            assert!(q.validate_element(x).is_ok());
+        }
+        // 5 is not prime in Z[i] so Z[i]/(5) has zero divisors
+        assert_eq!(elems.iter().filter(|x| q.is_unit(x)).count(), 16);
+        assert!(q.is_zero(&q.mul(&q.from_element(&elem("2+a")), &q.from_element(&elem("2-a")))));
+
+        // ring operations agree with those in Z[i]
+        for (a, b) in [("7+3*a", "-2+a"), ("1", "13*a"), ("-4-9*a", "6+6*a")] {
+            let a = elem(a);
+            let b = elem(b);
+            let qa = q.from_element(&a);
+            let qb = q.from_element(&b);
+            assert_eq!(q.add(&qa, &qb), q.from_element(&roi.add(&a, &b)));
+            assert_eq!(q.mul(&qa, &qb), q.from_element(&roi.mul(&a, &b)));
+            assert_eq!(q.neg(&qa), q.from_element(&roi.neg(&a)));
+            assert!(roi_ideals.contains_element(&five, &roi.sub(&q.to_element(&qa), &a)));
+        }
+        for x in &elems {
+            if let Some(y) = q.try_reciprocal(x) {
+                assert_eq!(q.mul(x, &y), q.one());
+            }
+        }
+    }
+}
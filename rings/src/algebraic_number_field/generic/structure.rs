@@ -5,8 +5,8 @@ use crate::{
     },
     structure::{
         AdditiveGroupSignature, CharZeroFieldSignature, CharZeroRingSignature,
-        DedekindDomainSignature, FieldOfFractionsInclusion, FiniteDimensionalFieldExtension,
-        FiniteRankFreeRingExtension, MetaGreatestCommonDivisorSignature, RingHomomorphism,
+        DedekindDomainSignature, Factored, FactoringMonoidSignature, FieldOfFractionsInclusion,
+        FiniteDimensionalFieldExtension, FiniteRankFreeRingExtension, RingHomomorphism,
     },
 };
 use algebraeon_structures::*;
@@ -38,6 +38,24 @@ pub trait AlgebraicNumberFieldSignature: CharZeroFieldSignature {
     /// Implementations should not compute this by constructing the ring of integers, as the constructor for a maximal OrderWithBasis calls this function to validate its input
     fn discriminant(&self) -> Integer;
 
+    /// The prime factorization of the discriminant, whose unit is its sign
+    fn discriminant_factored(&self) -> Factored<Integer, Natural> {
+        Integer::structure().factor(&self.discriminant())
+    }
+
+    /// The rational primes which ramify in this algebraic number field, which are exactly the primes dividing the discriminant, in increasing order
+    fn ramified_primes(&self) -> Vec<Natural> {
+        let mut primes = self
+            .discriminant_factored()
+            .into_distinct_irreducibles()
+            .unwrap()
+            .into_iter()
+            .map(|p| p.abs())
+            .collect::<Vec<_>>();
+        primes.sort();
+        primes
+    }
+
     /// A list of self.n() elements which generate the ring of integers as a Z-module
     fn integral_basis(&self) -> Vec<Self::Elem>;
 
@@ -64,11 +82,12 @@ pub trait AlgebraicNumberFieldSignature: CharZeroFieldSignature {
     /// It may well be >1 even when the element a is an algebraic integer.
     fn min_poly_denominator_lcm(&self, a: &Self::Elem) -> Integer {
         Integer::lcm_list(
-            self.inbound_finite_dimensional_rational_extension()
+            &self
+                .inbound_finite_dimensional_rational_extension()
                 .min_poly(a)
                 .coeffs()
                 .map(|c| Integer::from(c.denominator()))
-                .collect(),
+                .collect::<Vec<_>>(),
         )
     }
 
@@ -0,0 +1,180 @@
+use super::polynomial_quotient_number_field::AlgebraicNumberFieldPolynomialQuotientStructure;
+use crate::isolated_algebraic::{ComplexAlgebraic, RealAlgebraic};
+use crate::polynomial::*;
+use crate::structure::*;
+use algebraeon_structures::*;
+
+/// The `n`th cyclotomic polynomial `Φ_n`, the minimal polynomial of a primitive `n`th root of unity.
+///
+/// Panics if `n` is zero.
+pub fn cyclotomic_polynomial(n: usize) -> Polynomial<Integer> {
+    assert!(n > 0);
+    // x^d - 1 is the product of Φ_e over the divisors e of d, so compute Φ_d for the divisors d of n in increasing order
+    let divisors = (1..=n).filter(|d| n.is_multiple_of(*d)).collect::<Vec<_>>();
+    let mut polys: Vec<Polynomial<Integer>> = vec![];
+    for &d in &divisors {
+        let mut poly = Polynomial::add(
+            &Polynomial::var_pow(d),
+            &Polynomial::constant(Integer::from(-1)),
+        );
+        for (e, phi_e) in divisors.iter().zip(&polys) {
+            if d.is_multiple_of(*e) {
+                poly = Polynomial::try_divide(&poly, phi_e).unwrap();
+            }
+        }
+        polys.push(poly);
+    }
+    polys.pop().unwrap()
+}
+
+/// The cyclotomic field `Q(ζ_n)` as `Q[x] / Φ_n` together with the distinguished primitive root `ζ_n = e^(2πi/n)`.
+///
+/// The generator `x` of the field corresponds to `ζ_n` under the returned embedding.
+///
+/// Panics if `n` is zero.
+pub fn cyclotomic_field(
+    n: usize,
+) -> (
+    AlgebraicNumberFieldPolynomialQuotientStructure,
+    ComplexAlgebraic,
+) {
+    let modulus = cyclotomic_polynomial(n);
+    // e^(2πi/n) is the root with the smallest positive argument
+    let zeta = modulus
+        .all_complex_roots()
+        .into_iter()
+        .filter(|root| n <= 2 || root.imag_part() > RealAlgebraic::zero())
+        .max_by_key(|root| root.real_part())
+        .unwrap();
+    let anf = modulus
+        .apply_map(|c| Rational::from(c))
+        .algebraic_number_field()
+        .unwrap();
+    (anf, zeta)
+}
+
+/// The automorphism `ζ ↦ ζ^k` of a cyclotomic field `(anf, zeta)` returned by `cyclotomic_field`, given as the image of the generator.
+///
+/// Panics if `k` is not coprime to the order `n` of `zeta`.
+pub fn cyclotomic_automorphism(
+    anf: &AlgebraicNumberFieldPolynomialQuotientStructure,
+    zeta: &ComplexAlgebraic,
+    k: usize,
+) -> Polynomial<Rational> {
+    debug_assert_eq!(
+        zeta.clone().apply_poly(&anf.modulus()),
+        ComplexAlgebraic::zero()
+    );
+    // the generator corresponds to ζ, so n is its multiplicative order
+    // φ(n) >= sqrt(n/2) so n is at most 2 deg^2
+    let generator = anf.generator();
+    let max_n = 2 * anf.degree() * anf.degree();
+    let mut power = generator.clone();
+    let mut n = 1;
+    while !anf.is_one(&power) {
+        assert!(n < max_n, "the field is not cyclotomic");
+        power = anf.mul(&power, &generator);
+        n += 1;
+    }
+    // ζ^k is a primitive nth root of unity, so the image of ζ under an automorphism, exactly when k is coprime to n
+    assert_eq!(
+        Natural::gcd(&Natural::from(k), &Natural::from(n)),
+        Natural::ONE,
+        "k must be coprime to the order of zeta"
+    );
+    anf.nat_pow(&generator, &Natural::from(k))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebraic_number_field::AlgebraicNumberFieldSignature;
+    use crate::parsing::parse_integer_polynomial;
+
+    #[test]
+    fn test_cyclotomic_polynomial() {
+        for (n, f) in [
+            (1, "x-1"),
+            (2, "x+1"),
+            (4, "x^2+1"),
+            (6, "x^2-x+1"),
+            (7, "x^6+x^5+x^4+x^3+x^2+x+1"),
+            (12, "x^4-x^2+1"),
+            (36, "x^12-x^6+1"),
+            (
+                105,
+                "x^48+x^47+x^46-x^43-x^42-2*x^41-x^40-x^39+x^36+x^35+x^34+x^33+x^32+x^31-x^28-x^26-x^24-x^22-x^20+x^17+x^16+x^15+x^14+x^13+x^12-x^9-x^8-2*x^7-x^6-x^5+x^2+x+1",
+            ),
+        ] {
+            assert_eq!(
+                cyclotomic_polynomial(n),
+                parse_integer_polynomial(f, "x").unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_cyclotomic_field() {
+        let (_, zeta) = cyclotomic_field(2);
+        assert_eq!(zeta, ComplexAlgebraic::one().neg());
+        let (_, zeta) = cyclotomic_field(4);
+        assert_eq!(zeta, ComplexAlgebraic::i());
+
+        // Q(ζ_5) has discriminant 5^3
+        let (anf, _) = cyclotomic_field(5);
+        assert_eq!(anf.degree(), 4);
+        assert_eq!(anf.discriminant(), Integer::from(125));
+    }
+
+    #[test]
+    fn test_cyclotomic_automorphisms() {
+        // the Galois group of Q(ζ_7) is (Z/7Z)^* of order 6
+        let n = 7;
+        let (anf, zeta) = cyclotomic_field(n);
+        let automorphisms = (1..n)
+            .map(|k| cyclotomic_automorphism(&anf, &zeta, k))
+            .collect::<Vec<_>>();
+        assert_eq!(automorphisms.len(), 6);
+        for (i, a) in automorphisms.iter().enumerate() {
+            for b in &automorphisms[..i] {
+                assert!(!anf.equal(a, b));
+            }
+        }
+        assert!(anf.equal(&automorphisms[0], &anf.generator()));
+
+        // the image of the generator is ζ^k under the distinguished embedding
+        let complex = ComplexAlgebraic::structure();
+        for (k, a) in (1..n).zip(&automorphisms) {
+            assert_eq!(
+                zeta.clone().apply_poly(a),
+                complex.nat_pow(&zeta, &Natural::from(k))
+            );
+        }
+
+        // σ_a ∘ σ_b = σ_(ab mod n)
+        for n in [5, 7, 8] {
+            let (anf, zeta) = cyclotomic_field(n);
+            let units = (1..n)
+                .filter(|k| Natural::gcd(&Natural::from(*k), &Natural::from(n)) == Natural::ONE);
+            for a in units.clone() {
+                for b in units.clone() {
+                    let composed = anf.reduce(&Polynomial::compose(
+                        &cyclotomic_automorphism(&anf, &zeta, b),
+                        &cyclotomic_automorphism(&anf, &zeta, a),
+                    ));
+                    assert!(anf.equal(
+                        &composed,
+                        &cyclotomic_automorphism(&anf, &zeta, (a * b) % n)
+                    ));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_cyclotomic_automorphism_not_coprime() {
+        let (anf, zeta) = cyclotomic_field(6);
+        cyclotomic_automorphism(&anf, &zeta, 3);
+    }
+}
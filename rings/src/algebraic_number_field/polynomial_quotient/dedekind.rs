@@ -0,0 +1,105 @@
+use crate::polynomial::{Polynomial, ToPolynomialSignature};
+use crate::structure::*;
+use algebraeon_structures::*;
+
+/// The outcome of the Dedekind criterion for a monic integer polynomial `f` with root `a` at a prime `p`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DedekindResult {
+    /// `p` does not divide the index `[O_K : Z[a]]`.
+    Passes,
+    /// `p` divides the index `[O_K : Z[a]]`.
+    ///
+    /// The obstruction is the monic greatest common divisor `U` of `F`, `G` and `H` modulo `p` described in [`dedekind_criterion`], with coefficients in `[0, p)`.
+    Fails { obstruction: Polynomial<Integer> },
+}
+
+/// Decide whether the prime `p` divides the index `[O_K : Z[a]]` where `a` is a root of the monic irreducible polynomial `poly` and `K = Q(a)`.
+///
+/// Write `poly = g_1^e_1 ... g_k^e_k mod p` for distinct monic irreducible `g_i`, lift `G = g_1 ... g_k` and `H = g_1^(e_1 - 1) ... g_k^(e_k - 1)` to integer polynomials and let `F = (GH - poly) / p`.
+/// Then `p` divides the index if and only if `F`, `G` and `H` have a common factor modulo `p`.
+///
+/// # Panics
+/// If `poly` is not monic.
+pub fn dedekind_criterion(poly: &Polynomial<Integer>, p: &Natural) -> DedekindResult {
+    assert_eq!(poly.leading_coeff(), Some(&Integer::ONE));
+    let p = Integer::from(p);
+    let mod_p = Integer::structure().into_quotient_field_unchecked(p.clone());
+    let poly_mod_p = mod_p.polynomials();
+    let reduce =
+        |f: &Polynomial<Integer>| Polynomial::from_coeffs(f.coeffs().map(|c| c % &p).collect());
+
+    let mut g = Polynomial::<Integer>::one();
+    let mut h = Polynomial::<Integer>::one();
+    for (factor, power) in poly_mod_p.factor(poly).into_powers().unwrap() {
+        let factor = reduce(&factor);
+        g = Polynomial::mul(&g, &factor);
+        h = Polynomial::mul(&h, &factor.nat_pow(&(power - Natural::ONE)));
+    }
+    let f = Polynomial::from_coeffs(
+        Polynomial::add(&Polynomial::mul(&g, &h), &poly.neg())
+            .coeffs()
+            .map(|c| Integer::try_divide(c, &p).unwrap())
+            .collect(),
+    );
+
+    let u = poly_mod_p.gcd(&poly_mod_p.gcd(&f, &g), &h);
+    if poly_mod_p.degree(&u).unwrap() == 0 {
+        DedekindResult::Passes
+    } else {
+        let u = poly_mod_p.factorize_monic(&u).unwrap().into_monic_part();
+        DedekindResult::Fails {
+            obstruction: reduce(&u),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dedekind_criterion_cube_root_of_two() {
+        // Z[2^(1/3)] is the ring of integers of Q(2^(1/3))
+        let f = Polynomial::<Integer>::from_coeffs(vec![-2, 0, 0, 1]);
+        assert_eq!(
+            dedekind_criterion(&f, &Natural::TWO),
+            DedekindResult::Passes
+        );
+        assert_eq!(
+            dedekind_criterion(&f, &Natural::from(3u32)),
+            DedekindResult::Passes
+        );
+    }
+
+    #[test]
+    fn dedekind_criterion_quadratic() {
+        // -5 = 3 mod 4 so Z[sqrt(-5)] is the ring of integers of Q(sqrt(-5))
+        let f = Polynomial::<Integer>::from_coeffs(vec![5, 0, 1]);
+        assert_eq!(
+            dedekind_criterion(&f, &Natural::TWO),
+            DedekindResult::Passes
+        );
+
+        // -3 = 1 mod 4 so Z[sqrt(-3)] has index 2 in the ring of integers
+        let f = Polynomial::<Integer>::from_coeffs(vec![3, 0, 1]);
+        assert_eq!(
+            dedekind_criterion(&f, &Natural::TWO),
+            DedekindResult::Fails {
+                obstruction: Polynomial::from_coeffs(vec![1, 1])
+            }
+        );
+        assert_eq!(
+            dedekind_criterion(&f, &Natural::from(3u32)),
+            DedekindResult::Passes
+        );
+
+        // x^2 - 45 = x^2 mod 3 and Z[sqrt(45)] has index 6 in the ring of integers of Q(sqrt(5)), which is divisible by 3
+        let f = Polynomial::<Integer>::from_coeffs(vec![-45, 0, 1]);
+        assert_eq!(
+            dedekind_criterion(&f, &Natural::from(3u32)),
+            DedekindResult::Fails {
+                obstruction: Polynomial::from_coeffs(vec![0, 1])
+            }
+        );
+    }
+}
@@ -1,4 +1,7 @@
 use super::polynomial_quotient_number_field::AlgebraicNumberFieldPolynomialQuotientStructure;
+use crate::algebraic_number_field::{
+    AlgebraicIntegerRingSignature, RingOfIntegersWithIntegralBasis,
+};
 use crate::isolated_algebraic::ComplexAlgebraicCanonicalStructure;
 use crate::structure::*;
 use crate::{
@@ -11,7 +14,6 @@ use std::rc::Rc;
 #[derive(Debug, Clone)]
 pub struct EmbeddedAnf {
     // must have anf.modulus() equal to gen.min_poly()
-    #[allow(unused)]
     anf: Rc<AlgebraicNumberFieldPolynomialQuotientStructure>,
     generator: ComplexAlgebraic,
 }
@@ -55,6 +57,70 @@ impl AlgebraicNumberFieldPolynomialQuotientStructure {
         self.signature().1 == 0
     }
 
+    /// If complex conjugation restricts to the same automorphism of this field under every complex embedding, return it as a polynomial in the generator.
+    fn complex_conjugation(&self) -> Option<Polynomial<Rational>> {
+        let mut roots = self.modulus().primitive_part_fof().all_complex_roots();
+        let conj = as_poly_expr(&roots[0].conjugate(), &roots[0])?;
+        if roots
+            .iter_mut()
+            .all(|root| root.conjugate() == root.apply_poly(&conj))
+        {
+            Some(conj)
+        } else {
+            None
+        }
+    }
+
+    /// Whether this is a CM field, that is, a totally imaginary quadratic extension of a totally real field.
+    ///
+    /// This is the case exactly when the field is totally imaginary and complex conjugation induces the same automorphism under every complex embedding.
+    /// The fixed field of that automorphism is then the maximal totally real subfield.
+    pub fn is_cm_field(&self) -> bool {
+        self.signature().0 == 0 && self.complex_conjugation().is_some()
+    }
+
+    /// For a CM field, return the maximal totally real subfield together with a polynomial expressing its generator in terms of the generator of this field.
+    ///
+    /// Returns `None` if this is not a CM field.
+    pub fn totally_real_subfield(&self) -> Option<(Self, Polynomial<Rational>)> {
+        if self.signature().0 != 0 {
+            return None;
+        }
+        let conj = self.complex_conjugation()?;
+        // if a is the generator of a CM field K with totally real subfield K+ then (x - a)(x - conj(a)) is its minimal polynomial over K+
+        // so K+ is generated by a + conj(a) and a conj(a)
+        let mut a = self.modulus().primitive_part_fof().all_complex_roots()[0].clone();
+        let a_conj = a.apply_poly(&conj);
+        let (generator, _, _, _, _) = anf_pair_primitive_element_theorem(
+            &ComplexAlgebraic::add(&a, &a_conj),
+            &ComplexAlgebraic::mul(&a, &a_conj),
+        );
+        let embedding = as_poly_expr(&generator, &a).unwrap();
+        Some((generator.generated_algebraic_number_field(), embedding))
+    }
+
+    /// The sum of the images of `a` under all complex embeddings, which is its trace.
+    pub fn sum_over_embeddings(&self, a: &Polynomial<Rational>) -> Rational {
+        rational_value(
+            self.all_complex_embeddings()
+                .iter()
+                .fold(ComplexAlgebraic::zero(), |total, v| {
+                    ComplexAlgebraic::add(&total, &v.embed(a))
+                }),
+        )
+    }
+
+    /// The product of the images of `a` under all complex embeddings, which is its norm.
+    pub fn product_over_embeddings(&self, a: &Polynomial<Rational>) -> Rational {
+        rational_value(
+            self.all_complex_embeddings()
+                .iter()
+                .fold(ComplexAlgebraic::one(), |total, v| {
+                    ComplexAlgebraic::mul(&total, &v.embed(a))
+                }),
+        )
+    }
+
     pub fn is_totally_positive(&self, a: Polynomial<Rational>) -> bool {
         self.all_real_embeddings()
             .into_iter()
@@ -65,6 +131,39 @@ impl AlgebraicNumberFieldPolynomialQuotientStructure {
     }
 }
 
+fn rational_value(x: ComplexAlgebraic) -> Rational {
+    match x {
+        ComplexAlgebraic::Real(RealAlgebraic::Rational(x)) => x,
+        _ => panic!("expected a rational value"),
+    }
+}
+
+impl EmbeddedAnf {
+    pub fn anf(&self) -> &AlgebraicNumberFieldPolynomialQuotientStructure {
+        &self.anf
+    }
+
+    /// The image of the generator of the number field.
+    pub fn generator(&self) -> &ComplexAlgebraic {
+        &self.generator
+    }
+
+    /// The image of `a` under this embedding.
+    pub fn embed(&self, a: &Polynomial<Rational>) -> ComplexAlgebraic {
+        self.generator.clone().apply_poly(a)
+    }
+}
+
+impl<KB: BorrowedStructure<AlgebraicNumberFieldPolynomialQuotientStructure>>
+    RingOfIntegersWithIntegralBasis<AlgebraicNumberFieldPolynomialQuotientStructure, KB>
+{
+    /// The image of the element `a` of the ring of integers under the complex embedding `embedding` of its number field.
+    pub fn to_embedding(&self, a: &Vec<Integer>, embedding: &EmbeddedAnf) -> ComplexAlgebraic {
+        debug_assert_eq!(self.anf().modulus(), embedding.anf().modulus());
+        embedding.embed(&self.to_anf(a))
+    }
+}
+
 impl ComplexAlgebraic {
     pub fn generated_algebraic_number_field(
         &self,
@@ -154,6 +253,33 @@ pub fn as_poly_expr(
     None
 }
 
+/// The minimal polynomial of `element` over the embedded field `subfield`.
+///
+/// The output is monic with coefficients in `subfield`, represented as polynomials in its generator.
+pub fn min_poly_over_subfield(
+    element: &ComplexAlgebraic,
+    subfield: &EmbeddedAnf,
+) -> Polynomial<Polynomial<Rational>> {
+    //idea: factor the minimal polynomial of element over the subfield
+    //and find the irreducible factor which vanishes at element under the embedding
+    let gen_anf = subfield.anf.as_ref();
+    let gen_anf_poly = gen_anf.polynomials();
+
+    let element_min_poly = element
+        .min_poly()
+        .apply_map(|c| Polynomial::constant(c.clone()));
+
+    let element_min_poly_factored = gen_anf_poly.factor(&element_min_poly);
+    for (factor, _factor_mult) in element_min_poly_factored.into_powers().unwrap() {
+        debug_assert!(gen_anf_poly.is_monic(&factor));
+        let embedded_factor = factor.apply_map(|c| subfield.generator.clone().apply_poly(c));
+        if embedded_factor.evaluate(element) == ComplexAlgebraic::zero() {
+            return factor;
+        }
+    }
+    unreachable!()
+}
+
 pub fn anf_pair_primitive_element_theorem(
     a: &ComplexAlgebraic,
     b: &ComplexAlgebraic,
@@ -255,7 +381,8 @@ pub fn anf_multi_primitive_element_theorem(
 mod tests {
     use crate::{
         algebraic_number_field::{
-            AlgebraicNumberFieldSignature, FullRankIntegerSubmoduleWithBasisSignature,
+            AlgebraicIntegerRingSignature, AlgebraicNumberFieldSignature,
+            FullRankIntegerSubmoduleWithBasisSignature,
         },
         structure::IntoErgonomic,
     };
@@ -276,6 +403,98 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cm_fields() {
+        let x = &Polynomial::<Rational>::var().into_ergonomic();
+
+        // Q(i) is CM with totally real subfield Q
+        let anf = (x.pow(2) + 1)
+            .into_verbose()
+            .algebraic_number_field()
+            .unwrap();
+        assert!(anf.is_cm_field());
+        let (real_subfield, embedding) = anf.totally_real_subfield().unwrap();
+        assert_eq!(real_subfield.degree(), 1);
+        assert!(embedding.degree().unwrap_or(0) == 0);
+
+        let anf = (x.pow(2) + 5)
+            .into_verbose()
+            .algebraic_number_field()
+            .unwrap();
+        assert!(anf.is_cm_field());
+        assert_eq!(anf.totally_real_subfield().unwrap().0.degree(), 1);
+
+        // real quadratic fields are not CM
+        let anf = (x.pow(2) - 2)
+            .into_verbose()
+            .algebraic_number_field()
+            .unwrap();
+        assert!(!anf.is_cm_field());
+        assert!(anf.totally_real_subfield().is_none());
+
+        // Q(zeta_5) is CM with totally real subfield Q(sqrt(5)) generated by zeta_5 + zeta_5^-1
+        let anf = (x.pow(4) + x.pow(3) + x.pow(2) + x + 1)
+            .into_verbose()
+            .algebraic_number_field()
+            .unwrap();
+        assert!(anf.is_cm_field());
+        let (real_subfield, embedding) = anf.totally_real_subfield().unwrap();
+        assert!(real_subfield.is_totally_real());
+        assert_eq!(real_subfield.degree(), 2);
+        // the embedding sends a root of the modulus of the subfield to a root of it in Q(zeta_5)
+        assert!(
+            anf.is_zero(&anf.reduce(&Polynomial::compose(&real_subfield.modulus(), &embedding)))
+        );
+
+        // a totally imaginary quartic field with Galois group S4 is not CM
+        let anf = (x.pow(4) + x + 1)
+            .into_verbose()
+            .algebraic_number_field()
+            .unwrap();
+        assert_eq!(anf.signature(), (0, 2));
+        assert!(!anf.is_cm_field());
+        assert!(anf.totally_real_subfield().is_none());
+    }
+
+    #[test]
+    fn test_embeddings_trace_and_norm() {
+        for (modulus, elements) in [
+            ("x^2 - 2", vec!["x", "1 + x", "3/2", "0", "2 - 5/7 * x"]),
+            (
+                "x^3 - 2",
+                vec!["x", "x^2", "1 + x", "x^2 - x + 3", "1/2 * x"],
+            ),
+        ] {
+            let anf = Polynomial::<Rational>::from_str(modulus, "x")
+                .unwrap()
+                .algebraic_number_field()
+                .unwrap();
+            for a in elements {
+                let a = Polynomial::<Rational>::from_str(a, "x").unwrap();
+                assert_eq!(anf.sum_over_embeddings(&a), anf.trace(&a));
+                assert_eq!(anf.product_over_embeddings(&a), anf.norm(&a));
+            }
+
+            // the images of an algebraic integer under the embeddings are the roots of its minimal polynomial
+            let roi = anf.ring_of_integers();
+            for a in [vec![1, 1, 0], vec![0, 2, 1], vec![-3, 0, 1]] {
+                let a = a
+                    .into_iter()
+                    .take(anf.degree())
+                    .map(Integer::from)
+                    .collect::<Vec<_>>();
+                let min_poly = anf
+                    .min_poly(&roi.to_anf(&a))
+                    .apply_map(|c| ComplexAlgebraic::Real(RealAlgebraic::Rational(c.clone())));
+                for v in anf.all_complex_embeddings() {
+                    let image = roi.to_embedding(&a, &v);
+                    assert_eq!(image, v.embed(&roi.to_anf(&a)));
+                    assert_eq!(min_poly.evaluate(&image), ComplexAlgebraic::zero());
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_as_poly_expr() {
         let x = &Polynomial::<Rational>::var().into_ergonomic();
@@ -295,6 +514,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_min_poly_over_subfield() {
+        let sqrt_two = ComplexAlgebraic::Real(RealAlgebraic::Rational(Rational::from(2)))
+            .nth_root(2)
+            .unwrap();
+        let sqrt_three = ComplexAlgebraic::Real(RealAlgebraic::Rational(Rational::from(3)))
+            .nth_root(2)
+            .unwrap();
+        let a = ComplexAlgebraic::add(&sqrt_two, &sqrt_three);
+
+        // over Q(sqrt(3)) the minimal polynomial of sqrt(2) + sqrt(3) is x^2 - 2sqrt(3)x + 1
+        let subfield = sqrt_three
+            .clone()
+            .embedded_generated_algebraic_number_field();
+        assert_eq!(
+            min_poly_over_subfield(&a, &subfield),
+            Polynomial::from_coeffs(vec![
+                Polynomial::constant(Rational::ONE),
+                Polynomial::from_coeffs(vec![Rational::ZERO, Rational::from(-2)]),
+                Polynomial::constant(Rational::ONE),
+            ])
+        );
+
+        // with the conjugate embedding sqrt(3) -> -sqrt(3) the sign flips
+        let subfield = sqrt_three.neg().embedded_generated_algebraic_number_field();
+        assert_eq!(
+            min_poly_over_subfield(&a, &subfield),
+            Polynomial::from_coeffs(vec![
+                Polynomial::constant(Rational::ONE),
+                Polynomial::from_coeffs(vec![Rational::ZERO, Rational::from(2)]),
+                Polynomial::constant(Rational::ONE),
+            ])
+        );
+
+        // over Q(sqrt(2) + sqrt(3)) itself the minimal polynomial is linear
+        let subfield = a.clone().embedded_generated_algebraic_number_field();
+        assert_eq!(
+            min_poly_over_subfield(&a, &subfield),
+            Polynomial::from_coeffs(vec![
+                Polynomial::from_coeffs(vec![Rational::ZERO, Rational::from(-1)]),
+                Polynomial::constant(Rational::ONE),
+            ])
+        );
+    }
+
     #[test]
     fn test_pair_generated_anf() {
         // let x = &Polynomial::<Rational>::var().into_ergonomic();
@@ -1,6 +1,12 @@
+mod cyclotomic;
+mod dedekind;
 mod embedded;
 mod polynomial;
 mod polynomial_quotient_number_field;
+mod relative;
 
+pub use cyclotomic::*;
+pub use dedekind::*;
 pub use embedded::*;
 pub use polynomial_quotient_number_field::*;
+pub use relative::*;
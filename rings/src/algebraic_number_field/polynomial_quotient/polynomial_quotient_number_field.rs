@@ -1,5 +1,8 @@
 use crate::{
-    algebraic_number_field::{AlgebraicNumberFieldSignature, anf_multi_primitive_element_theorem},
+    algebraic_number_field::{
+        AlgebraicNumberFieldSignature, DedekindResult, anf_multi_primitive_element_theorem,
+        dedekind_criterion,
+    },
     matrix::Matrix,
     polynomial::{
         Polynomial, PolynomialQuotientRingStructure, PolynomialStructure, ToPolynomialSignature,
@@ -7,12 +10,13 @@ use crate::{
     structure::{
         AdditiveGroupSignature, CancellativeMultiplicationSignature, CharZeroFieldSignature,
         FactoringMonoidSignature, FiniteDimensionalFieldExtension, FreeModuleSignature,
-        IntegralDomainExtensionAllPolynomialRoots, MetaAdditiveMonoidSignature,
-        MetaFactoringMonoid, MetaMultiplicationSignature, PrincipalRationalMap, RingHomomorphism,
-        RingHomomorphismRangeModuleStructure, RingToQuotientFieldSignature, ZeroEqSignature,
+        IntegralDomainExtensionAllPolynomialRoots, IntoErgonomicSignature,
+        MetaAdditiveMonoidSignature, MetaFactoringMonoid, MetaMultiplicationSignature,
+        PrincipalRationalMap, RingHomomorphism, RingHomomorphismRangeModuleStructure,
+        RingToQuotientFieldSignature, StructuredElement, ZeroEqSignature,
     },
 };
-use algebraeon_sets::sets::EnumeratedFiniteSetStructure;
+use algebraeon_sets::sets::{EnumeratedFiniteSetStructure, FinitelySupportedPermutation};
 use algebraeon_structures::*;
 use itertools::Itertools;
 use std::borrow::{Borrow, Cow};
@@ -24,6 +28,9 @@ pub type AlgebraicNumberFieldPolynomialQuotientStructure = PolynomialQuotientRin
     true,
 >;
 
+/// An element of an algebraic number field supporting arithmetic with the usual operators, such as `&a * &b` in place of `anf.mul(&a, &b)`.
+pub type AnfElement = StructuredElement<AlgebraicNumberFieldPolynomialQuotientStructure>;
+
 impl Polynomial<Rational> {
     pub fn algebraic_number_field(self) -> Option<AlgebraicNumberFieldPolynomialQuotientStructure> {
         Rational::structure()
@@ -39,16 +46,59 @@ impl Polynomial<Rational> {
             .into_quotient_field_unchecked(self)
     }
 
-    //return the splitting field and the roots of f in the splitting field
+    /// The element of `anf` represented by `self`, reduced modulo the defining polynomial, for use with arithmetic operators.
+    pub fn into_anf_element(
+        self,
+        anf: &AlgebraicNumberFieldPolynomialQuotientStructure,
+    ) -> AnfElement {
+        anf.into_ergonomic(anf.reduce(&self))
+    }
+
+    /// Return the splitting field, the roots of `self` in the splitting field, and the Galois group as permutations of the roots.
+    ///
+    /// The `k`th automorphism sends `roots[i]` to `roots[automorphisms[k].image(&i)]`.
+    /// Repeated roots are listed once for each time they occur and are permuted among themselves in order.
     pub fn splitting_field(
         &self,
     ) -> (
         AlgebraicNumberFieldPolynomialQuotientStructure,
         Vec<Polynomial<Rational>>,
+        Vec<FinitelySupportedPermutation<usize>>,
     ) {
         let roots = self.primitive_part_fof().all_complex_roots();
         let (g, roots_rel_g) = anf_multi_primitive_element_theorem(roots.iter().collect());
-        (g.generated_algebraic_number_field(), roots_rel_g)
+        let anf = g.generated_algebraic_number_field();
+        let roots_rel_g = roots_rel_g
+            .into_iter()
+            .map(|root| anf.reduce(&root))
+            .collect::<Vec<_>>();
+        // the splitting field is normal, so the minimal polynomial of g splits into linear factors x - h(g)
+        // and the automorphisms are given by g -> h(g)
+        let anf_poly = anf.polynomials();
+        let automorphisms = anf_poly
+            .factor(&g.min_poly().apply_map(|c| Polynomial::constant(c.clone())))
+            .into_powers()
+            .unwrap()
+            .into_iter()
+            .map(|(factor, _)| {
+                debug_assert_eq!(anf_poly.degree(&factor), Some(1));
+                let g_image = anf.neg(anf_poly.coeff(&factor, 0).as_ref());
+                let mut mapping = vec![];
+                for (i, root) in roots_rel_g.iter().enumerate() {
+                    let root_image = anf.reduce(&Polynomial::compose(root, &g_image));
+                    let occurrence = (0..i).filter(|j| roots_rel_g[*j] == *root).count();
+                    let j = (0..roots_rel_g.len())
+                        .filter(|j| roots_rel_g[*j] == root_image)
+                        .nth(occurrence)
+                        .unwrap();
+                    if i != j {
+                        mapping.push((i, j));
+                    }
+                }
+                FinitelySupportedPermutation::new_perm(mapping).unwrap()
+            })
+            .collect();
+        (anf, roots_rel_g, automorphisms)
     }
 }
 
@@ -140,6 +190,16 @@ impl AlgebraicNumberFieldPolynomialQuotientStructure {
         //https://www.ucl.ac.uk/~ucahmki/intbasis.pdf
         // println!("compute_basis_ring_of_integers");
         let n = self.degree();
+        // when the generator is an algebraic integer the initial guess is Z[generator]
+        // and primes passing the Dedekind criterion do not divide the index of any later guess
+        let integral_modulus = if self.is_algebraic_integer(&self.generator()) {
+            Some(
+                self.min_poly(&self.generator())
+                    .apply_map(|c| c.numerator()),
+            )
+        } else {
+            None
+        };
         let mut guess = (0..n)
             .map(|i| self.integral_multiple(&Polynomial::<Rational>::var_pow(i)))
             .collect::<Vec<_>>();
@@ -170,9 +230,13 @@ impl AlgebraicNumberFieldPolynomialQuotientStructure {
 
             for (p, k) in disc_factors {
                 debug_assert!(p >= Integer::ZERO);
-                let p = p.abs().try_into().unwrap(); //if p is too big for usize then this algorithm was doomed to take longer than my lifespan anyway
+                let p: usize = p.abs().try_into().unwrap(); //if p is too big for usize then this algorithm was doomed to take longer than my lifespan anyway
 
-                if k >= Natural::TWO {
+                if k >= Natural::TWO
+                    && !integral_modulus.as_ref().is_some_and(|f| {
+                        dedekind_criterion(f, &Natural::from(p)) == DedekindResult::Passes
+                    })
+                {
                     // println!("p = {}", p);
 
                     for coeffs in (0..n).map(|_i| 0..p).multi_cartesian_product() {
@@ -231,6 +295,53 @@ impl AlgebraicNumberFieldPolynomialQuotientStructure {
             return (guess, disc);
         }
     }
+
+    /// The integer coordinates of `elem` with respect to `basis`, or `None` if `elem` is not in the span of `basis` over `Z`.
+    ///
+    /// `basis` should be a basis of the number field over `Q`, such as the integral basis returned by [`Self::compute_integral_basis_and_discriminant`],
+    /// in which case `elem` has integer coordinates if and only if it is an algebraic integer.
+    pub fn to_integral_basis_vector(
+        &self,
+        elem: &Polynomial<Rational>,
+        basis: &[Polynomial<Rational>],
+    ) -> Option<Vec<Integer>> {
+        let n = self.degree();
+        assert_eq!(basis.len(), n);
+        let basis_mat =
+            Matrix::construct(n, n, |r, c| self.reduce(&basis[c]).coeff(r).into_owned());
+        let elem = self.reduce(elem);
+        let coords = basis_mat
+            .col_solve(&(0..n).map(|i| elem.coeff(i).into_owned()).collect())
+            .unwrap();
+        coords
+            .into_iter()
+            .map(|c| {
+                if c.clone().denominator() == Natural::ONE {
+                    Some(c.numerator())
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// The element with integer coordinates `coords` with respect to `basis`.
+    ///
+    /// This is inverse to [`Self::to_integral_basis_vector`].
+    pub fn from_integral_basis_vector(
+        &self,
+        coords: &[Integer],
+        basis: &[Polynomial<Rational>],
+    ) -> Polynomial<Rational> {
+        assert_eq!(coords.len(), basis.len());
+        self.reduce(&Polynomial::sum(
+            &coords
+                .iter()
+                .zip(basis.iter())
+                .map(|(c, b)| Polynomial::mul(&Polynomial::constant(Rational::from(c)), b))
+                .collect::<Vec<_>>(),
+        ))
+    }
 }
 
 impl
@@ -282,7 +393,103 @@ impl
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{polynomial::PolynomialFromStr, structure::IntoErgonomic};
+    use crate::{
+        polynomial::PolynomialFromStr,
+        structure::{AdditionSignature, IntoErgonomic, MultiplicationSignature, ZeroEqSignature},
+    };
+
+    #[test]
+    fn test_splitting_field_galois_action() {
+        let x = &Polynomial::<Rational>::var().into_ergonomic();
+
+        // x^4 - 5x^2 + 6 = (x^2 - 2)(x^2 - 3) has Galois group the Klein four-group
+        let f = (x.pow(4) - 5 * x.pow(2) + 6).into_verbose();
+        let (anf, roots, automorphisms) = f.splitting_field();
+        assert_eq!(anf.degree(), 4);
+        assert_eq!(roots.len(), 4);
+        assert_eq!(automorphisms.len(), 4);
+        assert_eq!(
+            automorphisms
+                .iter()
+                .cloned()
+                .collect::<std::collections::HashSet<_>>()
+                .len(),
+            4
+        );
+        for sigma in &automorphisms {
+            // each automorphism has order at most 2
+            for i in 0..4 {
+                assert_eq!(sigma.image(&sigma.image(&i)), i);
+            }
+            for i in 0..4 {
+                let image = &roots[sigma.image(&i)];
+                // roots of x^2 - 2 are sent to roots of x^2 - 2 and similarly for x^2 - 3
+                assert_eq!(anf.mul(&roots[i], &roots[i]), anf.mul(image, image));
+                // negatives are sent to negatives
+                for j in 0..4 {
+                    if anf.is_zero(&anf.add(&roots[i], &roots[j])) {
+                        assert!(anf.is_zero(&anf.add(image, &roots[sigma.image(&j)])));
+                    }
+                }
+            }
+        }
+
+        // x^3 - 3x + 1 has cyclic Galois group acting transitively on its roots
+        let f = (x.pow(3) - 3 * x + 1).into_verbose();
+        let (anf, roots, automorphisms) = f.splitting_field();
+        assert_eq!(anf.degree(), 3);
+        assert_eq!(roots.len(), 3);
+        assert_eq!(automorphisms.len(), 3);
+        for sigma in &automorphisms {
+            let fixed_points = (0..3).filter(|i| sigma.image(i) == *i).count();
+            assert!(fixed_points == 0 || fixed_points == 3);
+        }
+        assert_eq!(
+            automorphisms
+                .into_iter()
+                .collect::<std::collections::HashSet<_>>()
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_norm_and_trace() {
+        let anf = Polynomial::<Rational>::from_str("x^2 - 2", "x")
+            .unwrap()
+            .algebraic_number_field()
+            .unwrap();
+        let sqrt2 = Polynomial::<Rational>::from_str("x", "x").unwrap();
+        assert_eq!(
+            anf.norm_and_trace(&sqrt2),
+            (Rational::from(-2), Rational::from(0))
+        );
+        let a = Polynomial::<Rational>::from_str("1 + x", "x").unwrap();
+        assert_eq!(
+            anf.norm_and_trace(&a),
+            (Rational::from(-1), Rational::from(2))
+        );
+        let a = Polynomial::<Rational>::from_str("3/2", "x").unwrap();
+        assert_eq!(
+            anf.norm_and_trace(&a),
+            (Rational::from_integers(9, 4), Rational::from(3))
+        );
+
+        // odd degree and agreement with the separate computations
+        let anf = Polynomial::<Rational>::from_str("x^3 - x - 1", "x")
+            .unwrap()
+            .algebraic_number_field()
+            .unwrap();
+        for a in ["x", "x^2 + 1", "2 - 1/3 * x", "0", "5"] {
+            let a = Polynomial::<Rational>::from_str(a, "x").unwrap();
+            assert_eq!(anf.norm_and_trace(&a), (anf.norm(&a), anf.trace(&a)));
+        }
+        let x = Polynomial::<Rational>::from_str("x", "x").unwrap();
+        assert_eq!(
+            anf.norm_and_trace(&x),
+            (Rational::from(1), Rational::from(0))
+        );
+    }
 
     #[test]
     fn test_anf_integral_multiple() {
@@ -334,4 +541,140 @@ mod tests {
             &alpha
         ));
     }
+
+    #[test]
+    fn test_integral_basis_vector_round_trip() {
+        let x = &Polynomial::<Rational>::var().into_ergonomic();
+        for f in [
+            x.pow(2) - 5,
+            x.pow(3) - 2,
+            // Dedekind's example where the ring of integers is not generated by a single element
+            x.pow(3) - x.pow(2) - 2 * x - 8,
+        ] {
+            let anf = f.into_verbose().algebraic_number_field().unwrap();
+            let (basis, _) = anf.compute_integral_basis_and_discriminant();
+            let n = basis.len();
+            for i in 0..n {
+                let mut e = vec![Integer::ZERO; n];
+                e[i] = Integer::ONE;
+                assert_eq!(
+                    anf.to_integral_basis_vector(&basis[i], &basis),
+                    Some(e.clone())
+                );
+                assert!(anf.equal(&anf.from_integral_basis_vector(&e, &basis), &basis[i]));
+                for j in 0..n {
+                    let mut v = e.clone();
+                    v[j] += Integer::from(-3);
+                    let sum = anf.from_integral_basis_vector(&v, &basis);
+                    assert!(anf.equal(
+                        &sum,
+                        &anf.add(
+                            &basis[i],
+                            &anf.mul(&Polynomial::constant(Rational::from(-3)), &basis[j])
+                        )
+                    ));
+                    assert_eq!(anf.to_integral_basis_vector(&sum, &basis), Some(v));
+                }
+            }
+        }
+
+        // sqrt(5) / 2 is not an algebraic integer but (1 + sqrt(5)) / 2 is
+        let anf = (x.pow(2) - 5)
+            .into_verbose()
+            .algebraic_number_field()
+            .unwrap();
+        let (basis, _) = anf.compute_integral_basis_and_discriminant();
+        assert_eq!(
+            anf.to_integral_basis_vector(
+                &Polynomial::from_coeffs(vec![Rational::ZERO, Rational::ONE_HALF]),
+                &basis
+            ),
+            None
+        );
+        assert!(
+            anf.to_integral_basis_vector(
+                &Polynomial::from_coeffs(vec![Rational::ONE_HALF, Rational::ONE_HALF]),
+                &basis
+            )
+            .is_some()
+        );
+    }
+
+    #[test]
+    fn test_anf_element_arithmetic() {
+        let x = &Polynomial::<Rational>::var().into_ergonomic();
+        let anf = (x.pow(2) - 2)
+            .into_verbose()
+            .algebraic_number_field()
+            .unwrap();
+        let sqrt2 = &Polynomial::<Rational>::var().into_anf_element(&anf);
+        let one = &Polynomial::constant(Rational::ONE).into_anf_element(&anf);
+
+        assert_eq!(sqrt2 * sqrt2, 2 * one);
+        assert_eq!((one + sqrt2) * (one - sqrt2), -one);
+        assert_eq!((one + sqrt2).pow(-1), sqrt2 - 1);
+        assert_eq!(one / (sqrt2 + 1), sqrt2 - 1);
+        assert_eq!(sqrt2.pow(5), 4 * sqrt2);
+        assert_ne!(sqrt2 + 1, sqrt2 - 1);
+        // elements are reduced modulo x^2 - 2 when converted
+        assert_eq!(
+            (x.pow(3) + x.pow(2)).into_verbose().into_anf_element(&anf),
+            2 * sqrt2 + 2
+        );
+        assert_eq!(format!("{}", sqrt2 * 3 + 1), format!("{}", (3 * x + 1)));
+
+        // agrees with the verbose operations
+        let a = (3 * x.pow(2) + x - 5).into_verbose();
+        let b = (-2 * x + 7).into_verbose();
+        let a_e = a.clone().into_anf_element(&anf);
+        let b_e = b.clone().into_anf_element(&anf);
+        assert!(anf.equal(&(&a_e + &b_e).into_verbose(), &anf.add(&a, &b)));
+        assert!(anf.equal(&(&a_e - &b_e).into_verbose(), &anf.sub(&a, &b)));
+        assert!(anf.equal(&(&a_e * &b_e).into_verbose(), &anf.mul(&a, &b)));
+        assert!(anf.equal(&(-&a_e).into_verbose(), &anf.neg(&a)));
+        assert!(anf.equal(
+            &(&a_e / &b_e).into_verbose(),
+            &anf.try_divide(&a, &b).unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_ramified_primes() {
+        let anf = Polynomial::<Rational>::from_str("x^2+5", "x")
+            .unwrap()
+            .algebraic_number_field()
+            .unwrap();
+        assert_eq!(anf.discriminant(), Integer::from(-20));
+        let (unit, powers) = anf.discriminant_factored().into_unit_and_powers().unwrap();
+        assert_eq!(unit, Integer::from(-1));
+        let mut powers = powers;
+        powers.sort();
+        assert_eq!(
+            powers,
+            vec![
+                (Integer::from(2), Natural::from(2u32)),
+                (Integer::from(5), Natural::from(1u32))
+            ]
+        );
+        assert_eq!(
+            anf.ramified_primes(),
+            vec![Natural::from(2u32), Natural::from(5u32)]
+        );
+
+        // Q(i) has discriminant -4 and only 2 ramifies
+        let anf = Polynomial::<Rational>::from_str("x^2+1", "x")
+            .unwrap()
+            .algebraic_number_field()
+            .unwrap();
+        assert_eq!(anf.ramified_primes(), vec![Natural::from(2u32)]);
+
+        // only p ramifies in the cyclotomic field Q(zeta_p), whose discriminant is +-p^(p-2)
+        for p in [3u32, 5, 7] {
+            let anf = Polynomial::<Rational>::from_coeffs(vec![Rational::ONE; p as usize])
+                .algebraic_number_field()
+                .unwrap();
+            assert_eq!(anf.ramified_primes(), vec![Natural::from(p)]);
+            assert_eq!(anf.discriminant().abs(), Natural::from(p.pow(p - 2)));
+        }
+    }
 }
@@ -0,0 +1,256 @@
+use super::polynomial_quotient_number_field::AlgebraicNumberFieldPolynomialQuotientStructure;
+use crate::algebraic_number_field::AlgebraicNumberFieldSignature;
+use crate::matrix::{Matrix, MatrixStructure};
+use crate::polynomial::*;
+use crate::structure::*;
+use algebraeon_structures::*;
+
+/// A tower of number fields `L/K/Q` where `K` is the `base` and `L` is the `top`.
+///
+/// The inclusion of `K` into `L` is determined by `embedding`, the image in `L` of the generator of `K`.
+/// Since the generator `b` of `L` also generates `L` over `K`, the powers `1, b, ..., b^(m-1)` form a basis of `L` over `K` where `m` is the relative degree.
+#[derive(Debug, Clone)]
+pub struct RelativeExtension {
+    base: AlgebraicNumberFieldPolynomialQuotientStructure,
+    top: AlgebraicNumberFieldPolynomialQuotientStructure,
+    embedding: Polynomial<Rational>,
+    // the minimal polynomial of the generator of top over base
+    relative_min_poly: Polynomial<Polynomial<Rational>>,
+    // change of basis from the power basis of top over Q to the basis a^i b^j of top over Q
+    // where a is the image of the generator of base and b is the generator of top
+    to_tower_basis: Matrix<Rational>,
+}
+
+impl RelativeExtension {
+    /// Construct the tower `top/base/Q` where the generator of `base` is sent to `embedding` in `top`.
+    ///
+    /// Returns an error if `embedding` is not a root of the minimal polynomial of the generator of `base`.
+    pub fn new(
+        base: AlgebraicNumberFieldPolynomialQuotientStructure,
+        top: AlgebraicNumberFieldPolynomialQuotientStructure,
+        embedding: Polynomial<Rational>,
+    ) -> Result<Self, String> {
+        let embedding = top.reduce(&embedding);
+        if !top.is_zero(&top.reduce(&Polynomial::compose(&base.modulus(), &embedding))) {
+            return Err("the embedding is not a root of the modulus of the base".to_string());
+        }
+        let k = base.degree();
+        let n = top.degree();
+        debug_assert_eq!(n % k, 0);
+        let m = n / k;
+        let top_over_q = top.inbound_finite_dimensional_rational_extension();
+        let generator = top.generator();
+        // the column with index j*k+i is a^i b^j
+        let mut cols = vec![];
+        for j in 0..m {
+            let b_pow = top.nat_pow(&generator, &Natural::from(j));
+            for i in 0..k {
+                let a_pow = top.nat_pow(&embedding, &Natural::from(i));
+                cols.push(top_over_q.to_vec(&top.mul(&a_pow, &b_pow)));
+            }
+        }
+        let to_tower_basis = Matrix::<Rational>::from_cols(cols).inv().unwrap();
+        let mut ext = Self {
+            base,
+            top,
+            embedding,
+            relative_min_poly: Polynomial::one(),
+            to_tower_basis,
+        };
+        // b^m = c_0 + c_1 b + ... + c_{m-1} b^{m-1} with c_j in base
+        let b_pow_m = ext.top.nat_pow(&generator, &Natural::from(m));
+        let mut coeffs = ext
+            .to_relative_coeffs(&b_pow_m)
+            .into_iter()
+            .map(|c| ext.base.neg(&c))
+            .collect::<Vec<_>>();
+        coeffs.push(Polynomial::one());
+        ext.relative_min_poly = Polynomial::from_coeffs(coeffs);
+        Ok(ext)
+    }
+
+    pub fn base(&self) -> &AlgebraicNumberFieldPolynomialQuotientStructure {
+        &self.base
+    }
+
+    pub fn top(&self) -> &AlgebraicNumberFieldPolynomialQuotientStructure {
+        &self.top
+    }
+
+    /// The image in `top` of the generator of `base`.
+    pub fn embedding(&self) -> &Polynomial<Rational> {
+        &self.embedding
+    }
+
+    /// The monic minimal polynomial over `base` of the generator of `top`.
+    pub fn relative_min_poly(&self) -> &Polynomial<Polynomial<Rational>> {
+        &self.relative_min_poly
+    }
+
+    /// The degree of `top` as a vector space over `base`.
+    pub fn relative_degree(&self) -> usize {
+        self.top.degree() / self.base.degree()
+    }
+
+    /// The image in `top` of an element of `base`.
+    pub fn base_to_top(&self, a: &Polynomial<Rational>) -> Polynomial<Rational> {
+        self.top.reduce(&Polynomial::compose(a, &self.embedding))
+    }
+
+    /// The coordinates over `base` of an element of `top` with respect to the basis `1, b, ..., b^(m-1)`.
+    pub fn to_relative_coeffs(&self, x: &Polynomial<Rational>) -> Vec<Polynomial<Rational>> {
+        let k = self.base.degree();
+        let v = self.to_tower_basis.apply_col(
+            &self
+                .top
+                .inbound_finite_dimensional_rational_extension()
+                .to_vec(&self.top.reduce(x)),
+        );
+        (0..self.relative_degree())
+            .map(|j| Polynomial::from_coeffs(v[j * k..(j + 1) * k].to_vec()))
+            .collect()
+    }
+
+    /// The matrix over `base` of multiplication by `x` with respect to the basis `1, b, ..., b^(m-1)`.
+    fn relative_multiplication_matrix(
+        &self,
+        x: &Polynomial<Rational>,
+    ) -> Matrix<Polynomial<Rational>> {
+        let generator = self.top.generator();
+        Matrix::from_cols(
+            (0..self.relative_degree())
+                .map(|j| {
+                    self.to_relative_coeffs(
+                        &self
+                            .top
+                            .mul(x, &self.top.nat_pow(&generator, &Natural::from(j))),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    /// The trace from `top` to `base` of an element of `top`.
+    pub fn relative_trace(&self, x: &Polynomial<Rational>) -> Polynomial<Rational> {
+        MatrixStructure::new(self.base.clone())
+            .trace(&self.relative_multiplication_matrix(x))
+            .unwrap()
+    }
+
+    /// The norm from `top` to `base` of an element of `top`.
+    pub fn relative_norm(&self, x: &Polynomial<Rational>) -> Polynomial<Rational> {
+        MatrixStructure::new(self.base.clone())
+            .det(self.relative_multiplication_matrix(x))
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Q(sqrt(2), sqrt(3)) = Q(b) where b = sqrt(2) + sqrt(3) over Q(sqrt(2))
+    fn sqrt2_sqrt3_over_sqrt2() -> RelativeExtension {
+        let x = Polynomial::<Rational>::var().into_ergonomic();
+        let base = (x.pow(2) - 2)
+            .into_verbose()
+            .algebraic_number_field()
+            .unwrap();
+        let top = (x.pow(4) - 10 * x.pow(2) + 1)
+            .into_verbose()
+            .algebraic_number_field()
+            .unwrap();
+        // b^3 = 11 sqrt(2) + 9 sqrt(3) so sqrt(2) = (b^3 - 9b) / 2
+        let sqrt2 = Polynomial::from_coeffs(vec![
+            Rational::ZERO,
+            Rational::from_integers(-9, 2),
+            Rational::ZERO,
+            Rational::ONE_HALF,
+        ]);
+        RelativeExtension::new(base, top, sqrt2).unwrap()
+    }
+
+    #[test]
+    fn relative_degree_and_min_poly() {
+        let ext = sqrt2_sqrt3_over_sqrt2();
+        assert_eq!(ext.relative_degree(), 2);
+
+        // b = sqrt(2) + sqrt(3) satisfies b^2 - 2 sqrt(2) b - 1 = 0 over Q(sqrt(2))
+        let a = Polynomial::<Rational>::var();
+        assert_eq!(
+            ext.relative_min_poly(),
+            &Polynomial::from_coeffs(vec![
+                Polynomial::constant(Rational::from(-1)),
+                Polynomial::constant(Rational::from(-2)).mul(&a),
+                Polynomial::one(),
+            ])
+        );
+    }
+
+    #[test]
+    fn relative_norm_and_trace() {
+        let x = Polynomial::<Rational>::var().into_ergonomic();
+        let ext = sqrt2_sqrt3_over_sqrt2();
+        // sqrt(3) = (11b - b^3) / 2
+        let sqrt3 = Polynomial::from_coeffs(vec![
+            Rational::ZERO,
+            Rational::from_integers(11, 2),
+            Rational::ZERO,
+            -Rational::ONE_HALF,
+        ]);
+
+        assert_eq!(
+            ext.relative_norm(&sqrt3),
+            Polynomial::constant(Rational::from(-3))
+        );
+        assert_eq!(ext.relative_trace(&sqrt3), Polynomial::zero());
+
+        // 1 + sqrt(3) has norm (1 + sqrt(3))(1 - sqrt(3)) = -2
+        let one_plus_sqrt3 = ext.top().add(&Polynomial::one(), &sqrt3);
+        assert_eq!(
+            ext.relative_norm(&one_plus_sqrt3),
+            Polynomial::constant(Rational::from(-2))
+        );
+
+        // elements of the base have norm a^2 and trace 2a
+        let a = Polynomial::<Rational>::var();
+        let a_in_top = ext.base_to_top(&a);
+        assert_eq!(
+            ext.relative_norm(&a_in_top),
+            Polynomial::constant(Rational::TWO)
+        );
+        assert_eq!(
+            ext.relative_trace(&a_in_top),
+            Polynomial::constant(Rational::TWO).mul(&a)
+        );
+
+        // the norm is transitive through the tower
+        let y = ext.top().add(
+            &x.clone().into_verbose(),
+            &ext.top().nat_pow(&sqrt3, &Natural::TWO),
+        );
+        let y = ext.top().add(&y, &Polynomial::one());
+        assert_eq!(
+            ext.base()
+                .inbound_finite_dimensional_rational_extension()
+                .norm(&ext.relative_norm(&y)),
+            ext.top()
+                .inbound_finite_dimensional_rational_extension()
+                .norm(&y)
+        );
+    }
+
+    #[test]
+    fn bad_embedding() {
+        let x = Polynomial::<Rational>::var().into_ergonomic();
+        let base = (x.pow(2) - 2)
+            .into_verbose()
+            .algebraic_number_field()
+            .unwrap();
+        let top = (x.pow(4) - 10 * x.pow(2) + 1)
+            .into_verbose()
+            .algebraic_number_field()
+            .unwrap();
+        assert!(RelativeExtension::new(base, top, x.into_verbose()).is_err());
+    }
+}
@@ -0,0 +1,232 @@
+use super::*;
+use crate::{algebraic_number_field::AlgebraicNumberFieldSignature, structure::*};
+use algebraeon_structures::*;
+use std::collections::HashSet;
+
+/// The integer binary quadratic form `ax^2 + bxy + cy^2`.
+pub(super) type Form = (Integer, Integer, Integer);
+
+fn is_primitive((a, b, c): &Form) -> bool {
+    Integer::gcd(&Integer::gcd(a, b), c) == Integer::ONE
+}
+
+/// The primitive reduced forms of negative discriminant `disc`, that is, those with `|b| <= a <= c` and `b >= 0` whenever `|b| = a` or `a = c`.
+/// Each class of positive definite forms contains exactly one of them.
+pub(super) fn reduced_definite_forms(disc: &Integer) -> Vec<Form> {
+    debug_assert!(disc < &Integer::ZERO);
+    let mut forms = vec![];
+    // a reduced form has 3a^2 <= |disc|
+    let mut a = Integer::ONE;
+    while Integer::from(3) * &a * &a <= -disc {
+        let mut b = -&a + Integer::ONE;
+        while b <= a {
+            if let Some(c) = Integer::try_divide(&(&b * &b - disc), &(Integer::from(4) * &a)) {
+                let form = (a.clone(), b.clone(), c);
+                let (_, b, c) = &form;
+                if c >= &a && (b >= &Integer::ZERO || c != &a) && is_primitive(&form) {
+                    forms.push(form);
+                }
+            }
+            b += Integer::ONE;
+        }
+        a += Integer::ONE;
+    }
+    forms
+}
+
+/// The primitive reduced forms of positive non-square discriminant `disc`, that is, those with `|sqrt(disc) - 2|a|| < b < sqrt(disc)`.
+fn reduced_indefinite_forms(disc: &Integer) -> Vec<Form> {
+    debug_assert!(disc > &Integer::ZERO);
+    let r = Integer::from(Natural::try_from(disc).unwrap().sqrt_floor());
+    let mut forms = vec![];
+    let mut b = Integer::ONE;
+    while b <= r {
+        // ac = (b^2 - disc) / 4 < 0
+        if let Some(m) = Integer::try_divide(&(disc - &b * &b), &Integer::from(4)) {
+            let mut k = Integer::ONE;
+            while k <= m {
+                if let Some(l) = Integer::try_divide(&m, &k) {
+                    let two_k = Integer::TWO * &k;
+                    // sqrt(disc) - b < 2k < sqrt(disc) + b
+                    let upper =
+                        &two_k - &b < Integer::ZERO || (&two_k - &b) * (&two_k - &b) < *disc;
+                    let lower = *disc < (&two_k + &b) * (&two_k + &b);
+                    if upper && lower {
+                        for form in [(k.clone(), b.clone(), -&l), (-&k, b.clone(), l.clone())] {
+                            if is_primitive(&form) {
+                                forms.push(form);
+                            }
+                        }
+                    }
+                }
+                k += Integer::ONE;
+            }
+        }
+        b += Integer::ONE;
+    }
+    forms
+}
+
+/// The neighbour `(c, b', (b'^2 - disc) / 4c)` of a reduced indefinite form `(a, b, c)` of discriminant `disc`, where `b' = -b mod 2|c|` and `sqrt(disc) - 2|c| < b' < sqrt(disc)`.
+fn rho((_, b, c): &Form, disc: &Integer) -> Form {
+    let r = Integer::from(Natural::try_from(disc).unwrap().sqrt_floor());
+    let two_c = Integer::TWO * Integer::from(Abs::abs(c));
+    // the least b' >= r - 2|c| + 1 with b' = -b mod 2|c|
+    let low = &r - &two_c + Integer::ONE;
+    let b_new = &low + Integer::rem(&(-b - &low), &two_c);
+    let b_new = if b_new < low { b_new + two_c } else { b_new };
+    let a_new = Integer::try_divide(&(&b_new * &b_new - disc), &(Integer::from(4) * c)).unwrap();
+    (c.clone(), b_new, a_new)
+}
+
+impl<D: BorrowedElem<Integer>> QuadraticRingOfIntegersStructure<D> {
+    /// The order of the ideal class group.
+    ///
+    /// This is computed by counting reduced binary quadratic forms of discriminant equal to the discriminant of the field.
+    /// For real quadratic fields the cycles of reduced forms count the narrow class number, which is halved when the fundamental unit has norm `1`.
+    pub fn class_number(&self) -> Natural {
+        let disc = self.anf().discriminant();
+        if disc < Integer::ZERO {
+            Natural::from(reduced_definite_forms(&disc).len())
+        } else {
+            let forms = reduced_indefinite_forms(&disc);
+            let mut seen = HashSet::new();
+            let mut narrow_class_number = 0usize;
+            let mut principal_cycle_has_minus_one = false;
+            for form in &forms {
+                if seen.contains(form) {
+                    continue;
+                }
+                narrow_class_number += 1;
+                let mut cycle = vec![];
+                let mut f = form.clone();
+                while seen.insert(f.clone()) {
+                    cycle.push(f.clone());
+                    f = rho(&f, &disc);
+                }
+                if cycle.iter().any(|(a, _, _)| a == &Integer::ONE) {
+                    // the principal form represents -1 if and only if there is a unit of norm -1
+                    principal_cycle_has_minus_one =
+                        cycle.iter().any(|(a, _, _)| a == &-Integer::ONE);
+                }
+            }
+            if principal_cycle_has_minus_one {
+                Natural::from(narrow_class_number)
+            } else {
+                Natural::from(narrow_class_number / 2)
+            }
+        }
+    }
+
+    /// Whether this ring is a principal ideal domain, that is, whether its class number is `1`.
+    pub fn is_pid(&self) -> bool {
+        self.class_number() == Natural::ONE
+    }
+
+    /// Whether this ring is a unique factorization domain, which for a Dedekind domain is the same as being a principal ideal domain.
+    pub fn is_ufd(&self) -> bool {
+        self.is_pid()
+    }
+
+    /// Whether this ring is a Euclidean domain, or `None` if this is not known.
+    ///
+    /// The imaginary quadratic rings which are Euclidean are exactly the norm-Euclidean ones, for `d = -1, -2, -3, -7, -11`.
+    /// In particular the remaining principal ideal domains from Stark's list, for `d = -19, -43, -67, -163`, are not Euclidean.
+    /// The real quadratic rings which are norm-Euclidean are known, and those which are not principal ideal domains are not Euclidean.
+    /// Whether the remaining real quadratic principal ideal domains are Euclidean is unknown in general.
+    pub fn is_euclidean_domain_check(&self) -> Option<bool> {
+        let d = self.d();
+        if d < &Integer::ZERO {
+            Some(
+                [-1, -2, -3, -7, -11]
+                    .into_iter()
+                    .any(|e| d == &Integer::from(e)),
+            )
+        } else if [2, 3, 5, 6, 7, 11, 13, 17, 19, 21, 29, 33, 37, 41, 57, 73]
+            .into_iter()
+            .any(|e| d == &Integer::from(e))
+        {
+            Some(true)
+        } else if !self.is_pid() {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn class_number(d: i32) -> Natural {
+        QuadraticRingOfIntegersStructure::new(Integer::from(d))
+            .unwrap()
+            .class_number()
+    }
+
+    #[test]
+    fn imaginary_class_numbers() {
+        // Stark's list of imaginary quadratic fields with class number 1
+        for d in [-1, -2, -3, -7, -11, -19, -43, -67, -163] {
+            assert_eq!(class_number(d), Natural::ONE);
+        }
+        for (d, h) in [
+            (-5, 2),
+            (-6, 2),
+            (-14, 4),
+            (-15, 2),
+            (-23, 3),
+            (-47, 5),
+            (-71, 7),
+        ] {
+            assert_eq!(class_number(d), Natural::from(h as u32));
+        }
+    }
+
+    #[test]
+    fn real_class_numbers() {
+        // for d = 3 the fundamental unit 2 + sqrt(3) has norm 1 so the narrow class number is 2 but the class number is 1
+        for d in [2, 3, 5, 6, 7, 11, 13, 14, 17, 19, 21, 22, 23] {
+            assert_eq!(class_number(d), Natural::ONE);
+        }
+        for (d, h) in [
+            (10, 2),
+            (15, 2),
+            (26, 2),
+            (30, 2),
+            (79, 3),
+            (82, 4),
+            (226, 8),
+        ] {
+            assert_eq!(class_number(d), Natural::from(h as u32));
+        }
+    }
+
+    #[test]
+    fn pid_and_euclidean() {
+        let gaussian = QuadraticRingOfIntegersStructure::new(Integer::from(-1)).unwrap();
+        assert!(gaussian.is_pid());
+        assert!(gaussian.is_ufd());
+        assert_eq!(gaussian.is_euclidean_domain_check(), Some(true));
+
+        let eisenstein = QuadraticRingOfIntegersStructure::new(Integer::from(-3)).unwrap();
+        assert!(eisenstein.is_pid());
+        assert_eq!(eisenstein.is_euclidean_domain_check(), Some(true));
+
+        let roi = QuadraticRingOfIntegersStructure::new(Integer::from(-5)).unwrap();
+        assert!(!roi.is_pid());
+        assert!(!roi.is_ufd());
+        assert_eq!(roi.is_euclidean_domain_check(), Some(false));
+
+        // a principal ideal domain which is not Euclidean
+        let roi = QuadraticRingOfIntegersStructure::new(Integer::from(-19)).unwrap();
+        assert!(roi.is_pid());
+        assert_eq!(roi.is_euclidean_domain_check(), Some(false));
+
+        let roi = QuadraticRingOfIntegersStructure::new(Integer::from(10)).unwrap();
+        assert_eq!(roi.is_euclidean_domain_check(), Some(false));
+        let roi = QuadraticRingOfIntegersStructure::new(Integer::from(14)).unwrap();
+        assert_eq!(roi.is_euclidean_domain_check(), None);
+    }
+}
@@ -0,0 +1,361 @@
+use super::class_number::{Form, reduced_definite_forms};
+use super::*;
+use crate::{
+    algebraic_number_field::{
+        AlgebraicNumberFieldPolynomialQuotientStructure, AlgebraicNumberFieldSignature,
+    },
+    polynomial::*,
+    structure::*,
+};
+use algebraeon_structures::*;
+
+/// Complex numbers `x + iy` approximated in fixed point, with `x` and `y` stored as integer multiples of `2^-prec`.
+struct FixedPoint {
+    one: Integer,
+}
+
+type FixedComplex = (Integer, Integer);
+
+impl FixedPoint {
+    fn new(prec: usize) -> Self {
+        Self {
+            one: Integer::from(Natural::ONE << prec),
+        }
+    }
+
+    fn mul(&self, a: &Integer, b: &Integer) -> Integer {
+        a * b / &self.one
+    }
+
+    fn div(&self, a: &Integer, b: &Integer) -> Integer {
+        a * &self.one / b
+    }
+
+    /// The nearest integer to `a`.
+    fn round(&self, a: &Integer) -> Integer {
+        Floor::floor(Rational::from_integers(
+            Integer::TWO * a + &self.one,
+            Integer::TWO * &self.one,
+        ))
+    }
+
+    fn sqrt(&self, n: &Natural) -> Integer {
+        let one = Natural::try_from(&self.one).unwrap();
+        Integer::from((n * &one * &one).sqrt_floor())
+    }
+
+    /// `arctan(1/k) = sum_n (-1)^n / ((2n+1) k^(2n+1))`.
+    fn arctan_reciprocal(&self, k: u32) -> Integer {
+        let k_sqrd = Integer::from(k * k);
+        let mut power = &self.one / Integer::from(k);
+        let mut total = Integer::ZERO;
+        let mut n = 0u32;
+        while power != Integer::ZERO {
+            let term = &power / Integer::from(2 * n + 1);
+            if n.is_multiple_of(2) {
+                total += term;
+            } else {
+                total -= term;
+            }
+            power = &power / &k_sqrd;
+            n += 1;
+        }
+        total
+    }
+
+    /// Machin's formula `pi = 16 arctan(1/5) - 4 arctan(1/239)`.
+    fn pi(&self) -> Integer {
+        Integer::from(16) * self.arctan_reciprocal(5)
+            - Integer::from(4) * self.arctan_reciprocal(239)
+    }
+
+    /// `exp(x)` for `x >= 0`, summing the power series whose terms are all positive.
+    fn exp(&self, x: &Integer) -> Integer {
+        debug_assert!(x >= &Integer::ZERO);
+        let mut term = self.one.clone();
+        let mut total = Integer::ZERO;
+        let mut n = 1u32;
+        while term != Integer::ZERO {
+            total += &term;
+            term = self.mul(&term, x) / Integer::from(n);
+            n += 1;
+        }
+        total
+    }
+
+    /// `(cos(x), sin(x))` for `|x| <= pi`.
+    fn cos_sin(&self, x: &Integer) -> FixedComplex {
+        let mut term = self.one.clone();
+        let (mut cos, mut sin) = (Integer::ZERO, Integer::ZERO);
+        let mut n = 0u32;
+        while term != Integer::ZERO {
+            let signed_term = if n % 4 < 2 { term.clone() } else { -&term };
+            if n.is_multiple_of(2) {
+                cos += signed_term;
+            } else {
+                sin += signed_term;
+            }
+            n += 1;
+            term = self.mul(&term, x) / Integer::from(n);
+        }
+        (cos, sin)
+    }
+
+    fn complex_mul(&self, a: &FixedComplex, b: &FixedComplex) -> FixedComplex {
+        (
+            self.mul(&a.0, &b.0) - self.mul(&a.1, &b.1),
+            self.mul(&a.0, &b.1) + self.mul(&a.1, &b.0),
+        )
+    }
+
+    fn complex_div(&self, a: &FixedComplex, b: &FixedComplex) -> FixedComplex {
+        let d = self.mul(&b.0, &b.0) + self.mul(&b.1, &b.1);
+        (
+            self.div(&(self.mul(&a.0, &b.0) + self.mul(&a.1, &b.1)), &d),
+            self.div(&(self.mul(&a.1, &b.0) - self.mul(&a.0, &b.1)), &d),
+        )
+    }
+}
+
+/// The j-invariant `j(tau) = E_4(tau)^3 / Delta(tau)` at `tau = (-b + sqrt(disc)) / 2a` for a positive definite form `(a, b, c)` of discriminant `disc`.
+///
+/// With `q = exp(2 pi i tau)` this is `q^-1 (1 + 240 sum_n sigma_3(n) q^n)^3 / prod_n (1 - q^n)^24`.
+fn j_invariant(fp: &FixedPoint, pi: &Integer, disc: &Integer, (a, b, _): &Form) -> FixedComplex {
+    // 2 pi i tau = -y - i theta
+    let y = fp.mul(pi, &fp.sqrt(&Abs::abs(disc))) / a;
+    let theta = pi * b / a;
+    let exp_y = fp.exp(&y);
+    let exp_minus_y = fp.div(&fp.one, &exp_y);
+    let (cos, sin) = fp.cos_sin(&theta);
+    let q = (fp.mul(&exp_minus_y, &cos), -fp.mul(&exp_minus_y, &sin));
+    let q_inv = (fp.mul(&exp_y, &cos), fp.mul(&exp_y, &sin));
+
+    let mut e4 = (fp.one.clone(), Integer::ZERO);
+    let mut eta24 = (fp.one.clone(), Integer::ZERO);
+    let mut q_pow = q.clone();
+    let mut n = 1u32;
+    while q_pow != (Integer::ZERO, Integer::ZERO) {
+        let sigma_3 = (1..=n)
+            .filter(|d| n.is_multiple_of(*d))
+            .map(|d| Integer::from(d).nat_pow(&Natural::from(3u32)))
+            .fold(Integer::ZERO, |acc, t| acc + t);
+        let coeff = Integer::from(240) * sigma_3;
+        e4 = (e4.0 + &coeff * &q_pow.0, e4.1 + &coeff * &q_pow.1);
+        let one_minus_q_pow = (&fp.one - &q_pow.0, -&q_pow.1);
+        for _ in 0..24 {
+            eta24 = fp.complex_mul(&eta24, &one_minus_q_pow);
+        }
+        q_pow = fp.complex_mul(&q_pow, &q);
+        n += 1;
+    }
+    let e4_cubed = fp.complex_mul(&fp.complex_mul(&e4, &e4), &e4);
+    fp.complex_mul(&fp.complex_div(&e4_cubed, &eta24), &q_inv)
+}
+
+/// The Hilbert class polynomial `prod (x - j((-b + sqrt(disc)) / 2a))` of a negative discriminant `disc`, where the product is over the reduced forms `(a, b, c)` of discriminant `disc`.
+///
+/// The j-invariants are approximated to enough precision that the integer coefficients can be recovered by rounding.
+pub fn hilbert_class_polynomial(disc: &Integer) -> Polynomial<Integer> {
+    assert!(disc < &Integer::ZERO);
+    let forms = reduced_definite_forms(disc);
+    // |j(tau)| <= |q|^-1 + 2079 so log_2 of the largest coefficient is at most sum_i (log_2 |q_i|^-1 + 12)
+    let sqrt_disc = Rational::from(disc).to_f64().abs().sqrt();
+    let coeff_bits = forms
+        .iter()
+        .map(|(a, _, _)| {
+            std::f64::consts::PI * sqrt_disc / Rational::from(a).to_f64() * std::f64::consts::LOG2_E
+                + 12.0
+        })
+        .sum::<f64>()
+        .ceil() as usize;
+    let fp = FixedPoint::new(2 * coeff_bits + 64);
+    let pi = fp.pi();
+    let mut h: Vec<FixedComplex> = vec![(fp.one.clone(), Integer::ZERO)];
+    for form in &forms {
+        // multiply by x - j
+        let j = j_invariant(&fp, &pi, disc, form);
+        let mut next = vec![(Integer::ZERO, Integer::ZERO); h.len() + 1];
+        for (i, c) in h.iter().enumerate() {
+            next[i + 1].0 += &c.0;
+            next[i + 1].1 += &c.1;
+            let jc = fp.complex_mul(&j, c);
+            next[i].0 -= jc.0;
+            next[i].1 -= jc.1;
+        }
+        h = next;
+    }
+    // the roots come in complex conjugate pairs so the coefficients are real
+    debug_assert!(h.iter().all(|(_, im)| fp.round(im) == Integer::ZERO));
+    Polynomial::from_coeffs(h.iter().map(|(re, _)| fp.round(re)).collect())
+}
+
+impl<D: BorrowedElem<Integer>> QuadraticRingOfIntegersStructure<D> {
+    /// The Hilbert class polynomial of the discriminant of an imaginary quadratic field, or `None` for a real quadratic field.
+    ///
+    /// Its roots are the j-invariants of the elliptic curves with complex multiplication by this ring.
+    /// It is irreducible over the field of fractions and its degree is the class number.
+    pub fn hilbert_class_polynomial(&self) -> Option<Polynomial<Integer>> {
+        let disc = self.anf().discriminant();
+        if disc > Integer::ZERO {
+            return None;
+        }
+        Some(hilbert_class_polynomial(&disc))
+    }
+
+    /// The Hilbert class field of an imaginary quadratic field `K` as an absolute number field, or `None` for a real quadratic field.
+    ///
+    /// This is the maximal abelian unramified extension of `K` and has Galois group over `K` isomorphic to the ideal class group.
+    /// By the theory of complex multiplication it is generated over `K` by a root `j` of the Hilbert class polynomial.
+    /// It is returned as `Q(j + k sqrt(d))` for the least `k >= 1` for which `j + k sqrt(d)` has `2h` distinct conjugates, where `h` is the class number.
+    /// When the class number is `1` this is `K` itself.
+    pub fn hilbert_class_field(&self) -> Option<AlgebraicNumberFieldPolynomialQuotientStructure> {
+        let polys = Integer::structure().into_polynomials();
+        let d = self.d();
+        let h = self.hilbert_class_polynomial()?;
+        let x = Polynomial::<Integer>::var();
+        if h.degree() == Some(1) {
+            return Some(
+                Polynomial::<Rational>::from_coeffs(vec![
+                    Rational::from(-d),
+                    Rational::ZERO,
+                    Rational::ONE,
+                ])
+                .algebraic_number_field_unchecked(),
+            );
+        }
+        let mut k = Integer::ONE;
+        loop {
+            // H(x - k sqrt(d)) = A + sqrt(d) B by Horner's method
+            let mut a = Polynomial::<Integer>::zero();
+            let mut b = Polynomial::<Integer>::zero();
+            for c in h.coeffs().collect::<Vec<_>>().into_iter().rev() {
+                (a, b) = (
+                    polys.add(
+                        &polys.sub(
+                            &polys.mul(&a, &x),
+                            &polys.mul(&Polynomial::constant(&k * d), &b),
+                        ),
+                        &Polynomial::constant(c.clone()),
+                    ),
+                    polys.sub(
+                        &polys.mul(&b, &x),
+                        &polys.mul(&Polynomial::constant(k.clone()), &a),
+                    ),
+                );
+            }
+            // the norm H(x - k sqrt(d)) H(x + k sqrt(d)) is the characteristic polynomial of j + k sqrt(d)
+            let f = polys.sub(
+                &polys.mul(&a, &a),
+                &polys.mul(&Polynomial::constant(d.clone()), &polys.mul(&b, &b)),
+            );
+            if gcd_subresultant(&f, &polys.derivative(f.clone())).degree() == Some(0) {
+                return Some(
+                    f.apply_map(|c| Rational::from(c))
+                        .algebraic_number_field_unchecked(),
+                );
+            }
+            k += Integer::ONE;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poly(coeffs: Vec<i64>) -> Polynomial<Integer> {
+        Polynomial::from_coeffs(coeffs.into_iter().map(Integer::from).collect())
+    }
+
+    #[test]
+    fn hilbert_class_polynomials() {
+        assert_eq!(
+            hilbert_class_polynomial(&Integer::from(-3)),
+            poly(vec![0, 1])
+        );
+        assert_eq!(
+            hilbert_class_polynomial(&Integer::from(-4)),
+            poly(vec![-1728, 1])
+        );
+        assert_eq!(
+            hilbert_class_polynomial(&Integer::from(-7)),
+            poly(vec![3375, 1])
+        );
+        assert_eq!(
+            hilbert_class_polynomial(&Integer::from(-15)),
+            poly(vec![-121287375, 191025, 1])
+        );
+        assert_eq!(
+            hilbert_class_polynomial(&Integer::from(-20)),
+            poly(vec![-681472000, -1264000, 1])
+        );
+        assert_eq!(
+            hilbert_class_polynomial(&Integer::from(-23)),
+            poly(vec![12771880859375, -5151296875, 3491750, 1])
+        );
+        // j((1 + sqrt(-163)) / 2) = -640320^3
+        assert_eq!(
+            hilbert_class_polynomial(&Integer::from(-163)),
+            Polynomial::from_coeffs(vec![
+                Integer::from(640320).nat_pow(&Natural::from(3u32)),
+                Integer::ONE
+            ])
+        );
+    }
+
+    #[test]
+    fn hilbert_class_polynomial_degree_is_class_number() {
+        for d in [-5, -14, -71, -89] {
+            let roi = QuadraticRingOfIntegersStructure::new(Integer::from(d)).unwrap();
+            let h = roi.hilbert_class_polynomial().unwrap();
+            assert_eq!(Natural::from(h.degree().unwrap()), roi.class_number());
+            assert!(h.is_monic());
+        }
+    }
+
+    #[test]
+    fn hilbert_class_field_of_class_number_one() {
+        let roi = QuadraticRingOfIntegersStructure::new(Integer::from(-1)).unwrap();
+        let hcf = roi.hilbert_class_field().unwrap();
+        assert_eq!(hcf.degree(), 2);
+        assert_eq!(hcf.discriminant(), Integer::from(-4));
+
+        let roi = QuadraticRingOfIntegersStructure::new(Integer::from(5)).unwrap();
+        assert!(roi.hilbert_class_polynomial().is_none());
+        assert!(roi.hilbert_class_field().is_none());
+    }
+
+    #[test]
+    fn hilbert_class_field_of_sqrt_minus_23() {
+        let roi = QuadraticRingOfIntegersStructure::new(Integer::from(-23)).unwrap();
+        assert_eq!(roi.class_number(), Natural::from(3u32));
+        let h = roi.hilbert_class_polynomial().unwrap();
+        assert_eq!(h.degree(), Some(3));
+        let hcf = roi.hilbert_class_field().unwrap();
+        assert_eq!(hcf.degree(), 6);
+
+        // the generator is j + k sqrt(-23) where j is a root of H and k is a nonzero integer
+        // so gcd(H(g - y), y^2 + 23) over the Hilbert class field is linear with root k sqrt(-23)
+        let hcf_polys = hcf.polynomials();
+        let g = hcf.generator();
+        let y = Polynomial::<Polynomial<Rational>>::var();
+        let g_minus_y = hcf_polys.sub(&Polynomial::constant(g.clone()), &y);
+        let h_of_g_minus_y = hcf_polys.compose(
+            &h.apply_map(|c| Polynomial::constant(Rational::from(c))),
+            &g_minus_y,
+        );
+        let y_sqrd_plus_23 = Polynomial::from_coeffs(vec![
+            Polynomial::constant(Rational::from(23)),
+            Polynomial::zero(),
+            Polynomial::one(),
+        ]);
+        let common = hcf_polys.gcd(&h_of_g_minus_y, &y_sqrd_plus_23);
+        assert_eq!(hcf_polys.degree(&common), Some(1));
+        let s = hcf.neg(&hcf_polys.fav_assoc(&common).coeff(0));
+        let j = hcf.sub(&g, &s);
+        assert!(hcf.is_zero(&hcf.reduce(&Polynomial::compose(
+            &h.apply_map(|c| Rational::from(c)),
+            &j
+        ))));
+    }
+}
@@ -1,7 +1,12 @@
+mod class_number;
+mod hilbert_class_field;
 mod isomorphism_with_polynomial_quotient;
+mod prime_splitting;
 mod quadratic_number_field;
 mod quadratic_ring_of_integers;
 
+pub use hilbert_class_field::*;
 pub use isomorphism_with_polynomial_quotient::*;
+pub use prime_splitting::*;
 pub use quadratic_number_field::*;
 pub use quadratic_ring_of_integers::*;
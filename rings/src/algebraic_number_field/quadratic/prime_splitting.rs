@@ -0,0 +1,108 @@
+use super::*;
+use crate::{
+    algebraic_number_field::AlgebraicNumberFieldSignature,
+    num_theory::modulo::quadratic_symbols::{QuadraticSymbolValue, kronecker_symbol},
+    structure::MetaFactoringMonoid,
+};
+use algebraeon_structures::*;
+
+/// How a rational prime `p` factors into prime ideals in the ring of integers of a quadratic number field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplittingType {
+    /// `pO = P Q` for distinct prime ideals `P` and `Q`.
+    Split,
+    /// `pO` is prime.
+    Inert,
+    /// `pO = P^2` for a prime ideal `P`.
+    Ramified,
+}
+
+/// How the prime `p` factors in the ring of integers of `Q(sqrt(d))` where `d` is a squarefree integer other than `0` and `1`.
+///
+/// If `D` is the discriminant of `Q(sqrt(d))` then `p` ramifies when `p` divides `D` and otherwise splits or is inert according to whether the Kronecker symbol `(D/p)` is `1` or `-1`.
+pub fn splitting_type(d: &Integer, p: &Natural) -> SplittingType {
+    QuadraticNumberFieldStructure::new(d.clone())
+        .unwrap()
+        .prime_splitting_type(p)
+}
+
+impl<D: BorrowedElem<Integer>> QuadraticNumberFieldStructure<D> {
+    /// How the prime `p` factors in the ring of integers.
+    pub fn prime_splitting_type(&self, p: &Natural) -> SplittingType {
+        debug_assert!(p.is_irreducible());
+        match kronecker_symbol(&self.discriminant(), &Integer::from(p)) {
+            QuadraticSymbolValue::Zero => SplittingType::Ramified,
+            QuadraticSymbolValue::Pos => SplittingType::Split,
+            QuadraticSymbolValue::Neg => SplittingType::Inert,
+        }
+    }
+}
+
+impl<D: BorrowedElem<Integer>> QuadraticRingOfIntegersStructure<D> {
+    /// How the prime `p` factors in this ring.
+    pub fn prime_splitting_type(&self, p: &Natural) -> SplittingType {
+        self.anf().prime_splitting_type(p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splitting_in_gaussian_integers() {
+        let d = Integer::from(-1);
+        assert_eq!(
+            splitting_type(&d, &Natural::from(2u32)),
+            SplittingType::Ramified
+        );
+        for p in [5u32, 13, 17, 29] {
+            assert_eq!(splitting_type(&d, &Natural::from(p)), SplittingType::Split);
+        }
+        for p in [3u32, 7, 11, 19] {
+            assert_eq!(splitting_type(&d, &Natural::from(p)), SplittingType::Inert);
+        }
+    }
+
+    #[test]
+    fn splitting_in_sqrt_minus_5() {
+        // the discriminant of Q(sqrt(-5)) is -20
+        let roi = QuadraticRingOfIntegersStructure::new(Integer::from(-5)).unwrap();
+        assert_eq!(
+            roi.prime_splitting_type(&Natural::from(2u32)),
+            SplittingType::Ramified
+        );
+        assert_eq!(
+            roi.prime_splitting_type(&Natural::from(3u32)),
+            SplittingType::Split
+        );
+        assert_eq!(
+            roi.prime_splitting_type(&Natural::from(5u32)),
+            SplittingType::Ramified
+        );
+        assert_eq!(
+            roi.prime_splitting_type(&Natural::from(7u32)),
+            SplittingType::Split
+        );
+        assert_eq!(
+            roi.prime_splitting_type(&Natural::from(11u32)),
+            SplittingType::Inert
+        );
+    }
+
+    #[test]
+    fn splitting_at_two() {
+        // 2 splits when d = 1 mod 8, is inert when d = 5 mod 8 and ramifies otherwise
+        for (d, t) in [
+            (-7, SplittingType::Split),
+            (17, SplittingType::Split),
+            (-3, SplittingType::Inert),
+            (5, SplittingType::Inert),
+            (2, SplittingType::Ramified),
+            (3, SplittingType::Ramified),
+            (-1, SplittingType::Ramified),
+        ] {
+            assert_eq!(splitting_type(&Integer::from(d), &Natural::TWO), t);
+        }
+    }
+}
@@ -0,0 +1,378 @@
+use crate::structure::*;
+use algebraeon_structures::*;
+use std::marker::PhantomData;
+
+/// The ring `R[i]` of complex numbers `re + im i` with `re` and `im` in the ring `R` and `i^2 = -1`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComplexNumberStructure<R: RingSignature> {
+    base: R,
+}
+
+/// The complex number `re + im i`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComplexNumber<R: RingSignature> {
+    pub re: R::Elem,
+    pub im: R::Elem,
+}
+
+/// Complex numbers with rational real and imaginary parts.
+pub type ComplexRational = ComplexNumber<RationalCanonicalStructure>;
+
+impl ComplexRational {
+    pub fn structure() -> ComplexNumberStructure<RationalCanonicalStructure> {
+        ComplexNumberStructure::new(Rational::structure())
+    }
+}
+
+impl<R: RingSignature> ComplexNumberStructure<R> {
+    pub fn new(base: R) -> Self {
+        Self { base }
+    }
+
+    pub fn base_ring(&self) -> &R {
+        &self.base
+    }
+
+    pub fn i(&self) -> ComplexNumber<R> {
+        ComplexNumber {
+            re: self.base.zero(),
+            im: self.base.one(),
+        }
+    }
+
+    pub fn from_real(&self, x: &R::Elem) -> ComplexNumber<R> {
+        ComplexNumber {
+            re: x.clone(),
+            im: self.base.zero(),
+        }
+    }
+
+    pub fn from_parts(&self, re: R::Elem, im: R::Elem) -> ComplexNumber<R> {
+        ComplexNumber { re, im }
+    }
+
+    pub fn conj(&self, a: &ComplexNumber<R>) -> ComplexNumber<R> {
+        ComplexNumber {
+            re: a.re.clone(),
+            im: self.base.neg(&a.im),
+        }
+    }
+
+    /// `re^2 + im^2`
+    pub fn norm_sq(&self, a: &ComplexNumber<R>) -> R::Elem {
+        self.base
+            .add(&self.base.mul(&a.re, &a.re), &self.base.mul(&a.im, &a.im))
+    }
+
+    /// The inclusion `R -> R[i]`.
+    pub fn embed_ring(&self) -> ComplexNumberRealInclusion<R, &Self> {
+        ComplexNumberRealInclusion::new(self)
+    }
+
+    pub fn into_embed_ring(self) -> ComplexNumberRealInclusion<R, Self> {
+        ComplexNumberRealInclusion::new(self)
+    }
+}
+
+impl<R: RingSignature> Signature for ComplexNumberStructure<R> {}
+
+impl<R: RingSignature> SetSignature for ComplexNumberStructure<R> {
+    type Elem = ComplexNumber<R>;
+
+    fn validate_element(&self, x: &Self::Elem) -> Result<(), String> {
+        self.base.validate_element(&x.re)?;
+        self.base.validate_element(&x.im)
+    }
+}
+
+impl<R: RingEqSignature> EqSignature for ComplexNumberStructure<R> {
+    fn equal(&self, a: &Self::Elem, b: &Self::Elem) -> bool {
+        self.base.equal(&a.re, &b.re) && self.base.equal(&a.im, &b.im)
+    }
+}
+
+impl<R: RingEqSignature> RinglikeSpecializationSignature for ComplexNumberStructure<R> {
+    fn try_ring_restructure(&self) -> Option<impl EqSignature<Elem = Self::Elem> + RingSignature> {
+        Some(self.clone())
+    }
+}
+
+impl<R: RingEqSignature> ZeroSignature for ComplexNumberStructure<R> {
+    fn zero(&self) -> Self::Elem {
+        self.from_real(&self.base.zero())
+    }
+}
+
+impl<R: RingEqSignature> AdditionSignature for ComplexNumberStructure<R> {
+    fn add(&self, a: &Self::Elem, b: &Self::Elem) -> Self::Elem {
+        ComplexNumber {
+            re: self.base.add(&a.re, &b.re),
+            im: self.base.add(&a.im, &b.im),
+        }
+    }
+}
+
+impl<R: RingEqSignature> CancellativeAdditionSignature for ComplexNumberStructure<R> {
+    fn try_sub(&self, a: &Self::Elem, b: &Self::Elem) -> Option<Self::Elem> {
+        Some(self.sub(a, b))
+    }
+}
+
+impl<R: RingEqSignature> TryNegateSignature for ComplexNumberStructure<R> {
+    fn try_neg(&self, a: &Self::Elem) -> Option<Self::Elem> {
+        Some(self.neg(a))
+    }
+}
+
+impl<R: RingEqSignature> AdditiveMonoidSignature for ComplexNumberStructure<R> {}
+
+impl<R: RingEqSignature> AdditiveGroupSignature for ComplexNumberStructure<R> {
+    fn neg(&self, a: &Self::Elem) -> Self::Elem {
+        ComplexNumber {
+            re: self.base.neg(&a.re),
+            im: self.base.neg(&a.im),
+        }
+    }
+
+    fn sub(&self, a: &Self::Elem, b: &Self::Elem) -> Self::Elem {
+        ComplexNumber {
+            re: self.base.sub(&a.re, &b.re),
+            im: self.base.sub(&a.im, &b.im),
+        }
+    }
+}
+
+impl<R: RingEqSignature> OneSignature for ComplexNumberStructure<R> {
+    fn one(&self) -> Self::Elem {
+        self.from_real(&self.base.one())
+    }
+}
+
+impl<R: RingEqSignature> MultiplicationSignature for ComplexNumberStructure<R> {
+    fn mul(&self, a: &Self::Elem, b: &Self::Elem) -> Self::Elem {
+        // (a + bi)(c + di) = (ac - bd) + (ad + bc)i
+        let base = &self.base;
+        ComplexNumber {
+            re: base.sub(&base.mul(&a.re, &b.re), &base.mul(&a.im, &b.im)),
+            im: base.add(&base.mul(&a.re, &b.im), &base.mul(&a.im, &b.re)),
+        }
+    }
+}
+
+impl<R: RingEqSignature> CommutativeMultiplicationSignature for ComplexNumberStructure<R> {}
+
+impl<R: RingEqSignature> MultiplicativeMonoidSignature for ComplexNumberStructure<R> {}
+
+impl<R: RingEqSignature> MultiplicativeAbsorptionMonoidSignature for ComplexNumberStructure<R> {}
+
+impl<R: RingEqSignature> LeftDistributiveMultiplicationOverAddition for ComplexNumberStructure<R> {}
+
+impl<R: RingEqSignature> RightDistributiveMultiplicationOverAddition for ComplexNumberStructure<R> {}
+
+impl<R: RingEqSignature> SemiRingSignature for ComplexNumberStructure<R> {}
+
+impl<R: RingEqSignature> RingSignature for ComplexNumberStructure<R> {}
+
+impl<R: RingEqSignature + TryReciprocalSignature> TryReciprocalSignature
+    for ComplexNumberStructure<R>
+{
+    fn try_reciprocal(&self, a: &Self::Elem) -> Option<Self::Elem> {
+        // a * conj(a) = norm_sq(a) so a is a unit exactly when norm_sq(a) is
+        let n_inv = self.base.try_reciprocal(&self.norm_sq(a))?;
+        let c = self.conj(a);
+        Some(ComplexNumber {
+            re: self.base.mul(&c.re, &n_inv),
+            im: self.base.mul(&c.im, &n_inv),
+        })
+    }
+
+    fn is_unit(&self, a: &Self::Elem) -> bool {
+        self.base.is_unit(&self.norm_sq(a))
+    }
+}
+
+impl<R: RingEqSignature + CharacteristicSignature> CharacteristicSignature
+    for ComplexNumberStructure<R>
+{
+    fn characteristic(&self) -> Natural {
+        self.base.characteristic()
+    }
+}
+
+impl<R: RingEqSignature + CharZeroRingSignature> CharZeroRingSignature
+    for ComplexNumberStructure<R>
+{
+    fn try_to_int(&self, a: &Self::Elem) -> Option<Integer> {
+        if self.base.is_zero(&a.im) {
+            self.base.try_to_int(&a.re)
+        } else {
+            None
+        }
+    }
+}
+
+impl<R: RingEqSignature + RealSubsetSignature> ComplexSubsetSignature
+    for ComplexNumberStructure<R>
+{
+    fn as_f32_real_and_imaginary_parts(&self, z: &Self::Elem) -> (f32, f32) {
+        (self.base.as_f32(&z.re), self.base.as_f32(&z.im))
+    }
+
+    fn as_f64_real_and_imaginary_parts(&self, z: &Self::Elem) -> (f64, f64) {
+        (self.base.as_f64(&z.re), self.base.as_f64(&z.im))
+    }
+}
+
+impl<R: RingEqSignature + RealSubsetSignature> ComplexConjugateSignature
+    for ComplexNumberStructure<R>
+{
+    fn conjugate(&self, x: &Self::Elem) -> Self::Elem {
+        self.conj(x)
+    }
+}
+
+/// The inclusion `R -> R[i]` sending `x` to `x + 0i`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComplexNumberRealInclusion<
+    R: RingSignature,
+    CB: BorrowedStructure<ComplexNumberStructure<R>>,
+> {
+    _r: PhantomData<R>,
+    complex: CB,
+}
+
+impl<R: RingSignature, CB: BorrowedStructure<ComplexNumberStructure<R>>>
+    ComplexNumberRealInclusion<R, CB>
+{
+    pub fn new(complex: CB) -> Self {
+        Self {
+            _r: PhantomData,
+            complex,
+        }
+    }
+}
+
+impl<R: RingEqSignature, CB: BorrowedStructure<ComplexNumberStructure<R>>>
+    Morphism<R, ComplexNumberStructure<R>> for ComplexNumberRealInclusion<R, CB>
+{
+    fn domain(&self) -> &R {
+        self.complex.borrow().base_ring()
+    }
+
+    fn range(&self) -> &ComplexNumberStructure<R> {
+        self.complex.borrow()
+    }
+}
+
+impl<R: RingEqSignature, CB: BorrowedStructure<ComplexNumberStructure<R>>>
+    Function<R, ComplexNumberStructure<R>> for ComplexNumberRealInclusion<R, CB>
+{
+    fn image(&self, x: &R::Elem) -> ComplexNumber<R> {
+        self.range().from_real(x)
+    }
+}
+
+impl<R: RingEqSignature, CB: BorrowedStructure<ComplexNumberStructure<R>>>
+    InjectiveFunction<R, ComplexNumberStructure<R>> for ComplexNumberRealInclusion<R, CB>
+{
+    fn try_preimage(&self, y: &ComplexNumber<R>) -> Option<R::Elem> {
+        if self.domain().is_zero(&y.im) {
+            Some(y.re.clone())
+        } else {
+            None
+        }
+    }
+}
+
+impl<R: RingEqSignature, CB: BorrowedStructure<ComplexNumberStructure<R>>>
+    RingHomomorphism<R, ComplexNumberStructure<R>> for ComplexNumberRealInclusion<R, CB>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn q(n: i64, d: i64) -> Rational {
+        Rational::from_integers(n, d)
+    }
+
+    #[test]
+    fn test_complex_rational_arithmetic() {
+        let c = ComplexRational::structure();
+        let a = c.from_parts(q(1, 2), q(3, 1));
+        let b = c.from_parts(q(-2, 1), q(1, 3));
+
+        assert_eq!(c.add(&a, &b), c.from_parts(q(-3, 2), q(10, 3)));
+        assert_eq!(c.sub(&a, &b), c.from_parts(q(5, 2), q(8, 3)));
+        // (1/2 + 3i)(-2 + i/3) = (-1 - 1) + (1/6 - 6)i
+        assert_eq!(c.mul(&a, &b), c.from_parts(q(-2, 1), q(-35, 6)));
+        assert_eq!(c.neg(&a), c.from_parts(q(-1, 2), q(-3, 1)));
+        assert_eq!(c.conj(&a), c.from_parts(q(1, 2), q(-3, 1)));
+        assert_eq!(c.norm_sq(&a), q(37, 4));
+        assert_eq!(c.mul(&a, &c.conj(&a)), c.from_real(&c.norm_sq(&a)));
+
+        let i = c.i();
+        assert_eq!(c.mul(&i, &i), c.neg(&c.one()));
+        assert_eq!(c.nat_pow(&i, &Natural::from(4u32)), c.one());
+
+        let a_inv = c.try_reciprocal(&a).unwrap();
+        assert_eq!(c.mul(&a, &a_inv), c.one());
+        assert!(c.try_reciprocal(&c.zero()).is_none());
+
+        assert_eq!(c.try_to_int(&c.from_int(-7)), Some(Integer::from(-7)));
+        assert_eq!(c.try_to_int(&i), None);
+        assert_eq!(c.as_f64_real_and_imaginary_parts(&a), (0.5, 3.0));
+    }
+
+    #[test]
+    fn test_gaussian_integer_units() {
+        let c = ComplexNumberStructure::new(Integer::structure());
+        let i = c.i();
+        assert!(c.is_unit(&i));
+        assert_eq!(c.try_reciprocal(&i), Some(c.neg(&i)));
+        let one_plus_i = c.add(&c.one(), &i);
+        assert!(!c.is_unit(&one_plus_i));
+        assert_eq!(c.norm_sq(&one_plus_i), Integer::from(2));
+    }
+
+    #[test]
+    fn test_embed_ring() {
+        let c = ComplexRational::structure();
+        let embed = c.embed_ring();
+        let x = q(5, 7);
+        assert_eq!(embed.image(&x), c.from_parts(q(5, 7), q(0, 1)));
+        assert_eq!(embed.try_preimage(&embed.image(&x)), Some(x));
+        assert_eq!(embed.try_preimage(&c.i()), None);
+        assert_eq!(
+            c.mul(&embed.image(&q(2, 1)), &embed.image(&q(3, 1))),
+            embed.image(&q(6, 1))
+        );
+    }
+
+    #[test]
+    fn test_ring_axioms() {
+        let c = ComplexRational::structure();
+        let values = [q(0, 1), q(1, 1), q(-1, 2), q(3, 5)];
+        let mut elements = vec![];
+        for re in &values {
+            for im in &values {
+                elements.push(c.from_parts(re.clone(), im.clone()));
+            }
+        }
+        for a in &elements {
+            assert!(c.equal(&c.add(a, &c.zero()), a));
+            assert!(c.equal(&c.mul(a, &c.one()), a));
+            assert!(c.is_zero(&c.add(a, &c.neg(a))));
+            for b in &elements {
+                assert!(c.equal(&c.add(a, b), &c.add(b, a)));
+                assert!(c.equal(&c.mul(a, b), &c.mul(b, a)));
+                for d in &elements {
+                    assert!(c.equal(&c.add(&c.add(a, b), d), &c.add(a, &c.add(b, d))));
+                    assert!(c.equal(&c.mul(&c.mul(a, b), d), &c.mul(a, &c.mul(b, d))));
+                    assert!(c.equal(&c.mul(a, &c.add(b, d)), &c.add(&c.mul(a, b), &c.mul(a, d))));
+                }
+            }
+        }
+    }
+}
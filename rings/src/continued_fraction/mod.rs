@@ -21,6 +21,22 @@ pub trait SimpleContinuedFraction: Debug + Clone + Send + Sync {
             .map(|c| c.unwrap())
     }
 
+    /// The convergents `p_k / q_k` obtained by truncating after the `k`th coefficient, computed lazily using
+    /// `p_k = a_k p_{k-1} + p_{k-2}` and `q_k = a_k q_{k-1} + q_{k-2}`.
+    fn convergents(&'_ self) -> impl Iterator<Item = Rational> + '_ {
+        // start from (p_{-1}, q_{-1}) = (1, 0) and (p_{-2}, q_{-2}) = (0, 1)
+        self.iter().scan(
+            ((Integer::ONE, Integer::ZERO), (Integer::ZERO, Integer::ONE)),
+            |(prev, prev_prev), a| {
+                let p = a.as_ref() * &prev.0 + &prev_prev.0;
+                let q = a.as_ref() * &prev.1 + &prev_prev.1;
+                let convergent = Rational::from_integers(&p, &q);
+                *prev_prev = std::mem::replace(prev, (p, q));
+                Some(convergent)
+            },
+        )
+    }
+
     fn rational_approximations(self) -> RationalApproximations<Self>
     where
         Self: Sized,
@@ -225,6 +241,35 @@ impl<R: ToSimpleContinuedFractionSignature> SimpleContinuedFraction
     }
 }
 
+/// The rational number closest to `r` among those with denominator at most `max_denom`.
+///
+/// This is either the last convergent of `r` with denominator at most `max_denom` or the semiconvergent `(p_{k-1} + t p_k) / (q_{k-1} + t q_k)` with the largest `t` keeping the denominator in range.
+/// Panics if `max_denom` is zero.
+pub fn best_approximation_with_bounded_denominator(r: &Rational, max_denom: &Natural) -> Rational {
+    assert_ne!(max_denom, &Natural::ZERO);
+    let max_denom = Integer::from(max_denom);
+    let scf = r.clone().simple_continued_fraction();
+    let (mut prev, mut prev_prev) = ((Integer::ONE, Integer::ZERO), (Integer::ZERO, Integer::ONE));
+    for a in scf.iter() {
+        let q = a.as_ref() * &prev.1 + &prev_prev.1;
+        if q > max_denom {
+            // the largest t with q_{k-1} + t q_k <= max_denom, which is less than a
+            let t = (&max_denom - &prev_prev.1) / &prev.1;
+            let convergent = Rational::from_integers(&prev.0, &prev.1);
+            let semiconvergent =
+                Rational::from_integers(&t * &prev.0 + &prev_prev.0, &t * &prev.1 + &prev_prev.1);
+            return if (&semiconvergent - r).abs() < (&convergent - r).abs() {
+                semiconvergent
+            } else {
+                convergent
+            };
+        }
+        let p = a.as_ref() * &prev.0 + &prev_prev.0;
+        prev_prev = std::mem::replace(&mut prev, (p, q));
+    }
+    r.clone()
+}
+
 /// Implementing this trait is only valid if self.try_inv only returns None when given 0
 #[signature_meta_trait]
 pub trait ToSimpleContinuedFractionSignature: RealRoundingSignature + RingUnitsSignature {
@@ -343,4 +388,88 @@ mod tests {
             .is_err()
         );
     }
+
+    #[test]
+    fn test_convergents() {
+        let convergents = |rat: &'static str| {
+            Rational::from_str(rat)
+                .unwrap()
+                .simple_continued_fraction()
+                .convergents()
+                .collect::<Vec<_>>()
+        };
+        let rats = |rats: Vec<&'static str>| {
+            rats.into_iter()
+                .map(|r| Rational::from_str(r).unwrap())
+                .collect::<Vec<_>>()
+        };
+        // 355/113 = [3; 7, 16]
+        assert_eq!(convergents("355/113"), rats(vec!["3", "22/7", "355/113"]));
+        assert_eq!(
+            convergents("-5678/1234"),
+            rats(vec![
+                "-5",
+                "-9/2",
+                "-14/3",
+                "-23/5",
+                "-704/153",
+                "-2839/617"
+            ])
+        );
+        assert_eq!(convergents("0"), rats(vec!["0"]));
+
+        // the convergents of the golden ratio [1; 1, 1, ...] are ratios of consecutive Fibonacci numbers
+        let golden_ratio =
+            PeriodicSimpleContinuedFraction::new(vec![], vec![Integer::ONE]).unwrap();
+        assert_eq!(
+            golden_ratio.convergents().take(6).collect::<Vec<_>>(),
+            rats(vec!["1", "2", "3/2", "5/3", "8/5", "13/8"])
+        );
+        // agrees with the cached approximations
+        let approximations = golden_ratio.clone().rational_approximations();
+        for (n, c) in golden_ratio.convergents().take(20).enumerate() {
+            assert_eq!(c, approximations.get_rat(n));
+        }
+    }
+
+    #[test]
+    fn test_best_approximation_with_bounded_denominator() {
+        let best = |rat: &'static str, n: u32| {
+            best_approximation_with_bounded_denominator(
+                &Rational::from_str(rat).unwrap(),
+                &Natural::from(n),
+            )
+        };
+        assert_eq!(best("355/113", 7), Rational::from_str("22/7").unwrap());
+        assert_eq!(best("355/113", 113), Rational::from_str("355/113").unwrap());
+        assert_eq!(
+            best("355/113", 1000),
+            Rational::from_str("355/113").unwrap()
+        );
+        assert_eq!(best("355/113", 1), Rational::from(3));
+        assert_eq!(best("27/10", 1), Rational::from(3));
+        assert_eq!(best("27/10", 2), Rational::from_str("5/2").unwrap());
+        // 333/106 is a semiconvergent of 3.14159
+        assert_eq!(
+            best("314159/100000", 110),
+            Rational::from_str("333/106").unwrap()
+        );
+
+        // no fraction with a bounded denominator is strictly closer
+        for rat in ["314159/100000", "-271828/100000", "141421/100000", "7/1000"] {
+            let r = Rational::from_str(rat).unwrap();
+            for n in 1..60u32 {
+                let b = best_approximation_with_bounded_denominator(&r, &Natural::from(n));
+                assert!(b.clone().denominator() <= Natural::from(n));
+                for q in 1..=n {
+                    let q = Rational::from(q);
+                    let p = (&r * &q).floor();
+                    for p in [p.clone(), p + Integer::ONE] {
+                        let other = Rational::from(p) / &q;
+                        assert!((&b - &r).abs() <= (&other - &r).abs());
+                    }
+                }
+            }
+        }
+    }
 }
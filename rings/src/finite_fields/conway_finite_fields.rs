@@ -169,7 +169,11 @@ impl CountableSetSignature for ConwayFiniteFieldStructure {
     }
 }
 
-impl FiniteSetSignature for ConwayFiniteFieldStructure {}
+impl FiniteSetSignature for ConwayFiniteFieldStructure {
+    fn generate_random_elements(&self, seed: u64) -> impl Iterator<Item = Self::Elem> {
+        self.structure.generate_random_elements(seed)
+    }
+}
 
 impl CharacteristicSignature for ConwayFiniteFieldStructure {
     fn characteristic(&self) -> Natural {
@@ -1,5 +1,8 @@
 use crate::{
-    num_theory::modulo::const_naive::ModuloCanonicalStructure, polynomial::*, structure::*,
+    matrix::{Matrix, RingMatricesSignature},
+    num_theory::modulo::const_naive::ModuloCanonicalStructure,
+    polynomial::*,
+    structure::*,
 };
 use algebraeon_structures::*;
 use itertools::Itertools;
@@ -66,6 +69,94 @@ impl<
     }
 }
 
+impl<
+    FS: FiniteFieldSignature,
+    FSB: BorrowedStructure<FS>,
+    FSPB: BorrowedStructure<PolynomialStructure<FS, FSB>>,
+> PolynomialQuotientRingStructure<FS, FSB, FSPB, true>
+{
+    /// The Frobenius automorphism `x -> x^p` where `p` is the characteristic.
+    pub fn frobenius(&self, x: &Polynomial<FS::Elem>) -> Polynomial<FS::Elem> {
+        self.nat_pow(x, &self.characteristic_and_power().0)
+    }
+
+    /// The `k`th power of the Frobenius automorphism `x -> x^{p^k}` where `p` is the characteristic.
+    pub fn frobenius_k(&self, x: &Polynomial<FS::Elem>, k: usize) -> Polynomial<FS::Elem> {
+        let mut y = x.clone();
+        for _ in 0..k {
+            y = self.frobenius(&y);
+        }
+        y
+    }
+
+    /// `x -> x^q` where `q` is the size of the base field, which generates the Galois group over the base field.
+    fn relative_frobenius(&self, x: &Polynomial<FS::Elem>) -> Polynomial<FS::Elem> {
+        self.nat_pow(x, &self.ring().coeff_ring().size())
+    }
+
+    /// The Galois conjugates `x, x^q, ..., x^{q^{d-1}}` of `x` over the base field of size `q` where `d` is the degree of the extension.
+    fn galois_conjugates(&self, x: &Polynomial<FS::Elem>) -> Vec<Polynomial<FS::Elem>> {
+        let mut conjugates = vec![x.clone()];
+        for _ in 1..self.degree() {
+            conjugates.push(self.relative_frobenius(conjugates.last().unwrap()));
+        }
+        conjugates
+    }
+
+    /// The trace of `x` over the base field, computed as the sum of the Galois conjugates of `x`.
+    pub fn field_trace(&self, x: &Polynomial<FS::Elem>) -> FS::Elem {
+        let t = self.sum(&self.galois_conjugates(x));
+        debug_assert!(self.ring().degree(&t).unwrap_or(0) == 0);
+        self.ring().coeff(&t, 0).into_owned()
+    }
+
+    /// The norm of `x` over the base field, computed as the product of the Galois conjugates of `x`.
+    pub fn field_norm(&self, x: &Polynomial<FS::Elem>) -> FS::Elem {
+        let n = self.product(&self.galois_conjugates(x));
+        debug_assert!(self.ring().degree(&n).unwrap_or(0) == 0);
+        self.ring().coeff(&n, 0).into_owned()
+    }
+
+    /// Whether `x` lies in the prime subfield, which is exactly when `x` is fixed by the Frobenius automorphism.
+    pub fn is_in_subfield(&self, x: &Polynomial<FS::Elem>) -> bool {
+        self.equal(&self.frobenius(x), x)
+    }
+
+    /// Whether the Galois conjugates `x, x^q, ..., x^{q^{d-1}}` of `x` over the base field of size `q` form a basis of the extension.
+    pub fn is_normal_basis_element(&self, x: &Polynomial<FS::Elem>) -> bool {
+        let d = self.degree();
+        let conjugates = Matrix::join_cols(
+            d,
+            self.galois_conjugates(x)
+                .iter()
+                .map(|y| self.to_col(y))
+                .collect(),
+        );
+        self.ring().coeff_ring().matrix_structure().rank(conjugates) == d
+    }
+
+    /// An element whose Galois conjugates over the base field form a basis of the extension.
+    /// Such an element exists by the normal basis theorem.
+    pub fn find_normal_basis_element(&self) -> Polynomial<FS::Elem> {
+        self.generate_all_elements()
+            .find(|x| self.is_normal_basis_element(x))
+            .unwrap()
+    }
+
+    /// The matrix whose columns are the images of the power basis `1, α, ..., α^{d-1}` under the Frobenius automorphism.
+    ///
+    /// When the base field is a prime field the Frobenius is linear and this is its matrix acting on column vectors.
+    pub fn frobenius_matrix(&self) -> Matrix<FS::Elem> {
+        let alpha = self.generator();
+        Matrix::join_cols(
+            self.degree(),
+            (0..self.degree())
+                .map(|i| self.to_col(&self.frobenius(&self.nat_pow(&alpha, &Natural::from(i)))))
+                .collect(),
+        )
+    }
+}
+
 pub fn new_finite_field_extension<FS: FiniteFieldSignature>(
     finite_field: FS,
     poly: Polynomial<FS::Elem>,
@@ -112,4 +203,175 @@ mod tests {
         }
         assert_eq!(c, 9);
     }
+
+    #[test]
+    fn test_f9_primitive_element() {
+        let f9 = f9();
+        let g = f9.primitive_element();
+        assert!(f9.is_primitive_element(&g));
+        assert_eq!(f9.multiplicative_order(&g), Natural::from(8u32));
+
+        let mut orders = f9
+            .all_units()
+            .iter()
+            .map(|x| f9.multiplicative_order(x))
+            .collect::<Vec<_>>();
+        orders.sort();
+        assert_eq!(
+            orders,
+            [1u32, 2, 4, 4, 8, 8, 8, 8].map(Natural::from).to_vec()
+        );
+        assert_eq!(
+            f9.all_units()
+                .iter()
+                .filter(|x| f9.is_primitive_element(x))
+                .count(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_f9_frobenius() {
+        let f9 = f9();
+        let d = f9.degree();
+        assert_eq!(d, 2);
+
+        // α is a root of 2x^2 + x + 1 whose other root is 1 - α
+        let alpha = f9.generator();
+        assert!(f9.equal(&f9.frobenius(&alpha), &f9.sub(&f9.one(), &alpha)));
+        assert!(!f9.equal(&f9.frobenius(&alpha), &alpha));
+
+        let frob = f9.frobenius_matrix();
+        for x in f9.list_all_elements() {
+            // frobenius^d is the identity
+            assert!(f9.equal(&f9.frobenius_k(&x, d), &x));
+            // the trace is the sum of the Galois conjugates
+            assert!(f9.equal(
+                &f9.sum(&(0..d).map(|k| f9.frobenius_k(&x, k)).collect::<Vec<_>>()),
+                &Polynomial::constant(f9.trace(&x))
+            ));
+            // the frobenius matrix agrees with the frobenius
+            assert!(f9.equal(
+                &f9.from_col(Matrix::mul(&frob, &f9.to_col(&x)).unwrap()),
+                &f9.frobenius(&x)
+            ));
+        }
+
+        // the fixed points of the frobenius are the prime subfield
+        assert_eq!(
+            f9.list_all_elements()
+                .into_iter()
+                .filter(|x| f9.equal(&f9.frobenius(x), x))
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_field_trace_and_norm() {
+        use crate::num_theory::modulo::const_naive::*;
+
+        fn check<FS: FiniteFieldSignature>(
+            field: PolynomialQuotientRingStructure<FS, FS, PolynomialStructure<FS, FS>, true>,
+        ) {
+            let (p, _) = field.characteristic_and_power();
+            let d = field.degree();
+            let base = field.ring().coeff_ring();
+
+            // Tr(1) = d
+            assert!(base.equal(
+                &field.field_trace(&field.one()),
+                &base.sum(&(0..d).map(|_| base.one()).collect::<Vec<_>>())
+            ));
+
+            let mut trace_zero_count = 0usize;
+            for x in field.list_all_elements() {
+                // agrees with the coefficients of the characteristic polynomial
+                assert!(base.equal(&field.field_trace(&x), &field.trace(&x)));
+                assert!(base.equal(&field.field_norm(&x), &field.norm(&x)));
+                assert_eq!(field.is_zero(&x), base.is_zero(&field.field_norm(&x)));
+                if base.is_zero(&field.field_trace(&x)) {
+                    trace_zero_count += 1;
+                }
+            }
+            // the trace is surjective so its kernel has size p^{d-1}
+            assert_eq!(
+                Natural::from(trace_zero_count),
+                p.pow(&Natural::from(d - 1))
+            );
+
+            assert_eq!(
+                field
+                    .list_all_elements()
+                    .into_iter()
+                    .filter(|x| field.is_in_subfield(x))
+                    .count(),
+                usize::try_from(&p).unwrap()
+            );
+        }
+
+        check(f9());
+        // GF(8)
+        check(new_finite_field_extension::<ModuloCanonicalStructure<2>>(
+            Modulo::<2>::structure(),
+            Polynomial::from_coeffs(vec![1, 1, 0, 1]),
+        ));
+        // GF(125)
+        check(new_finite_field_extension::<ModuloCanonicalStructure<5>>(
+            Modulo::<5>::structure(),
+            Polynomial::from_coeffs(vec![2, 3, 0, 1]),
+        ));
+    }
+
+    #[test]
+    fn test_normal_basis() {
+        use crate::num_theory::modulo::const_naive::*;
+
+        fn check<FS: FiniteFieldSignature>(
+            field: PolynomialQuotientRingStructure<FS, FS, PolynomialStructure<FS, FS>, true>,
+        ) {
+            let alpha = field.find_normal_basis_element();
+            assert!(field.is_normal_basis_element(&alpha));
+            let d = field.degree();
+            let conjugates = (0..d)
+                .map(|k| field.frobenius_k(&alpha, k))
+                .collect::<Vec<_>>();
+            assert!(
+                field
+                    .ring()
+                    .coeff_ring()
+                    .matrix_structure()
+                    .rank(Matrix::join_cols(
+                        d,
+                        conjugates.iter().map(|y| field.to_col(y)).collect()
+                    ))
+                    == d
+            );
+            // elements of the base field are never normal basis elements of a proper extension
+            assert!(!field.is_normal_basis_element(&field.zero()));
+            assert!(!field.is_normal_basis_element(&field.one()));
+        }
+
+        // GF(4)
+        check(new_finite_field_extension::<ModuloCanonicalStructure<2>>(
+            Modulo::<2>::structure(),
+            Polynomial::from_coeffs(vec![1, 1, 1]),
+        ));
+        // GF(8)
+        check(new_finite_field_extension::<ModuloCanonicalStructure<2>>(
+            Modulo::<2>::structure(),
+            Polynomial::from_coeffs(vec![1, 1, 0, 1]),
+        ));
+        // GF(9)
+        check(f9());
+
+        // in GF(8) = F2[α]/(α^3 + α + 1) the conjugates of α sum to 0 so α is not a normal basis element
+        let f8 = new_finite_field_extension::<ModuloCanonicalStructure<2>>(
+            Modulo::<2>::structure(),
+            Polynomial::from_coeffs(vec![1, 1, 0, 1]),
+        );
+        assert!(!f8.is_normal_basis_element(&f8.generator()));
+        // but α^3 = α + 1 is
+        assert!(f8.is_normal_basis_element(&f8.nat_pow(&f8.generator(), &Natural::from(3u32))));
+    }
 }
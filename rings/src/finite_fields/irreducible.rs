@@ -0,0 +1,124 @@
+use crate::{polynomial::*, structure::*};
+use algebraeon_structures::*;
+use itertools::Itertools;
+
+impl<FS: FiniteFieldSignature, FSB: BorrowedStructure<FS>> PolynomialStructure<FS, FSB>
+where
+    PolynomialStructure<FS, FSB>: SetSignature<Elem = Polynomial<FS::Elem>>,
+{
+    /// Whether the monic polynomial `f` of degree `n >= 1` is irreducible.
+    ///
+    /// `f` has an irreducible factor of degree `i` if and only if `gcd(x^{q^i} - x, f) != 1` for the smallest such `i`,
+    /// and a reducible `f` has a factor of degree at most `n/2`, so it suffices to check `i = 1, ..., n/2`.
+    pub fn is_monic_irreducible_by_gcd(&self, f: &Polynomial<FS::Elem>) -> bool {
+        debug_assert!(self.is_monic(f));
+        let n = self.degree(f).unwrap();
+        debug_assert!(n >= 1);
+        let (p, k) = self.coeff_ring().characteristic_and_power();
+        let q = p.nat_pow(&k);
+        let mod_f_ring = self.euclidean_quotient_ring(f.clone()).unwrap();
+        // x^{q^i} mod f
+        let mut xqi = self.var();
+        for _ in 1..=(n / 2) {
+            xqi = mod_f_ring.nat_pow(&xqi, &q);
+            let g = self.subresultant_gcd(f.clone(), self.sub(&xqi, &self.var()));
+            if self.degree(&g) != Some(0) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// All monic irreducible polynomials of the given degree, found by testing every monic polynomial of that degree with `is_monic_irreducible_by_gcd`.
+    pub fn monic_irreducibles_of_degree(
+        &self,
+        degree: usize,
+    ) -> impl Iterator<Item = Polynomial<FS::Elem>> {
+        let elements = self.coeff_ring().list_all_elements();
+        (0..degree)
+            .map(move |_| elements.clone())
+            .multi_cartesian_product()
+            .filter(move |_| degree >= 1)
+            .map(|mut coeffs| {
+                coeffs.push(self.coeff_ring().one());
+                Polynomial::from_coeffs(coeffs)
+            })
+            .filter(|f| self.is_monic_irreducible_by_gcd(f))
+    }
+}
+
+impl<F: MetaType> Polynomial<F>
+where
+    F::Signature: FiniteFieldSignature,
+    PolynomialStructure<F::Signature, F::Signature>: SetSignature<Elem = Polynomial<F>>,
+{
+    pub fn is_monic_irreducible_by_gcd(&self) -> bool {
+        Self::structure().is_monic_irreducible_by_gcd(self)
+    }
+
+    pub fn monic_irreducibles_of_degree(degree: usize) -> Vec<Self> {
+        Self::structure()
+            .monic_irreducibles_of_degree(degree)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::num_theory::modulo::const_naive::Modulo;
+
+    fn check_counts<FS: FiniteFieldSignature>(
+        poly_ring: PolynomialStructure<FS, FS>,
+        max_degree: usize,
+    ) where
+        PolynomialStructure<FS, FS>: SetSignature<Elem = Polynomial<FS::Elem>>
+            + FactoringMonoidSignature<FactoredExponent = NaturalCanonicalStructure>,
+    {
+        let q = usize::try_from(poly_ring.coeff_ring().size()).unwrap();
+        // q^d = sum over k dividing d of k times the number of monic irreducibles of degree k
+        let mut counts = vec![0];
+        for d in 1..=max_degree {
+            let irreducibles = poly_ring
+                .monic_irreducibles_of_degree(d)
+                .collect::<Vec<_>>();
+            for f in &irreducibles {
+                assert_eq!(poly_ring.degree(f), Some(d));
+                assert!(poly_ring.is_monic(f));
+                assert!(
+                    poly_ring
+                        .factorizations()
+                        .is_irreducible(&poly_ring.factor(f))
+                );
+            }
+            let smaller = (1..d)
+                .filter(|k| d.is_multiple_of(*k))
+                .map(|k| k * counts[k])
+                .sum::<usize>();
+            assert_eq!(irreducibles.len(), (q.pow(d as u32) - smaller) / d);
+            counts.push(irreducibles.len());
+        }
+    }
+
+    #[test]
+    fn count_monic_irreducibles() {
+        assert!(Polynomial::<Modulo<2>>::monic_irreducibles_of_degree(0).is_empty());
+        check_counts(Polynomial::<Modulo<2>>::structure(), 7);
+        check_counts(Polynomial::<Modulo<3>>::structure(), 4);
+        check_counts(Polynomial::<Modulo<5>>::structure(), 3);
+    }
+
+    #[test]
+    fn monic_irreducible_quadratics_over_f3() {
+        let x = &Polynomial::<Modulo<3>>::var().into_ergonomic();
+        let expected = [x.pow(2) + 1, x.pow(2) + x + 2, x.pow(2) + 2 * x + 2]
+            .into_iter()
+            .map(|f| f.into_verbose())
+            .collect::<Vec<_>>();
+        let irreducibles = Polynomial::<Modulo<3>>::monic_irreducibles_of_degree(2);
+        assert_eq!(irreducibles.len(), expected.len());
+        for f in &expected {
+            assert!(irreducibles.contains(f));
+        }
+    }
+}
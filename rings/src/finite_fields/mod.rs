@@ -2,6 +2,7 @@ use crate::{polynomial::*, structure::*};
 pub mod conway_finite_fields;
 pub mod conway_polynomials;
 pub mod extension;
+pub mod irreducible;
 pub mod polynomial;
 pub mod quaternary_field;
 use algebraeon_structures::*;
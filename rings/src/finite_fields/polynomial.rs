@@ -488,6 +488,25 @@ where
     }
 }
 
+impl<FS: FiniteFieldSignature, FSB: BorrowedStructure<FS>> PolynomialStructure<FS, FSB>
+where
+    PolynomialStructure<FS, FSB>: SetSignature<Elem = Polynomial<FS::Elem>>
+        + FactoringMonoidSignature<FactoredExponent = NaturalCanonicalStructure>,
+{
+    /// Factor a polynomial by a squarefree factorization followed by Berlekamps algorithm on each squarefree factor.
+    /// An alternative to the distinct degree and Cantor-Zassenhaus factorization used by default.
+    pub fn factorize_by_berlekamps(
+        &self,
+        f: &Polynomial<FS::Elem>,
+    ) -> Factored<Polynomial<FS::Elem>, Natural> {
+        if let Some(f) = self.factorize_monic(f) {
+            f.factorize_squarefree().factorize_berlekamps()
+        } else {
+            Factored::Zero
+        }
+    }
+}
+
 impl<FS: FiniteFieldSignature, FSB: BorrowedStructure<FS>> SquarefreeFactored<FS, FSB>
 where
     PolynomialStructure<FS, FSB>: SetSignature<Elem = Polynomial<FS::Elem>>,
@@ -1010,4 +1029,67 @@ mod tests {
                 .equal(&f, &ans)
         );
     }
+
+    #[test]
+    fn test_berlekamps_agrees_with_cantor_zassenhaus() {
+        fn check<FS: FiniteFieldSignature>(
+            poly_ring: PolynomialStructure<FS, FS>,
+            f: Polynomial<FS::Elem>,
+        ) where
+            PolynomialStructure<FS, FS>: SetSignature<Elem = Polynomial<FS::Elem>>
+                + FactoringMonoidSignature<FactoredExponent = NaturalCanonicalStructure>,
+        {
+            let berlekamps = poly_ring.factorize_by_berlekamps(&f);
+            let cantor_zassenhaus = poly_ring
+                .factorize_monic(&f)
+                .unwrap()
+                .factorize_squarefree()
+                .factorize_distinct_degree()
+                .factorize_cantor_zassenhaus();
+            assert!(
+                poly_ring
+                    .factorizations()
+                    .equal(&berlekamps, &cantor_zassenhaus)
+            );
+            assert!(poly_ring.equal(&poly_ring.factorizations().expand(&berlekamps), &f));
+        }
+
+        let x = &Polynomial::<Modulo<2>>::var().into_ergonomic();
+        for f in [
+            (x.pow(3) + x.pow(2) + 1) * (x.pow(3) + 1),
+            x.pow(16) - x,
+            (1 + x.pow(4) + x.pow(5)).pow(3) * (1 + x.pow(2)),
+        ] {
+            check(Polynomial::<Modulo<2>>::structure(), f.into_verbose());
+        }
+
+        let x = &Polynomial::<Modulo<3>>::var().into_ergonomic();
+        for f in [
+            2 * (x.pow(3) + x.pow(2) + 2) * (x.pow(3) + 2 * x.pow(2) + 1),
+            x.pow(9) - x,
+            (x.pow(2) + 1).pow(4) * (x.pow(4) + x + 2),
+        ] {
+            check(Polynomial::<Modulo<3>>::structure(), f.into_verbose());
+        }
+
+        let x = &Polynomial::<Modulo<7>>::var().into_ergonomic();
+        for f in [
+            3 * x.pow(10) + x.pow(7) + 5 * x.pow(3) + 2,
+            (x.pow(2) + 1) * (x.pow(3) + 2 * x + 5).pow(2) * (x + 4),
+        ] {
+            check(Polynomial::<Modulo<7>>::structure(), f.into_verbose());
+        }
+
+        let x = &Polynomial::<QuaternaryField>::var().into_ergonomic();
+        let alpha = Polynomial::constant(QuaternaryField::Alpha).into_ergonomic();
+        for f in [(1 - x.pow(3)).pow(4), x.pow(5) + &alpha * x.pow(2) + 1] {
+            check(Polynomial::<QuaternaryField>::structure(), f.into_verbose());
+        }
+
+        assert!(
+            Polynomial::<Modulo<5>>::structure()
+                .factorize_by_berlekamps(&Polynomial::zero())
+                .is_zero()
+        );
+    }
 }
@@ -0,0 +1,222 @@
+use super::*;
+
+/// An interval of width at most `eps` containing `arctan(t)` for `|t| <= 1`.
+fn atan_interval(t: &Rational, eps: &Rational) -> (Rational, Rational) {
+    debug_assert!(Abs::abs(t) <= Rational::ONE);
+    if t < &Rational::ZERO {
+        let (lo, hi) = atan_interval(&-t, eps);
+        return (-hi, -lo);
+    }
+    let half = Rational::ONE_HALF;
+    if t > &half {
+        // arctan(t) = arctan(1/2) + arctan((t - 1/2) / (1 + t/2)) and the second argument is at most 1/3
+        let eps = eps / Rational::TWO;
+        let (lo1, hi1) = atan_interval(&half, &eps);
+        let (lo2, hi2) = atan_interval(&((t - &half) / (Rational::ONE + t * &half)), &eps);
+        return (lo1 + lo2, hi1 + hi2);
+    }
+    // the taylor series is alternating with decreasing terms so consecutive partial sums enclose arctan(t)
+    let t_sq = t * t;
+    let mut power = t.clone();
+    let mut sum = Rational::ZERO;
+    let mut k = 0usize;
+    loop {
+        let term = &power / Rational::from(2 * k + 1);
+        if &term <= eps {
+            let next = if k.is_multiple_of(2) {
+                &sum + &term
+            } else {
+                &sum - &term
+            };
+            return if sum <= next {
+                (sum, next)
+            } else {
+                (next, sum)
+            };
+        }
+        if k.is_multiple_of(2) {
+            sum += term;
+        } else {
+            sum -= term;
+        }
+        power *= &t_sq;
+        k += 1;
+    }
+}
+
+/// An interval of width at most `eps` containing `pi`, using `pi = 16 arctan(1/5) - 4 arctan(1/239)`.
+fn pi_interval(eps: &Rational) -> (Rational, Rational) {
+    let eps = eps / Rational::from(32);
+    let (lo1, hi1) = atan_interval(&Rational::from_integers(1, 5), &eps);
+    let (lo2, hi2) = atan_interval(&Rational::from_integers(1, 239), &eps);
+    (
+        Rational::from(16) * lo1 - Rational::from(4) * hi2,
+        Rational::from(16) * hi1 - Rational::from(4) * lo2,
+    )
+}
+
+/// An interval of width at most `eps` containing the argument in `[0, 2pi)` of the nonzero point `(x, y)`.
+fn arg_interval(x: &Rational, y: &Rational, eps: &Rational) -> (Rational, Rational) {
+    debug_assert!(x != &Rational::ZERO || y != &Rational::ZERO);
+    // the argument is (k/2) pi + s arctan(t) for some |t| <= 1
+    let (k, s, t) = if Abs::abs(y) <= Abs::abs(x) {
+        if x > &Rational::ZERO {
+            if y >= &Rational::ZERO {
+                (0, 1, y / x)
+            } else {
+                (4, 1, y / x)
+            }
+        } else {
+            (2, 1, y / x)
+        }
+    } else if y > &Rational::ZERO {
+        (1, -1, x / y)
+    } else {
+        (3, -1, x / y)
+    };
+    let eps = eps / Rational::TWO;
+    let (atan_lo, atan_hi) = atan_interval(&t, &eps);
+    let (atan_lo, atan_hi) = if s == 1 {
+        (atan_lo, atan_hi)
+    } else {
+        (-atan_hi, -atan_lo)
+    };
+    if k == 0 {
+        (atan_lo, atan_hi)
+    } else {
+        let k = Rational::from_integers(k, 2);
+        let (pi_lo, pi_hi) = pi_interval(&(&eps / &k));
+        (&k * pi_lo + atan_lo, k * pi_hi + atan_hi)
+    }
+}
+
+impl ComplexAlgebraic {
+    pub fn is_real(&self) -> bool {
+        matches!(self, ComplexAlgebraic::Real(_))
+    }
+
+    /// The sign of the imaginary part: `1`, `0` or `-1`.
+    pub fn imaginary_part_sign(&self) -> i8 {
+        match self {
+            ComplexAlgebraic::Real(_) => 0,
+            ComplexAlgebraic::Complex(z) => {
+                // the root is not real so refining eventually gives a box on one side of the real axis
+                let mut z = z.clone();
+                loop {
+                    if z.tight_c >= Rational::ZERO {
+                        return 1;
+                    }
+                    if z.tight_d <= Rational::ZERO {
+                        return -1;
+                    }
+                    z.refine_mut();
+                }
+            }
+        }
+    }
+
+    /// Return rationals `(lo, hi)` with `hi - lo <= max_err` such that the argument of `self` in `[0, 2pi)` lies in `[lo, hi]`.
+    ///
+    /// Panics if `self` is zero.
+    pub fn arg_approx(&self, max_err: &Rational) -> (Rational, Rational) {
+        assert!(max_err > &Rational::ZERO);
+        match self {
+            ComplexAlgebraic::Real(x) => match x.cmp(&RealAlgebraic::zero()) {
+                std::cmp::Ordering::Less => pi_interval(max_err),
+                std::cmp::Ordering::Equal => panic!("The argument of zero is undefined"),
+                std::cmp::Ordering::Greater => (Rational::ZERO, Rational::ZERO),
+            },
+            ComplexAlgebraic::Complex(z) => {
+                let mut z = z.clone();
+                loop {
+                    // points of the box are at least r from the origin and within delta of each other,
+                    // so if delta < r their arguments differ by at most (pi/2) delta / r < 2 delta / r
+                    let dist = |lo: &Rational, hi: &Rational| {
+                        if lo > &Rational::ZERO {
+                            lo.clone()
+                        } else if hi < &Rational::ZERO {
+                            -hi
+                        } else {
+                            Rational::ZERO
+                        }
+                    };
+                    let r =
+                        std::cmp::max(dist(&z.tight_a, &z.tight_b), dist(&z.tight_c, &z.tight_d));
+                    let delta = z.accuracy_re() + z.accuracy_im();
+                    let off_real_axis = z.tight_c >= Rational::ZERO || z.tight_d <= Rational::ZERO;
+                    if off_real_axis && delta < r {
+                        let box_err = Rational::TWO * &delta / &r;
+                        if Rational::from(4) * &box_err <= *max_err {
+                            let x = (&z.tight_a + &z.tight_b) / Rational::TWO;
+                            let y = (&z.tight_c + &z.tight_d) / Rational::TWO;
+                            let (lo, hi) = arg_interval(&x, &y, &(max_err / Rational::TWO));
+                            let lo = std::cmp::max(lo - &box_err, Rational::ZERO);
+                            return (lo, hi + box_err);
+                        }
+                    }
+                    z.refine_mut();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_arg_close(z: &ComplexAlgebraic, expected: f64) {
+        let max_err = Rational::from_integers(1, 1_000_000);
+        let (lo, hi) = z.arg_approx(&max_err);
+        assert!(lo <= hi);
+        assert!(&hi - &lo <= max_err);
+        assert!(lo.as_f64() - 1e-12 <= expected && expected <= hi.as_f64() + 1e-12);
+    }
+
+    #[test]
+    fn test_is_real() {
+        let sqrt2 = ComplexAlgebraic::Real(RealAlgebraic::from_int(2).nth_root(2).unwrap());
+        assert!(sqrt2.is_real());
+        assert_eq!(sqrt2.imaginary_part_sign(), 0);
+        assert!(!ComplexAlgebraic::i().is_real());
+        assert_eq!(ComplexAlgebraic::i().imaginary_part_sign(), 1);
+        assert_eq!(ComplexAlgebraic::i().neg().imaginary_part_sign(), -1);
+    }
+
+    #[test]
+    fn test_arg_approx() {
+        use std::f64::consts::PI;
+        assert_arg_close(&ComplexAlgebraic::i(), PI / 2.0);
+        assert_arg_close(&ComplexAlgebraic::i().neg(), 3.0 * PI / 2.0);
+        assert_arg_close(&ComplexAlgebraic::from_int(3), 0.0);
+        assert_arg_close(&ComplexAlgebraic::from_int(-3), PI);
+
+        // roots of x^2 + 2x + 2 are -1 +- i
+        let x = &Polynomial::<Integer>::var().into_ergonomic();
+        let roots = (x.pow(2) + 2 * x + 2).into_verbose().all_complex_roots();
+        assert_eq!(roots.len(), 2);
+        for root in &roots {
+            if root.imaginary_part_sign() == 1 {
+                assert_arg_close(root, 3.0 * PI / 4.0);
+            } else {
+                assert_arg_close(root, 5.0 * PI / 4.0);
+            }
+        }
+
+        // the fifth roots of unity other than 1
+        let roots = (x.pow(4) + x.pow(3) + x.pow(2) + x + 1)
+            .into_verbose()
+            .all_complex_roots();
+        let mut args = roots
+            .iter()
+            .map(|root| {
+                let (lo, hi) = root.arg_approx(&Rational::from_integers(1, 1000));
+                ((lo + hi) / Rational::TWO).as_f64()
+            })
+            .collect::<Vec<_>>();
+        args.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        for (k, arg) in args.into_iter().enumerate() {
+            assert!((arg - 2.0 * PI * (k + 1) as f64 / 5.0).abs() < 1e-3);
+        }
+    }
+}
@@ -6,6 +6,7 @@ use crate::structure::*;
 use algebraeon_macros::CanonicalStructure;
 use boxes::*;
 use std::{collections::HashSet, fmt::Display, str::FromStr};
+mod arg;
 mod boxes;
 mod polynomial;
 use algebraeon_structures::*;
@@ -1125,6 +1126,24 @@ impl ComplexAlgebraic {
             ComplexAlgebraic::Complex(complex_root) => complex_root.min_poly(),
         }
     }
+
+    /// Whether this is a root of a monic polynomial with integer coefficients.
+    pub fn is_algebraic_integer(&self) -> bool {
+        self.min_poly()
+            .coeffs()
+            .all(|c| c.denominator() == Natural::ONE)
+    }
+
+    /// The maximum absolute value of the coefficients of the primitive integer minimal polynomial.
+    pub fn height(&self) -> Natural {
+        self.min_poly()
+            .primitive_part_fof()
+            .into_coeffs()
+            .into_iter()
+            .map(|c| c.abs())
+            .max()
+            .unwrap()
+    }
 }
 
 impl<B: BorrowedStructure<ComplexAlgebraicCanonicalStructure>>
@@ -1261,6 +1280,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_degree_height_algebraic_integer() {
+        let x = &Polynomial::<Integer>::var().into_ergonomic();
+
+        let sqrt2 = (x.pow(2) - 2)
+            .into_verbose()
+            .all_complex_roots()
+            .pop()
+            .unwrap();
+        assert!(sqrt2.is_algebraic_integer());
+        assert_eq!(sqrt2.degree(), 2);
+        assert_eq!(sqrt2.height(), Natural::from(2u32));
+
+        let half = ComplexAlgebraic::Real(RealAlgebraic::Rational(Rational::ONE_HALF));
+        assert!(!half.is_algebraic_integer());
+        assert_eq!(half.degree(), 1);
+        assert_eq!(half.height(), Natural::from(2u32));
+
+        let i_plus_one = ComplexAlgebraic::add(&ComplexAlgebraic::i(), &ComplexAlgebraic::one());
+        assert!(i_plus_one.is_algebraic_integer());
+        assert_eq!(i_plus_one.degree(), 2);
+        assert_eq!(i_plus_one.height(), Natural::from(2u32));
+
+        // roots of 2x^3 - 5x + 1 are not algebraic integers
+        for root in (2 * x.pow(3) - 5 * x + 1)
+            .into_verbose()
+            .all_complex_roots()
+        {
+            assert!(!root.is_algebraic_integer());
+            assert_eq!(root.degree(), 3);
+            assert_eq!(root.height(), Natural::from(5u32));
+        }
+    }
+
     #[test]
     fn test_complex_inv() {
         let x = &Polynomial::<Integer>::var().into_ergonomic();
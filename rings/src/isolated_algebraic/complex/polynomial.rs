@@ -711,6 +711,23 @@ impl Polynomial<Integer> {
         }
         roots
     }
+
+    /// The distinct complex roots, each paired with its multiplicity.
+    pub fn all_complex_roots_with_multiplicity(&self) -> Vec<(ComplexAlgebraic, usize)> {
+        assert_ne!(self, &Self::zero());
+        let mut roots = vec![];
+        for (factor, k) in self.factor().into_powers().unwrap() {
+            let k = usize::try_from(k).unwrap();
+            for root in factor.all_complex_roots_irreducible() {
+                roots.push((root, k));
+            }
+        }
+        debug_assert_eq!(
+            roots.iter().map(|(_, k)| k).sum::<usize>(),
+            self.degree().unwrap()
+        );
+        roots
+    }
 }
 
 impl Polynomial<Rational> {
@@ -718,6 +735,13 @@ impl Polynomial<Rational> {
         assert_ne!(self, &Self::zero());
         self.primitive_part_fof().all_complex_roots()
     }
+
+    /// The distinct complex roots, each paired with its multiplicity.
+    pub fn all_complex_roots_with_multiplicity(&self) -> Vec<(ComplexAlgebraic, usize)> {
+        assert_ne!(self, &Self::zero());
+        self.primitive_part_fof()
+            .all_complex_roots_with_multiplicity()
+    }
 }
 
 #[cfg(test)]
@@ -961,4 +985,61 @@ mod tests {
             assert_eq!(poly.count_complex_roots(&a, &b, &c, &d), Some(1));
         }
     }
+
+    #[test]
+    fn test_roots_with_multiplicity() {
+        let one = ComplexAlgebraic::Real(RealAlgebraic::Rational(Rational::ONE));
+        let f = Polynomial::<Rational>::from_str("(x - 1)^3", "x").unwrap();
+        assert_eq!(
+            f.all_complex_roots_with_multiplicity(),
+            vec![(one.clone(), 3)]
+        );
+        let f = Polynomial::<Rational>::from_str("x^2 - 2*x + 1", "x").unwrap();
+        assert_eq!(
+            f.all_complex_roots_with_multiplicity(),
+            vec![(one.clone(), 2)]
+        );
+        assert_eq!(
+            f.all_real_roots_with_multiplicity(),
+            vec![(RealAlgebraic::Rational(Rational::ONE), 2)]
+        );
+
+        let f =
+            Polynomial::<Rational>::from_str("(x^2 + 1)^2 * (x^3 - 2) * (2*x + 1)^3", "x").unwrap();
+        let roots = f.all_complex_roots_with_multiplicity();
+        assert_eq!(roots.len(), 6);
+        assert_eq!(
+            roots.iter().map(|(_, k)| k).sum::<usize>(),
+            f.degree().unwrap()
+        );
+        let real_roots = f.all_real_roots_with_multiplicity();
+        assert_eq!(real_roots.len(), 2);
+        for (root, k) in &real_roots {
+            assert!(roots.contains(&(ComplexAlgebraic::Real(root.clone()), *k)));
+        }
+
+        // multiplying polynomials adds the multiplicities of their roots
+        let multiplicity = |roots: &Vec<(ComplexAlgebraic, usize)>, x: &ComplexAlgebraic| {
+            roots.iter().find(|(r, _)| r == x).map_or(0, |(_, k)| *k)
+        };
+        let g =
+            Polynomial::<Rational>::from_str("(x^2 + 1) * (x - 1)^2 * (x^3 - 2)^2", "x").unwrap();
+        let fg = Polynomial::mul(&f, &g);
+        let f_roots = f.all_complex_roots_with_multiplicity();
+        let g_roots = g.all_complex_roots_with_multiplicity();
+        let fg_roots = fg.all_complex_roots_with_multiplicity();
+        assert_eq!(
+            fg_roots.iter().map(|(_, k)| k).sum::<usize>(),
+            fg.degree().unwrap()
+        );
+        for (root, k) in &fg_roots {
+            assert_eq!(
+                *k,
+                multiplicity(&f_roots, root) + multiplicity(&g_roots, root)
+            );
+        }
+        for (root, _) in f_roots.iter().chain(g_roots.iter()) {
+            assert!(multiplicity(&fg_roots, root) > 0);
+        }
+    }
 }
@@ -850,6 +850,9 @@ impl RealRoundingSignature for RealAlgebraicCanonicalStructure {
             }
         }
     }
+    /// Round to the nearest integer, rounding half up.
+    ///
+    /// Only rational values can lie exactly midway, and those are rounded by [`Rational`].
     fn round(&self, x: &Self::Elem) -> Integer {
         let mut x = x.clone();
         loop {
@@ -1072,4 +1075,45 @@ mod tests {
         });
         assert_eq!(all_roots, all_roots_sorted_by_lower_tight_bound);
     }
+
+    #[test]
+    fn test_real_rounding() {
+        let sqrt2 = RealAlgebraic::from_int(2).nth_root(2).unwrap();
+        assert_eq!(sqrt2.floor(), Integer::from(1));
+        assert_eq!(sqrt2.ceil(), Integer::from(2));
+        assert_eq!(sqrt2.round(), Integer::from(1));
+        let neg_sqrt2 = sqrt2.neg();
+        assert_eq!(neg_sqrt2.floor(), Integer::from(-2));
+        assert_eq!(neg_sqrt2.ceil(), Integer::from(-1));
+        assert_eq!(neg_sqrt2.round(), Integer::from(-1));
+
+        let x = &Polynomial::<Integer>::var().into_ergonomic();
+        let golden = (x.pow(2) - x - 1).into_verbose().all_real_roots();
+        assert_eq!(golden.len(), 2);
+        assert_eq!(golden[0].floor(), Integer::from(-1));
+        assert_eq!(golden[0].round(), Integer::from(-1));
+        assert_eq!(golden[1].floor(), Integer::from(1));
+        assert_eq!(golden[1].round(), Integer::from(2));
+
+        for (n, d) in [(7, 2), (-7, 2), (5, 1), (-5, 1), (1, 3), (-1, 3), (22, 7)] {
+            let q = Rational::from_integers(n, d);
+            let a = RealAlgebraic::try_from_rat(&q).unwrap();
+            assert_eq!(a.floor(), Floor::floor(&q));
+            assert_eq!(a.ceil(), Ceil::ceil(&q));
+            assert_eq!(a.round(), q.round());
+        }
+        // midpoints round up
+        assert_eq!(
+            RealAlgebraic::try_from_rat(&Rational::from_integers(5, 2))
+                .unwrap()
+                .round(),
+            Integer::from(3)
+        );
+        assert_eq!(
+            RealAlgebraic::try_from_rat(&Rational::from_integers(-5, 2))
+                .unwrap()
+                .round(),
+            Integer::from(-2)
+        );
+    }
 }
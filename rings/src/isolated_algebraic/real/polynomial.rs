@@ -907,6 +907,19 @@ impl Polynomial<Integer> {
         self.real_roots(None, None, false, false)
     }
 
+    /// The distinct real roots, each paired with its multiplicity.
+    pub fn all_real_roots_with_multiplicity(&self) -> Vec<(RealAlgebraic, usize)> {
+        assert_ne!(self, &Self::zero());
+        let mut roots = vec![];
+        for (factor, k) in self.factor().into_powers().unwrap() {
+            let k = usize::try_from(k).unwrap();
+            for root in factor.real_roots_irreducible(None, None, false, false) {
+                roots.push((root, k));
+            }
+        }
+        roots
+    }
+
     /// return the number of real roots counted with multiplicity
     pub fn count_real_roots(&self) -> usize {
         match self.degree() {
@@ -929,6 +942,12 @@ impl Polynomial<Rational> {
         self.primitive_part_fof().all_real_roots()
     }
 
+    /// The distinct real roots, each paired with its multiplicity.
+    pub fn all_real_roots_with_multiplicity(&self) -> Vec<(RealAlgebraic, usize)> {
+        assert_ne!(self, &Self::zero());
+        self.primitive_part_fof().all_real_roots_with_multiplicity()
+    }
+
     /// return the number of real roots counted with multiplicity
     pub fn count_real_roots(&self) -> usize {
         assert_ne!(self, &Self::zero());
@@ -6,6 +6,7 @@
 
 pub mod algebraic_number_field;
 pub mod approximation;
+pub mod complex_number;
 pub mod continued_fraction;
 pub mod finite_fields;
 pub mod isolated_algebraic;
@@ -99,8 +99,8 @@ impl<Ring: ReducedHermiteAlgorithmSignature, RingB: BorrowedStructure<Ring>>
         debug_assert_eq!(cols, self.module().rank());
         let (h, u, _det, pivots) =
             MatrixStructure::new(ring.clone()).row_reduced_hermite_algorithm(matrix);
-        let row_basis = h.submatrix((0..pivots.len()).collect(), (0..cols).collect());
-        let u = u.submatrix((0..pivots.len()).collect(), (0..rows).collect());
+        let row_basis = h.submatrix(0..pivots.len(), 0..cols);
+        let u = u.submatrix(0..pivots.len(), 0..rows);
         (FinitelyFreeSubmodule { row_basis, pivots }, u)
     }
 
@@ -174,10 +174,7 @@ impl<Ring: ReducedHermiteAlgorithmSignature, RingB: BorrowedStructure<Ring>>
         let (h, u, _u_det, pivs) =
             MatrixStructure::<Ring, _>::new(self.ring()).row_hermite_algorithm(a);
 
-        self.matrix_row_span(u.submatrix(
-            (pivs.len()..h.rows()).collect(),
-            (0..matrix.rows()).collect(),
-        ))
+        self.matrix_row_span(u.submatrix(pivs.len()..h.rows(), 0..matrix.rows()))
     }
 
     pub fn matrix_col_preimage(
@@ -198,7 +195,7 @@ impl<Ring: ReducedHermiteAlgorithmSignature, RingB: BorrowedStructure<Ring>>
             MatrixStructure::<Ring, _>::new(self.ring()).row_hermite_algorithm(matrix);
         debug_assert_eq!(rows, u.rows());
         debug_assert_eq!(rows, u.cols());
-        let ker = u.submatrix((pivs.len()..rows).collect(), (0..rows).collect());
+        let ker = u.submatrix(pivs.len()..rows, 0..rows);
         self.matrix_row_span(ker)
     }
 
@@ -332,10 +329,7 @@ impl<Ring: ReducedHermiteAlgorithmSignature, RingB: BorrowedStructure<Ring>>
             .submodules()
             .matrix_row_kernel(matrix)
             .into_row_basis_matrix();
-        let matrix_ker_first_part = matrix_ker.submatrix(
-            (0..matrix_ker.rows()).collect(),
-            (0..x_rows.rows()).collect(),
-        );
+        let matrix_ker_first_part = matrix_ker.submatrix(0..matrix_ker.rows(), 0..x_rows.rows());
         self.matrix_row_span(
             MatrixStructure::<Ring, _>::new(self.ring())
                 .mul(&matrix_ker_first_part, &x_rows)
@@ -0,0 +1,209 @@
+use super::*;
+use algebraeon_structures::*;
+
+impl<FS: OrderedRingSignature + FieldSignature, FSB: BorrowedStructure<FS>>
+    MatrixStructure<FS, FSB>
+{
+    fn is_symmetric(&self, mat: &Matrix<FS::Elem>) -> bool {
+        mat.rows() == mat.cols() && self.equal(mat, &mat.transpose_ref())
+    }
+
+    /// Return `(p, q)` where `p` and `q` are the number of positive and negative eigenvalues of the symmetric matrix `mat`, counted with multiplicity.
+    ///
+    /// By Sylvester's law of inertia these are the number of positive and negative entries in any diagonal matrix congruent to `mat`, so they can be found using symmetric row and column operations.
+    pub fn indefinite_index(&self, mat: &Matrix<FS::Elem>) -> Result<(usize, usize), MatOppErr> {
+        if mat.rows() != mat.cols() {
+            return Err(MatOppErr::NotSquare);
+        }
+        debug_assert!(self.is_symmetric(mat));
+        let ring = self.ring();
+        let n = mat.rows();
+        let mut a = mat
+            .rows_iter()
+            .map(|row| row.cloned().collect())
+            .collect::<Vec<Vec<FS::Elem>>>();
+        let (mut p, mut q) = (0, 0);
+        for k in 0..n {
+            if !(k..n).any(|i| !ring.is_zero(&a[i][i])) {
+                // all remaining diagonal entries are zero
+                // adding row and column j to row and column i makes the ith diagonal entry 2 a_ij
+                let Some((i, j)) = (k..n)
+                    .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+                    .find(|(i, j)| !ring.is_zero(&a[*i][*j]))
+                else {
+                    break;
+                };
+                let row_j = a[j].clone();
+                for (x, y) in a[i].iter_mut().zip(row_j.iter()) {
+                    ring.add_mut(x, y);
+                }
+                for row in &mut a {
+                    let x = row[j].clone();
+                    ring.add_mut(&mut row[i], &x);
+                }
+            }
+            let i = (k..n).find(|i| !ring.is_zero(&a[*i][*i])).unwrap();
+            a.swap(i, k);
+            for row in &mut a {
+                row.swap(i, k);
+            }
+
+            let d = a[k][k].clone();
+            match ring.cmp(&d, &ring.zero()) {
+                std::cmp::Ordering::Greater => p += 1,
+                std::cmp::Ordering::Less => q += 1,
+                std::cmp::Ordering::Equal => unreachable!(),
+            }
+            for i in (k + 1)..n {
+                let f = ring.try_divide(&a[i][k], &d).unwrap();
+                let row_k = a[k].clone();
+                for (x, y) in a[i].iter_mut().zip(row_k.iter()).skip(k) {
+                    ring.sub_mut(x, &ring.mul(&f, y));
+                }
+                for row in a.iter_mut().skip(k) {
+                    let x = ring.mul(&f, &row[k]);
+                    ring.sub_mut(&mut row[i], &x);
+                }
+            }
+        }
+        Ok((p, q))
+    }
+
+    /// Whether `mat` is symmetric and positive definite.
+    ///
+    /// Uses Sylvester's criterion, that all leading principal minors are positive.
+    pub fn is_positive_definite(&self, mat: &Matrix<FS::Elem>) -> bool {
+        if !self.is_symmetric(mat) {
+            return false;
+        }
+        let ring = self.ring();
+        (1..=mat.rows()).all(|i| {
+            ring.cmp(&self.det(mat.submatrix(0..i, 0..i)).unwrap(), &ring.zero())
+                .is_gt()
+        })
+    }
+
+    /// Whether `mat` is symmetric and negative definite.
+    pub fn is_negative_definite(&self, mat: &Matrix<FS::Elem>) -> bool {
+        self.is_positive_definite(&self.neg(mat.clone()))
+    }
+
+    /// Whether `mat` is symmetric and positive semidefinite, that is, has no negative eigenvalues.
+    pub fn is_positive_semidefinite(&self, mat: &Matrix<FS::Elem>) -> bool {
+        self.is_symmetric(mat) && self.indefinite_index(mat).unwrap().1 == 0
+    }
+
+    /// Whether `mat` is symmetric and negative semidefinite, that is, has no positive eigenvalues.
+    pub fn is_negative_semidefinite(&self, mat: &Matrix<FS::Elem>) -> bool {
+        self.is_symmetric(mat) && self.indefinite_index(mat).unwrap().0 == 0
+    }
+}
+
+impl<R: MetaType> Matrix<R>
+where
+    R::Signature: OrderedRingSignature + FieldSignature,
+{
+    pub fn indefinite_index(&self) -> Result<(usize, usize), MatOppErr> {
+        Self::structure().indefinite_index(self)
+    }
+
+    pub fn is_positive_definite(&self) -> bool {
+        Self::structure().is_positive_definite(self)
+    }
+
+    pub fn is_negative_definite(&self) -> bool {
+        Self::structure().is_negative_definite(self)
+    }
+
+    pub fn is_positive_semidefinite(&self) -> bool {
+        Self::structure().is_positive_semidefinite(self)
+    }
+
+    pub fn is_negative_semidefinite(&self) -> bool {
+        Self::structure().is_negative_semidefinite(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mat(rows: Vec<Vec<i32>>) -> Matrix<Rational> {
+        Matrix::from_rows(
+            rows.into_iter()
+                .map(|row| row.into_iter().map(Rational::from).collect())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn identity_and_diagonal() {
+        let i = Matrix::<Rational>::ident(4);
+        assert!(i.is_positive_definite());
+        assert!(i.is_positive_semidefinite());
+        assert!(!i.is_negative_definite());
+        assert_eq!(i.indefinite_index().unwrap(), (4, 0));
+
+        let d = Matrix::<Rational>::diag(&[
+            Rational::from(2),
+            Rational::from(-1),
+            Rational::from(3),
+            Rational::from(0),
+        ]);
+        assert!(!d.is_positive_definite());
+        assert!(!d.is_positive_semidefinite());
+        assert!(!d.is_negative_definite());
+        assert!(!d.is_negative_semidefinite());
+        assert_eq!(d.indefinite_index().unwrap(), (2, 1));
+
+        let d = mat(vec![vec![-1, 0], vec![0, -5]]);
+        assert!(d.is_negative_definite());
+        assert!(!d.is_positive_semidefinite());
+        assert_eq!(d.indefinite_index().unwrap(), (0, 2));
+
+        assert_eq!(
+            Matrix::<Rational>::zero(3, 3).indefinite_index().unwrap(),
+            (0, 0)
+        );
+        assert!(Matrix::<Rational>::zero(3, 3).is_positive_semidefinite());
+        assert!(Matrix::<Rational>::zero(3, 3).is_negative_semidefinite());
+    }
+
+    #[test]
+    fn gram_matrix_is_positive_definite() {
+        // the rows are linearly independent
+        let v = mat(vec![vec![1, 2, 0, -1], vec![0, 1, 3, 1], vec![2, 0, 1, 1]]);
+        let g = Matrix::mul(&v, &v.transpose_ref()).unwrap();
+        assert!(g.is_positive_definite());
+        assert_eq!(g.indefinite_index().unwrap(), (3, 0));
+
+        // the rows are linearly dependent
+        let v = mat(vec![vec![1, 2, 0], vec![0, 1, 3], vec![1, 3, 3]]);
+        let g = Matrix::mul(&v, &v.transpose_ref()).unwrap();
+        assert!(!g.is_positive_definite());
+        assert!(g.is_positive_semidefinite());
+        assert_eq!(g.indefinite_index().unwrap(), (2, 0));
+    }
+
+    #[test]
+    fn indefinite_index_with_zero_diagonal() {
+        // the quadratic form 2xy has signature (1, 1)
+        let a = mat(vec![vec![0, 1], vec![1, 0]]);
+        assert_eq!(a.indefinite_index().unwrap(), (1, 1));
+        assert!(!a.is_positive_semidefinite());
+
+        // all leading principal minors are non-negative but this is not positive semidefinite
+        let a = mat(vec![vec![0, 0], vec![0, -1]]);
+        assert!(!a.is_positive_semidefinite());
+        assert!(a.is_negative_semidefinite());
+
+        // trace 0 and determinant -4 so the eigenvalues have signs + + -
+        let a = mat(vec![vec![0, 2, 1], vec![2, 0, -1], vec![1, -1, 0]]);
+        assert_eq!(a.indefinite_index().unwrap(), (2, 1));
+
+        // non-symmetric matrices are not definite
+        let a = mat(vec![vec![1, 1], vec![0, 1]]);
+        assert!(!a.is_positive_definite());
+        assert!(!a.is_positive_semidefinite());
+    }
+}
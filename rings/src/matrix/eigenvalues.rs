@@ -0,0 +1,129 @@
+use super::*;
+use crate::isolated_algebraic::{ComplexAlgebraic, RealAlgebraic};
+use algebraeon_structures::*;
+
+impl<B: BorrowedStructure<RationalCanonicalStructure>>
+    MatrixStructure<RationalCanonicalStructure, B>
+{
+    /// The eigenvalues of the square matrix `mat` in the algebraic closure of the rationals, listed with algebraic multiplicity.
+    ///
+    /// These are the complex roots of the characteristic polynomial.
+    pub fn eigenvalues(&self, mat: &Matrix<Rational>) -> Result<Vec<ComplexAlgebraic>, MatOppErr> {
+        let char_poly = self.characteristic_polynomial(mat.clone())?;
+        Ok(char_poly.all_complex_roots())
+    }
+
+    /// The real eigenvalues of the square matrix `mat`, listed with algebraic multiplicity.
+    pub fn real_eigenvalues(
+        &self,
+        mat: &Matrix<Rational>,
+    ) -> Result<Vec<RealAlgebraic>, MatOppErr> {
+        let char_poly = self.characteristic_polynomial(mat.clone())?;
+        Ok(char_poly.all_real_roots())
+    }
+
+    /// The largest absolute value of an eigenvalue of the square matrix `mat`.
+    pub fn spectral_radius(&self, mat: &Matrix<Rational>) -> Result<RealAlgebraic, MatOppErr> {
+        let complex = ComplexAlgebraic::structure();
+        // compare the squared absolute values so that only one square root is needed
+        let max_abs_squared = self
+            .eigenvalues(mat)?
+            .into_iter()
+            .map(|lambda| {
+                RealAlgebraic::try_from(complex.mul(&lambda, &lambda.conjugate())).unwrap()
+            })
+            .max()
+            .unwrap_or(RealAlgebraic::Rational(Rational::ZERO));
+        Ok(RealAlgebraic::structure()
+            .nth_root(&max_abs_squared, 2)
+            .unwrap())
+    }
+}
+
+impl Matrix<Rational> {
+    pub fn eigenvalues(&self) -> Result<Vec<ComplexAlgebraic>, MatOppErr> {
+        Self::structure().eigenvalues(self)
+    }
+
+    pub fn real_eigenvalues(&self) -> Result<Vec<RealAlgebraic>, MatOppErr> {
+        Self::structure().real_eigenvalues(self)
+    }
+
+    pub fn spectral_radius(&self) -> Result<RealAlgebraic, MatOppErr> {
+        Self::structure().spectral_radius(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsing::parse_rational_polynomial;
+
+    fn mat(rows: Vec<Vec<i32>>) -> Matrix<Rational> {
+        Matrix::from_rows(
+            rows.into_iter()
+                .map(|row| row.into_iter().map(Rational::from).collect())
+                .collect(),
+        )
+    }
+
+    fn rat(x: i32) -> RealAlgebraic {
+        RealAlgebraic::Rational(Rational::from(x))
+    }
+
+    #[test]
+    fn swap_matrix() {
+        let a = mat(vec![vec![0, 1], vec![1, 0]]);
+        let mut eigenvalues = a.eigenvalues().unwrap();
+        eigenvalues.sort_by_key(|lambda| lambda.real_part());
+        assert_eq!(
+            eigenvalues,
+            vec![
+                ComplexAlgebraic::Real(rat(-1)),
+                ComplexAlgebraic::Real(rat(1))
+            ]
+        );
+        let mut real_eigenvalues = a.real_eigenvalues().unwrap();
+        real_eigenvalues.sort();
+        assert_eq!(real_eigenvalues, vec![rat(-1), rat(1)]);
+        assert_eq!(a.spectral_radius().unwrap(), rat(1));
+    }
+
+    #[test]
+    fn companion_matrix_eigenvalues() {
+        for f in [
+            "x^3-2",
+            "x^4+1",
+            "(x-1)^2*(x+3)",
+            "x^5-x-1",
+            "(x^2+4)*(x-1)",
+        ] {
+            let f = parse_rational_polynomial(f, "x").unwrap();
+            let a = f.companion_matrix();
+            assert_eq!(a.characteristic_polynomial().unwrap(), f);
+            assert_eq!(a.eigenvalues().unwrap(), f.all_complex_roots());
+            assert_eq!(a.real_eigenvalues().unwrap(), f.all_real_roots());
+        }
+
+        // x^2 + 4 has roots of absolute value 2
+        let a = parse_rational_polynomial("(x^2+4)*(x-1)", "x")
+            .unwrap()
+            .companion_matrix();
+        assert_eq!(a.spectral_radius().unwrap(), rat(2));
+        // x^3 - 2 has roots of absolute value 2^(1/3)
+        let a = parse_rational_polynomial("x^3-2", "x")
+            .unwrap()
+            .companion_matrix();
+        assert_eq!(
+            a.spectral_radius().unwrap(),
+            RealAlgebraic::structure().nth_root(&rat(2), 3).unwrap()
+        );
+    }
+
+    #[test]
+    fn non_square() {
+        let a = mat(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert!(matches!(a.eigenvalues(), Err(MatOppErr::NotSquare)));
+        assert!(matches!(a.spectral_radius(), Err(MatOppErr::NotSquare)));
+    }
+}
@@ -0,0 +1,216 @@
+use super::*;
+use algebraeon_structures::*;
+
+impl<FS: FiniteFieldSignature, FSB: BorrowedStructure<FS>> MatrixStructure<FS, FSB> {
+    /// Check whether `a * b = c` using Freivalds' algorithm.
+    ///
+    /// Each trial picks a uniformly random column vector `r` and checks `a * (b * r) = c * r`, which takes quadratically many field operations rather than the cubically many needed to compute `a * b`.
+    /// If `a * b = c` then `true` is always returned. Otherwise each trial detects the difference with probability at least `1 - 1/q` where `q` is the size of the field,
+    /// so `true` is returned incorrectly with probability at most `(1/q)^trials`.
+    /// The random vectors come from a generator with a fixed seed, so the result is reproducible.
+    pub fn freivalds_verify_mul(
+        &self,
+        a: &Matrix<FS::Elem>,
+        b: &Matrix<FS::Elem>,
+        c: &Matrix<FS::Elem>,
+        trials: usize,
+    ) -> bool {
+        if a.cols() != b.rows() || a.rows() != c.rows() || b.cols() != c.cols() {
+            return false;
+        }
+        let mut random_elements = self.ring().generate_random_elements(0);
+        for _ in 0..trials {
+            let r = Matrix::from_cols(vec![
+                (0..b.cols())
+                    .map(|_| random_elements.next().unwrap())
+                    .collect(),
+            ]);
+            let abr = self.mul(a, &self.mul(b, &r).unwrap()).unwrap();
+            let cr = self.mul(c, &r).unwrap();
+            if !self.equal(&abr, &cr) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl<RS: RingEqSignature, RSB: BorrowedStructure<RS>> MatrixStructure<RS, RSB> {
+    /// Check whether `a * b = c` exactly by computing the product.
+    ///
+    /// For rings such as the rationals there is no uniform distribution to sample from, and invariants such as the characteristic polynomial do not determine a matrix,
+    /// so unlike `freivalds_verify_mul` this always gives the correct answer.
+    pub fn verify_mul(
+        &self,
+        a: &Matrix<RS::Elem>,
+        b: &Matrix<RS::Elem>,
+        c: &Matrix<RS::Elem>,
+    ) -> bool {
+        match self.mul(a, b) {
+            Ok(ab) => self.equal(&ab, c),
+            Err(MatOppErr::DimMismatch) => false,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+impl<F: MetaType> Matrix<F>
+where
+    F::Signature: FiniteFieldSignature,
+{
+    pub fn freivalds_verify_mul(a: &Self, b: &Self, c: &Self, trials: usize) -> bool {
+        Self::structure().freivalds_verify_mul(a, b, c, trials)
+    }
+}
+
+impl<R: MetaType> Matrix<R>
+where
+    R::Signature: RingEqSignature,
+{
+    pub fn verify_mul(a: &Self, b: &Self, c: &Self) -> bool {
+        Self::structure().verify_mul(a, b, c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::num_theory::modulo::const_naive::{Modulo, ModuloCanonicalStructure};
+
+    #[test]
+    fn freivalds_accepts_correct_products() {
+        let a = Matrix::<Modulo<5>>::from_rows(vec![
+            vec![1, 2, 3],
+            vec![4, 0, 1],
+            vec![2, 2, 2],
+            vec![0, 3, 4],
+        ]);
+        let b = Matrix::<Modulo<5>>::from_rows(vec![vec![1, 0], vec![3, 4], vec![2, 2]]);
+        let c = Matrix::mul(&a, &b).unwrap();
+        assert!(Matrix::freivalds_verify_mul(&a, &b, &c, 10));
+        // zero trials accept any matrix of the right shape
+        assert!(Matrix::freivalds_verify_mul(
+            &a,
+            &b,
+            &Matrix::<Modulo<5>>::structure().zero(4, 2),
+            0
+        ));
+        // shapes that do not match
+        assert!(!Matrix::freivalds_verify_mul(&a, &b, &a, 0));
+        assert!(!Matrix::freivalds_verify_mul(&b, &a, &c, 10));
+    }
+
+    #[test]
+    fn freivalds_rejects_incorrect_products() {
+        // over F_2 a single wrong entry survives each trial with probability 1/2
+        let a = Matrix::<Modulo<2>>::from_rows(vec![
+            vec![1, 0, 1, 1],
+            vec![0, 1, 1, 0],
+            vec![1, 1, 0, 1],
+            vec![0, 0, 1, 1],
+        ]);
+        let b = Matrix::<Modulo<2>>::from_rows(vec![
+            vec![0, 1, 1, 0],
+            vec![1, 1, 0, 1],
+            vec![1, 0, 0, 1],
+            vec![0, 1, 1, 1],
+        ]);
+        let c = Matrix::mul(&a, &b).unwrap();
+        assert!(Matrix::freivalds_verify_mul(&a, &b, &c, 64));
+        for r in 0..4 {
+            for col in 0..4 {
+                let mut wrong = c.clone();
+                let entry = wrong.at_mut(r, col).unwrap();
+                *entry = Modulo::<2>::add(entry, &Modulo::<2>::one());
+                assert!(!Matrix::freivalds_verify_mul(&a, &b, &wrong, 64));
+            }
+        }
+
+        let a = Matrix::<Modulo<17>>::from_rows(vec![vec![3, 16, 5], vec![7, 0, 2]]);
+        let b = Matrix::<Modulo<17>>::from_rows(vec![vec![1, 9], vec![4, 4], vec![13, 0]]);
+        let mut wrong = Matrix::mul(&a, &b).unwrap();
+        *wrong.at_mut(1, 0).unwrap() = Modulo::<17>::from(5);
+        assert!(!Matrix::freivalds_verify_mul(&a, &b, &wrong, 16));
+    }
+
+    #[test]
+    fn freivalds_over_large_prime_field() {
+        // the field is far too large to list its elements
+        let field = Integer::structure()
+            .into_quotient_field_unchecked(Integer::from(2305843009213693951u64));
+        let mat = MatrixStructure::new(field.clone());
+        let a = Matrix::construct(3, 3, |i, j| {
+            field.from_int(Integer::from(7 * i + j * j + 1))
+        });
+        let b = Matrix::construct(3, 3, |i, j| {
+            field.from_int(Integer::from(i * j) - Integer::from(5))
+        });
+        let c = mat.mul(&a, &b).unwrap();
+        assert!(mat.freivalds_verify_mul(&a, &b, &c, 4));
+        let mut wrong = c.clone();
+        *wrong.at_mut(2, 1).unwrap() = field.add(wrong.at(2, 1).unwrap(), &field.one());
+        assert!(!mat.freivalds_verify_mul(&a, &b, &wrong, 4));
+    }
+
+    #[test]
+    fn freivalds_over_extension_field() {
+        use crate::finite_fields::extension::new_finite_field_extension;
+        // GF(8) = F_2[x] / (x^3 + x + 1)
+        let field = new_finite_field_extension::<ModuloCanonicalStructure<2>>(
+            Modulo::<2>::structure(),
+            Polynomial::from_coeffs(vec![1, 1, 0, 1]),
+        );
+        let alpha = field.from_vec(vec![
+            Modulo::<2>::from(0),
+            Modulo::<2>::from(1),
+            Modulo::<2>::from(0),
+        ]);
+        let mat = MatrixStructure::new(field.clone());
+        let a = Matrix::construct(3, 3, |i, j| {
+            field.nat_pow(&alpha, &Natural::from(i + 2 * j))
+        });
+        let b = Matrix::construct(3, 2, |i, j| {
+            field.add(&field.nat_pow(&alpha, &Natural::from(i * j)), &field.one())
+        });
+        let c = mat.mul(&a, &b).unwrap();
+        assert!(mat.freivalds_verify_mul(&a, &b, &c, 8));
+        for r in 0..3 {
+            for col in 0..2 {
+                let mut wrong = c.clone();
+                *wrong.at_mut(r, col).unwrap() = field.add(wrong.at(r, col).unwrap(), &alpha);
+                assert!(!mat.freivalds_verify_mul(&a, &b, &wrong, 16));
+            }
+        }
+    }
+
+    #[test]
+    fn exact_verify_mul_over_rationals() {
+        let a = Matrix::<Rational>::from_rows(vec![
+            vec![Rational::from(1), Rational::from_integers(1, 2)],
+            vec![Rational::from(-3), Rational::from(2)],
+        ]);
+        let b = Matrix::<Rational>::from_rows(vec![
+            vec![
+                Rational::from(2),
+                Rational::from(0),
+                Rational::from_integers(2, 3),
+            ],
+            vec![Rational::from(4), Rational::from(1), Rational::from(-1)],
+        ]);
+        let c = Matrix::mul(&a, &b).unwrap();
+        assert!(Matrix::verify_mul(&a, &b, &c));
+        assert!(!Matrix::verify_mul(&b, &a, &c));
+
+        // b a and a b share a characteristic polynomial but differ, so comparing invariants is not enough
+        let a = Matrix::<Rational>::from_rows(vec![vec![1, 1], vec![0, 0]]);
+        let b = Matrix::<Rational>::from_rows(vec![vec![0, 0], vec![1, 0]]);
+        let ab = Matrix::mul(&a, &b).unwrap();
+        let ba = Matrix::mul(&b, &a).unwrap();
+        assert_eq!(
+            ab.characteristic_polynomial().unwrap(),
+            ba.characteristic_polynomial().unwrap()
+        );
+        assert!(Matrix::verify_mul(&a, &b, &ab));
+        assert!(!Matrix::verify_mul(&a, &b, &ba));
+    }
+}
@@ -9,12 +9,50 @@ impl<FS: ComplexConjugateSignature, FSB: BorrowedStructure<FS>> MatrixStructure<
     pub fn conjugate_transpose(&self, mat: &Matrix<FS::Elem>) -> Matrix<FS::Elem> {
         self.conjugate(mat).transpose()
     }
+
+    /// The Hermitian adjoint `A^*` of `mat`, which is its conjugate transpose.
+    pub fn hermitian_adjoint(&self, mat: &Matrix<FS::Elem>) -> Matrix<FS::Elem> {
+        self.conjugate_transpose(mat)
+    }
+}
+
+impl<FS: ComplexConjugateSignature + RingEqSignature, FSB: BorrowedStructure<FS>>
+    MatrixStructure<FS, FSB>
+{
+    /// Whether `mat` is square and `mat * mat^* = I`.
+    pub fn is_unitary(&self, mat: &Matrix<FS::Elem>) -> bool {
+        mat.rows() == mat.cols()
+            && self.equal(
+                &self.mul(mat, &self.hermitian_adjoint(mat)).unwrap(),
+                &self.ident(mat.rows()),
+            )
+    }
+}
+
+impl<F: MetaType> Matrix<F>
+where
+    F::Signature: ComplexConjugateSignature,
+{
+    pub fn hermitian_adjoint(&self) -> Matrix<F> {
+        Self::structure().hermitian_adjoint(self)
+    }
+}
+
+impl<F: MetaType> Matrix<F>
+where
+    F::Signature: ComplexConjugateSignature + RingEqSignature,
+{
+    pub fn is_unitary(&self) -> bool {
+        Self::structure().is_unitary(self)
+    }
 }
 
 impl<FS: ComplexConjugateSignature + FieldSignature + ToStringSignature, FSB: BorrowedStructure<FS>>
     MatrixStructure<FS, FSB>
 {
-    /// return L and Q such that mat=L*Q where L is lower triangular and Q is row-orthogonal (not orthonormal)
+    /// Return `(L, Q)` such that `L * mat = Q` where `L` is lower unitriangular and the rows of `Q` are orthogonal (not orthonormal).
+    ///
+    /// The rows of `mat` must be linearly independent.
     pub fn gram_schmidt_row_orthogonalization_algorithm(
         &self,
         mut mat: Matrix<FS::Elem>,
@@ -63,7 +101,9 @@ impl<FS: ComplexConjugateSignature + FieldSignature + ToStringSignature, FSB: Bo
         (lt, mat)
     }
 
-    //return mat=QR where Q is col-orthogonal (not orthonormal) and R is upper triangular and
+    /// Return `(Q, U)` such that `mat * U = Q` where `U` is upper unitriangular and the columns of `Q` are orthogonal (not orthonormal).
+    ///
+    /// The columns of `mat` must be linearly independent.
     pub fn gram_schmidt_col_orthogonalization_algorithm(
         &self,
         mat: Matrix<FS::Elem>,
@@ -74,6 +114,7 @@ impl<FS: ComplexConjugateSignature + FieldSignature + ToStringSignature, FSB: Bo
         (q.transpose(), l.transpose())
     }
 
+    /// The rows of `mat` made orthogonal by the Gram-Schmidt process.
     pub fn gram_schmidt_row_orthogonalization(
         &self,
         mat: Matrix<FS::Elem>,
@@ -83,6 +124,9 @@ impl<FS: ComplexConjugateSignature + FieldSignature + ToStringSignature, FSB: Bo
             .1
     }
 
+    /// The columns of `mat` made orthogonal by the Gram-Schmidt process.
+    ///
+    /// The `i`th column of the result is the `i`th column of `mat` minus its projection onto the span of the previous columns.
     pub fn gram_schmidt_col_orthogonalization(
         &self,
         mat: Matrix<FS::Elem>,
@@ -91,6 +135,35 @@ impl<FS: ComplexConjugateSignature + FieldSignature + ToStringSignature, FSB: Bo
         self.gram_schmidt_col_orthogonalization_algorithm(mat, inner_product)
             .0
     }
+
+    /// Return the columns of `mat` made orthogonal by the Gram-Schmidt process together with the squared norm of each of them.
+    pub fn gram_schmidt_with_norms(
+        &self,
+        mat: Matrix<FS::Elem>,
+        inner_product: &impl ComplexInnerProduct<FS>,
+    ) -> (Matrix<FS::Elem>, Vec<FS::Elem>) {
+        let q = self.gram_schmidt_col_orthogonalization(mat, inner_product);
+        let norms = (0..q.cols())
+            .map(|c| {
+                let col = q.get_col(c);
+                inner_product.inner_product(&col, &col)
+            })
+            .collect();
+        (q, norms)
+    }
+
+    /// Return `(Q, R)` such that `mat = Q * R` where the columns of `Q` are orthogonal (not orthonormal) and `R` is upper unitriangular.
+    ///
+    /// Unlike [`MatrixStructure::qr_decomposition_algorithm`] no square roots are needed, so this works over any ordered field such as the rationals.
+    /// The columns of `mat` must be linearly independent.
+    pub fn qr_decomposition(
+        &self,
+        mat: Matrix<FS::Elem>,
+        inner_product: &impl ComplexInnerProduct<FS>,
+    ) -> (Matrix<FS::Elem>, Matrix<FS::Elem>) {
+        let (q, u) = self.gram_schmidt_col_orthogonalization_algorithm(mat, inner_product);
+        (q, self.inv(u).unwrap())
+    }
 }
 
 impl<
@@ -98,7 +171,7 @@ impl<
     FSB: BorrowedStructure<FS>,
 > MatrixStructure<FS, FSB>
 {
-    //return L*mat=Q where L is lower triangular and Q is orthonormal
+    /// Return `(L, Q)` such that `L * mat = Q` where `L` is lower triangular and the rows of `Q` are orthonormal.
     pub fn lq_decomposition_algorithm(
         &self,
         mat: Matrix<FS::Elem>,
@@ -136,7 +209,7 @@ impl<
         (lt, mat)
     }
 
-    //return mat*R=Q where Q is col-orthogonal (not orthonormal) and R is upper triangular
+    /// Return `(Q, U)` such that `mat * U = Q` where `U` is upper triangular and the columns of `Q` are orthonormal.
     pub fn qr_decomposition_algorithm(
         &self,
         mat: Matrix<FS::Elem>,
@@ -194,6 +267,20 @@ where
     ) -> Matrix<F> {
         Self::structure().gram_schmidt_col_orthogonalization(self, inner_product)
     }
+
+    pub fn gram_schmidt_with_norms(
+        self,
+        inner_product: &impl ComplexInnerProduct<F::Signature>,
+    ) -> (Matrix<F>, Vec<F>) {
+        Self::structure().gram_schmidt_with_norms(self, inner_product)
+    }
+
+    pub fn qr_decomposition(
+        self,
+        inner_product: &impl ComplexInnerProduct<F::Signature>,
+    ) -> (Matrix<F>, Matrix<F>) {
+        Self::structure().qr_decomposition(self, inner_product)
+    }
 }
 
 impl<F: MetaType> Matrix<F>
@@ -351,4 +438,96 @@ mod tests {
             ))
             .pprint();
     }
+
+    #[allow(clippy::erasing_op)]
+    #[test]
+    fn hermitian_adjoint_and_unitary() {
+        let i = &ComplexAlgebraic::i().into_ergonomic();
+
+        let mat = Matrix::<ComplexAlgebraic>::from_rows(vec![
+            vec![(1 + 2 * i).into_verbose(), (3 + 0 * i).into_verbose()],
+            vec![(-1 * i).into_verbose(), (4 - 5 * i).into_verbose()],
+            vec![(0 * i).into_verbose(), (2 + 1 * i).into_verbose()],
+        ]);
+        let adj = mat.hermitian_adjoint();
+        assert_eq!(adj.rows(), 2);
+        assert_eq!(adj.cols(), 3);
+        assert_eq!(adj.at(0, 0).unwrap(), &(1 - 2 * i).into_verbose());
+        assert_eq!(adj.at(0, 1).unwrap(), &(1 * i).into_verbose());
+        assert_eq!(adj.at(1, 1).unwrap(), &(4 + 5 * i).into_verbose());
+        assert_eq!(adj.hermitian_adjoint(), mat);
+        assert!(!mat.is_unitary());
+
+        assert!(Matrix::<ComplexAlgebraic>::ident(3).is_unitary());
+        // [[0, i], [i, 0]] is unitary but not Hermitian
+        let mat = Matrix::<ComplexAlgebraic>::from_rows(vec![
+            vec![(0 * i).into_verbose(), (1 * i).into_verbose()],
+            vec![(1 * i).into_verbose(), (0 * i).into_verbose()],
+        ]);
+        assert!(mat.is_unitary());
+        assert!(
+            !Matrix::mul(
+                &mat,
+                &Matrix::from_rows(vec![
+                    vec![(2 + 0 * i).into_verbose(), (0 * i).into_verbose()],
+                    vec![(0 * i).into_verbose(), (1 + 0 * i).into_verbose()],
+                ])
+            )
+            .unwrap()
+            .is_unitary()
+        );
+
+        // the orthonormalized columns of an invertible matrix form a unitary matrix
+        let mat = Matrix::<ComplexAlgebraic>::from_rows(vec![
+            vec![(-2 + 2 * i).into_verbose(), (-9 + 1 * i).into_verbose()],
+            vec![(3 + 3 * i).into_verbose(), (-2 + 4 * i).into_verbose()],
+        ]);
+        let q = mat
+            .clone()
+            .gram_schmidt_col_orthonormalization(&StandardInnerProduct::new(
+                ComplexAlgebraic::structure(),
+            ));
+        assert!(q.is_unitary());
+        assert!(q.hermitian_adjoint().is_unitary());
+        assert_eq!(q.hermitian_adjoint().hermitian_adjoint(), q);
+    }
+
+    #[test]
+    fn rational_gram_schmidt_with_norms_and_qr() {
+        let mat = Matrix::<Rational>::from_rows(vec![
+            vec![Rational::from(1), Rational::from(-1), Rational::from(3)],
+            vec![Rational::from(1), Rational::from(0), Rational::from(5)],
+            vec![Rational::from(1), Rational::from(2), Rational::from(6)],
+            vec![Rational::from(0), Rational::from(1), Rational::from(-2)],
+        ]);
+        let inner_product = StandardInnerProduct::new(Rational::structure());
+
+        let (q, norms) = mat.clone().gram_schmidt_with_norms(&inner_product);
+        assert_eq!(norms.len(), 3);
+        for (i, norm) in norms.iter().enumerate() {
+            for j in 0..3 {
+                let qi = q.get_col(i);
+                let qj = q.get_col(j);
+                let ip = inner_product.inner_product(&qi, &qj);
+                if i == j {
+                    assert_eq!(&ip, norm);
+                    assert!(norm > &Rational::ZERO);
+                } else {
+                    assert_eq!(ip, Rational::ZERO);
+                }
+            }
+        }
+        assert_eq!(norms[0], Rational::from(3));
+        assert_eq!(norms[1], Rational::from(6) - Rational::from_integers(1, 3));
+
+        let (q2, r) = mat.clone().qr_decomposition(&inner_product);
+        assert_eq!(q2, q);
+        assert_eq!(Matrix::mul(&q2, &r).unwrap(), mat);
+        for i in 0..3 {
+            assert_eq!(r.at(i, i).unwrap(), &Rational::ONE);
+            for j in 0..i {
+                assert_eq!(r.at(i, j).unwrap(), &Rational::ZERO);
+            }
+        }
+    }
 }
@@ -1,7 +1,7 @@
 use crate::{
     matrix::{Matrix, RingMatricesSignature, SymmetricMatrix},
     structure::{
-        ComplexConjugateSignature, ComplexSubsetSignature, OrderedRingSignature,
+        ComplexConjugateSignature, ComplexSubsetSignature, FieldSignature, OrderedRingSignature,
         RealSubsetSignature, RingSignature,
     },
 };
@@ -79,37 +79,11 @@ pub struct RealSymmetricInnerProduct<Ring: RealSubsetSignature, RingB: BorrowedS
     mat: SymmetricMatrix<Ring::Elem>, // symmetric and positive-definite
 }
 
-fn is_positive_definite<Ring: OrderedRingSignature + RealSubsetSignature>(
-    ring: &Ring,
-    mat: &SymmetricMatrix<Ring::Elem>,
-) -> bool {
-    let ring_mat = ring.matrix_structure();
-    let n = mat.n();
-    let mat = Matrix::construct(n, n, |r, c| mat.get(r, c).unwrap().clone());
-    for i in 1..n {
-        if ring
-            .cmp(
-                &ring_mat
-                    .det_naive(&mat.submatrix((0..i).collect(), (0..i).collect()))
-                    .unwrap(),
-                &ring.zero(),
-            )
-            .is_le()
-        {
-            return false;
-        }
-    }
-    true
-}
-
 impl<Ring: RealSubsetSignature, RingB: BorrowedStructure<Ring>>
     RealSymmetricInnerProduct<Ring, RingB>
 {
-    pub fn new(ring: RingB, mat: SymmetricMatrix<Ring::Elem>) -> Self
-    where
-        Ring: OrderedRingSignature,
-    {
-        debug_assert!(is_positive_definite(ring.borrow(), &mat));
+    /// `mat` should be positive-definite, which can be checked with `is_positive_definite` when the ring is an ordered field.
+    pub fn new(ring: RingB, mat: SymmetricMatrix<Ring::Elem>) -> Self {
         Self {
             _ring: PhantomData,
             ring,
@@ -122,6 +96,22 @@ impl<Ring: RealSubsetSignature, RingB: BorrowedStructure<Ring>>
     }
 }
 
+impl<
+    Ring: RealSubsetSignature + OrderedRingSignature + FieldSignature,
+    RingB: BorrowedStructure<Ring>,
+> RealSymmetricInnerProduct<Ring, RingB>
+{
+    /// Whether the matrix defining the inner product is positive-definite, as it should be.
+    pub fn is_positive_definite(&self) -> bool {
+        let n = self.mat.n();
+        self.ring()
+            .matrix_structure()
+            .is_positive_definite(&Matrix::construct(n, n, |r, c| {
+                self.mat.get(r, c).unwrap().clone()
+            }))
+    }
+}
+
 impl<Ring: RingSignature + RealSubsetSignature, RingB: BorrowedStructure<Ring>>
     ComplexInnerProduct<Ring> for RealSymmetricInnerProduct<Ring, RingB>
 {
@@ -0,0 +1,130 @@
+use super::*;
+use crate::linear::finitely_free_module::RingToFinitelyFreeModuleSignature;
+use algebraeon_structures::*;
+
+/// Lattices in `Z^n` given by a matrix whose rows are a basis (or just a spanning set) of the lattice.
+impl Matrix<Integer> {
+    /// The covolume `|det(basis)|` of the lattice whose basis is given by the rows of the square matrix `self`.
+    ///
+    /// This is the index of the lattice in `Z^n`, or `0` if the rows are linearly dependent.
+    pub fn integer_lattice_volume(&self) -> Natural {
+        self.det().unwrap().abs()
+    }
+
+    /// The index `[L1 : L2]` where `L2` is spanned by the rows of `self` and `L1` is spanned by the rows of `super_basis`.
+    ///
+    /// Returns `None` if `L2` is not contained in `L1` or if the index is infinite.
+    pub fn integer_lattice_index(&self, super_basis: &Matrix<Integer>) -> Option<Natural> {
+        assert_eq!(self.cols(), super_basis.cols());
+        let submodules = Integer::structure()
+            .into_free_module(self.cols())
+            .into_submodules();
+        let sub = submodules.matrix_row_span(self.clone());
+        let sup = submodules.matrix_row_span(super_basis.clone());
+        if sub.rank() != sup.rank() || !submodules.contains(&sup, &sub) {
+            return None;
+        }
+        let sub = sub.into_row_basis_matrix();
+        let sup = sup.into_row_basis_matrix();
+        // the rows of sub are integer combinations of the rows of sup
+        // restricting to the pivot columns of sup turns this into a change of basis between full rank lattices
+        let (_, _, _, pivots) = sup.row_hermite_algorithm();
+        let rows = sup.rows();
+        let sub_vol = sub
+            .submatrix(0..rows, pivots.iter().copied())
+            .integer_lattice_volume();
+        let sup_vol = sup
+            .submatrix(0..rows, pivots.iter().copied())
+            .integer_lattice_volume();
+        Some(sub_vol / sup_vol)
+    }
+
+    /// A row basis in reduced hermite normal form of the intersection of the lattices spanned by the rows of `self` and of `other`.
+    pub fn integer_lattice_intersection(&self, other: &Matrix<Integer>) -> Matrix<Integer> {
+        assert_eq!(self.cols(), other.cols());
+        let submodules = Integer::structure()
+            .into_free_module(self.cols())
+            .into_submodules();
+        let x = submodules.matrix_row_span(self.clone());
+        let y = submodules.matrix_row_span(other.clone());
+        submodules.intersect(x, y).into_row_basis_matrix()
+    }
+
+    /// A row basis in reduced hermite normal form of the sum of the lattices spanned by the rows of `self` and of `other`.
+    pub fn integer_lattice_sum(&self, other: &Matrix<Integer>) -> Matrix<Integer> {
+        assert_eq!(self.cols(), other.cols());
+        let submodules = Integer::structure()
+            .into_free_module(self.cols())
+            .into_submodules();
+        submodules
+            .matrix_row_span(Matrix::join_rows(self.cols(), vec![self, other]))
+            .into_row_basis_matrix()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lattice_volume() {
+        for n in 0..5 {
+            assert_eq!(
+                Matrix::<Integer>::ident(n).integer_lattice_volume(),
+                Natural::ONE
+            );
+        }
+        assert_eq!(
+            Matrix::<Integer>::from_rows(vec![vec![2, 1], vec![1, -3]]).integer_lattice_volume(),
+            Natural::from(7u32)
+        );
+        assert_eq!(
+            Matrix::<Integer>::from_rows(vec![vec![2, 4], vec![1, 2]]).integer_lattice_volume(),
+            Natural::ZERO
+        );
+    }
+
+    #[test]
+    fn lattice_index() {
+        let z = Matrix::<Integer>::from_rows(vec![vec![1]]);
+        let two_z = Matrix::<Integer>::from_rows(vec![vec![2]]);
+        assert_eq!(two_z.integer_lattice_index(&z), Some(Natural::from(2u32)));
+        assert_eq!(z.integer_lattice_index(&two_z), None);
+        assert_eq!(z.integer_lattice_index(&z), Some(Natural::ONE));
+
+        // a rank 2 lattice inside a rank 2 sublattice of Z^3
+        let l1 = Matrix::<Integer>::from_rows(vec![vec![1, 1, 0], vec![0, 1, 1]]);
+        let l2 = Matrix::<Integer>::from_rows(vec![vec![2, 2, 0], vec![1, 4, 3]]);
+        assert_eq!(l2.integer_lattice_index(&l1), Some(Natural::from(6u32)));
+        // a lattice of smaller rank has infinite index
+        let l3 = Matrix::<Integer>::from_rows(vec![vec![1, 2, 1]]);
+        assert_eq!(l3.integer_lattice_index(&l1), None);
+        // and the index in Z^n is the volume
+        let l4 = Matrix::<Integer>::from_rows(vec![vec![3, 1], vec![1, 2]]);
+        assert_eq!(
+            l4.integer_lattice_index(&Matrix::ident(2)),
+            Some(l4.integer_lattice_volume())
+        );
+    }
+
+    #[test]
+    fn lattice_intersection_and_sum() {
+        let a = Matrix::<Integer>::from_rows(vec![vec![4, 0], vec![0, 6]]);
+        let b = Matrix::<Integer>::from_rows(vec![vec![6, 0], vec![0, 4]]);
+        assert_eq!(
+            a.integer_lattice_intersection(&b),
+            Matrix::from_rows(vec![vec![12, 0], vec![0, 12]])
+        );
+        assert_eq!(
+            a.integer_lattice_sum(&b),
+            Matrix::from_rows(vec![vec![2, 0], vec![0, 2]])
+        );
+
+        // [L1 + L2 : L1] = [L2 : L1 n L2]
+        let a = Matrix::<Integer>::from_rows(vec![vec![2, 1], vec![0, 3]]);
+        let b = Matrix::<Integer>::from_rows(vec![vec![1, 1], vec![0, 4]]);
+        let s = a.integer_lattice_sum(&b);
+        let i = a.integer_lattice_intersection(&b);
+        assert_eq!(a.integer_lattice_index(&s), i.integer_lattice_index(&b));
+    }
+}
@@ -0,0 +1,151 @@
+use super::*;
+use algebraeon_structures::*;
+
+impl<RS: RingSignature, RSB: BorrowedStructure<RS>> MatrixStructure<RS, RSB> {
+    /// The Kronecker product `a ⊗ b`, the block matrix whose block at `(i, j)` is `a[i][j] b`.
+    pub fn kronecker_product(
+        &self,
+        a: &Matrix<RS::Elem>,
+        b: &Matrix<RS::Elem>,
+    ) -> Matrix<RS::Elem> {
+        let (p, q) = (b.rows(), b.cols());
+        Matrix::construct(a.rows() * p, a.cols() * q, |r, c| {
+            self.ring()
+                .mul(a.at(r / p, c / q).unwrap(), b.at(r % p, c % q).unwrap())
+        })
+    }
+
+    /// The Kronecker sum `a ⊕ b = a ⊗ I_n + I_m ⊗ b` of the `m` by `m` matrix `a` and the `n` by `n` matrix `b`.
+    ///
+    /// Its eigenvalues are the sums `λ + μ` of an eigenvalue `λ` of `a` and an eigenvalue `μ` of `b`.
+    pub fn kronecker_sum(
+        &self,
+        a: &Matrix<RS::Elem>,
+        b: &Matrix<RS::Elem>,
+    ) -> Result<Matrix<RS::Elem>, MatOppErr> {
+        let (m, n) = (a.rows(), b.rows());
+        if m != a.cols() || n != b.cols() {
+            return Err(MatOppErr::NotSquare);
+        }
+        self.add(
+            &self.kronecker_product(a, &self.ident(n)),
+            &self.kronecker_product(&self.ident(m), b),
+        )
+    }
+
+    /// The vectorization `vec(mat)`, the column vector obtained by stacking the columns of `mat`.
+    ///
+    /// It satisfies `vec(a x b) = (b^T ⊗ a) vec(x)`.
+    pub fn vec_operator(&self, mat: &Matrix<RS::Elem>) -> Matrix<RS::Elem> {
+        Matrix::from_col(mat.cols_iter().flatten().cloned().collect())
+    }
+}
+
+impl<R: MetaType> Matrix<R>
+where
+    R::Signature: RingSignature,
+{
+    pub fn kronecker_product(a: &Self, b: &Self) -> Self {
+        Self::structure().kronecker_product(a, b)
+    }
+
+    pub fn kronecker_sum(a: &Self, b: &Self) -> Result<Self, MatOppErr> {
+        Self::structure().kronecker_sum(a, b)
+    }
+
+    pub fn vec_operator(&self) -> Self {
+        Self::structure().vec_operator(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::isolated_algebraic::ComplexAlgebraic;
+
+    #[test]
+    fn kronecker_product() {
+        let a = Matrix::<Integer>::from_rows(vec![vec![1, 2], vec![3, 4]]);
+        let b = Matrix::<Integer>::from_rows(vec![vec![0, 5, 1]]);
+        assert_eq!(
+            Matrix::kronecker_product(&a, &b),
+            Matrix::from_rows(vec![vec![0, 5, 1, 0, 10, 2], vec![0, 15, 3, 0, 20, 4]])
+        );
+        assert_eq!(
+            Matrix::kronecker_product(&Matrix::<Integer>::ident(2), &Matrix::ident(3)),
+            Matrix::ident(6)
+        );
+        assert_eq!(
+            Matrix::kronecker_sum(&a, &Matrix::<Integer>::from_rows(vec![vec![7]])).unwrap(),
+            Matrix::from_rows(vec![vec![8, 2], vec![3, 11]])
+        );
+        assert!(matches!(
+            Matrix::kronecker_sum(&a, &b),
+            Err(MatOppErr::NotSquare)
+        ));
+    }
+
+    #[test]
+    fn vec_operator() {
+        let a = Matrix::<Integer>::from_rows(vec![vec![1, -2], vec![0, 3], vec![4, 1]]);
+        let x = Matrix::<Integer>::from_rows(vec![vec![2, 0, 1], vec![-1, 5, 3]]);
+        let b = Matrix::<Integer>::from_rows(vec![vec![1, 1], vec![2, 0], vec![-3, 4]]);
+        assert_eq!(x.vec_operator(), Matrix::from_col(vec![2, -1, 0, 5, 1, 3]));
+        let axb = Matrix::mul(&Matrix::mul(&a, &x).unwrap(), &b).unwrap();
+        assert_eq!(
+            axb.vec_operator(),
+            Matrix::mul(
+                &Matrix::kronecker_product(&b.transpose_ref(), &a),
+                &x.vec_operator()
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn kronecker_sum_eigenvalues() {
+        // the companion matrices of x^2 - 2 and x^2 - 3 have Kronecker sum with eigenvalues ±sqrt(2) ± sqrt(3)
+        let a = Polynomial::<Rational>::from_coeffs(vec![-2, 0, 1]).companion_matrix();
+        let b = Polynomial::<Rational>::from_coeffs(vec![-3, 0, 1]).companion_matrix();
+        assert_eq!(
+            Matrix::kronecker_sum(&a, &b)
+                .unwrap()
+                .characteristic_polynomial()
+                .unwrap(),
+            Polynomial::from_coeffs(vec![1, 0, -10, 0, 1])
+        );
+
+        // char_poly(a ⊕ b) = prod_{i, j} (x - λ_i - μ_j)
+        let complex = ComplexAlgebraic::structure();
+        for (a, b) in [
+            (
+                Matrix::<Rational>::from_rows(vec![vec![1, 2], vec![3, 4]]),
+                Matrix::<Rational>::from_rows(vec![vec![0, -1], vec![1, 0]]),
+            ),
+            (
+                Matrix::<Rational>::from_rows(vec![vec![2, 1, 0], vec![0, 2, 0], vec![1, 0, -1]]),
+                Matrix::<Rational>::from_rows(vec![vec![5]]),
+            ),
+            (
+                Polynomial::<Rational>::from_coeffs(vec![-2, 0, 0, 1]).companion_matrix(),
+                Matrix::<Rational>::from_rows(vec![vec![1, 1], vec![1, 0]]),
+            ),
+        ] {
+            let mut sums = vec![];
+            for lambda in a.eigenvalues().unwrap() {
+                for mu in b.eigenvalues().unwrap() {
+                    sums.push(complex.add(&lambda, &mu));
+                }
+            }
+            let mut eigenvalues = Matrix::kronecker_sum(&a, &b)
+                .unwrap()
+                .eigenvalues()
+                .unwrap();
+            assert_eq!(eigenvalues.len(), sums.len());
+            for s in sums {
+                let i = eigenvalues.iter().position(|e| e == &s).unwrap();
+                eigenvalues.remove(i);
+            }
+        }
+    }
+}
@@ -374,6 +374,7 @@ impl<B: BorrowedStructure<IntegerCanonicalStructure>>
                 )
             }),
         );
+        debug_assert!(rational_inner_product.is_positive_definite());
 
         #[cfg(debug_assertions)]
         let orig_basis = basis.clone();
@@ -418,7 +419,7 @@ impl<B: BorrowedStructure<IntegerCanonicalStructure>>
             let true_d = (0..n)
                 .map(|i| {
                     true_gram_mat
-                        .submatrix((0..(i + 1)).collect(), (0..(i + 1)).collect())
+                        .submatrix(0..(i + 1), 0..(i + 1))
                         .det()
                         .unwrap()
                 })
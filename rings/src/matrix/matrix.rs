@@ -89,6 +89,28 @@ impl<Set> Matrix<Set> {
         }
     }
 
+    /// Iterate over the entries in row `r`.
+    pub fn row_iter(&self, r: usize) -> impl Iterator<Item = &Set> {
+        assert!(r < self.rows());
+        (0..self.cols()).map(move |c| self.at(r, c).unwrap())
+    }
+
+    /// Iterate over the entries in column `c`.
+    pub fn col_iter(&self, c: usize) -> impl Iterator<Item = &Set> {
+        assert!(c < self.cols());
+        (0..self.rows()).map(move |r| self.at(r, c).unwrap())
+    }
+
+    /// Iterate over the rows, each given as an iterator over its entries.
+    pub fn rows_iter(&self) -> impl Iterator<Item = impl Iterator<Item = &Set>> {
+        (0..self.rows()).map(|r| self.row_iter(r))
+    }
+
+    /// Iterate over the columns, each given as an iterator over its entries.
+    pub fn cols_iter(&self) -> impl Iterator<Item = impl Iterator<Item = &Set>> {
+        (0..self.cols()).map(|c| self.col_iter(c))
+    }
+
     /// Get a mutable reference to the entry at row `r` and column `c`.
     pub fn at_mut(&mut self, r: usize, c: usize) -> Result<&mut Set, MatOppErr> {
         if r >= self.rows() || c >= self.cols() {
@@ -168,8 +190,78 @@ impl<Set: Clone> Matrix<Set> {
         Self::from_rows(vec![elems]).transpose()
     }
 
+    /// Construct the Toeplitz matrix with first column `col` and first row `row`, so that the entry at row `r` and column `c` depends only on `r - c`.
+    ///
+    /// The diagonal entries are taken from `col[0]` and `row[0]` is ignored.
+    ///
+    /// ```rust
+    /// use algebraeon_structures::Integer;
+    /// use algebraeon_rings::matrix::Matrix;
+    /// let a = Matrix::<Integer>::toeplitz(&[1, 2, 3].map(Integer::from), &[1, 4].map(Integer::from));
+    /// let b = Matrix::<Integer>::from_rows(vec![vec![1, 4], vec![2, 1], vec![3, 2]]);
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn toeplitz(col: &[Set], row: &[Set]) -> Self {
+        assert!(!col.is_empty());
+        assert!(!row.is_empty());
+        Self::construct(col.len(), row.len(), |r, c| {
+            if r >= c {
+                col[r - c].clone()
+            } else {
+                row[c - r].clone()
+            }
+        })
+    }
+
+    /// Construct the square Hankel matrix with the given anti-diagonals, so that the entry at row `r` and column `c` is `anti_diagonals[r + c]`.
+    ///
+    /// `anti_diagonals` must have odd length `2n - 1` for an `n` by `n` matrix.
+    ///
+    /// ```rust
+    /// use algebraeon_structures::Integer;
+    /// use algebraeon_rings::matrix::Matrix;
+    /// let a = Matrix::<Integer>::hankel(&[1, 2, 3, 4, 5].map(Integer::from));
+    /// let b = Matrix::<Integer>::from_rows(vec![vec![1, 2, 3], vec![2, 3, 4], vec![3, 4, 5]]);
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn hankel(anti_diagonals: &[Set]) -> Self {
+        assert_eq!(anti_diagonals.len() % 2, 1);
+        let n = anti_diagonals.len().div_ceil(2);
+        Self::construct(n, n, |r, c| anti_diagonals[r + c].clone())
+    }
+
+    /// Construct the square circulant matrix with first row `row`, where each row is the previous row cyclically shifted one place to the right.
+    ///
+    /// ```rust
+    /// use algebraeon_structures::Integer;
+    /// use algebraeon_rings::matrix::Matrix;
+    /// let a = Matrix::<Integer>::circulant(&[1, 2, 3].map(Integer::from));
+    /// let b = Matrix::<Integer>::from_rows(vec![vec![1, 2, 3], vec![3, 1, 2], vec![2, 3, 1]]);
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn circulant(row: &[Set]) -> Self {
+        let n = row.len();
+        Self::construct(n, n, |r, c| row[(c + n - r) % n].clone())
+    }
+
     /// Return the submatrix given by the intersection of the rows defined by `rows` and the columns defined by `cols`.
-    pub fn submatrix(&self, rows: Vec<usize>, cols: Vec<usize>) -> Self {
+    ///
+    /// `rows` and `cols` may be lists of indices or ranges.
+    ///
+    /// ```rust
+    /// use algebraeon_structures::Integer;
+    /// use algebraeon_rings::matrix::Matrix;
+    /// let a = Matrix::<Integer>::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+    /// assert_eq!(a.submatrix(1..3, 0..2), Matrix::from_rows(vec![vec![4, 5], vec![7, 8]]));
+    /// assert_eq!(a.submatrix(vec![2, 0], vec![1]), Matrix::from_rows(vec![vec![8], vec![2]]));
+    /// ```
+    pub fn submatrix(
+        &self,
+        rows: impl IntoIterator<Item = usize>,
+        cols: impl IntoIterator<Item = usize>,
+    ) -> Self {
+        let rows = rows.into_iter().collect::<Vec<_>>();
+        let cols = cols.into_iter().collect::<Vec<_>>();
         let mut elems = vec![];
         for r in &rows {
             for c in &cols {
@@ -187,11 +279,11 @@ impl<Set: Clone> Matrix<Set> {
     }
 
     pub fn get_row_submatrix(&self, row: usize) -> Self {
-        self.submatrix(vec![row], (0..self.cols()).collect())
+        self.submatrix(vec![row], 0..self.cols())
     }
 
     pub fn get_col_submatrix(&self, col: usize) -> Self {
-        self.submatrix((0..self.rows()).collect(), vec![col])
+        self.submatrix(0..self.rows(), vec![col])
     }
 
     pub fn get_row_refs(&self, row: usize) -> Vec<&Set> {
@@ -317,6 +409,22 @@ impl<Set: Clone> Matrix<Set> {
         joined.transpose()
     }
 
+    /// Place the columns of `b` to the right of the columns of `a`.
+    pub fn hstack(a: &Self, b: &Self) -> Result<Self, MatOppErr> {
+        if a.rows() != b.rows() {
+            return Err(MatOppErr::DimMismatch);
+        }
+        Ok(Self::join_cols(a.rows(), vec![a, b]))
+    }
+
+    /// Place the rows of `b` below the rows of `a`.
+    pub fn vstack(a: &Self, b: &Self) -> Result<Self, MatOppErr> {
+        if a.cols() != b.cols() {
+            return Err(MatOppErr::DimMismatch);
+        }
+        Ok(Self::join_rows(a.cols(), vec![a, b]))
+    }
+
     /// Return a vector containing the entries of this matrix.
     ///
     /// Most useful when this matrix is a row vector or a column vector.
@@ -390,62 +498,108 @@ impl<RS: EqSignature, RSB: BorrowedStructure<RS>> MatrixStructure<RS, RSB> {
     }
 }
 
-impl<RS: ToStringSignature, RSB: BorrowedStructure<RS>> MatrixStructure<RS, RSB> {
-    pub fn pprint(&self, mat: &Matrix<RS::Elem>) {
-        let mut str_rows = vec![];
-        for r in 0..mat.rows() {
-            str_rows.push(vec![]);
-            for c in 0..mat.cols() {
-                str_rows[r].push(self.ring().to_string(mat.at(r, c).unwrap()));
-            }
-        }
-        #[allow(clippy::redundant_closure_for_method_calls)]
-        let cols_widths: Vec<usize> = (0..mat.cols())
-            .map(|c| {
-                (0..mat.rows())
-                    .map(|r| str_rows[r][c].chars().count())
-                    .fold(0usize, |a, b| a.max(b))
-            })
-            .collect();
+/// Lay out the entries of a matrix as an aligned grid with bracket characters at each end of each row.
+fn format_matrix_grid(rows: usize, cols: usize, mut str_rows: Vec<Vec<String>>) -> String {
+    use std::fmt::Write;
 
-        #[allow(clippy::needless_range_loop)]
-        for r in 0..mat.rows() {
-            for c in 0..mat.cols() {
-                while str_rows[r][c].chars().count() < cols_widths[c] {
-                    str_rows[r][c].push(' ');
-                }
-                debug_assert_eq!(str_rows[r][c].chars().count(), cols_widths[c]);
+    #[allow(clippy::redundant_closure_for_method_calls)]
+    let cols_widths: Vec<usize> = (0..cols)
+        .map(|c| {
+            (0..rows)
+                .map(|r| str_rows[r][c].chars().count())
+                .fold(0usize, |a, b| a.max(b))
+        })
+        .collect();
+
+    #[allow(clippy::needless_range_loop)]
+    for r in 0..rows {
+        for c in 0..cols {
+            while str_rows[r][c].chars().count() < cols_widths[c] {
+                str_rows[r][c].push(' ');
             }
+            debug_assert_eq!(str_rows[r][c].chars().count(), cols_widths[c]);
         }
+    }
 
-        #[allow(clippy::needless_range_loop)]
-        for r in 0..mat.rows() {
-            if mat.rows() == 1 {
-                print!("( ");
-            } else if r == 0 {
-                print!("/ ");
-            } else if r == mat.rows() - 1 {
-                print!("\\ ");
-            } else {
-                print!("| ");
-            }
-            for c in 0..mat.cols() {
-                if c != 0 {
-                    print!("    ");
-                }
-                print!("{}", str_rows[r][c]);
-            }
-            if mat.rows() == 1 {
-                print!(" )");
-            } else if r == 0 {
-                print!(" \\");
-            } else if r == mat.rows() - 1 {
-                print!(" /");
-            } else {
-                print!(" |");
+    let mut f = String::new();
+    #[allow(clippy::needless_range_loop)]
+    for r in 0..rows {
+        if rows == 1 {
+            write!(f, "( ").unwrap();
+        } else if r == 0 {
+            write!(f, "/ ").unwrap();
+        } else if r == rows - 1 {
+            write!(f, "\\ ").unwrap();
+        } else {
+            write!(f, "| ").unwrap();
+        }
+        for c in 0..cols {
+            if c != 0 {
+                write!(f, "    ").unwrap();
             }
-            println!();
+            write!(f, "{}", str_rows[r][c]).unwrap();
         }
+        if rows == 1 {
+            write!(f, " )").unwrap();
+        } else if r == 0 {
+            write!(f, " \\").unwrap();
+        } else if r == rows - 1 {
+            write!(f, " /").unwrap();
+        } else {
+            write!(f, " |").unwrap();
+        }
+        writeln!(f).unwrap();
+    }
+    f
+}
+
+impl<Set: std::fmt::Display> std::fmt::Display for Matrix<Set> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str_rows = self
+            .rows_iter()
+            .map(|row| row.map(|x| x.to_string()).collect())
+            .collect();
+        write!(
+            f,
+            "{}",
+            format_matrix_grid(self.rows(), self.cols(), str_rows)
+        )
+    }
+}
+
+impl<Set: std::fmt::Display> Matrix<Set> {
+    /// LaTeX source for this matrix as a `pmatrix`.
+    ///
+    /// ```rust
+    /// use algebraeon_structures::Integer;
+    /// use algebraeon_rings::matrix::Matrix;
+    /// let a = Matrix::<Integer>::from_rows(vec![vec![1, 2], vec![3, -4]]);
+    /// assert_eq!(a.to_latex(), "\\begin{pmatrix}\n1 & 2 \\\\\n3 & -4\n\\end{pmatrix}");
+    /// ```
+    pub fn to_latex(&self) -> String {
+        let rows = self
+            .rows_iter()
+            .map(|row| row.map(|x| x.to_string()).collect::<Vec<_>>().join(" & "))
+            .collect::<Vec<_>>();
+        format!(
+            "\\begin{{pmatrix}}\n{}\n\\end{{pmatrix}}",
+            rows.join(" \\\\\n")
+        )
+    }
+}
+
+impl<RS: ToStringSignature, RSB: BorrowedStructure<RS>> MatrixStructure<RS, RSB> {
+    /// The matrix laid out as an aligned grid, as printed by [`MatrixStructure::pprint`].
+    pub fn pretty_string(&self, mat: &Matrix<RS::Elem>) -> String {
+        let str_rows = mat
+            .rows_iter()
+            .map(|row| row.map(|x| self.ring().to_string(x)).collect())
+            .collect();
+        format_matrix_grid(mat.rows(), mat.cols(), str_rows)
+    }
+
+    pub fn pprint(&self, mat: &Matrix<RS::Elem>) {
+        print!("{}", self.pretty_string(mat));
     }
 }
 
@@ -475,6 +629,14 @@ impl<RS: RingSignature, RSB: BorrowedStructure<RS>> MatrixStructure<RS, RSB> {
         })
     }
 
+    /// Construct the square Vandermonde matrix whose entry at row `r` and column `c` is `points[r]^c`.
+    pub fn vandermonde(&self, points: &[impl Borrow<RS::Elem>]) -> Matrix<RS::Elem> {
+        let n = points.len();
+        Matrix::construct(n, n, |r, c| {
+            self.ring().nat_pow(points[r].borrow(), &Natural::from(c))
+        })
+    }
+
     pub fn join_diag<MatT: Borrow<Matrix<RS::Elem>>>(&self, mats: Vec<MatT>) -> Matrix<RS::Elem> {
         if mats.is_empty() {
             Matrix::construct(0, 0, |_r, _c| unreachable!())
@@ -753,6 +915,11 @@ where
         Self::structure().diag(diag)
     }
 
+    /// Construct the square Vandermonde matrix whose entry at row `r` and column `c` is `points[r]^c`.
+    pub fn vandermonde(points: &[R]) -> Self {
+        Self::structure().vandermonde(points)
+    }
+
     pub fn dot(a: &Self, b: &Self) -> R {
         Self::structure().dot(a, b)
     }
@@ -1306,4 +1473,201 @@ mod tests {
         println!("{}", m.det_naive().unwrap());
         assert_eq!(m.det_naive().unwrap(), Integer::from(-15));
     }
+
+    #[test]
+    fn vandermonde_det() {
+        let xs = [2, -1, 5, 3, 0].map(Integer::from);
+        let v = Matrix::<Integer>::vandermonde(&xs);
+        assert_eq!(v.at(2, 3).unwrap(), &Integer::from(125));
+        let mut prod = Integer::ONE;
+        for i in 0..xs.len() {
+            for j in (i + 1)..xs.len() {
+                prod *= &xs[j] - &xs[i];
+            }
+        }
+        assert_eq!(v.det_naive().unwrap(), prod);
+
+        // repeated points give a singular matrix
+        let v = Matrix::<Integer>::vandermonde(&[1, 2, 1].map(Integer::from));
+        assert_eq!(v.det_naive().unwrap(), Integer::ZERO);
+    }
+
+    #[test]
+    fn toeplitz_mul() {
+        // multiplying by a lower triangular Toeplitz matrix is polynomial multiplication by 1 + 2x + 3x^2
+        let t = Matrix::<Integer>::toeplitz(
+            &[1, 2, 3, 0, 0].map(Integer::from),
+            &[1, 0, 0].map(Integer::from),
+        );
+        // (1 + 2x + 3x^2)(4 + 5x + 6x^2) = 4 + 13x + 28x^2 + 27x^3 + 18x^4
+        assert_eq!(
+            Matrix::mul(&t, &Matrix::from_col(vec![4, 5, 6])).unwrap(),
+            Matrix::from_col(vec![4, 13, 28, 27, 18])
+        );
+
+        // the product of Toeplitz matrices computed entrywise
+        let a = Matrix::<Integer>::toeplitz(
+            &[1, 2, 3].map(Integer::from),
+            &[1, -1, 4].map(Integer::from),
+        );
+        let b = Matrix::<Integer>::toeplitz(
+            &[0, 1, -2].map(Integer::from),
+            &[0, 3, 1].map(Integer::from),
+        );
+        assert_eq!(
+            Matrix::mul(&a, &b).unwrap(),
+            Matrix::from_rows(vec![vec![-9, 7, -2], vec![3, 5, 5], vec![0, 10, 9]])
+        );
+    }
+
+    #[test]
+    fn hankel_is_flipped_toeplitz() {
+        let h = Matrix::<Integer>::hankel(&[1, 2, 3, 4, 5, 6, 7].map(Integer::from));
+        assert_eq!(h.clone().transpose(), h);
+        assert_eq!(
+            h.flip_cols(),
+            Matrix::toeplitz(
+                &[4, 5, 6, 7].map(Integer::from),
+                &[4, 3, 2, 1].map(Integer::from)
+            )
+        );
+    }
+
+    #[test]
+    fn circulant_eigenvectors() {
+        let row = [3, -1, 4, 1, -5, 9].map(Integer::from);
+        let c = Matrix::<Integer>::circulant(&row);
+        assert_eq!(c.get_row(1), [9, 3, -1, 4, 1, -5].map(Integer::from));
+
+        // for each n-th root of unity w the vector (1, w, w^2, ...) is an eigenvector with eigenvalue sum_k row[k] w^k
+        // check this for the roots of unity 1 and -1 which lie in the integers
+        for w in [Integer::ONE, Integer::from(-1)] {
+            let v = (0..row.len())
+                .map(|k| Integer::structure().nat_pow(&w, &Natural::from(k)))
+                .collect::<Vec<_>>();
+            let lambda = row
+                .iter()
+                .zip(v.iter())
+                .map(|(a, b)| a * b)
+                .sum::<Integer>();
+            assert_eq!(
+                Matrix::mul(&c, &Matrix::from_col(v.clone())).unwrap(),
+                Matrix::from_col(v).mul_scalar(&lambda)
+            );
+        }
+    }
+
+    #[test]
+    fn row_and_col_iters() {
+        let a = Matrix::<Integer>::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(a.row_iter(0).count(), 3);
+        assert_eq!(a.col_iter(0).count(), 2);
+        assert_eq!(a.rows_iter().count(), 2);
+        assert_eq!(a.cols_iter().count(), 3);
+        assert_eq!(
+            a.row_iter(1).cloned().collect::<Vec<_>>(),
+            [4, 5, 6].map(Integer::from)
+        );
+        assert_eq!(
+            a.col_iter(2).cloned().collect::<Vec<_>>(),
+            [3, 6].map(Integer::from)
+        );
+        let t = a.transpose_ref();
+        for (r, row) in a.rows_iter().enumerate() {
+            assert!(row.eq(t.col_iter(r)));
+        }
+        let f = a.flip_cols_ref();
+        assert_eq!(
+            f.row_iter(0).cloned().collect::<Vec<_>>(),
+            [3, 2, 1].map(Integer::from)
+        );
+    }
+
+    #[test]
+    fn hstack_and_vstack() {
+        let a = Matrix::<Integer>::from_rows(vec![vec![1, 2], vec![3, 4]]);
+        let b = Matrix::<Integer>::from_rows(vec![vec![5, 6, 7], vec![8, 9, 10]]);
+        let c = Matrix::<Integer>::from_rows(vec![vec![0, -1]]);
+
+        let ab = Matrix::hstack(&a, &b).unwrap();
+        assert_eq!(ab.rows(), 2);
+        assert_eq!(ab.cols(), 5);
+        assert!(ab.col_iter(a.cols()).eq(b.col_iter(0)));
+        assert_eq!(ab.submatrix(0..2, 2..5), b);
+        assert!(Matrix::hstack(&a, &c).is_err());
+
+        let ac = Matrix::vstack(&a, &c).unwrap();
+        assert_eq!(
+            ac,
+            Matrix::from_rows(vec![vec![1, 2], vec![3, 4], vec![0, -1]])
+        );
+        assert!(ac.row_iter(a.rows()).eq(c.row_iter(0)));
+        assert!(Matrix::vstack(&a, &b).is_err());
+    }
+
+    #[test]
+    fn submatrix_ranges() {
+        let a = Matrix::<Integer>::construct(4, 5, |r, c| Integer::from(10 * r + c));
+        let s = a.submatrix(1..3, 2..5);
+        assert_eq!(
+            s,
+            Matrix::from_rows(vec![vec![12, 13, 14], vec![22, 23, 24]])
+        );
+
+        // re-embedding the submatrix into a zero matrix and extracting it again is a projection
+        let embed = |s: &Matrix<Integer>| {
+            Matrix::construct(4, 5, |r, c| {
+                if (1..3).contains(&r) && (2..5).contains(&c) {
+                    s.at(r - 1, c - 2).unwrap().clone()
+                } else {
+                    Integer::ZERO
+                }
+            })
+        };
+        let p = embed(&s);
+        assert_eq!(embed(&p.submatrix(1..3, 2..5)), p);
+        assert_eq!(p.submatrix(1..3, 2..5), s);
+    }
+
+    #[test]
+    fn display_and_latex() {
+        let a = Matrix::<Rational>::from_rows(vec![
+            vec![
+                Rational::from(1),
+                Rational::from_integers(-1, 2),
+                Rational::from(0),
+            ],
+            vec![
+                Rational::from(10),
+                Rational::from(3),
+                Rational::from_integers(7, 3),
+            ],
+        ]);
+        assert_eq!(
+            a.to_string(),
+            "/ 1     -1/2    0   \\\n\\ 10    3       7/3 /\n"
+        );
+        assert_eq!(
+            format!("{}", a),
+            Matrix::<Rational>::structure().pretty_string(&a)
+        );
+        assert_eq!(
+            a.to_latex(),
+            "\\begin{pmatrix}\n1 & -1/2 & 0 \\\\\n10 & 3 & 7/3\n\\end{pmatrix}"
+        );
+
+        let b = Matrix::<Integer>::from_rows(vec![vec![1, 22], vec![-3, 4], vec![5, 6]]);
+        assert_eq!(
+            b.to_string(),
+            "/ 1     22 \\\n| -3    4  |\n\\ 5     6  /\n"
+        );
+        assert_eq!(
+            Matrix::<Integer>::from_row(vec![1, 2]).to_string(),
+            "( 1    2 )\n"
+        );
+        assert_eq!(
+            b.to_string(),
+            Matrix::<Integer>::structure().pretty_string(&b)
+        );
+    }
 }
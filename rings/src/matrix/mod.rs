@@ -2,16 +2,22 @@ use crate::polynomial::*;
 use crate::structure::*;
 use itertools::Itertools;
 
+mod definiteness;
+mod eigenvalues;
+mod freivalds;
 mod general_linear_group;
 mod gram_schmidt;
 mod hermite_reduction;
 mod inner_product;
+mod integer_lattice;
 mod jordan_normal_form;
+mod kronecker;
 mod lll_reduction;
 #[allow(clippy::module_inception)]
 mod matrix;
 mod polynomial;
 mod primitive;
+mod pseudoinverse;
 mod row_operations;
 mod smith_normal_form;
 mod symmetric_matrix;
@@ -1,6 +1,65 @@
 use super::*;
 use algebraeon_structures::*;
 
+impl<RS: RingEqSignature, RSB: BorrowedStructure<RS>> PolynomialStructure<RS, RSB> {
+    /// Evaluate `p` at the square matrix `mat` using Horner's method.
+    pub fn evaluate_at_matrix(
+        &self,
+        p: &Polynomial<RS::Elem>,
+        mat: &Matrix<RS::Elem>,
+    ) -> Result<Matrix<RS::Elem>, MatOppErr> {
+        let n = mat.rows();
+        if n != mat.cols() {
+            return Err(MatOppErr::NotSquare);
+        }
+        let mat_struct = self.coeff_ring().matrix_structure();
+        // p(A) = a I + A(b I + A(c I + A(d I)))
+        let mut y = mat_struct.zero(n, n);
+        for c in self.coeffs(p).collect::<Vec<_>>().into_iter().rev() {
+            y = mat_struct.mul(mat, &y).unwrap();
+            mat_struct
+                .add_mut(&mut y, &mat_struct.mul_scalar(mat_struct.ident(n), c))
+                .unwrap();
+        }
+        Ok(y)
+    }
+
+    /// The companion matrix of the monic polynomial `p = x^n + a_{n-1} x^{n-1} + ... + a_0`.
+    ///
+    /// This is the `n` by `n` matrix with `1`s on the sub-diagonal and `-a_0, ..., -a_{n-1}` down the last column, and its characteristic polynomial is `p`.
+    /// Panics if `p` is not monic.
+    pub fn companion_matrix(&self, p: &Polynomial<RS::Elem>) -> Matrix<RS::Elem> {
+        assert!(
+            self.is_monic(p),
+            "companion matrix of a non-monic polynomial"
+        );
+        let ring = self.coeff_ring();
+        let n = self.degree(p).unwrap();
+        Matrix::construct(n, n, |r, c| {
+            if c + 1 == n {
+                ring.neg(&self.coeff(p, r))
+            } else if r == c + 1 {
+                ring.one()
+            } else {
+                ring.zero()
+            }
+        })
+    }
+}
+
+impl<R: MetaType> Polynomial<R>
+where
+    R::Signature: RingEqSignature,
+{
+    pub fn evaluate_at_matrix(&self, mat: &Matrix<R>) -> Result<Matrix<R>, MatOppErr> {
+        Self::structure().evaluate_at_matrix(self, mat)
+    }
+
+    pub fn companion_matrix(&self) -> Matrix<R> {
+        Self::structure().companion_matrix(self)
+    }
+}
+
 impl<FS: FieldSignature, FSB: BorrowedStructure<FS>> MatrixStructure<FS, FSB> {
     pub fn presentation_matrix(
         &self,
@@ -60,6 +119,19 @@ impl<FS: FieldSignature, FSB: BorrowedStructure<FS>> MatrixStructure<FS, FSB> {
             Err(_) => panic!(),
         }
     }
+
+    /// Check the Cayley-Hamilton theorem for `m`, that `m` is a root of its characteristic polynomial.
+    pub fn char_poly_satisfies_ch(&self, m: &Matrix<FS::Elem>) -> Result<bool, MatOppErr> {
+        let n = m.rows();
+        let char_poly = self.characteristic_polynomial(m.clone())?;
+        Ok(self.equal(
+            &self
+                .ring()
+                .polynomials()
+                .evaluate_at_matrix(&char_poly, m)?,
+            &self.zero(n, n),
+        ))
+    }
 }
 
 impl<F: MetaType> Matrix<F>
@@ -77,6 +149,10 @@ where
     pub fn characteristic_polynomial(&self) -> Result<Polynomial<F>, MatOppErr> {
         Self::structure().characteristic_polynomial(self.clone())
     }
+
+    pub fn char_poly_satisfies_ch(&self) -> Result<bool, MatOppErr> {
+        Self::structure().char_poly_satisfies_ch(self)
+    }
 }
 
 #[cfg(test)]
@@ -169,4 +245,105 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn evaluate_at_matrix() {
+        let a = Matrix::<Integer>::from_rows(vec![vec![1, 2], vec![3, 4]]);
+        // x^2 + 1
+        let p = Polynomial::<Integer>::from_coeffs(vec![1, 0, 1]);
+        assert_eq!(
+            p.evaluate_at_matrix(&a).unwrap(),
+            Matrix::from_rows(vec![vec![8, 10], vec![15, 23]])
+        );
+        assert_eq!(
+            Polynomial::<Integer>::zero()
+                .evaluate_at_matrix(&a)
+                .unwrap(),
+            Matrix::zero(2, 2)
+        );
+        assert_eq!(
+            Polynomial::<Integer>::from_coeffs(vec![5])
+                .evaluate_at_matrix(&a)
+                .unwrap(),
+            Matrix::from_rows(vec![vec![5, 0], vec![0, 5]])
+        );
+        assert!(matches!(
+            p.evaluate_at_matrix(&Matrix::<Integer>::from_rows(vec![vec![1, 2]])),
+            Err(MatOppErr::NotSquare)
+        ));
+
+        // A^2 - tr(A) A + det(A) I = 0 for 2x2 matrices
+        for rows in [
+            vec![vec![1, 2], vec![3, 4]],
+            vec![vec![0, -1], vec![1, 0]],
+            vec![vec![7, 3], vec![-2, 5]],
+            vec![vec![2, 0], vec![0, 2]],
+        ] {
+            let a = Matrix::<Integer>::from_rows(rows);
+            let p =
+                Polynomial::from_coeffs(vec![a.det().unwrap(), -a.trace().unwrap(), Integer::ONE]);
+            assert_eq!(p.evaluate_at_matrix(&a).unwrap(), Matrix::zero(2, 2));
+        }
+    }
+
+    #[test]
+    fn companion_matrix() {
+        // x^3 - 2x + 5
+        let p = Polynomial::<Integer>::from_coeffs(vec![5, -2, 0, 1]);
+        assert_eq!(
+            p.companion_matrix(),
+            Matrix::from_rows(vec![vec![0, 0, -5], vec![1, 0, 2], vec![0, 1, 0]])
+        );
+        assert_eq!(
+            p.evaluate_at_matrix(&p.companion_matrix()).unwrap(),
+            Matrix::zero(3, 3)
+        );
+        assert_eq!(
+            Polynomial::<Integer>::one().companion_matrix(),
+            Matrix::zero(0, 0)
+        );
+
+        for coeffs in [
+            vec![3, 1],
+            vec![-1, 0, 1],
+            vec![1, 1, 1, 1, 1],
+            vec![0, 0, 2, -7, 0, 1],
+        ] {
+            let p = Polynomial::<Rational>::from_coeffs(coeffs);
+            let a = p.companion_matrix();
+            assert_eq!(a.characteristic_polynomial().unwrap(), p);
+            let n = a.rows();
+            assert_eq!(p.evaluate_at_matrix(&a).unwrap(), Matrix::zero(n, n));
+        }
+    }
+
+    #[test]
+    fn cayley_hamilton() {
+        let mut seed = 12345u64;
+        let mut next = || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            Rational::from_integers(
+                Integer::from(((seed >> 33) % 21) as i64 - 10),
+                Integer::from(((seed >> 20) % 4) as i64 + 1),
+            )
+        };
+        for _ in 0..5 {
+            let a = Matrix::<Rational>::from_rows(
+                (0..4)
+                    .map(|_| (0..4).map(|_| next()).collect())
+                    .collect::<Vec<Vec<_>>>(),
+            );
+            assert!(a.char_poly_satisfies_ch().unwrap());
+            // and so char_poly + 1 evaluates to the identity
+            let char_poly = a.characteristic_polynomial().unwrap();
+            let q = Polynomial::add(&char_poly, &Polynomial::one());
+            assert_eq!(q.evaluate_at_matrix(&a).unwrap(), Matrix::ident(4));
+        }
+        assert!(matches!(
+            Matrix::<Rational>::zero(2, 3).char_poly_satisfies_ch(),
+            Err(MatOppErr::NotSquare)
+        ));
+    }
 }
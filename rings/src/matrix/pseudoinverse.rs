@@ -0,0 +1,240 @@
+use super::*;
+use algebraeon_structures::*;
+
+impl<FS: OrderedRingSignature + FieldSignature, FSB: BorrowedStructure<FS>>
+    MatrixStructure<FS, FSB>
+{
+    /// The Moore-Penrose pseudoinverse of `mat`.
+    ///
+    /// Let `C` be the columns of `mat` at the pivots of its row echelon form, so that the columns of `C` are a basis for the column space of `mat`, and let `F = (C^T C)^{-1} C^T mat` so that `mat = C F` is a full rank factorization.
+    /// Then the pseudoinverse is `F^T (F F^T)^{-1} (C^T C)^{-1} C^T`.
+    /// The inverses exist since `C^T C` and `F F^T` are Gram matrices of linearly independent vectors over an ordered field.
+    pub fn moore_penrose_pseudoinverse(&self, mat: &Matrix<FS::Elem>) -> Matrix<FS::Elem> {
+        let (m, n) = (mat.rows(), mat.cols());
+        let (_, _, _, pivots) = self.row_hermite_algorithm(mat.clone());
+        if pivots.is_empty() {
+            return self.zero(n, m);
+        }
+        let c = mat.submatrix(0..m, pivots);
+        let ct = c.transpose_ref();
+        let ctc_inv = self.inv(self.mul(&ct, &c).unwrap()).unwrap();
+        let f = self.mul(&self.mul(&ctc_inv, &ct).unwrap(), mat).unwrap();
+        let ft = f.transpose_ref();
+        let fft_inv = self.inv(self.mul(&f, &ft).unwrap()).unwrap();
+        self.mul(
+            &self.mul(&ft, &fft_inv).unwrap(),
+            &self.mul(&ctc_inv, &ct).unwrap(),
+        )
+        .unwrap()
+    }
+
+    /// Return `x` minimizing the euclidean norm of `a x - b`, choosing the `x` of smallest norm when there is more than one.
+    pub fn least_squares_solve(
+        &self,
+        a: &Matrix<FS::Elem>,
+        b: &Matrix<FS::Elem>,
+    ) -> Result<Matrix<FS::Elem>, MatOppErr> {
+        self.mul(&self.moore_penrose_pseudoinverse(a), b)
+    }
+
+    /// The matrix of the orthogonal projection onto the column space of `mat`.
+    ///
+    /// This is `mat mat^+` where `mat^+` is the pseudoinverse, which is `A (A^T A)^{-1} A^T` when `A = mat` has linearly independent columns.
+    pub fn orthogonal_projection_matrix(&self, mat: &Matrix<FS::Elem>) -> Matrix<FS::Elem> {
+        self.mul(mat, &self.moore_penrose_pseudoinverse(mat))
+            .unwrap()
+    }
+
+    /// The matrix of the orthogonal projection onto the orthogonal complement of the column space of `mat`.
+    ///
+    /// This is `I - P` where `P` is the [`MatrixStructure::orthogonal_projection_matrix`] of `mat`.
+    pub fn orthogonal_complement_matrix(&self, mat: &Matrix<FS::Elem>) -> Matrix<FS::Elem> {
+        let p = self.orthogonal_projection_matrix(mat);
+        self.add(&self.ident(mat.rows()), &self.neg(p)).unwrap()
+    }
+}
+
+impl<R: MetaType> Matrix<R>
+where
+    R::Signature: OrderedRingSignature + FieldSignature,
+{
+    pub fn moore_penrose_pseudoinverse(&self) -> Matrix<R> {
+        Self::structure().moore_penrose_pseudoinverse(self)
+    }
+
+    pub fn least_squares_solve(&self, b: &Matrix<R>) -> Result<Matrix<R>, MatOppErr> {
+        Self::structure().least_squares_solve(self, b)
+    }
+
+    pub fn orthogonal_projection_matrix(&self) -> Matrix<R> {
+        Self::structure().orthogonal_projection_matrix(self)
+    }
+
+    pub fn orthogonal_complement_matrix(&self) -> Matrix<R> {
+        Self::structure().orthogonal_complement_matrix(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mat(rows: Vec<Vec<i32>>) -> Matrix<Rational> {
+        Matrix::from_rows(
+            rows.into_iter()
+                .map(|row| row.into_iter().map(Rational::from).collect())
+                .collect(),
+        )
+    }
+
+    fn check_penrose_conditions(a: &Matrix<Rational>) {
+        let p = a.moore_penrose_pseudoinverse();
+        assert_eq!(p.rows(), a.cols());
+        assert_eq!(p.cols(), a.rows());
+        let ap = Matrix::mul(a, &p).unwrap();
+        let pa = Matrix::mul(&p, a).unwrap();
+        assert_eq!(&Matrix::mul(&ap, a).unwrap(), a);
+        assert_eq!(Matrix::mul(&pa, &p).unwrap(), p);
+        assert_eq!(ap.transpose_ref(), ap);
+        assert_eq!(pa.transpose_ref(), pa);
+    }
+
+    #[test]
+    fn pseudoinverse_of_invertible_is_inverse() {
+        let a = mat(vec![vec![2, 1, 0], vec![1, 3, -1], vec![0, 5, 4]]);
+        assert_eq!(a.moore_penrose_pseudoinverse(), a.inv().unwrap());
+        check_penrose_conditions(&a);
+    }
+
+    #[test]
+    fn pseudoinverse_full_column_rank() {
+        let a = mat(vec![vec![1, 0], vec![1, 1], vec![1, 2], vec![1, 5]]);
+        let at = a.transpose_ref();
+        assert_eq!(
+            a.moore_penrose_pseudoinverse(),
+            Matrix::mul(&Matrix::mul(&at, &a).unwrap().inv().unwrap(), &at).unwrap()
+        );
+        check_penrose_conditions(&a);
+    }
+
+    #[test]
+    fn pseudoinverse_rank_deficient() {
+        check_penrose_conditions(&mat(vec![vec![1, 2, 3], vec![2, 4, 6]]));
+        check_penrose_conditions(&mat(vec![
+            vec![1, 0, 1, 2],
+            vec![0, 1, 1, -1],
+            vec![1, 1, 2, 1],
+        ]));
+        check_penrose_conditions(&mat(vec![vec![0, 0], vec![0, 0], vec![0, 0]]));
+        assert_eq!(
+            mat(vec![vec![0, 0, 0], vec![0, 0, 0]]).moore_penrose_pseudoinverse(),
+            Matrix::zero(3, 2)
+        );
+        // the pseudoinverse of a column vector v is v^T / |v|^2
+        assert_eq!(
+            mat(vec![vec![1], vec![2], vec![2]]).moore_penrose_pseudoinverse(),
+            Matrix::from_rows(vec![vec![
+                Rational::from_integers(1, 9),
+                Rational::from_integers(2, 9),
+                Rational::from_integers(2, 9),
+            ]])
+        );
+    }
+
+    #[test]
+    fn least_squares() {
+        // fit a line y = c + m x through (0, 1), (1, 2), (2, 2), (3, 4)
+        let a = mat(vec![vec![1, 0], vec![1, 1], vec![1, 2], vec![1, 3]]);
+        let b = mat(vec![vec![1], vec![2], vec![2], vec![4]]);
+        let x = a.least_squares_solve(&b).unwrap();
+        assert_eq!(
+            x,
+            Matrix::from_col(vec![
+                Rational::from_integers(9, 10),
+                Rational::from_integers(9, 10)
+            ])
+        );
+
+        // the residual is orthogonal to the column space
+        let residual = Matrix::add(&Matrix::mul(&a, &x).unwrap(), &b.neg()).unwrap();
+        assert_eq!(
+            Matrix::mul(&a.transpose_ref(), &residual).unwrap(),
+            Matrix::zero(2, 1)
+        );
+
+        // so perturbing the solution does not decrease the norm of the residual
+        let residual_norm_sqrd = |x: &Matrix<Rational>| {
+            let r = Matrix::add(&Matrix::mul(&a, x).unwrap(), &b.neg()).unwrap();
+            Matrix::dot(&r, &r)
+        };
+        for dx in [vec![1, 0], vec![0, 1], vec![-1, 2], vec![3, -1]] {
+            let y = Matrix::add(
+                &x,
+                &Matrix::from_col(dx)
+                    .apply_map(|d: &Integer| Rational::from_integers(d.clone(), 100)),
+            )
+            .unwrap();
+            assert!(residual_norm_sqrd(&y) > residual_norm_sqrd(&x));
+        }
+
+        // consistent systems are solved exactly
+        let a = mat(vec![vec![2, 1], vec![1, 3]]);
+        let b = mat(vec![vec![3], vec![5]]);
+        assert_eq!(
+            Matrix::mul(&a, &a.least_squares_solve(&b).unwrap()).unwrap(),
+            b
+        );
+    }
+
+    #[test]
+    fn orthogonal_projections() {
+        // the plane x + y + z = 0 in 3-space
+        let a = mat(vec![vec![1, 0], vec![-1, 1], vec![0, -1]]);
+        let p = a.orthogonal_projection_matrix();
+        let q = a.orthogonal_complement_matrix();
+
+        // P = A (A^T A)^{-1} A^T
+        let at = a.transpose_ref();
+        assert_eq!(
+            p,
+            Matrix::mul(
+                &Matrix::mul(&a, &Matrix::mul(&at, &a).unwrap().inv().unwrap()).unwrap(),
+                &at
+            )
+            .unwrap()
+        );
+
+        // projections are idempotent and symmetric
+        for m in [&p, &q] {
+            assert_eq!(&Matrix::mul(m, m).unwrap(), m);
+            assert_eq!(&m.transpose_ref(), m);
+        }
+        assert_eq!(Matrix::add(&p, &q).unwrap(), Matrix::ident(3));
+
+        // v - (v.n / n.n) n where n = (1, 1, 1) is the normal of the plane
+        let v = mat(vec![vec![3], vec![-1], vec![4]]);
+        assert_eq!(
+            Matrix::mul(&p, &v).unwrap(),
+            Matrix::from_col(vec![
+                Rational::from(1),
+                Rational::from(-3),
+                Rational::from(2)
+            ])
+        );
+        assert_eq!(
+            Matrix::mul(&q, &v).unwrap(),
+            Matrix::from_col(vec![
+                Rational::from(2),
+                Rational::from(2),
+                Rational::from(2)
+            ])
+        );
+
+        // dependent columns project onto their span
+        let b = mat(vec![vec![1, 2], vec![2, 4], vec![2, 4]]);
+        assert_eq!(
+            b.orthogonal_projection_matrix(),
+            mat(vec![vec![1], vec![2], vec![2]]).orthogonal_projection_matrix()
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use super::*;
+use algebraeon_groups::abelian::FinitelyGeneratedAbelianGroup;
 use algebraeon_structures::*;
 
 impl<RS: BezoutDomainSignature, RSB: BorrowedStructure<RS>> MatrixStructure<RS, RSB> {
@@ -35,15 +36,17 @@ impl<RS: BezoutDomainSignature, RSB: BorrowedStructure<RS>> MatrixStructure<RS,
                         for c in n..m.cols() {
                             if !self.ring().equal(m.at(r, c).unwrap(), &self.ring().zero()) {
                                 //swap column n and column c
-                                let col_opp = ElementaryOpp::new_col_opp(
-                                    self.ring().clone(),
-                                    ElementaryOppType::Swap(n, c),
-                                );
-                                col_opp.apply(&mut m);
-                                col_opp.apply(&mut v);
+                                if c != n {
+                                    let col_opp = ElementaryOpp::new_col_opp(
+                                        self.ring().clone(),
+                                        ElementaryOppType::Swap(n, c),
+                                    );
+                                    col_opp.apply(&mut m);
+                                    col_opp.apply(&mut v);
+                                }
 
                                 //swap row n and row r
-                                let row_opp = ElementaryOpp::new_col_opp(
+                                let row_opp = ElementaryOpp::new_row_opp(
                                     self.ring().clone(),
                                     ElementaryOppType::Swap(n, r),
                                 );
@@ -292,6 +295,75 @@ where
     }
 }
 
+impl<
+    FS: FieldSignature,
+    FSB: BorrowedStructure<FS>,
+    PB: BorrowedStructure<PolynomialStructure<FS, FSB>>,
+> MatrixStructure<PolynomialStructure<FS, FSB>, PB>
+{
+    /// Return `(u, s, v)` such that `s = u a v` is the Smith normal form of `a` over the polynomial ring `F[x]`, and `u` and `v` are invertible.
+    ///
+    /// The non-zero diagonal entries of `s` are monic and each divides the next.
+    pub fn smith_normal_form_polynomial(
+        &self,
+        a: &Matrix<Polynomial<FS::Elem>>,
+    ) -> (
+        Matrix<Polynomial<FS::Elem>>,
+        Matrix<Polynomial<FS::Elem>>,
+        Matrix<Polynomial<FS::Elem>>,
+    ) {
+        let (mut u, mut s, v, k) = self.smith_algorithm(a.clone());
+        // the gcd steps do not necessarily leave the diagonal entries monic
+        for i in 0..k {
+            let (unit, _) = self.ring().factor_fav_assoc(s.at(i, i).unwrap());
+            let row_opp = ElementaryOpp::new_row_opp(
+                self.ring().clone(),
+                ElementaryOppType::UnitMul {
+                    row: i,
+                    unit: self.ring().try_reciprocal(&unit).unwrap(),
+                },
+            );
+            row_opp.apply(&mut s);
+            row_opp.apply(&mut u);
+        }
+        (u, s, v)
+    }
+}
+
+impl<F: MetaType> Matrix<Polynomial<F>>
+where
+    F::Signature: FieldSignature,
+{
+    pub fn smith_normal_form_polynomial(&self) -> (Self, Self, Self) {
+        Self::structure().smith_normal_form_polynomial(self)
+    }
+}
+
+impl Matrix<Integer> {
+    /// For `self` in Smith normal form, the abelian group generated by the columns of `self` subject to the relations given by its rows.
+    ///
+    /// If `self` has `n` columns and non-zero diagonal entries `d_1 | ... | d_k` this is `Z^(n-k) + Z/d_1Z + ... + Z/d_kZ`.
+    pub fn abelian_group_from_smith_form(&self) -> FinitelyGeneratedAbelianGroup {
+        let mut orders = (0..std::cmp::min(self.rows(), self.cols()))
+            .map(|i| Abs::abs(self.at(i, i).unwrap()))
+            .collect::<Vec<_>>();
+        orders.resize(self.cols(), Natural::ZERO);
+        FinitelyGeneratedAbelianGroup::from_cyclic_orders(orders)
+    }
+
+    /// The abelian group generated by the columns of `self` subject to the relations given by its rows, that is, the cokernel of `self` acting on row vectors.
+    pub fn cokernel_abelian_group(&self) -> FinitelyGeneratedAbelianGroup {
+        self.smith_algorithm().1.abelian_group_from_smith_form()
+    }
+
+    /// The abelian group `Z^m / im(self)` for `self` an `m` by `n` matrix acting on column vectors.
+    ///
+    /// This is the abelian group presented by the transpose of `self`, whose rows are the columns of `self`.
+    pub fn cokernel(&self) -> FinitelyGeneratedAbelianGroup {
+        self.transpose_ref().cokernel_abelian_group()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -408,4 +480,150 @@ mod tests {
             assert_eq!(k, 1);
         }
     }
+
+    #[test]
+    fn test_smith_algorithm_zero_first_row() {
+        // the first non-zero entry is below the first row, in the first column or a later one
+        for rows in [
+            vec![vec![0, 0], vec![2, 0]],
+            vec![vec![0, 0], vec![0, 3]],
+            vec![vec![0, 0, 0], vec![0, 0, 4], vec![6, 0, 0]],
+        ] {
+            let a = Matrix::<Integer>::from_rows(rows);
+            let (u, s, v, _k) = a.clone().smith_algorithm();
+            assert_eq!(s, Matrix::mul(&Matrix::mul(&u, &a).unwrap(), &v).unwrap());
+        }
+        let (_u, s, _v, k) =
+            Matrix::<Integer>::from_rows(vec![vec![0, 0, 0], vec![0, 0, 4], vec![6, 0, 0]])
+                .smith_algorithm();
+        assert_eq!(k, 2);
+        assert_eq!(s.at(0, 0).unwrap(), &Integer::from(2));
+        assert_eq!(s.at(1, 1).unwrap(), &Integer::from(12));
+    }
+
+    #[test]
+    fn test_cokernel_abelian_group() {
+        // Z^3 / <(2, 4, 4), (-6, 6, 12), (10, 4, 16)> = Z/2 + Z/2 + Z/156
+        let a = Matrix::<Integer>::from_rows(vec![vec![2, 4, 4], vec![-6, 6, 12], vec![10, 4, 16]]);
+        assert_eq!(
+            a.cokernel_abelian_group(),
+            FinitelyGeneratedAbelianGroup::new(
+                0,
+                vec![
+                    Natural::from(2u32),
+                    Natural::from(2u32),
+                    Natural::from(156u32)
+                ]
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            a.cokernel_abelian_group().order(),
+            Some(a.det().unwrap().abs())
+        );
+
+        // Z^3 / <(1, 2, 3), (0, 0, 6)> = Z + Z/6
+        let a = Matrix::<Integer>::from_rows(vec![vec![1, 2, 3], vec![0, 0, 6]]);
+        assert_eq!(
+            a.cokernel_abelian_group(),
+            FinitelyGeneratedAbelianGroup::new(1, vec![Natural::from(6u32)]).unwrap()
+        );
+
+        // more relations than generators
+        let a = Matrix::<Integer>::from_rows(vec![vec![4], vec![6], vec![10]]);
+        assert_eq!(
+            a.cokernel_abelian_group(),
+            FinitelyGeneratedAbelianGroup::cyclic(Natural::from(2u32))
+        );
+
+        assert_eq!(
+            Matrix::<Integer>::zero(0, 2).cokernel_abelian_group(),
+            FinitelyGeneratedAbelianGroup::free(2)
+        );
+        assert!(
+            Matrix::<Integer>::ident(3)
+                .cokernel_abelian_group()
+                .is_trivial()
+        );
+
+        let s = Matrix::<Integer>::from_rows(vec![
+            vec![1, 0, 0, 0],
+            vec![0, 3, 0, 0],
+            vec![0, 0, 0, 0],
+        ]);
+        let g = s.abelian_group_from_smith_form();
+        assert_eq!(g.to_string(), "Z^2 + Z/3Z");
+        assert!(g.to_finite_group().is_none());
+    }
+
+    #[test]
+    fn test_smith_normal_form_polynomial() {
+        let x = &Polynomial::<Rational>::var().into_ergonomic();
+        let a = Matrix::from_rows(vec![
+            vec![x.clone().into_verbose(), (x + 1).into_verbose()],
+            vec![x.pow(2).into_verbose(), (x.pow(2) + x).into_verbose()],
+        ]);
+        let (u, s, v) = a.smith_normal_form_polynomial();
+        assert_eq!(s, Matrix::mul(&Matrix::mul(&u, &a).unwrap(), &v).unwrap());
+        // the second row is x times the first and gcd(x, x + 1) = 1
+        assert_eq!(
+            s,
+            Matrix::from_rows(vec![
+                vec![Polynomial::one(), Polynomial::zero()],
+                vec![Polynomial::zero(), Polynomial::zero()],
+            ])
+        );
+        assert!(u.det().unwrap().is_unit());
+        assert!(v.det().unwrap().is_unit());
+
+        // the invariant factors of xI - m for m in Jordan normal form
+        for (m, invariant_factors) in [
+            (
+                vec![vec![2, 0], vec![0, 2]],
+                vec![(x - 2).into_verbose(), (x - 2).into_verbose()],
+            ),
+            (
+                vec![vec![2, 1], vec![0, 2]],
+                vec![Polynomial::one(), (x - 2).pow(2).into_verbose()],
+            ),
+            (
+                vec![vec![1, 0], vec![0, 2]],
+                vec![Polynomial::one(), ((x - 1) * (x - 2)).into_verbose()],
+            ),
+        ] {
+            let m = Matrix::<Rational>::from_rows(m);
+            let a = m.presentation_matrix().unwrap().neg();
+            let (u, s, v) = a.smith_normal_form_polynomial();
+            assert_eq!(s, Matrix::mul(&Matrix::mul(&u, &a).unwrap(), &v).unwrap());
+            for (i, d) in invariant_factors.iter().enumerate() {
+                assert_eq!(s.at(i, i).unwrap(), d);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cokernel() {
+        // Z^3 / im(diag(2, 3, 0)) = Z/2 + Z/3 + Z = Z/6 + Z
+        let a = Matrix::<Integer>::from_rows(vec![vec![2, 0, 0], vec![0, 3, 0], vec![0, 0, 0]]);
+        assert_eq!(
+            a.cokernel(),
+            FinitelyGeneratedAbelianGroup::new(1, vec![Natural::from(6u32)]).unwrap()
+        );
+        assert!(Matrix::<Integer>::ident(4).cokernel().is_trivial());
+
+        // Z^2 / im(a) for a map Z^3 -> Z^2, whereas the rows of a present Z^3 / <(2, 0, 0), (0, 3, 0)>
+        let a = Matrix::<Integer>::from_rows(vec![vec![2, 0, 0], vec![0, 3, 0]]);
+        assert_eq!(
+            a.cokernel(),
+            FinitelyGeneratedAbelianGroup::cyclic(Natural::from(6u32))
+        );
+        assert_eq!(
+            a.cokernel_abelian_group(),
+            FinitelyGeneratedAbelianGroup::new(1, vec![Natural::from(6u32)]).unwrap()
+        );
+
+        // a map onto Z^2 has trivial cokernel
+        let a = Matrix::<Integer>::from_rows(vec![vec![2, 3, 0], vec![1, 1, 5]]);
+        assert!(a.cokernel().is_trivial());
+    }
 }
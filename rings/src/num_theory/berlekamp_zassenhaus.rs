@@ -71,7 +71,7 @@ use std::collections::BTreeSet;
 use std::ops::Rem;
 
 fn compute_polynomial_factor_bound(poly: &Polynomial<Integer>) -> Natural {
-    poly.mignotte_factor_coefficient_bound().unwrap()
+    landau_mignotte_bound(poly, poly.degree().unwrap())
 }
 
 #[derive(Debug, Clone)]
@@ -659,7 +659,7 @@ fn find_factor_primitive_sqfree_by_berlekamp_zassenhaus_algorithm_naive(
 ) -> FindFactorResult<Polynomial<Integer>> {
     let f_deg = f.degree().unwrap();
     debug_assert_ne!(f_deg, 0);
-    let factor_coeff_bound = f.mignotte_factor_coefficient_bound().unwrap();
+    let factor_coeff_bound = compute_polynomial_factor_bound(&f);
     let minimum_modulus = Natural::TWO * factor_coeff_bound;
 
     if f_deg == 1 {
@@ -1,32 +1,81 @@
+use crate::structure::*;
 use algebraeon_structures::*;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum IsPowerTestResult {
-    Zero,
-    One,
-    Power(Natural, usize),
-    No,
+pub use algebraeon_structures::{IsPowerTestResult, is_power_test};
+
+/// Given pairs `(a_i, n_i)` with the `n_i` pairwise coprime, return `(x, n)` where `n` is the product of the `n_i` and `x` is the unique integer with `0 <= x < n` and `x = a_i mod n_i` for each `i`.
+///
+/// Returns `None` if the moduli are not pairwise coprime or any of them is zero, even when the congruences have a common solution.
+/// Unlike [`Integer::chinese_remainder`], which solves congruences with any moduli modulo their least common multiple, this rejects moduli which are not coprime.
+pub fn chinese_remainder_coprime(residues: &[(Integer, Natural)]) -> Option<(Integer, Natural)> {
+    if residues.iter().any(|(_, m)| *m == Natural::ZERO) {
+        return None;
+    }
+    // the moduli are pairwise coprime exactly when their least common multiple is their product
+    let (x, l) = Integer::chinese_remainder(residues)?;
+    (l == residues.iter().map(|(_, m)| m.clone()).product::<Natural>()).then_some((x, l))
+}
+
+/// The number of positive divisors of `n`.
+///
+/// Panics if `n` is zero.
+pub fn num_divisors(n: &Natural) -> Natural {
+    assert_ne!(*n, Natural::ZERO);
+    n.factor()
+        .into_powers()
+        .unwrap()
+        .into_iter()
+        .fold(Natural::ONE, |acc, (_p, k)| acc * (k + Natural::ONE))
+}
+
+/// The positive divisors of `n` in increasing order.
+///
+/// Panics if `n` is zero.
+pub fn divisors(n: &Natural) -> Vec<Natural> {
+    assert_ne!(*n, Natural::ZERO);
+    let factored = n.factor();
+    let mut ds = Natural::structure()
+        .factorizations()
+        .divisors(&factored)
+        .unwrap()
+        .collect::<Vec<_>>();
+    ds.sort();
+    ds
 }
 
-/// Returns n=a^p where a is as large as possible and p is prime
-pub fn is_power_test(n: &Natural) -> IsPowerTestResult {
-    if *n == Natural::ZERO {
-        IsPowerTestResult::Zero
-    } else if *n == Natural::ONE {
-        IsPowerTestResult::One
-    } else {
-        // The largest power n can possibly be is when n is a power of 2 and n = 2^{bitcount(n)+1}
-        // So we only need to check n isn't a kth power up to k = bitcount(n)+1
-        // We also only need to check for prime k
-        let max_k = n.bitcount() + 1;
-        for k in primes().take_while(|&k| k <= max_k) {
-            let a = n.nth_root_floor(&k.into());
-            if *n == a.pow(&k.into()) {
-                return IsPowerTestResult::Power(a, k);
+/// The sum of the `k`th powers of the positive divisors of `n`.
+///
+/// Panics if `n` is zero.
+pub fn sigma(k: u32, n: &Natural) -> Natural {
+    assert_ne!(*n, Natural::ZERO);
+    let k = Natural::from(k);
+    // sigma_k is multiplicative and sigma_k(p^e) = 1 + p^k + p^2k + ... + p^ek
+    n.factor()
+        .into_powers()
+        .unwrap()
+        .into_iter()
+        .fold(Natural::ONE, |acc, (p, e)| {
+            let p_k = p.pow(&k);
+            let mut term = Natural::ONE;
+            let mut p_k_pow = Natural::ONE;
+            let mut i = Natural::ZERO;
+            while i < e {
+                p_k_pow *= &p_k;
+                term += &p_k_pow;
+                i += Natural::ONE;
             }
-        }
-        IsPowerTestResult::No
-    }
+            acc * term
+        })
+}
+
+/// Whether `n` is equal to the sum of its proper divisors.
+pub fn is_perfect(n: &Natural) -> bool {
+    *n != Natural::ZERO && sigma(1, n) == Natural::TWO * n
+}
+
+/// Whether the sum of the proper divisors of `n` exceeds `n`.
+pub fn is_abundant(n: &Natural) -> bool {
+    *n != Natural::ZERO && sigma(1, n) > Natural::TWO * n
 }
 
 #[cfg(test)]
@@ -34,37 +83,74 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_is_power_test() {
+    fn test_chinese_remainder_coprime() {
         assert_eq!(
-            is_power_test(&Natural::from(0usize)),
-            IsPowerTestResult::Zero
+            chinese_remainder_coprime(&[]),
+            Some((Integer::ZERO, Natural::ONE))
         );
         assert_eq!(
-            is_power_test(&Natural::from(1usize)),
-            IsPowerTestResult::One
+            chinese_remainder_coprime(&[
+                (Integer::from(2), Natural::from(3u32)),
+                (Integer::from(3), Natural::from(5u32)),
+                (Integer::from(2), Natural::from(7u32)),
+            ]),
+            Some((Integer::from(23), Natural::from(105u32)))
         );
-        assert_eq!(is_power_test(&Natural::from(2usize)), IsPowerTestResult::No);
-        assert_eq!(is_power_test(&Natural::from(3usize)), IsPowerTestResult::No);
         assert_eq!(
-            is_power_test(&Natural::from(4usize)),
-            IsPowerTestResult::Power(Natural::from(2usize), 2)
+            chinese_remainder_coprime(&[
+                (Integer::from(-1), Natural::from(4u32)),
+                (Integer::from(10), Natural::from(9u32)),
+            ]),
+            Some((Integer::from(19), Natural::from(36u32)))
         );
-        assert_eq!(is_power_test(&Natural::from(5usize)), IsPowerTestResult::No);
-        assert_eq!(is_power_test(&Natural::from(6usize)), IsPowerTestResult::No);
-        assert_eq!(is_power_test(&Natural::from(7usize)), IsPowerTestResult::No);
         assert_eq!(
-            is_power_test(&Natural::from(8usize)),
-            IsPowerTestResult::Power(Natural::from(2usize), 3)
-        );
-        assert_eq!(
-            is_power_test(&Natural::from(9usize)),
-            IsPowerTestResult::Power(Natural::from(3usize), 2)
+            chinese_remainder_coprime(&[
+                (Integer::from(1), Natural::from(4u32)),
+                (Integer::from(1), Natural::from(6u32)),
+            ]),
+            None
         );
+    }
+
+    #[test]
+    fn test_divisor_functions() {
+        assert_eq!(num_divisors(&Natural::from(12u32)), Natural::from(6u32));
+        assert_eq!(num_divisors(&Natural::ONE), Natural::ONE);
+        assert_eq!(num_divisors(&Natural::from(13u32)), Natural::TWO);
         assert_eq!(
-            is_power_test(&Natural::from(10usize)),
-            IsPowerTestResult::No
+            divisors(&Natural::from(30u32)),
+            [1u32, 2, 3, 5, 6, 10, 15, 30]
+                .into_iter()
+                .map(Natural::from)
+                .collect::<Vec<_>>()
         );
+        assert_eq!(divisors(&Natural::ONE), vec![Natural::ONE]);
+        assert_eq!(sigma(1, &Natural::from(6u32)), Natural::from(12u32));
+        assert_eq!(sigma(0, &Natural::from(12u32)), Natural::from(6u32));
+        // 1 + 4 + 16 + 25 + 100 + 400
+        assert_eq!(sigma(2, &Natural::from(20u32)), Natural::from(546u32));
+        for n in 1u32..200 {
+            let n = Natural::from(n);
+            let ds = divisors(&n);
+            assert_eq!(Natural::from(ds.len()), num_divisors(&n));
+            assert_eq!(
+                ds.iter().fold(Natural::ZERO, |acc, d| acc + d),
+                sigma(1, &n)
+            );
+        }
+    }
 
-        println!("{:?}", is_power_test(&Natural::from(0usize)));
+    #[test]
+    fn test_perfect_and_abundant() {
+        let perfect = (1u32..10000)
+            .filter(|n| is_perfect(&Natural::from(*n)))
+            .collect::<Vec<_>>();
+        assert_eq!(perfect, vec![6, 28, 496, 8128]);
+        let abundant = (1u32..50)
+            .filter(|n| is_abundant(&Natural::from(*n)))
+            .collect::<Vec<_>>();
+        assert_eq!(abundant, vec![12, 18, 20, 24, 30, 36, 40, 42, 48]);
+        assert!(!is_perfect(&Natural::ZERO));
+        assert!(!is_abundant(&Natural::ZERO));
     }
 }
@@ -1,13 +1,20 @@
 use crate::{
-    num_theory::berlekamp_zassenhaus::factorize_by_berlekamp_zassenhaus_algorithm,
-    polynomial::*,
-    structure::{
-        Factored, FactoringMonoidSignature, GreatestCommonDivisorSignature, MetaFactoringMonoid,
+    num_theory::{
+        berlekamp_zassenhaus::factorize_by_berlekamp_zassenhaus_algorithm,
+        functions::{divisors, num_divisors},
     },
+    polynomial::*,
+    structure::*,
 };
 use algebraeon_structures::*;
 use std::rc::Rc;
 
+/// `is_irreducible_quick_check` only factors integers with at most this many bits, so that the factoring is cheap.
+const QUICK_CHECK_MAX_FACTOR_BITS: usize = 32;
+
+/// `is_irreducible_quick_check` only searches for rational roots when there are at most this many candidate numerators and denominators.
+const QUICK_CHECK_MAX_ROOT_CANDIDATES: usize = 1024;
+
 impl<B: BorrowedStructure<IntegerCanonicalStructure>> GreatestCommonDivisorSignature
     for PolynomialStructure<IntegerCanonicalStructure, B>
 {
@@ -19,26 +26,205 @@ impl<B: BorrowedStructure<IntegerCanonicalStructure>> GreatestCommonDivisorSigna
 impl<B: BorrowedStructure<IntegerCanonicalStructure>> FactoringMonoidSignature
     for PolynomialStructure<IntegerCanonicalStructure, B>
 {
+    fn is_irreducible(&self, p: &Self::Elem) -> bool {
+        match p.is_irreducible_quick_check() {
+            Some(result) => result,
+            None => self
+                .factorizations()
+                .is_irreducible_impl(&self.factor_unchecked(p)),
+        }
+    }
+
     fn factor_unchecked(&self, p: &Self::Elem) -> Factored<Polynomial<Integer>, Natural> {
         // self.factorize_by_kroneckers_method(p)
         factorize_by_berlekamp_zassenhaus_algorithm(p.clone())
     }
 }
 
+/// The non-negative gcd of the coefficients of `poly`, which is `0` only for the zero polynomial. The same as [`Polynomial::content`].
+pub fn gcd_of_polynomial_coefficients(poly: &Polynomial<Integer>) -> Natural {
+    poly.content()
+}
+
+impl<B: BorrowedStructure<IntegerCanonicalStructure>>
+    PolynomialStructure<IntegerCanonicalStructure, B>
+{
+    /// The non-negative gcd of the coefficients of `p`, which is `0` only for the zero polynomial.
+    pub fn content(&self, p: &Polynomial<Integer>) -> Natural {
+        self.content_and_primitive_part(p).0
+    }
+
+    /// Return `(c, q)` where `c` is the content of `p` and `q` is the primitive polynomial with `p = c q`.
+    ///
+    /// The zero polynomial is returned as `(0, 0)`.
+    pub fn content_and_primitive_part(
+        &self,
+        p: &Polynomial<Integer>,
+    ) -> (Natural, Polynomial<Integer>) {
+        match self.factor_primitive(p.clone()) {
+            Some((g, q)) => {
+                if g < Integer::ZERO {
+                    (Abs::abs(g), self.neg(&q))
+                } else {
+                    (Abs::abs(g), q)
+                }
+            }
+            None => (Natural::ZERO, self.zero()),
+        }
+    }
+
+    /// The rational roots of `p` listed with multiplicity in increasing order.
+    ///
+    /// Panics if `p` is zero.
+    pub fn rational_roots(&self, p: &Polynomial<Integer>) -> Vec<Rational> {
+        self.rational_roots_and_quotient(p).0
+    }
+
+    /// Return `(roots, q)` where `roots` are the rational roots of `p` listed with multiplicity in increasing order
+    /// and `q` has no rational roots with `p = q (b_1 x - a_1) ... (b_k x - a_k)` for the roots `a_i / b_i` in lowest terms with `b_i > 0`.
+    ///
+    /// By the rational root theorem `a_i` divides the constant coefficient and `b_i` divides the leading coefficient once the root `0` is removed,
+    /// so only finitely many candidates need to be evaluated.
+    ///
+    /// Panics if `p` is zero.
+    pub fn rational_roots_and_quotient(
+        &self,
+        p: &Polynomial<Integer>,
+    ) -> (Vec<Rational>, Polynomial<Integer>) {
+        assert!(!self.is_zero(p));
+        let mut roots = vec![];
+        let mut q = p.clone();
+        while self.coeff(&q, 0).as_ref() == &Integer::ZERO {
+            roots.push(Rational::ZERO);
+            q = self.try_divide(&q, &self.var()).unwrap();
+        }
+        if self.degree(&q).unwrap() > 0 {
+            let c0_divisors = divisors(&Abs::abs(self.coeff(&q, 0).as_ref()));
+            let cn_divisors = divisors(&Abs::abs(self.leading_coeff(&q).unwrap()));
+            for a in &c0_divisors {
+                for b in &cn_divisors {
+                    if Natural::gcd(a, b) != Natural::ONE {
+                        continue;
+                    }
+                    let b = Integer::from(b);
+                    for a in [Integer::from(a), -Integer::from(a)] {
+                        // a / b is a root exactly when the binary form vanishes at (a, b)
+                        while self.degree(&q).unwrap() > 0
+                            && self.evaluate_homogeneous(&q, &a, &b) == Integer::ZERO
+                        {
+                            roots.push(Rational::from_integers(a.clone(), b.clone()));
+                            let lin = Polynomial::from_coeffs(vec![-&a, b.clone()]);
+                            q = self.try_divide(&q, &lin).unwrap();
+                        }
+                    }
+                }
+            }
+        }
+        roots.sort();
+        (roots, q)
+    }
+}
+
 impl Polynomial<Integer> {
-    //https://en.wikipedia.org/wiki/Landau-Mignotte_bound
-    /// Return the Mignotte bound for the coefficients of any factor of $f(x)$: If
-    /// $$f(x) = \sum_{i=0}^n \lambda_i x^i$$
-    /// then the absolute value of the coefficients of any factor of \(f\) are bounded by
-    /// $${n \choose \lfloor n/2 \rfloor} \max \lbrace |\lambda_i| : i = 0, \dots, n \rbrace$$
-    pub fn mignotte_factor_coefficient_bound(&self) -> Option<Natural> {
-        let deg = self.degree()?;
-        let mut bound = Natural::ZERO;
-        for coeff in self.coeffs() {
-            bound += Abs::abs(coeff);
+    /// The non-negative gcd of the coefficients, which is `0` only for the zero polynomial.
+    pub fn content(&self) -> Natural {
+        Self::structure().content(self)
+    }
+
+    /// Return `(c, q)` where `c` is the content and `q` is the primitive polynomial with `self = c q`.
+    ///
+    /// The zero polynomial is returned as `(0, 0)`.
+    pub fn content_and_primitive_part(&self) -> (Natural, Polynomial<Integer>) {
+        Self::structure().content_and_primitive_part(self)
+    }
+
+    /// The rational roots listed with multiplicity in increasing order.
+    ///
+    /// Panics if `self` is zero.
+    pub fn rational_roots(&self) -> Vec<Rational> {
+        Self::structure().rational_roots(self)
+    }
+
+    /// Return the rational roots listed with multiplicity and the quotient by the corresponding primitive linear factors.
+    ///
+    /// Panics if `self` is zero.
+    pub fn rational_roots_and_quotient(&self) -> (Vec<Rational>, Polynomial<Integer>) {
+        Self::structure().rational_roots_and_quotient(self)
+    }
+
+    /// Try to decide whether `self` is irreducible in `Z[x]` using only cheap checks.
+    ///
+    /// Returns `Some(false)` for zero, units, non-primitive polynomials of positive degree and polynomials of degree at least 2 with a rational root.
+    /// Returns `Some(true)` for primes, primitive linear polynomials, primitive quadratics and cubics with no rational root,
+    /// and primitive polynomials which are Eisenstein at some prime after substituting `x`, `1/x`, `x + 1` or `x - 1`.
+    /// The `x + 1` substitution detects the cyclotomic polynomials of prime power order.
+    /// Otherwise returns `None`.
+    ///
+    /// Since this runs before factoring, the checks which need to factor coefficients are skipped when the coefficients are large,
+    /// and rational roots are only searched for when the leading and constant coefficients have few divisors.
+    pub fn is_irreducible_quick_check(&self) -> Option<bool> {
+        let Some(n) = self.degree() else {
+            return Some(false);
+        };
+        if n == 0 {
+            return Some(self.coeff(0).as_ref().is_irreducible());
+        }
+        let (content, f) = self.clone().factor_primitive().unwrap();
+        if !Integer::structure().is_unit(&content) {
+            return Some(false);
+        }
+        if n == 1 {
+            return Some(true);
+        }
+
+        let c0 = Abs::abs(f.coeff(0).as_ref());
+        if c0 == Natural::ZERO {
+            return Some(false);
         }
-        bound *= choose(Natural::from(deg), Natural::from(deg / 2));
-        Some(bound)
+        let cn = Abs::abs(f.leading_coeff().unwrap());
+        let small = |c: &Natural| c.bitcount() <= QUICK_CHECK_MAX_FACTOR_BITS;
+        if small(&c0)
+            && small(&cn)
+            && num_divisors(&c0) * num_divisors(&cn)
+                <= Natural::from(QUICK_CHECK_MAX_ROOT_CANDIDATES)
+        {
+            if !f.rational_roots_and_quotient().0.is_empty() {
+                return Some(false);
+            }
+            if n <= 3 {
+                // a reducible quadratic or cubic has a linear factor
+                return Some(true);
+            }
+        }
+
+        let x = Polynomial::<Integer>::var();
+        let one = Polynomial::<Integer>::one();
+        for g in [
+            f.clone(),
+            f.reversed(),
+            Polynomial::compose(&f, &Polynomial::add(&x, &one)),
+            Polynomial::compose(&f, &Polynomial::sub(&x, &one)),
+        ] {
+            // any Eisenstein prime divides all but the leading coefficient
+            let g_lower_content =
+                Integer::structure().gcd_list((0..n).map(|i| g.coeff(i)).collect());
+            if g_lower_content == Integer::ZERO || !small(&Abs::abs(&g_lower_content)) {
+                continue;
+            }
+            for (prime, _) in g_lower_content.factor().into_powers().unwrap() {
+                if g.is_eisenstein(&Abs::abs(&prime)) {
+                    return Some(true);
+                }
+            }
+        }
+        None
+    }
+
+    /// A bound on the absolute values of the coefficients of any factor of $f(x)$ of degree $n = \deg f$, namely
+    /// $${n \choose \lfloor n/2 \rfloor} \lVert f \rVert_2$$
+    /// See [`landau_mignotte_bound`].
+    pub fn mignotte_factor_coefficient_bound(&self) -> Option<Natural> {
+        Some(landau_mignotte_bound(self, self.degree()?))
     }
 
     //https://en.wikipedia.org/wiki/Geometrical_properties_of_polynomial_roots#Lagrange's_and_Cauchy's_bounds
@@ -80,6 +266,7 @@ mod tests {
         num_theory::berlekamp_zassenhaus::factorize_by_berlekamp_zassenhaus_algorithm_naive,
         structure::{IntoErgonomic, UniqueFactorizationMonoidSignature},
     };
+    use std::str::FromStr;
 
     #[test]
     fn test_zassenhaus_against_kroneckers() {
@@ -124,4 +311,176 @@ mod tests {
                 .equal(&fs, &factorize_by_berlekamp_zassenhaus_algorithm(f.clone()))
         );
     }
+
+    #[test]
+    fn test_is_irreducible_quick_check() {
+        let x = &Polynomial::<Integer>::var().into_ergonomic();
+
+        // Eisenstein at 2
+        let f = (x.pow(5) + 2 * x + 2).into_verbose();
+        assert_eq!(f.is_irreducible_quick_check(), Some(true));
+        // reverse is Eisenstein at 2
+        let f = (2 * x.pow(5) + 2 * x.pow(4) + 1).into_verbose();
+        assert_eq!(f.is_irreducible_quick_check(), Some(true));
+        // x^4 + x^3 + x^2 + x + 1 is Eisenstein at 5 after x -> x + 1
+        let f = (x.pow(4) + x.pow(3) + x.pow(2) + x + 1).into_verbose();
+        assert_eq!(f.is_irreducible_quick_check(), Some(true));
+        // rational roots
+        assert_eq!(
+            (x.pow(2) - 4).into_verbose().is_irreducible_quick_check(),
+            Some(false)
+        );
+        assert_eq!(
+            (3 * x.pow(4) - 2 * x.pow(3) + x - 2)
+                .into_verbose()
+                .is_irreducible_quick_check(),
+            Some(false)
+        );
+        assert_eq!(
+            (x.pow(2) + 1).into_verbose().is_irreducible_quick_check(),
+            Some(true)
+        );
+        // content
+        assert_eq!(
+            (2 * x + 2).into_verbose().is_irreducible_quick_check(),
+            Some(false)
+        );
+        assert_eq!(
+            Polynomial::<Integer>::from_int(7).is_irreducible_quick_check(),
+            Some(true)
+        );
+        assert_eq!(
+            Polynomial::<Integer>::from_int(-1).is_irreducible_quick_check(),
+            Some(false)
+        );
+        assert_eq!(
+            Polynomial::<Integer>::zero().is_irreducible_quick_check(),
+            Some(false)
+        );
+        // no rational root but a product of two quadratics so no quick answer
+        assert_eq!(
+            ((x.pow(2) + 1) * (x.pow(2) + 2))
+                .into_verbose()
+                .is_irreducible_quick_check(),
+            None
+        );
+
+        // the constant coefficient is too large to factor cheaply so no quick answer
+        let big = Integer::from_str("340282366920938463463374607431768211457").unwrap();
+        assert_eq!(
+            Polynomial::<Integer>::from_coeffs(vec![
+                &big * &big,
+                Integer::ONE,
+                Integer::ZERO,
+                Integer::ZERO,
+                Integer::ONE,
+            ])
+            .is_irreducible_quick_check(),
+            None
+        );
+        // a zero constant coefficient is detected without factoring
+        assert_eq!(
+            Polynomial::<Integer>::from_coeffs(vec![
+                Integer::ZERO,
+                big,
+                Integer::ZERO,
+                Integer::ZERO,
+                Integer::ONE,
+            ])
+            .is_irreducible_quick_check(),
+            Some(false)
+        );
+
+        // agrees with the full factorization whenever it gives an answer
+        let polys = vec![
+            (x.pow(5) + 2 * x + 2).into_verbose(),
+            (x.pow(4) + 1).into_verbose(),
+            (x.pow(4) - 10 * x.pow(2) + 1).into_verbose(),
+            (x.pow(6) + x.pow(3) + 1).into_verbose(),
+            (x.pow(3) - 2).into_verbose(),
+            (x.pow(3) - 8).into_verbose(),
+            (x.pow(4) + 4).into_verbose(),
+            (6 * x.pow(4) + 3 * x + 3).into_verbose(),
+            ((x.pow(2) - 2) * (x.pow(3) + 3)).into_verbose(),
+            (x.pow(7) - x - 1).into_verbose(),
+        ];
+        for f in polys {
+            let full = Polynomial::<Integer>::structure()
+                .factorizations()
+                .is_irreducible_impl(&factorize_by_berlekamp_zassenhaus_algorithm(f.clone()));
+            if let Some(quick) = f.is_irreducible_quick_check() {
+                assert_eq!(quick, full);
+            }
+            assert_eq!(f.is_irreducible(), full);
+        }
+    }
+
+    #[test]
+    fn test_content_and_primitive_part() {
+        let x = &Polynomial::<Integer>::var().into_ergonomic();
+        for (f, c) in [
+            ((6 * x.pow(2) - 4 * x + 10).into_verbose(), 2u32),
+            ((-6 * x.pow(2) - 4 * x - 10).into_verbose(), 2u32),
+            ((3 * x.pow(3) + 2).into_verbose(), 1u32),
+            ((-x + 1).into_verbose(), 1u32),
+            (Polynomial::constant(Integer::from(-12)), 12u32),
+        ] {
+            let (content, prim) = f.content_and_primitive_part();
+            assert_eq!(content, Natural::from(c));
+            assert_eq!(f.content(), Natural::from(c));
+            assert_eq!(gcd_of_polynomial_coefficients(&f), Natural::from(c));
+            assert_eq!(prim.content(), Natural::ONE);
+            assert_eq!(
+                Polynomial::mul(&Polynomial::constant(Integer::from(content)), &prim),
+                f
+            );
+            assert_eq!(
+                Polynomial::<Integer>::structure().content(&f),
+                Natural::from(c)
+            );
+        }
+
+        assert_eq!(
+            Polynomial::<Integer>::zero().content_and_primitive_part(),
+            (Natural::ZERO, Polynomial::zero())
+        );
+    }
+
+    #[test]
+    fn test_rational_roots() {
+        let x = &Polynomial::<Integer>::var().into_ergonomic();
+        let q = |a: i64, b: i64| Rational::from_integers(a, b);
+
+        // 6x^3 - 5x^2 - x = x (x - 1) (6x + 1)
+        let f = (6 * x.pow(3) - 5 * x.pow(2) - x).into_verbose();
+        let (roots, quotient) = f.rational_roots_and_quotient();
+        assert_eq!(roots, vec![q(-1, 6), q(0, 1), q(1, 1)]);
+        assert_eq!(quotient, Polynomial::one());
+        assert_eq!(f.rational_roots(), roots);
+
+        // repeated roots and an irreducible quadratic factor which is left in the quotient
+        let f = (3 * (2 * x - 3).pow(2) * x.pow(2) * (x + 1) * (x.pow(2) + x + 1)).into_verbose();
+        let (roots, quotient) = f.rational_roots_and_quotient();
+        assert_eq!(roots, vec![q(-1, 1), q(0, 1), q(0, 1), q(3, 2), q(3, 2)]);
+        assert_eq!(quotient, (3 * (x.pow(2) + x + 1)).into_verbose());
+
+        // a constant times the linear factors recovers the polynomial
+        let f = (-4 * x.pow(3) + 4 * x.pow(2) + 9 * x - 9).into_verbose();
+        let (roots, quotient) = f.rational_roots_and_quotient();
+        assert_eq!(roots, vec![q(-3, 2), q(1, 1), q(3, 2)]);
+        let mut product = quotient;
+        for r in &roots {
+            let (a, b) = r.numerator_and_denominator();
+            let lin = Polynomial::from_coeffs(vec![-a, Integer::from(b)]);
+            product = Polynomial::mul(&product, &lin);
+        }
+        assert_eq!(product, f);
+
+        assert!((x.pow(2) - 2).into_verbose().rational_roots().is_empty());
+        assert!(
+            Polynomial::<Integer>::constant(Integer::from(5))
+                .rational_roots()
+                .is_empty()
+        );
+    }
 }
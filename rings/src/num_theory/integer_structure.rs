@@ -92,6 +92,10 @@ impl TryReciprocalSignature for IntegerCanonicalStructure {
     fn try_reciprocal(&self, a: &Self::Elem) -> Option<Self::Elem> {
         self.try_divide(&self.one(), a)
     }
+
+    fn is_unit(&self, a: &Self::Elem) -> bool {
+        Abs::abs(a) == Natural::ONE
+    }
 }
 
 impl CancellativeMultiplicationSignature for IntegerCanonicalStructure {
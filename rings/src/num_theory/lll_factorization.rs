@@ -0,0 +1,231 @@
+/*!
+ * # Factoring integer polynomials by lattice reduction
+ * The Lenstra-Lenstra-Lovász algorithm factors integer polynomials in polynomial time by replacing the exponential search over subsets of modular factors in the Berlekamp Zassenhaus algorithm with lattice basis reduction.
+ *
+ * Let $f(x) \in \mathbb{Z}\[x\]$ be a non-zero primitive squarefree polynomial of degree $n \ge 2$ with leading coefficient $b$.
+ * 1. A prime number $p$ is found such that the reduction of $f$ modulo $p$ has degree $n$ and is squarefree, and $f$ is factored modulo $p$ into monic modular factors.
+ * 2. The modular factorization is Hensel lifted to a factorization modulo $p^l$ where
+ *    $$p^l > 2^{n^2/2} B^{2n} \quad \text{with} \quad B = (n+1)^{1/2} L b$$
+ *    where $L = {n \choose \lfloor n/2 \rfloor} \lVert f \rVert_2$ is the Landau-Mignotte bound on the coefficients of the factors of $f$.
+ * 3. Take a modular factor $u$ of largest degree $d$. For $j = d+1, \dots, \deg(f)$ the lattice in $\mathbb{Z}^j$ spanned by the coefficient vectors of
+ *    $$u(x), x u(x), \dots, x^{j-d-1} u(x), p^l, p^l x, \dots, p^l x^{d-1}$$
+ *    is LLL reduced. If $g$ is the irreducible factor of $f$ which $u$ divides modulo $p$ then the first vector of the reduced basis is $\pm g$ once $j = \deg(g) + 1$,
+ *    and the bound on $p^l$ ensures no short vector yields a polynomial dividing $f$ for smaller $j$.
+ * 4. Remove $g$ from $f$ and the modular factors dividing $g$ modulo $p$ from the list of modular factors, and repeat until one modular factor remains.
+ *    If no $j$ yields a factor then $f$ itself is irreducible.
+ */
+
+use crate::matrix::{Matrix, StandardInnerProduct};
+use crate::polynomial::*;
+use crate::structure::*;
+use algebraeon_structures::*;
+
+/// A bound on the modulus `p^l` above which the factors recovered by lattice reduction are exactly the irreducible factors.
+fn compute_lll_modulus_bound(f: &Polynomial<Integer>) -> Natural {
+    let n = f.degree().unwrap();
+    // every coefficient of a factor g of f, scaled by lc(f) / lc(g), is at most the Landau-Mignotte bound L
+    // so B = (n+1)^{1/2} L b bounds the 2-norm of b g
+    let l = landau_mignotte_bound(f, n);
+    let b = Abs::abs(f.leading_coeff().unwrap());
+    let b_sqrd = Natural::from(n + 1) * &l * &l * &b * &b;
+    // 2^{n^2/2} B^{2n}
+    Natural::TWO.nat_pow(&Natural::from((n * n).div_ceil(2))) * b_sqrd.nat_pow(&Natural::from(n))
+}
+
+fn factorize_primitive_squarefree_by_lll_algorithm(
+    f: &Polynomial<Integer>,
+) -> Factored<Polynomial<Integer>, Natural> {
+    let n = f.degree().unwrap();
+    debug_assert_ne!(n, 0);
+    debug_assert!(Integer::structure().polynomials().is_primitive(f.clone()));
+    let factorizations = Polynomial::<Integer>::structure().into_factorizations();
+
+    if n == 1 {
+        return factorizations.new_irreducible_unchecked(f.clone());
+    }
+
+    let minimum_modulus = compute_lll_modulus_bound(f);
+    for p in primes() {
+        let mod_p = Integer::structure().into_quotient_field_unchecked(Integer::from(p));
+        let poly_mod_p = mod_p.polynomials();
+        if poly_mod_p.degree(f).unwrap() != n {
+            continue;
+        }
+        let Some(mut hensel_factorization) = poly_mod_p
+            .factorizations()
+            .into_hensel_factorization(poly_mod_p.factor(f).unwrap_nonzero(), f.clone())
+        else {
+            // f is not squarefree modulo p
+            continue;
+        };
+        while hensel_factorization.modulus() < minimum_modulus {
+            hensel_factorization.quadratic_lift();
+        }
+        let modulus = hensel_factorization.modulus();
+        let mut modular_factors = hensel_factorization
+            .factors()
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut factors = vec![];
+        let mut f = f.clone();
+        'find_factor: while modular_factors.len() > 1 {
+            let f_deg = f.degree().unwrap();
+            let u = modular_factors
+                .iter()
+                .max_by_key(|u| u.degree().unwrap())
+                .unwrap();
+            let d = u.degree().unwrap();
+            for j in (d + 1)..=f_deg {
+                let lattice = Matrix::from_rows(
+                    (0..d)
+                        .map(|i| {
+                            (0..j)
+                                .map(|k| {
+                                    if i == k {
+                                        modulus.clone()
+                                    } else {
+                                        Integer::ZERO
+                                    }
+                                })
+                                .collect::<Vec<_>>()
+                        })
+                        .chain((0..(j - d)).map(|i| {
+                            (0..j)
+                                .map(|k| {
+                                    if i <= k && k <= i + d {
+                                        u.coeff(k - i).into_owned()
+                                    } else {
+                                        Integer::ZERO
+                                    }
+                                })
+                                .collect::<Vec<_>>()
+                        }))
+                        .collect(),
+                );
+                let (_, reduced) = lattice.lll_integral_row_reduction_algorithm(
+                    &StandardInnerProduct::new(Integer::structure()),
+                    &Rational::from_integers(Integer::from(3), Integer::from(4)),
+                );
+                let g = Polynomial::from_coeffs(reduced.get_row(0))
+                    .primitive_part()
+                    .unwrap();
+                if g.degree().unwrap() == 0 {
+                    continue;
+                }
+                if let Some(h) = Polynomial::try_divide(&f, &g) {
+                    // the modular factors of g are exactly those which divide g modulo p
+                    modular_factors.retain(|v| poly_mod_p.try_divide(&g, v).is_none());
+                    f = h;
+                    factors.push(g);
+                    continue 'find_factor;
+                }
+            }
+            // no proper factor of f was found so f is irreducible
+            break;
+        }
+        factors.push(f);
+
+        return factorizations.product(
+            &factors
+                .into_iter()
+                .map(|g| factorizations.new_irreducible_unchecked(g))
+                .collect::<Vec<_>>(),
+        );
+    }
+    unreachable!("Because there are infinitely many primes")
+}
+
+/// Factor an integer polynomial using the Lenstra-Lenstra-Lovász lattice reduction algorithm to recombine modular factors.
+pub fn factorize_by_lll_algorithm(
+    poly: Polynomial<Integer>,
+) -> Factored<Polynomial<Integer>, Natural> {
+    if poly.is_zero() {
+        Factored::Zero
+    } else {
+        Polynomial::<Integer>::structure().factorize_by_primitive_factorize(
+            poly,
+            Integer::factor,
+            &|poly| {
+                Polynomial::<Integer>::structure()
+                    .factorize_using_primitive_sqfree_factorize_by_yuns_algorithm(poly, &|poly| {
+                        factorize_primitive_squarefree_by_lll_algorithm(&poly)
+                    })
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::num_theory::berlekamp_zassenhaus::factorize_by_berlekamp_zassenhaus_algorithm;
+
+    #[test]
+    fn test_lll_factorization() {
+        let x = &Polynomial::<Integer>::var().into_ergonomic();
+        let factorizations = Polynomial::<Integer>::structure().into_factorizations();
+
+        // irreducible but reducible modulo every prime
+        let f = (x.pow(4) + 1).into_verbose();
+        let fs = factorize_by_lll_algorithm(f.clone());
+        assert!(factorizations.equal(&fs, &factorizations.new_irreducible_unchecked(f.clone())));
+
+        let f = (x.pow(6) - 1).into_verbose();
+        let fs = factorize_by_lll_algorithm(f.clone());
+        assert!(
+            factorizations.equal(
+                &fs,
+                &factorizations.product(
+                    &[
+                        (x - 1).into_verbose(),
+                        (x + 1).into_verbose(),
+                        (x.pow(2) + x + 1).into_verbose(),
+                        (x.pow(2) - x + 1).into_verbose(),
+                    ]
+                    .into_iter()
+                    .map(|g| factorizations.new_irreducible_unchecked(g))
+                    .collect::<Vec<_>>()
+                )
+            )
+        );
+
+        for f in [
+            (49 * x.pow(2) - 10000).into_verbose(),
+            (-3 * x.pow(2) + 12).into_verbose(),
+            ((x.pow(2) - 2) * (x.pow(2) - 3)).into_verbose(),
+        ] {
+            assert!(factorizations.equal(
+                &factorize_by_lll_algorithm(f.clone()),
+                &factorize_by_berlekamp_zassenhaus_algorithm(f)
+            ));
+        }
+
+        // smaller versions of the instances below which still run quickly in debug
+        for f in [
+            ((x.pow(2) - 2) * (x.pow(2) + 3) * (x - 5)).into_verbose(),
+            ((10 * x.pow(2) - 9 * x + 123) * (7 * x.pow(2) + 65 * x - 3)).into_verbose(),
+            ((x.pow(4) - 10 * x.pow(2) + 1) * (x + 1).pow(2)).into_verbose(),
+        ] {
+            assert!(factorizations.equal(
+                &factorize_by_lll_algorithm(f.clone()),
+                &factorize_by_berlekamp_zassenhaus_algorithm(f)
+            ));
+        }
+
+        // These are a bit slow in debug due to the debug asserts in lattice reduction
+        #[cfg(not(debug_assertions))]
+        for f in [
+            ((x.pow(2) - 2) * (x.pow(2) - 3) * (x.pow(2) - 5)).into_verbose(),
+            ((1000 * x.pow(3) - 999 * x + 12345) * (777 * x.pow(2) + 65536 * x - 3)).into_verbose(),
+            ((x.pow(4) - 10 * x.pow(2) + 1) * (x + 1).pow(2) * (x.pow(5) - x - 1)).into_verbose(),
+            (x.pow(8) + 98 * x.pow(4) + 1).into_verbose(),
+        ] {
+            assert!(factorizations.equal(
+                &factorize_by_lll_algorithm(f.clone()),
+                &factorize_by_berlekamp_zassenhaus_algorithm(f)
+            ));
+        }
+    }
+}
@@ -3,6 +3,7 @@ pub mod functions;
 pub mod integer_ideal;
 pub mod integer_polynomial;
 pub mod integer_structure;
+pub mod lll_factorization;
 pub mod modulo;
 pub mod natural_factorization;
 pub mod natural_structure;
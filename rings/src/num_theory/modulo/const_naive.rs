@@ -1,6 +1,7 @@
 use crate::structure::*;
-use algebraeon_macros::repeat_small_primes;
+use algebraeon_macros::{CanonicalStructure, repeat_small_primes};
 use algebraeon_structures::*;
+use rand::{RngExt, SeedableRng, rngs::StdRng};
 use std::{borrow::Cow, cmp::Ordering, fmt::Display, hash::Hash};
 
 fn xgcd(mut x: usize, mut y: usize) -> (usize, isize, isize) {
@@ -27,7 +28,8 @@ fn modulo(a: isize, n: usize) -> usize {
     a as usize
 }
 
-#[derive(Clone)]
+#[derive(Clone, CanonicalStructure)]
+#[canonical_structure(eq)]
 pub struct Modulo<const N: usize> {
     x: usize,
 }
@@ -118,33 +120,6 @@ impl<const N: usize> Display for Modulo<N> {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct ModuloCanonicalStructure<const N: usize> {}
-
-impl<const N: usize> Signature for ModuloCanonicalStructure<N> {}
-
-impl<const N: usize> SetSignature for ModuloCanonicalStructure<N> {
-    type Elem = Modulo<N>;
-
-    fn validate_element(&self, _x: &Self::Elem) -> Result<(), String> {
-        Ok(())
-    }
-}
-
-impl<const N: usize> MetaType for Modulo<N> {
-    type Signature = ModuloCanonicalStructure<N>;
-
-    fn structure() -> Self::Signature {
-        ModuloCanonicalStructure {}
-    }
-}
-
-impl<const N: usize> EqSignature for ModuloCanonicalStructure<N> {
-    fn equal(&self, a: &Self::Elem, b: &Self::Elem) -> bool {
-        a == b
-    }
-}
-
 impl<const N: usize> PartialOrdSignature for ModuloCanonicalStructure<N> {
     fn partial_cmp(&self, a: &Self::Elem, b: &Self::Elem) -> Option<Ordering> {
         a.x.partial_cmp(&b.x)
@@ -304,6 +279,11 @@ impl<const N: usize> FiniteSetSignature for ModuloCanonicalStructure<N> {
     fn size(&self) -> Natural {
         Natural::from(N)
     }
+
+    fn generate_random_elements(&self, seed: u64) -> impl Iterator<Item = Self::Elem> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..).map(move |_| Modulo::new(rng.random_range(0..N)))
+    }
 }
 
 impl<const N: usize> EnumeratedOrdFiniteSetSignature for ModuloCanonicalStructure<N> {
@@ -431,6 +411,25 @@ impl<B: BorrowedStructure<IntegerCanonicalStructure>, const IS_FIELD: bool> Fini
     fn size(&self) -> Natural {
         Abs::abs(self.modulus().as_ref())
     }
+
+    fn generate_random_elements(&self, seed: u64) -> impl Iterator<Item = Self::Elem> {
+        let modulus = self.size();
+        let bits = modulus.bitcount();
+        let mut rng = StdRng::seed_from_u64(seed);
+        // sample numbers with as many bits as the modulus until one is below it, which happens with probability more than 1/2 each time
+        (0..).map(move |_| {
+            loop {
+                let mut x = Natural::ZERO;
+                for i in (0..bits).step_by(64) {
+                    let word = rng.random::<u64>() >> (64 - std::cmp::min(64, bits - i));
+                    x += Natural::from(word) << i;
+                }
+                if x < modulus {
+                    return Integer::from(x);
+                }
+            }
+        })
+    }
 }
 
 #[cfg(test)]
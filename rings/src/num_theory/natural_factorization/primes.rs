@@ -3,10 +3,7 @@ use crate::{
         functions::{IsPowerTestResult, is_power_test},
         natural_factorization::IsPrimitiveRootResult,
     },
-    structure::{
-        MetaMultiplicativeMonoidSignature, MultiplicationSignature, MultiplicativeMonoidSignature,
-        RingToQuotientRingSignature, UniqueFactorizationMonoidSignature,
-    },
+    structure::{MetaMultiplicativeMonoidSignature, UniqueFactorizationMonoidSignature},
 };
 use algebraeon_structures::*;
 use std::ops::Rem;
@@ -57,9 +54,6 @@ pub fn miller_rabin_primality_test(
     } else if n % Natural::TWO == Natural::ZERO {
         Ok(PrimalityTestResult::Composite)
     } else {
-        let mod_n = Integer::structure()
-            .into_euclidean_quotient_ring(n.into())
-            .unwrap();
         debug_assert!(n % Natural::TWO == Natural::ONE); // n is odd
         for a in &a_list {
             debug_assert!(Natural::TWO <= *a);
@@ -81,16 +75,16 @@ pub fn miller_rabin_primality_test(
         debug_assert_eq!(Natural::TWO.nat_pow(&Natural::from(s)) * &d, n_minus_one);
         debug_assert!(s >= 1); // n-1 is even
         for a in &a_list {
-            let mut x = mod_n.nat_pow(&a.into(), &d);
+            let mut x = a.pow_mod(&d, n);
             let mut y;
             for _ in 0..s {
-                y = mod_n.mul(&x, &x);
-                if y == Integer::ONE && x != Integer::ONE && x != Integer::from(n) - Integer::ONE {
+                y = (&x * &x) % n;
+                if y == Natural::ONE && x != Natural::ONE && x != n_minus_one {
                     return Ok(PrimalityTestResult::Composite);
                 }
                 x = y;
             }
-            if x != Integer::ONE {
+            if x != Natural::ONE {
                 return Ok(PrimalityTestResult::Composite);
             }
         }
@@ -482,11 +476,103 @@ pub fn is_prime_nat(n: &Natural) -> bool {
     }
 }
 
+/// Whether `n` is prime in the ring of integers, that is whether `|n|` is a prime natural number.
+///
+/// Uses `primality_test`, which is deterministic for `|n| < 3.3 * 10^24` and so for all values that fit in a `u64`.
+pub fn is_prime(n: &Integer) -> bool {
+    is_prime_nat(&Abs::abs(n))
+}
+
+/// The smallest prime strictly greater than `n`.
+pub fn next_prime_after(n: &Natural) -> Natural {
+    let mut p = n + Natural::ONE;
+    while !is_prime_nat(&p) {
+        p += Natural::ONE;
+    }
+    p
+}
+
+/// The smallest positive integer which generates the multiplicative group modulo the prime `p`.
+///
+/// For `p = 2` the group is trivial and this is `1`.
+pub fn primitive_root(p: &Natural) -> Natural {
+    let factorizations = Natural::structure_ref().factorizations();
+    let p_factored = factorizations.new_irreducible_unchecked(p.clone());
+    let mut g = Natural::ONE;
+    loop {
+        if factorizations.is_primitive_root(&g, &p_factored) == IsPrimitiveRootResult::Yes {
+            return g;
+        }
+        g += Natural::ONE;
+    }
+}
+
+// primes_up_to sieves up to this bound and tests the remaining candidates one by one
+const PRIME_SIEVE_LIMIT: usize = 1 << 24;
+
+/// All primes `p <= n` in increasing order.
+///
+/// Uses the sieve of Eratosthenes up to `min(n, 2^24)` and `next_prime_after` beyond that.
+pub fn primes_up_to(n: &Natural) -> Vec<Natural> {
+    let sieve_limit = match usize::try_from(n) {
+        Ok(n) => n.min(PRIME_SIEVE_LIMIT),
+        Err(_) => PRIME_SIEVE_LIMIT,
+    };
+    let mut is_composite = vec![false; sieve_limit + 1];
+    let mut primes = vec![];
+    for i in 2..=sieve_limit {
+        if !is_composite[i] {
+            primes.push(Natural::from(i));
+            let mut j = i * i;
+            while j <= sieve_limit {
+                is_composite[j] = true;
+                j += i;
+            }
+        }
+    }
+    let mut p = Natural::from(sieve_limit);
+    loop {
+        p = next_prime_after(&p);
+        if &p > n {
+            return primes;
+        }
+        primes.push(p.clone());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
     use super::*;
+    use crate::structure::{FiniteFieldSignature, RingToQuotientFieldSignature};
+
+    #[test]
+    fn test_primitive_root() {
+        // the multiplicative group modulo 2 is trivial
+        assert_eq!(primitive_root(&Natural::from(2u32)), Natural::from(1u32));
+        assert_eq!(primitive_root(&Natural::from(7u32)), Natural::from(3u32));
+        assert_eq!(primitive_root(&Natural::from(23u32)), Natural::from(5u32));
+        assert_eq!(primitive_root(&Natural::from(41u32)), Natural::from(6u32));
+
+        for p in [3u32, 5, 13, 101, 65537] {
+            let p = Natural::from(p);
+            let mod_p = Integer::structure().into_quotient_field_unchecked(Integer::from(&p));
+            let g = Integer::from(primitive_root(&p));
+            assert_eq!(mod_p.multiplicative_order(&g), &p - Natural::ONE);
+        }
+
+        // 2 has order 3 modulo 7 so is not a primitive root
+        let mod_7 = Integer::structure().into_quotient_field_unchecked(Integer::from(7));
+        assert_eq!(
+            mod_7.multiplicative_order(&Integer::from(2)),
+            Natural::from(3u32)
+        );
+        assert!(!mod_7.is_primitive_element(&Integer::from(2)));
+        assert!(!mod_7.is_primitive_element(&Integer::from(6)));
+        assert!(!mod_7.is_primitive_element(&Integer::from(0)));
+        assert!(mod_7.is_primitive_element(&Integer::from(5)));
+    }
 
     #[test]
     fn test_miller_rabin_primality_test() {
@@ -589,4 +675,50 @@ mod tests {
         //     PrimalityTestResult::Prime
         // );
     }
+
+    #[test]
+    fn test_primes_up_to() {
+        let primes = primes_up_to(&Natural::from(1000u32));
+        assert_eq!(primes.len(), 168);
+        assert_eq!(primes[0], Natural::TWO);
+        assert_eq!(primes[167], Natural::from(997u32));
+        for n in 0u32..=1000 {
+            let n = Natural::from(n);
+            assert_eq!(
+                primes.contains(&n),
+                try_divideisors_primality_test(&n) == PrimalityTestResult::Prime
+            );
+            assert_eq!(primes.contains(&n), is_prime(&Integer::from(&n)));
+            assert_eq!(primes.contains(&n), is_prime(&-Integer::from(&n)));
+        }
+        for w in primes.windows(2) {
+            assert_eq!(next_prime_after(&w[0]), w[1]);
+        }
+        assert!(primes_up_to(&Natural::ONE).is_empty());
+        assert_eq!(primes_up_to(&Natural::TWO), vec![Natural::TWO]);
+    }
+
+    #[test]
+    fn test_large_primes() {
+        // the largest prime below 2^64 and the Mersenne primes 2^61 - 1 and 2^89 - 1
+        let p64 = Natural::from(18_446_744_073_709_551_557u64);
+        let m61 = Natural::from(2_305_843_009_213_693_951u64);
+        let m89 = Natural::from_str("618970019642690137449562111").unwrap();
+        for p in [&p64, &m61, &m89] {
+            assert!(is_prime(&Integer::from(p)));
+        }
+        assert!(!is_prime(&Integer::from(&p64 - Natural::TWO)));
+        assert!(!is_prime(&Integer::from(&m61 * &m61)));
+        // 3215031751 = 151 * 751 * 28351 is a strong pseudoprime to the bases 2, 3, 5 and 7
+        assert!(!is_prime(&Integer::from(3_215_031_751u64)));
+        // 2^64 - 83 is the previous prime
+        assert_eq!(
+            next_prime_after(&Natural::from(18_446_744_073_709_551_533u64)),
+            p64
+        );
+        assert_eq!(
+            next_prime_after(&Natural::from(1_000_000_000u64)),
+            Natural::from(1_000_000_007u64)
+        );
+    }
 }
@@ -0,0 +1,562 @@
+use super::*;
+use crate::{
+    matrix::{Matrix, MatrixStructure},
+    structure::*,
+};
+use algebraeon_structures::*;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+/// The number of linear sections used to decide whether a positive dimensional variety is irreducible.
+const IRREDUCIBILITY_SECTIONS: usize = 5;
+/// The number of linear sections tried before giving up on finding `IRREDUCIBILITY_SECTIONS` zero dimensional ones.
+const IRREDUCIBILITY_MAX_ATTEMPTS: usize = 4 * IRREDUCIBILITY_SECTIONS;
+
+/// The zero set of a list of polynomials in the affine space over a field with coordinates `vars`.
+///
+/// The dimension is that of the zero set over an algebraic closure of the field, which is the Krull dimension of the quotient of the polynomial ring by the ideal.
+/// Points and irreducibility are with respect to the field itself.
+#[derive(Debug, Clone)]
+pub struct AffineVariety<FS: FieldSignature> {
+    field: FS,
+    vars: Vec<Variable>,
+    ideal: Vec<MultiPolynomial<FS::Elem>>,
+}
+
+impl<FS: FieldSignature> AffineVariety<FS> {
+    /// The zero set of `ideal` in the affine space with coordinates `vars`.
+    ///
+    /// Panics if some polynomial involves a variable not in `vars`.
+    pub fn new(field: FS, vars: Vec<Variable>, ideal: Vec<MultiPolynomial<FS::Elem>>) -> Self {
+        let var_set = vars.iter().collect::<HashSet<_>>();
+        assert_eq!(var_set.len(), vars.len(), "repeated variable");
+        for p in &ideal {
+            assert!(
+                p.free_vars().iter().all(|v| var_set.contains(v)),
+                "polynomial involves variables outside the given list"
+            );
+        }
+        Self { field, vars, ideal }
+    }
+
+    pub fn field(&self) -> &FS {
+        &self.field
+    }
+
+    /// The coordinates of the ambient affine space.
+    pub fn vars(&self) -> &Vec<Variable> {
+        &self.vars
+    }
+
+    /// The polynomials whose common zeros form the variety.
+    pub fn ideal(&self) -> &Vec<MultiPolynomial<FS::Elem>> {
+        &self.ideal
+    }
+
+    fn arithmetic(&self, order: MonomialOrder) -> ExponentTermsArithmetic<'_, FS> {
+        ExponentTermsArithmetic {
+            field: &self.field,
+            order,
+        }
+    }
+
+    fn groebner_basis_terms(&self, order: MonomialOrder) -> Vec<ExponentTerms<FS::Elem>> {
+        let arithmetic = self.arithmetic(order);
+        arithmetic.groebner_basis(
+            self.ideal
+                .iter()
+                .map(|p| arithmetic.from_multipoly(&self.vars, p))
+                .collect(),
+        )
+    }
+
+    /// The reduced Gröbner basis of the ideal with respect to the graded reverse lexicographic order.
+    pub fn groebner_basis(&self) -> Vec<MultiPolynomial<FS::Elem>> {
+        self.field.multivariable_polynomials().groebner_basis(
+            &self.vars,
+            MonomialOrder::GradedReverseLex,
+            &self.ideal,
+        )
+    }
+
+    /// Whether the variety has no points over an algebraic closure of the field, which by the Nullstellensatz is when the ideal contains `1`.
+    pub fn is_empty(&self) -> bool {
+        self.dimension() < 0
+    }
+
+    /// The dimension of the variety, or `-1` if it is empty.
+    ///
+    /// The ideal of leading monomials of a Gröbner basis has the same dimension as the ideal itself,
+    /// and this is the size of the largest set of variables such that no leading monomial involves only those variables.
+    pub fn dimension(&self) -> i64 {
+        let gb = self.groebner_basis_terms(MonomialOrder::GradedReverseLex);
+        let n = self.vars.len();
+        assert!(n < 64);
+        (0..(1u64 << n))
+            .filter(|subset| {
+                gb.iter().all(|g| {
+                    g[0].0
+                        .iter()
+                        .enumerate()
+                        .any(|(i, k)| *k > 0 && subset & (1 << i) == 0)
+                })
+            })
+            .map(|subset| subset.count_ones() as i64)
+            .max()
+            .unwrap_or(-1)
+    }
+
+    fn evaluate_ideal_at(&self, point: &[FS::Elem]) -> bool {
+        let polys = self.field.multivariable_polynomials();
+        let values = self
+            .vars
+            .iter()
+            .cloned()
+            .zip(point.iter())
+            .collect::<HashMap<_, _>>();
+        self.ideal
+            .iter()
+            .all(|p| self.field.is_zero(&polys.evaluate(p, values.clone())))
+    }
+}
+
+impl<FS: FieldSignature + FiniteSetSignature> AffineVariety<FS> {
+    /// The points of the variety with coordinates in the finite field, found by evaluating the ideal at every point of the affine space.
+    pub fn rational_points_finite_field(&self) -> Vec<Vec<FS::Elem>> {
+        let elements = self.field.list_all_elements();
+        (0..self.vars.len())
+            .map(|_| elements.iter().cloned())
+            .multi_cartesian_product()
+            .filter(|point| self.evaluate_ideal_at(point))
+            .collect()
+    }
+}
+
+impl<FS: FieldSignature> AffineVariety<FS>
+where
+    PolynomialStructure<FS, FS>: FactoringMonoidSignature<FactoredExponent = NaturalCanonicalStructure>
+        + SetSignature<Elem = Polynomial<FS::Elem>>,
+{
+    /// The points of a zero dimensional variety with coordinates in the field, or `None` if the variety is positive dimensional.
+    ///
+    /// With respect to the lexicographic order the reduced Gröbner basis is triangular, so the points are found one coordinate at a time starting from the last variable.
+    /// The possible values of each coordinate are the roots in the field of the univariate polynomials obtained by substituting the coordinates already found.
+    pub fn rational_points(&self) -> Option<Vec<Vec<FS::Elem>>> {
+        let dim = self.dimension();
+        if dim > 0 {
+            return None;
+        }
+        if dim < 0 {
+            return Some(vec![]);
+        }
+        let n = self.vars.len();
+        let gb = self.groebner_basis_terms(MonomialOrder::Lex);
+        let polys = self.field.clone().into_polynomials();
+        // the values of the coordinates k, k+1, ..., n-1
+        let mut partial_points: Vec<Vec<FS::Elem>> = vec![vec![]];
+        for k in (0..n).rev() {
+            let eliminated = gb
+                .iter()
+                .filter(|g| g.iter().all(|(e, _)| e[..k].iter().all(|i| *i == 0)))
+                .collect::<Vec<_>>();
+            let mut next_partial_points = vec![];
+            for partial_point in partial_points {
+                // substitute the known coordinates to get polynomials in the k-th variable
+                let mut h = polys.zero();
+                for g in &eliminated {
+                    let mut coeffs = vec![];
+                    for (e, c) in *g {
+                        while coeffs.len() <= e[k] {
+                            coeffs.push(self.field.zero());
+                        }
+                        let mut value = c.clone();
+                        for (i, x) in partial_point.iter().enumerate() {
+                            self.field.mul_mut(
+                                &mut value,
+                                &self.field.nat_pow(x, &Natural::from(e[k + 1 + i])),
+                            );
+                        }
+                        self.field.add_mut(&mut coeffs[e[k]], &value);
+                    }
+                    h = polys.gcd(&h, &Polynomial::from_coeffs(coeffs));
+                }
+                // a lexicographic Gröbner basis of a zero dimensional ideal contains a polynomial with leading monomial a power of the k-th variable
+                debug_assert!(!polys.is_zero(&h));
+                for (factor, _) in polys.factor(&h).into_powers().unwrap_or_default() {
+                    if polys.degree(&factor) == Some(1) {
+                        let root = self.field.neg(
+                            &self
+                                .field
+                                .try_divide(&polys.coeff(&factor, 0), &polys.coeff(&factor, 1))
+                                .unwrap(),
+                        );
+                        let mut point = vec![root];
+                        point.extend(partial_point.iter().cloned());
+                        next_partial_points.push(point);
+                    }
+                }
+            }
+            partial_points = next_partial_points;
+        }
+        debug_assert!(partial_points.iter().all(|p| self.evaluate_ideal_at(p)));
+        Some(partial_points)
+    }
+}
+
+impl<FS: FieldSignature + CharZeroRingSignature> AffineVariety<FS>
+where
+    PolynomialStructure<FS, FS>: FactoringMonoidSignature<FactoredExponent = NaturalCanonicalStructure>
+        + SetSignature<Elem = Polynomial<FS::Elem>>,
+{
+    /// Whether a nonempty zero dimensional variety is irreducible over the field, that is, whether its points over an algebraic closure form a single orbit under the Galois group.
+    ///
+    /// The quotient `A` of the polynomial ring by the ideal is finite dimensional with a basis of the monomials not divisible by any leading monomial of a Gröbner basis.
+    /// The number of distinct points is the rank of the trace form `(a, b) -> Tr(ab)` on `A`.
+    /// For a linear form `u` taking distinct values at the points, the roots of the characteristic polynomial of multiplication by `u` on `A` are these values,
+    /// so the orbits of points correspond to the irreducible factors of the characteristic polynomial.
+    fn zero_dimensional_is_irreducible(&self) -> bool {
+        let n = self.vars.len();
+        let arithmetic = self.arithmetic(MonomialOrder::GradedReverseLex);
+        let gb = self.groebner_basis_terms(MonomialOrder::GradedReverseLex);
+
+        let mut basis = vec![vec![0; n]];
+        let mut i = 0;
+        while i < basis.len() {
+            for k in 0..n {
+                let mut e = basis[i].clone();
+                e[k] += 1;
+                if !gb.iter().any(|g| divides(&g[0].0, &e)) && !basis.contains(&e) {
+                    basis.push(e);
+                }
+            }
+            i += 1;
+        }
+        let m = basis.len();
+        let index = basis
+            .iter()
+            .enumerate()
+            .map(|(i, e)| (e.clone(), i))
+            .collect::<HashMap<_, _>>();
+        // the coordinates in A of the product of p by the basis element x^e
+        let coords = |p: &ExponentTerms<FS::Elem>, e: &[usize]| {
+            let mut v = (0..m).map(|_| self.field.zero()).collect::<Vec<_>>();
+            let shifted = arithmetic.sub_mul(&vec![], &self.field.neg(&self.field.one()), e, p);
+            for (f, c) in arithmetic.normal_form(&shifted, &gb) {
+                v[index[&f]] = c;
+            }
+            v
+        };
+
+        let trace = |e: &[usize]| {
+            let monomial = vec![(e.to_vec(), self.field.one())];
+            self.field.sum(
+                &basis
+                    .iter()
+                    .enumerate()
+                    .map(|(k, b)| coords(&monomial, b)[k].clone())
+                    .collect::<Vec<_>>(),
+            )
+        };
+        let trace_form = Matrix::construct(m, m, |i, j| {
+            trace(
+                &basis[i]
+                    .iter()
+                    .zip(&basis[j])
+                    .map(|(a, b)| a + b)
+                    .collect::<Vec<_>>(),
+            )
+        });
+        let num_points = MatrixStructure::new(self.field.clone()).rank(trace_form);
+
+        let polys = self.field.clone().into_polynomials();
+        let mut c = 1;
+        loop {
+            // u = x_0 + c x_1 + c^2 x_2 + ...
+            let mut u = (0..n)
+                .map(|k| {
+                    let mut e = vec![0; n];
+                    e[k] = 1;
+                    (
+                        e,
+                        self.field
+                            .from_int(Integer::from(c).nat_pow(&Natural::from(k))),
+                    )
+                })
+                .collect::<Vec<_>>();
+            u.sort_by(|(a, _), (b, _)| arithmetic.order.cmp(b, a));
+            let mult_u = Matrix::from_cols(basis.iter().map(|b| coords(&u, b)).collect());
+            let char_poly = MatrixStructure::new(self.field.clone())
+                .characteristic_polynomial(mult_u)
+                .unwrap();
+            let factors = polys.factor(&char_poly).into_powers().unwrap();
+            let num_distinct_roots = factors
+                .iter()
+                .map(|(f, _)| polys.degree(f).unwrap())
+                .sum::<usize>();
+            if num_distinct_roots == num_points {
+                return factors.len() == 1;
+            }
+            c += 1;
+        }
+    }
+
+    /// Whether the variety is nonempty and not a union of two proper subvarieties defined over the field.
+    ///
+    /// This is decided exactly for empty and zero dimensional varieties, and `None` is returned for positive dimensional varieties.
+    /// See [`AffineVariety::is_probably_irreducible`] for a heuristic answer in positive dimension.
+    pub fn is_irreducible(&self) -> Option<bool> {
+        match self.dimension() {
+            d if d < 0 => Some(false),
+            0 => Some(self.zero_dimensional_is_irreducible()),
+            _ => None,
+        }
+    }
+
+    /// Whether the variety is nonempty and not a union of two proper subvarieties defined over the field, agreeing with [`AffineVariety::is_irreducible`] whenever that gives an answer.
+    ///
+    /// A positive dimensional variety is cut down to a zero dimensional one by intersecting with linear subspaces of complementary dimension with pseudo-random coefficients.
+    /// The intersection of such a subspace with an irreducible variety is irreducible for most choices of subspace, while the intersection with a reducible variety is reducible.
+    /// The answer is decided by a majority of several such intersections, so it may be wrong for unlucky choices of subspaces,
+    /// and components of dimension less than the dimension of the variety are not detected.
+    /// Intersections which fail to be zero dimensional are skipped, and after a bounded number of attempts the majority of those found so far is used, with `false` if there were none.
+    pub fn is_probably_irreducible(&self) -> bool {
+        let d = self.dimension();
+        if d <= 0 {
+            return d == 0 && self.zero_dimensional_is_irreducible();
+        }
+        let polys = self.field.multivariable_polynomials();
+        let mut seed = 0x2545f4914f6cdd1du64;
+        let mut random_nonzero_coeff = || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            let c = (seed % 9) as i64 + 1;
+            self.field.from_int(if (seed >> 32).is_multiple_of(2) {
+                c
+            } else {
+                -c
+            })
+        };
+        let mut votes_irreducible = 0;
+        let mut votes_reducible = 0;
+        // a section is zero dimensional for most choices of subspace, but cap the attempts in case the choices keep being unlucky
+        for _ in 0..IRREDUCIBILITY_MAX_ATTEMPTS {
+            if votes_irreducible + votes_reducible == IRREDUCIBILITY_SECTIONS {
+                break;
+            }
+            let mut ideal = self.ideal.clone();
+            for _ in 0..d {
+                let mut hyperplane = MultiPolynomial::constant(random_nonzero_coeff());
+                for v in &self.vars {
+                    hyperplane = polys.add(
+                        &hyperplane,
+                        &polys.mul(
+                            &MultiPolynomial::constant(random_nonzero_coeff()),
+                            &polys.var(v.clone()),
+                        ),
+                    );
+                }
+                ideal.push(hyperplane);
+            }
+            let section = AffineVariety::new(self.field.clone(), self.vars.clone(), ideal);
+            if section.dimension() == 0 {
+                if section.zero_dimensional_is_irreducible() {
+                    votes_irreducible += 1;
+                } else {
+                    votes_reducible += 1;
+                }
+            }
+        }
+        votes_irreducible > votes_reducible
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn circle() {
+        let xv = Variable::new("x");
+        let yv = Variable::new("y");
+        let x = &MultiPolynomial::<Rational>::var(xv.clone()).into_ergonomic();
+        let y = &MultiPolynomial::<Rational>::var(yv.clone()).into_ergonomic();
+        let circle = AffineVariety::new(
+            Rational::structure(),
+            vec![xv, yv],
+            vec![(x.pow(2) + y.pow(2) - 1).into_verbose()],
+        );
+        assert_eq!(circle.dimension(), 1);
+        assert!(!circle.is_empty());
+        assert_eq!(circle.is_irreducible(), None);
+        assert!(circle.is_probably_irreducible());
+        assert!(circle.rational_points().is_none());
+    }
+
+    #[test]
+    fn point() {
+        let xv = Variable::new("x");
+        let yv = Variable::new("y");
+        let x = &MultiPolynomial::<Rational>::var(xv.clone()).into_ergonomic();
+        let y = &MultiPolynomial::<Rational>::var(yv.clone()).into_ergonomic();
+        let point = AffineVariety::new(
+            Rational::structure(),
+            vec![xv, yv],
+            vec![x.clone().into_verbose(), y.clone().into_verbose()],
+        );
+        assert_eq!(point.dimension(), 0);
+        assert_eq!(point.is_irreducible(), Some(true));
+        assert_eq!(
+            point.rational_points(),
+            Some(vec![vec![Rational::ZERO, Rational::ZERO]])
+        );
+    }
+
+    #[test]
+    fn zero_dimensional_varieties() {
+        let xv = Variable::new("x");
+        let yv = Variable::new("y");
+        let x = &MultiPolynomial::<Rational>::var(xv.clone()).into_ergonomic();
+        let y = &MultiPolynomial::<Rational>::var(yv.clone()).into_ergonomic();
+
+        let v = AffineVariety::new(
+            Rational::structure(),
+            vec![xv.clone()],
+            vec![(x.pow(2) - 1).into_verbose()],
+        );
+        assert_eq!(v.dimension(), 0);
+        let mut points = v.rational_points().unwrap();
+        points.sort();
+        assert_eq!(points, vec![vec![Rational::from(-1)], vec![Rational::ONE]]);
+        assert_eq!(v.is_irreducible(), Some(false));
+
+        // the two points (+-sqrt(2)) are conjugate over Q
+        let v = AffineVariety::new(
+            Rational::structure(),
+            vec![xv.clone()],
+            vec![(x.pow(2) - 2).into_verbose()],
+        );
+        assert!(v.rational_points().unwrap().is_empty());
+        assert_eq!(v.is_irreducible(), Some(true));
+
+        // the four points (+-sqrt(2), +-sqrt(2)) split into the orbits x = y and x = -y
+        let v = AffineVariety::new(
+            Rational::structure(),
+            vec![xv.clone(), yv.clone()],
+            vec![(x.pow(2) - 2).into_verbose(), (y.pow(2) - 2).into_verbose()],
+        );
+        assert_eq!(v.is_irreducible(), Some(false));
+        // while (+-sqrt(2), +-sqrt(3)) is a single orbit
+        let v = AffineVariety::new(
+            Rational::structure(),
+            vec![xv.clone(), yv.clone()],
+            vec![(x.pow(2) - 2).into_verbose(), (y.pow(2) - 3).into_verbose()],
+        );
+        assert_eq!(v.is_irreducible(), Some(true));
+
+        // the circle meets the line x = y at (1/sqrt(2), 1/sqrt(2)) and its negative, and the line x + y = 1 at (1, 0) and (0, 1)
+        let v = AffineVariety::new(
+            Rational::structure(),
+            vec![xv.clone(), yv.clone()],
+            vec![
+                (x.pow(2) + y.pow(2) - 1).into_verbose(),
+                (x + y - 1).into_verbose(),
+            ],
+        );
+        let mut points = v.rational_points().unwrap();
+        points.sort();
+        assert_eq!(
+            points,
+            vec![
+                vec![Rational::ZERO, Rational::ONE],
+                vec![Rational::ONE, Rational::ZERO]
+            ]
+        );
+
+        let v = AffineVariety::new(
+            Rational::structure(),
+            vec![xv, yv],
+            vec![(x * y - 1).into_verbose(), x.clone().into_verbose()],
+        );
+        assert!(v.is_empty());
+        assert_eq!(v.dimension(), -1);
+        assert_eq!(v.is_irreducible(), Some(false));
+        assert_eq!(v.rational_points(), Some(vec![]));
+    }
+
+    #[test]
+    fn reducible_varieties() {
+        let xv = Variable::new("x");
+        let yv = Variable::new("y");
+        let zv = Variable::new("z");
+        let x = &MultiPolynomial::<Rational>::var(xv.clone()).into_ergonomic();
+        let y = &MultiPolynomial::<Rational>::var(yv.clone()).into_ergonomic();
+        let z = &MultiPolynomial::<Rational>::var(zv.clone()).into_ergonomic();
+
+        // the union of the coordinate axes
+        let v = AffineVariety::new(
+            Rational::structure(),
+            vec![xv.clone(), yv.clone()],
+            vec![(x * y).into_verbose()],
+        );
+        assert_eq!(v.dimension(), 1);
+        assert_eq!(v.is_irreducible(), None);
+        assert!(!v.is_probably_irreducible());
+
+        // the lines y = +-x are defined over Q but the lines y = +-x/sqrt(2) are conjugate
+        let v = AffineVariety::new(
+            Rational::structure(),
+            vec![xv.clone(), yv.clone()],
+            vec![(x.pow(2) - y.pow(2)).into_verbose()],
+        );
+        assert!(!v.is_probably_irreducible());
+        let v = AffineVariety::new(
+            Rational::structure(),
+            vec![xv.clone(), yv.clone()],
+            vec![(x.pow(2) - 2 * y.pow(2)).into_verbose()],
+        );
+        assert!(v.is_probably_irreducible());
+
+        // the twisted cubic is an irreducible curve in 3-space
+        let v = AffineVariety::new(
+            Rational::structure(),
+            vec![xv.clone(), yv.clone(), zv.clone()],
+            vec![(y - x.pow(2)).into_verbose(), (z - x.pow(3)).into_verbose()],
+        );
+        assert_eq!(v.dimension(), 1);
+        assert!(v.is_probably_irreducible());
+
+        // the whole space and a plane
+        let v = AffineVariety::new(
+            Rational::structure(),
+            vec![xv.clone(), yv.clone(), zv.clone()],
+            vec![],
+        );
+        assert_eq!(v.dimension(), 3);
+        let v = AffineVariety::new(
+            Rational::structure(),
+            vec![xv, yv, zv],
+            vec![(x + y + z).into_verbose()],
+        );
+        assert_eq!(v.dimension(), 2);
+        assert!(v.is_probably_irreducible());
+    }
+
+    #[test]
+    fn points_over_finite_field() {
+        let xv = Variable::new("x");
+        let yv = Variable::new("y");
+        let f5 = Integer::structure().into_quotient_field_unchecked(Integer::from(5));
+        let polys = f5.multivariable_polynomials();
+        let x = polys.var(xv.clone());
+        let y = polys.var(yv.clone());
+        // x^2 + y^2 = 1 has p - 1 = 4 points over F_5 since -1 is a square mod 5
+        let circle = polys.sub(
+            &polys.add(&polys.mul(&x, &x), &polys.mul(&y, &y)),
+            &polys.one(),
+        );
+        let v = AffineVariety::new(f5.clone(), vec![xv, yv], vec![circle]);
+        assert_eq!(v.rational_points_finite_field().len(), 4);
+        assert_eq!(v.dimension(), 1);
+    }
+}
@@ -0,0 +1,155 @@
+use super::*;
+use algebraeon_structures::*;
+
+/// The 1-norm of `f`, the sum of the absolute values of its coefficients.
+pub fn coefficient_bound(f: &Polynomial<Integer>) -> Natural {
+    f.coeffs()
+        .map(Abs::abs)
+        .fold(Natural::ZERO, |acc, c| acc + c)
+}
+
+/// A bound on the absolute values of the coefficients of any factor of `f` in `Z[x]` of degree at most `degree_of_factor`.
+///
+/// If `g` divides `f` and has degree `k` then the coefficient of `x^j` in `g` is at most `binomial(k, j) M(g) <= binomial(k, j) M(f) <= binomial(k, j) |f|` in absolute value,
+/// where `M` is the Mahler measure and `|f|` is the 2-norm of the coefficients of `f`.
+/// The same bound applies to `(lc(f) / lc(g)) g`, which is the form in which factors are recovered from modular factors.
+pub fn landau_mignotte_bound(f: &Polynomial<Integer>, degree_of_factor: usize) -> Natural {
+    let l2_norm_sqrd = f
+        .coeffs()
+        .map(|c| {
+            let c = Abs::abs(c);
+            &c * &c
+        })
+        .fold(Natural::ZERO, |acc, c| acc + c);
+    choose(
+        Natural::from(degree_of_factor),
+        Natural::from(degree_of_factor / 2),
+    ) * l2_norm_sqrd.sqrt_ceil()
+}
+
+/// An approximation of the Mahler measure `|a_n| prod_i max(1, |z_i|)` of `f` where `a_n` is the leading coefficient and the `z_i` are the complex roots of `f`.
+///
+/// The roots are approximated with the Durand-Kerner method in floating point.
+/// Returns `0` for the zero polynomial.
+pub fn mahler_measure_approx(f: &Polynomial<Integer>) -> f64 {
+    let Some(n) = f.degree() else {
+        return 0.0;
+    };
+    let lc = Rational::from(f.leading_coeff().unwrap()).to_f64();
+    if n == 0 {
+        return lc.abs();
+    }
+    // the monic polynomial with the same roots as f
+    let monic = (0..=n)
+        .map(|i| Rational::from(f.coeff(i).as_ref()).to_f64() / lc)
+        .collect::<Vec<_>>();
+    let eval = |z: (f64, f64)| {
+        let mut value = (0.0, 0.0);
+        for c in monic.iter().rev() {
+            value = complex_mul(value, z);
+            value.0 += c;
+        }
+        value
+    };
+    // start from the powers of a number which is neither real nor a root of unity
+    let mut roots = vec![];
+    let mut z = (1.0, 0.0);
+    for _ in 0..n {
+        roots.push(z);
+        z = complex_mul(z, (0.4, 0.9));
+    }
+    for _ in 0..1000 {
+        let mut max_step = 0.0f64;
+        for i in 0..n {
+            let mut denominator = (1.0, 0.0);
+            for j in 0..n {
+                if i != j {
+                    denominator = complex_mul(
+                        denominator,
+                        (roots[i].0 - roots[j].0, roots[i].1 - roots[j].1),
+                    );
+                }
+            }
+            let step = complex_div(eval(roots[i]), denominator);
+            roots[i] = (roots[i].0 - step.0, roots[i].1 - step.1);
+            max_step = max_step.max(step.0.hypot(step.1));
+        }
+        if max_step < 1e-15 {
+            break;
+        }
+    }
+    roots
+        .into_iter()
+        .fold(lc.abs(), |acc, z| acc * z.0.hypot(z.1).max(1.0))
+}
+
+fn complex_mul(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0)
+}
+
+fn complex_div(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    let d = b.0 * b.0 + b.1 * b.1;
+    ((a.0 * b.0 + a.1 * b.1) / d, (a.1 * b.0 - a.0 * b.1) / d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structure::*;
+
+    #[test]
+    fn bounds_for_x_squared_minus_two() {
+        let x = &Polynomial::<Integer>::var().into_ergonomic();
+        let f = (x.pow(2) - 2).into_verbose();
+        assert_eq!(coefficient_bound(&f), Natural::from(3u32));
+        // |f| = sqrt(5) rounds up to 3
+        assert_eq!(landau_mignotte_bound(&f, 1), Natural::from(3u32));
+        assert_eq!(landau_mignotte_bound(&f, 2), Natural::from(6u32));
+        // the roots are +-sqrt(2)
+        assert!((mahler_measure_approx(&f) - 2.0).abs() < 1e-9);
+
+        let f = (2 * x - 3).into_verbose();
+        assert!((mahler_measure_approx(&f) - 3.0).abs() < 1e-9);
+        // the real root of x^3 - x - 1 is the plastic number
+        let f = (x.pow(3) - x - 1).into_verbose();
+        assert!((mahler_measure_approx(&f) - 1.324_717_957_244_746).abs() < 1e-9);
+        assert_eq!(mahler_measure_approx(&Polynomial::zero()), 0.0);
+    }
+
+    #[test]
+    fn bounds_for_cyclotomic_105() {
+        let x = &Polynomial::<Integer>::var().into_ergonomic();
+        let x_pow_minus_one = |n: i32| (x.pow(n) - 1).into_verbose();
+        // the 105th cyclotomic polynomial by inclusion-exclusion over the divisors of 105 = 3 * 5 * 7
+        let numerator = Polynomial::product(
+            &[105, 3, 5, 7]
+                .into_iter()
+                .map(x_pow_minus_one)
+                .collect::<Vec<_>>()
+                .iter()
+                .collect::<Vec<_>>(),
+        );
+        let denominator = Polynomial::product(
+            &[35, 21, 15, 1]
+                .into_iter()
+                .map(x_pow_minus_one)
+                .collect::<Vec<_>>()
+                .iter()
+                .collect::<Vec<_>>(),
+        );
+        let phi = Polynomial::try_divide(&numerator, &denominator).unwrap();
+        assert_eq!(phi.degree(), Some(48));
+        // famously the first cyclotomic polynomial with a coefficient other than 0 and +-1
+        let max_coeff = phi.coeffs().map(Abs::abs).max().unwrap();
+        assert_eq!(max_coeff, Natural::TWO);
+
+        // phi divides x^105 - 1 so its coefficients are within the bound
+        let f = x_pow_minus_one(105);
+        assert!(max_coeff <= landau_mignotte_bound(&f, 48));
+        assert!(max_coeff <= landau_mignotte_bound(&phi, 48));
+        assert_eq!(coefficient_bound(&f), Natural::TWO);
+
+        // cyclotomic polynomials have Mahler measure 1
+        assert!((mahler_measure_approx(&phi) - 1.0).abs() < 1e-6);
+    }
+}
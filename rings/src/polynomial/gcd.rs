@@ -0,0 +1,187 @@
+use super::*;
+use crate::structure::*;
+use algebraeon_structures::*;
+
+/// The gcd of `f` and `g` with non-negative leading coefficient, computed with the Euclidean pseudo-remainder sequence.
+///
+/// No content is removed from the intermediate remainders, so their coefficients grow exponentially.
+/// This is only suitable for small inputs and is provided for comparison with [`gcd_subresultant`] and [`gcd_modular`].
+pub fn gcd_pseudo_remainder(
+    f: &Polynomial<Integer>,
+    g: &Polynomial<Integer>,
+) -> Polynomial<Integer> {
+    let polys = Integer::structure().into_polynomials();
+    let (f_content, mut a) = f.content_and_primitive_part();
+    let (g_content, mut b) = g.content_and_primitive_part();
+    if polys.degree(&a) < polys.degree(&b) {
+        (a, b) = (b, a);
+    }
+    while !b.is_zero() {
+        let r = polys.pseudorem(a, &b).unwrap().unwrap();
+        (a, b) = (b, r);
+    }
+    with_content(Natural::gcd(&f_content, &g_content), a)
+}
+
+/// The gcd of `f` and `g` with non-negative leading coefficient, computed with the subresultant pseudo-remainder sequence.
+pub fn gcd_subresultant(f: &Polynomial<Integer>, g: &Polynomial<Integer>) -> Polynomial<Integer> {
+    Polynomial::gcd_by_primitive_subresultant(f.clone(), g.clone()).fav_assoc()
+}
+
+/// The gcd of `f` and `g` with non-negative leading coefficient, computed by a modular algorithm.
+///
+/// The primitive parts of `f` and `g` are reduced modulo primes `p` not dividing the gcd `b` of their leading coefficients and their monic gcds modulo `p` are computed.
+/// The gcd `h` of the primitive parts divides `b`, so the monic gcds scaled by `b` are the reductions of `(b / lc(h)) h`
+/// for every prime except the finitely many at which the gcd modulo `p` has larger degree.
+/// Such primes are detected by comparing degrees and discarded.
+/// Once the product of the primes exceeds twice the Landau-Mignotte bound on the coefficients of `(b / lc(h)) h`, it is recovered with the Chinese remainder theorem.
+/// Its primitive part is the gcd when it divides both primitive parts.
+pub fn gcd_modular(f: &Polynomial<Integer>, g: &Polynomial<Integer>) -> Polynomial<Integer> {
+    if f.is_zero() {
+        return g.fav_assoc();
+    }
+    if g.is_zero() {
+        return f.fav_assoc();
+    }
+    let (f_content, f) = f.content_and_primitive_part();
+    let (g_content, g) = g.content_and_primitive_part();
+    let content = Natural::gcd(&f_content, &g_content);
+    let b = Integer::gcd(f.leading_coeff().unwrap(), g.leading_coeff().unwrap());
+    let bound = Natural::TWO
+        * Abs::abs(&b)
+        * std::cmp::min(
+            landau_mignotte_bound(&f, f.degree().unwrap()),
+            landau_mignotte_bound(&g, g.degree().unwrap()),
+        );
+
+    // the reductions of (b / lc(h)) h modulo the primes seen so far which gave the smallest degree
+    let mut residues: Vec<(Polynomial<Integer>, Natural)> = vec![];
+    let mut residues_deg = None;
+    let mut modulus = Natural::ONE;
+    // use primes large enough that few are needed and unlucky ones are rare
+    for p in primes().skip_while(|p| *p < (1 << 16)) {
+        let p_nat = Natural::from(p);
+        if &b % Integer::from(p) == Integer::ZERO {
+            continue;
+        }
+        let mod_p = Integer::structure().into_quotient_field_unchecked(Integer::from(p));
+        let poly_mod_p = mod_p.polynomials();
+        let h_mod_p = poly_mod_p.mul(
+            &Polynomial::constant(b.clone()),
+            &poly_mod_p.fav_assoc(&poly_mod_p.gcd(&f, &g)),
+        );
+        let h_deg = poly_mod_p.degree(&h_mod_p).unwrap();
+        if h_deg == 0 {
+            return Polynomial::constant(Integer::from(content));
+        }
+        match residues_deg {
+            Some(d) if d < h_deg => {
+                // p is unlucky
+                continue;
+            }
+            Some(d) if d > h_deg => {
+                // all the previous primes were unlucky
+                residues.clear();
+                modulus = Natural::ONE;
+            }
+            _ => {}
+        }
+        residues_deg = Some(h_deg);
+        residues.push((h_mod_p, p_nat.clone()));
+        modulus *= p_nat;
+        if modulus > bound {
+            let h = polynomial_crt_lift(&residues).primitive_part().unwrap();
+            if Polynomial::try_divide(&f, &h).is_some() && Polynomial::try_divide(&g, &h).is_some()
+            {
+                return with_content(content, h);
+            }
+        }
+    }
+    unreachable!("Because there are infinitely many primes")
+}
+
+fn with_content(content: Natural, h: Polynomial<Integer>) -> Polynomial<Integer> {
+    if h.is_zero() {
+        return h;
+    }
+    Polynomial::mul(
+        &Polynomial::constant(Integer::from(content)),
+        &h.primitive_part().unwrap().fav_assoc(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn all_gcds_agree(f: &Polynomial<Integer>, g: &Polynomial<Integer>) -> Polynomial<Integer> {
+        let h = gcd_subresultant(f, g);
+        assert_eq!(gcd_pseudo_remainder(f, g), h);
+        assert_eq!(gcd_modular(f, g), h);
+        h
+    }
+
+    #[test]
+    fn gcd_methods_agree() {
+        let x = &Polynomial::<Integer>::var().into_ergonomic();
+        for (f, g, h) in [
+            (x.pow(2) - 1, x.pow(2) + 2 * x + 1, x + 1),
+            (6 * x.pow(2) - 6, 4 * x - 4, 2 * x - 2),
+            (
+                (2 * x + 3) * (x.pow(3) - x + 5) * (x - 7),
+                (2 * x + 3) * (3 * x.pow(2) + 1) * (x - 7).pow(2),
+                (2 * x + 3) * (x - 7),
+            ),
+            (
+                (x.pow(8) + x.pow(6) - 3 * x.pow(4) - 3 * x.pow(3) + 8 * x.pow(2) + 2 * x - 5),
+                (3 * x.pow(6) + 5 * x.pow(4) - 4 * x.pow(2) - 9 * x + 21),
+                x.pow(0),
+            ),
+            (-x.pow(5) + 1, -x.pow(3) + 1, x - 1),
+            (12 * x.pow(0), 18 * x.pow(0), 6 * x.pow(0)),
+            (x.pow(4) - 4, x - x, x.pow(4) - 4),
+            (
+                (x - 1).pow(3) * (x + 1),
+                (x - 1).pow(2) * (x + 2),
+                (x - 1).pow(2),
+            ),
+        ] {
+            let (f, g, h) = (f.into_verbose(), g.into_verbose(), h.into_verbose());
+            assert_eq!(all_gcds_agree(&f, &g), h);
+            assert_eq!(all_gcds_agree(&g, &f), h);
+        }
+        assert_eq!(
+            all_gcds_agree(&Polynomial::zero(), &Polynomial::zero()),
+            Polynomial::zero()
+        );
+    }
+
+    #[test]
+    fn gcd_modular_large() {
+        // polynomials with 60 terms and coefficients of around 50 digits sharing a factor of degree 20
+        let mut seed = Integer::from_str("1234567890123456789").unwrap();
+        let modulus = Integer::from_str("10000000000000000000000000").unwrap();
+        let mut random_poly = |deg: usize| {
+            Polynomial::from_coeffs(
+                (0..=deg)
+                    .map(|_| {
+                        seed = (&seed * Integer::from(6364136223846793005u64)
+                            + Integer::from(1442695040888963407u64))
+                            % &modulus;
+                        &seed - Integer::from_str("5000000000000000000000000").unwrap()
+                    })
+                    .collect(),
+            )
+        };
+        let a = random_poly(20);
+        let f = Polynomial::mul(&a, &random_poly(40));
+        let g = Polynomial::mul(&a, &random_poly(39));
+        assert_eq!(f.num_coeffs(), 61);
+
+        let h = gcd_modular(&f, &g);
+        assert_eq!(h, gcd_subresultant(&f, &g));
+        assert_eq!(h.degree(), Some(20));
+        assert!(Polynomial::try_divide(&h, &a.primitive_part().unwrap()).is_some());
+    }
+}
@@ -0,0 +1,535 @@
+use super::*;
+use crate::structure::*;
+use algebraeon_structures::*;
+use std::cmp::Ordering;
+
+/// A monomial order on the monomials in an ordered list of variables, in which the earlier variables are larger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonomialOrder {
+    Lex,
+    GradedLex,
+    GradedReverseLex,
+}
+
+impl MonomialOrder {
+    /// Compare two monomials given by their exponent vectors.
+    pub fn cmp(&self, a: &[usize], b: &[usize]) -> Ordering {
+        let degree = |e: &[usize]| e.iter().sum::<usize>();
+        match self {
+            MonomialOrder::Lex => a.cmp(b),
+            MonomialOrder::GradedLex => degree(a).cmp(&degree(b)).then_with(|| a.cmp(b)),
+            // among monomials of equal degree, the larger one has the smaller exponent in the last variable where they differ
+            MonomialOrder::GradedReverseLex => degree(a)
+                .cmp(&degree(b))
+                .then_with(|| a.iter().rev().cmp(b.iter().rev()).reverse()),
+        }
+    }
+}
+
+/// A polynomial as a list of nonzero terms given by exponent vectors, sorted from the largest monomial to the smallest.
+pub(crate) type ExponentTerms<E> = Vec<(Vec<usize>, E)>;
+
+pub(crate) fn divides(a: &[usize], b: &[usize]) -> bool {
+    a.iter().zip(b).all(|(i, j)| i <= j)
+}
+
+/// Polynomial arithmetic over a field for polynomials stored as [`ExponentTerms`] with respect to a fixed monomial order.
+pub(crate) struct ExponentTermsArithmetic<'a, FS: FieldSignature> {
+    pub(crate) field: &'a FS,
+    pub(crate) order: MonomialOrder,
+}
+
+impl<FS: FieldSignature> ExponentTermsArithmetic<'_, FS> {
+    pub(crate) fn from_multipoly(
+        &self,
+        vars: &[Variable],
+        p: &MultiPolynomial<FS::Elem>,
+    ) -> ExponentTerms<FS::Elem> {
+        let mut terms = p
+            .terms
+            .iter()
+            .filter(|Term { coeff, .. }| !self.field.is_zero(coeff))
+            .map(|Term { coeff, monomial }| {
+                let exponents = vars
+                    .iter()
+                    .map(|v| monomial.get_var_pow(v))
+                    .collect::<Vec<_>>();
+                assert_eq!(
+                    exponents.iter().sum::<usize>(),
+                    monomial.degree(),
+                    "polynomial involves variables outside the given list"
+                );
+                (exponents, coeff.clone())
+            })
+            .collect::<Vec<_>>();
+        terms.sort_by(|(a, _), (b, _)| self.order.cmp(b, a));
+        terms
+    }
+
+    pub(crate) fn to_multipoly(
+        &self,
+        vars: &[Variable],
+        p: &ExponentTerms<FS::Elem>,
+    ) -> MultiPolynomial<FS::Elem> {
+        MultiPolynomial::new(
+            p.iter()
+                .map(|(exponents, coeff)| Term {
+                    coeff: coeff.clone(),
+                    monomial: Monomial::new(
+                        vars.iter()
+                            .zip(exponents)
+                            .map(|(var, pow)| VariablePower {
+                                var: var.clone(),
+                                pow: *pow,
+                            })
+                            .collect(),
+                    ),
+                })
+                .collect(),
+        )
+    }
+
+    /// `p - c x^shift q`
+    pub(crate) fn sub_mul(
+        &self,
+        p: &ExponentTerms<FS::Elem>,
+        c: &FS::Elem,
+        shift: &[usize],
+        q: &ExponentTerms<FS::Elem>,
+    ) -> ExponentTerms<FS::Elem> {
+        let mut result = Vec::with_capacity(p.len() + q.len());
+        let mut p = p.iter().peekable();
+        let mut q = q
+            .iter()
+            .map(|(e, a)| {
+                (
+                    e.iter().zip(shift).map(|(i, j)| i + j).collect::<Vec<_>>(),
+                    self.field.neg(&self.field.mul(c, a)),
+                )
+            })
+            .peekable();
+        loop {
+            match (p.peek(), q.peek()) {
+                (None, None) => break,
+                (Some(_), None) => result.push(p.next().unwrap().clone()),
+                (None, Some(_)) => result.push(q.next().unwrap()),
+                (Some((e, _)), Some((f, _))) => match self.order.cmp(e, f) {
+                    Ordering::Greater => result.push(p.next().unwrap().clone()),
+                    Ordering::Less => result.push(q.next().unwrap()),
+                    Ordering::Equal => {
+                        let (e, a) = p.next().unwrap();
+                        let (_, b) = q.next().unwrap();
+                        let s = self.field.add(a, &b);
+                        if !self.field.is_zero(&s) {
+                            result.push((e.clone(), s));
+                        }
+                    }
+                },
+            }
+        }
+        result
+    }
+
+    pub(crate) fn make_monic(&self, p: &mut ExponentTerms<FS::Elem>) {
+        if let Some((_, lc)) = p.first() {
+            let lc_inv = self.field.try_reciprocal(lc).unwrap();
+            for (_, c) in p.iter_mut() {
+                *c = self.field.mul(c, &lc_inv);
+            }
+        }
+    }
+
+    /// The remainder of `p` on division by `basis`, none of whose terms are divisible by a leading monomial of `basis`.
+    pub(crate) fn normal_form(
+        &self,
+        p: &ExponentTerms<FS::Elem>,
+        basis: &[ExponentTerms<FS::Elem>],
+    ) -> ExponentTerms<FS::Elem> {
+        let mut p = p.clone();
+        let mut remainder = vec![];
+        while let Some((e, a)) = p.first().cloned() {
+            match basis.iter().find(|g| divides(&g[0].0, &e)) {
+                Some(g) => {
+                    let shift = e
+                        .iter()
+                        .zip(&g[0].0)
+                        .map(|(i, j)| i - j)
+                        .collect::<Vec<_>>();
+                    let c = self.field.try_divide(&a, &g[0].1).unwrap();
+                    p = self.sub_mul(&p, &c, &shift, g);
+                }
+                None => {
+                    remainder.push(p.remove(0));
+                }
+            }
+        }
+        remainder
+    }
+
+    fn s_polynomial(
+        &self,
+        f: &ExponentTerms<FS::Elem>,
+        g: &ExponentTerms<FS::Elem>,
+    ) -> ExponentTerms<FS::Elem> {
+        let lcm = f[0]
+            .0
+            .iter()
+            .zip(&g[0].0)
+            .map(|(i, j)| *std::cmp::max(i, j))
+            .collect::<Vec<_>>();
+        let f_shift = lcm
+            .iter()
+            .zip(&f[0].0)
+            .map(|(i, j)| i - j)
+            .collect::<Vec<_>>();
+        let g_shift = lcm
+            .iter()
+            .zip(&g[0].0)
+            .map(|(i, j)| i - j)
+            .collect::<Vec<_>>();
+        // f and g are monic
+        let f_shifted = self.sub_mul(&vec![], &self.field.neg(&self.field.one()), &f_shift, f);
+        self.sub_mul(&f_shifted, &self.field.one(), &g_shift, g)
+    }
+
+    /// The reduced Gröbner basis of the ideal generated by `polys` computed with Buchberger's algorithm.
+    ///
+    /// The elements are monic and sorted by leading monomial from largest to smallest.
+    /// The zero ideal has the empty basis and the unit ideal has the basis `[1]`.
+    pub(crate) fn groebner_basis(
+        &self,
+        polys: Vec<ExponentTerms<FS::Elem>>,
+    ) -> Vec<ExponentTerms<FS::Elem>> {
+        let mut basis = vec![];
+        for mut p in polys {
+            if !p.is_empty() {
+                self.make_monic(&mut p);
+                basis.push(p);
+            }
+        }
+        let mut pairs = vec![];
+        for j in 0..basis.len() {
+            for i in 0..j {
+                pairs.push((i, j));
+            }
+        }
+        while let Some((i, j)) = pairs.pop() {
+            // Buchberger's criterion: the S-polynomial of polynomials with coprime leading monomials reduces to zero
+            if basis[i][0]
+                .0
+                .iter()
+                .zip(&basis[j][0].0)
+                .all(|(a, b)| *a == 0 || *b == 0)
+            {
+                continue;
+            }
+            let mut s = self.normal_form(&self.s_polynomial(&basis[i], &basis[j]), &basis);
+            if !s.is_empty() {
+                self.make_monic(&mut s);
+                for k in 0..basis.len() {
+                    pairs.push((k, basis.len()));
+                }
+                basis.push(s);
+            }
+        }
+
+        // keep only the elements whose leading monomial is not divisible by that of another element
+        let mut minimal: Vec<ExponentTerms<FS::Elem>> = vec![];
+        for (i, g) in basis.iter().enumerate() {
+            if !basis
+                .iter()
+                .enumerate()
+                .any(|(j, h)| j != i && divides(&h[0].0, &g[0].0) && (h[0].0 != g[0].0 || j < i))
+            {
+                minimal.push(g.clone());
+            }
+        }
+        // reduce the remaining terms of each element by the others
+        let mut reduced = vec![];
+        for i in 0..minimal.len() {
+            let others = minimal
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, g)| g.clone())
+                .collect::<Vec<_>>();
+            let g = &minimal[i];
+            let mut r = vec![g[0].clone()];
+            r.extend(self.normal_form(&g[1..].to_vec(), &others));
+            reduced.push(r);
+        }
+        reduced.sort_by(|f, g| self.order.cmp(&g[0].0, &f[0].0));
+        reduced
+    }
+}
+
+impl<FS: FieldSignature, FSB: BorrowedStructure<FS>> MultiPolynomialStructure<FS, FSB> {
+    /// The reduced Gröbner basis of the ideal generated by `polys`, with respect to `order` on the monomials in `vars`.
+    ///
+    /// The elements are monic and sorted by leading monomial from largest to smallest.
+    /// The zero ideal has the empty basis and the unit ideal has the basis `[1]`.
+    /// Panics if some polynomial involves a variable not in `vars`.
+    pub fn groebner_basis(
+        &self,
+        vars: &[Variable],
+        order: MonomialOrder,
+        polys: &[MultiPolynomial<FS::Elem>],
+    ) -> Vec<MultiPolynomial<FS::Elem>> {
+        let arithmetic = ExponentTermsArithmetic {
+            field: self.coeff_ring(),
+            order,
+        };
+        arithmetic
+            .groebner_basis(
+                polys
+                    .iter()
+                    .map(|p| arithmetic.from_multipoly(vars, p))
+                    .collect(),
+            )
+            .iter()
+            .map(|g| arithmetic.to_multipoly(vars, g))
+            .collect()
+    }
+
+    /// The remainder of `poly` on division by `basis`, with respect to `order` on the monomials in `vars`.
+    ///
+    /// When `basis` is a Gröbner basis this is the unique representative of `poly` modulo the ideal with no term divisible by a leading monomial of `basis`.
+    pub fn normal_form(
+        &self,
+        vars: &[Variable],
+        order: MonomialOrder,
+        poly: &MultiPolynomial<FS::Elem>,
+        basis: &[MultiPolynomial<FS::Elem>],
+    ) -> MultiPolynomial<FS::Elem> {
+        let arithmetic = ExponentTermsArithmetic {
+            field: self.coeff_ring(),
+            order,
+        };
+        let basis = basis
+            .iter()
+            .map(|g| arithmetic.from_multipoly(vars, g))
+            .filter(|g| !g.is_empty())
+            .collect::<Vec<_>>();
+        arithmetic.to_multipoly(
+            vars,
+            &arithmetic.normal_form(&arithmetic.from_multipoly(vars, poly), &basis),
+        )
+    }
+
+    /// Whether `poly` lies in the ideal generated by `basis`, which must be a Gröbner basis with respect to `order` on the monomials in `vars`.
+    pub fn ideal_membership(
+        &self,
+        vars: &[Variable],
+        order: MonomialOrder,
+        poly: &MultiPolynomial<FS::Elem>,
+        basis: &[MultiPolynomial<FS::Elem>],
+    ) -> bool {
+        self.is_zero(&self.normal_form(vars, order, poly, basis))
+    }
+
+    /// The reduced Gröbner basis of the elimination ideal of the ideal generated by `ideal` in the variables `vars[i]` for `i` in `keep`.
+    ///
+    /// This is computed as the elements of a lex Gröbner basis, with the eliminated variables ordered first, which involve only the kept variables.
+    /// The result is a reduced Gröbner basis for lex order on the kept variables, in the order they appear in `vars`.
+    pub fn eliminate_variables(
+        &self,
+        vars: &[Variable],
+        ideal: &[MultiPolynomial<FS::Elem>],
+        keep: &[usize],
+    ) -> Vec<MultiPolynomial<FS::Elem>> {
+        let (kept, eliminated): (Vec<_>, Vec<_>) = (0..vars.len())
+            .map(|i| (i, vars[i].clone()))
+            .partition(|(i, _)| keep.contains(i));
+        let num_eliminated = eliminated.len();
+        let elimination_vars = eliminated
+            .into_iter()
+            .chain(kept)
+            .map(|(_, v)| v)
+            .collect::<Vec<_>>();
+        let arithmetic = ExponentTermsArithmetic {
+            field: self.coeff_ring(),
+            order: MonomialOrder::Lex,
+        };
+        arithmetic
+            .groebner_basis(
+                ideal
+                    .iter()
+                    .map(|p| arithmetic.from_multipoly(&elimination_vars, p))
+                    .collect(),
+            )
+            .iter()
+            // in lex order a polynomial whose leading monomial involves only the kept variables has no other terms involving the eliminated ones
+            .filter(|g| g[0].0[..num_eliminated].iter().all(|k| *k == 0))
+            .map(|g| arithmetic.to_multipoly(&elimination_vars, g))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn monomial_orders() {
+        // x^2 y, x y^2 z and x^3 in each order with x > y > z
+        let a = [2, 1, 0];
+        let b = [1, 2, 1];
+        let c = [3, 0, 0];
+        assert_eq!(MonomialOrder::Lex.cmp(&a, &b), Ordering::Greater);
+        assert_eq!(MonomialOrder::Lex.cmp(&c, &a), Ordering::Greater);
+        assert_eq!(MonomialOrder::GradedLex.cmp(&b, &a), Ordering::Greater);
+        assert_eq!(MonomialOrder::GradedLex.cmp(&c, &a), Ordering::Greater);
+        // x y z^2 < x^2 z^2 < x y^3 in graded reverse lex
+        assert_eq!(
+            MonomialOrder::GradedReverseLex.cmp(&[1, 3, 0], &[2, 0, 2]),
+            Ordering::Greater
+        );
+        assert_eq!(
+            MonomialOrder::GradedReverseLex.cmp(&[2, 0, 2], &[1, 1, 2]),
+            Ordering::Greater
+        );
+        assert_eq!(
+            MonomialOrder::GradedLex.cmp(&[1, 1, 2], &[2, 0, 2]),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn groebner_basis_of_twisted_cubic() {
+        let xv = Variable::new("x");
+        let yv = Variable::new("y");
+        let zv = Variable::new("z");
+        let x = &MultiPolynomial::<Rational>::var(xv.clone()).into_ergonomic();
+        let y = &MultiPolynomial::<Rational>::var(yv.clone()).into_ergonomic();
+        let z = &MultiPolynomial::<Rational>::var(zv.clone()).into_ergonomic();
+        let vars = [xv, yv, zv];
+        let polys = Rational::structure().into_multivariable_polynomials();
+
+        // the twisted cubic (t, t^2, t^3) with lex order x > y > z ...
+        let gb = polys.groebner_basis(
+            &vars,
+            MonomialOrder::Lex,
+            &[(y - x.pow(2)).into_verbose(), (z - x.pow(3)).into_verbose()],
+        );
+        assert_eq!(
+            gb,
+            vec![
+                (x.pow(2) - y).into_verbose(),
+                (x * y - z).into_verbose(),
+                (x * z - y.pow(2)).into_verbose(),
+                (y.pow(3) - z.pow(2)).into_verbose(),
+            ]
+        );
+
+        // ... and with lex order z > y > x the generators are already a reduced Gröbner basis
+        let vars_rev = [vars[2].clone(), vars[1].clone(), vars[0].clone()];
+        let gb = polys.groebner_basis(
+            &vars_rev,
+            MonomialOrder::Lex,
+            &[(y - x.pow(2)).into_verbose(), (z - x.pow(3)).into_verbose()],
+        );
+        assert_eq!(
+            gb,
+            vec![(z - x.pow(3)).into_verbose(), (y - x.pow(2)).into_verbose()]
+        );
+    }
+
+    #[test]
+    fn ideal_membership_in_twisted_cubic() {
+        let xv = Variable::new("x");
+        let yv = Variable::new("y");
+        let zv = Variable::new("z");
+        let x = &MultiPolynomial::<Rational>::var(xv.clone()).into_ergonomic();
+        let y = &MultiPolynomial::<Rational>::var(yv.clone()).into_ergonomic();
+        let z = &MultiPolynomial::<Rational>::var(zv.clone()).into_ergonomic();
+        let vars = [xv, yv, zv];
+        let polys = Rational::structure().into_multivariable_polynomials();
+
+        for order in [
+            MonomialOrder::Lex,
+            MonomialOrder::GradedLex,
+            MonomialOrder::GradedReverseLex,
+        ] {
+            let gb = polys.groebner_basis(
+                &vars,
+                order,
+                &[(y - x.pow(2)).into_verbose(), (z - x.pow(3)).into_verbose()],
+            );
+            // every polynomial vanishing on (t, t^2, t^3) is in the ideal
+            for p in [
+                y.pow(3) - z.pow(2),
+                x * z - y.pow(2),
+                (x + z) * (x * y - z) + 7 * (y - x.pow(2)),
+                MultiPolynomial::<Rational>::zero().into_ergonomic(),
+            ] {
+                assert!(polys.ideal_membership(&vars, order, &p.into_verbose(), &gb));
+            }
+            for p in [
+                x.clone(),
+                y - x,
+                z - x.pow(2),
+                MultiPolynomial::<Rational>::one().into_ergonomic(),
+            ] {
+                assert!(!polys.ideal_membership(&vars, order, &p.into_verbose(), &gb));
+            }
+        }
+    }
+
+    #[test]
+    fn elimination_ideal_is_generated_by_resultant() {
+        let xv = Variable::new("x");
+        let yv = Variable::new("y");
+        let x = &MultiPolynomial::<Rational>::var(xv.clone()).into_ergonomic();
+        let y = &MultiPolynomial::<Rational>::var(yv.clone()).into_ergonomic();
+        let vars = [xv.clone(), yv.clone()];
+        let polys = Rational::structure().into_multivariable_polynomials();
+
+        for (f, g) in [
+            (x.pow(2) - y, x.pow(3) - 2),
+            (x.pow(2) + y * x + 2, x.pow(3) - y),
+            (x.pow(2) + y.pow(2) - 1, 2 * x - y + 3),
+            (x * y - 1, x.pow(2) + x + y),
+        ] {
+            let (f, g) = (f.into_verbose(), g.into_verbose());
+            let res = Polynomial::resultant(&f.expand(&xv), &g.expand(&xv));
+            let elim = polys.eliminate_variables(&vars, &[f, g], &[1]);
+            // the elimination ideal is principal, generated by the monic resultant
+            assert_eq!(
+                elim,
+                polys.groebner_basis(std::slice::from_ref(&yv), MonomialOrder::Lex, &[res])
+            );
+        }
+
+        // eliminating nothing gives the lex Gröbner basis and eliminating everything gives the zero ideal
+        let ideal = [(x.pow(2) - y).into_verbose(), (x * y - 1).into_verbose()];
+        assert_eq!(
+            polys.eliminate_variables(&vars, &ideal, &[0, 1]),
+            polys.groebner_basis(&vars, MonomialOrder::Lex, &ideal)
+        );
+        assert!(polys.eliminate_variables(&vars, &ideal, &[]).is_empty());
+    }
+
+    #[test]
+    fn groebner_basis_of_unit_and_zero_ideals() {
+        let xv = Variable::new("x");
+        let yv = Variable::new("y");
+        let x = &MultiPolynomial::<Rational>::var(xv.clone()).into_ergonomic();
+        let y = &MultiPolynomial::<Rational>::var(yv.clone()).into_ergonomic();
+        let vars = [xv, yv];
+        let polys = Rational::structure().into_multivariable_polynomials();
+
+        // x y - 1 and x vanish nowhere together
+        let gb = polys.groebner_basis(
+            &vars,
+            MonomialOrder::GradedReverseLex,
+            &[(x * y - 1).into_verbose(), (2 * x).into_verbose()],
+        );
+        assert_eq!(gb, vec![MultiPolynomial::one()]);
+
+        assert!(
+            polys
+                .groebner_basis(&vars, MonomialOrder::GradedReverseLex, &[])
+                .is_empty()
+        );
+    }
+}
@@ -1,15 +1,30 @@
+mod affine_variety;
+mod bounds;
 mod factoring;
+mod gcd;
+mod groebner;
 pub mod hensel_lifting_btree;
 pub mod hensel_lifting_linalg;
+mod multi_modular;
 mod multipoly;
 mod multipoly_structure;
+mod newton_polygon;
+mod ntt;
 mod polynomial_structure;
 mod quotient;
+mod sparse;
 mod symmetric;
 
+pub use affine_variety::*;
+pub use bounds::*;
 pub use factoring::*;
+pub use gcd::*;
+pub use groebner::*;
+pub use multi_modular::*;
 pub use multipoly::*;
 pub use multipoly_structure::*;
+pub use ntt::*;
 pub use polynomial_structure::*;
 pub use quotient::*;
+pub use sparse::*;
 pub use symmetric::*;
@@ -0,0 +1,103 @@
+use super::*;
+use crate::num_theory::functions::chinese_remainder_coprime;
+use algebraeon_structures::*;
+
+/// Reduce the coefficients of `poly` modulo `p`, representing each by the integer in `[0, p)`.
+///
+/// For small `p` known at compile time the result can be mapped into `Polynomial<Modulo<P>>` with `Modulo::from`.
+pub fn polynomial_to_modular(poly: &Polynomial<Integer>, p: &Natural) -> Polynomial<Integer> {
+    let p = Integer::from(p);
+    Polynomial::from_coeffs(poly.coeffs().map(|c| c % &p).collect())
+}
+
+/// Given pairs `(f_i, n_i)` where the `n_i` are pairwise coprime, return the polynomial `f` with `f = f_i mod n_i` for each `i` whose coefficients lie in `(-n/2, n/2]` where `n` is the product of the `n_i`.
+///
+/// This recovers an integer polynomial from its reductions `f_i` modulo the `n_i` provided the coefficients are known to be at most `n/2` in absolute value.
+/// Residues in `Polynomial<Modulo<P>>` can be lifted with `Modulo::lift_int` first.
+///
+/// # Panics
+/// If the moduli are not pairwise coprime.
+pub fn polynomial_crt_lift(residues: &[(Polynomial<Integer>, Natural)]) -> Polynomial<Integer> {
+    let len = residues
+        .iter()
+        .map(|(f, _)| f.num_coeffs())
+        .max()
+        .unwrap_or(0);
+    Polynomial::from_coeffs(
+        (0..len)
+            .map(|i| {
+                let (x, n) = chinese_remainder_coprime(
+                    &residues
+                        .iter()
+                        .map(|(f, m)| (f.coeff(i).into_owned(), m.clone()))
+                        .collect::<Vec<_>>(),
+                )
+                .unwrap();
+                // take the symmetric representative
+                let n = Integer::from(n);
+                if Integer::from(2) * &x > n { x - n } else { x }
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::num_theory::modulo::const_naive::Modulo;
+    use crate::structure::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn crt_lift_round_trip() {
+        let f = Polynomial::from_coeffs(
+            [
+                "123456789012345678901234567890",
+                "-987654321098765432109876543210",
+                "0",
+                "555555555555555555555555555555",
+                "-1",
+                "314159265358979323846264338327",
+            ]
+            .into_iter()
+            .map(|c| Integer::from_str(c).unwrap())
+            .collect(),
+        );
+        // the product of these primes is about 10^36 which is more than twice the largest coefficient
+        let primes = [1000000007u64, 998244353, 1000000009, 754974721]
+            .into_iter()
+            .map(Natural::from)
+            .collect::<Vec<_>>();
+        let residues = primes
+            .iter()
+            .map(|p| (polynomial_to_modular(&f, p), p.clone()))
+            .collect::<Vec<_>>();
+        for (r, p) in &residues {
+            assert!(
+                r.coeffs()
+                    .all(|c| c >= &Integer::ZERO && c < &Integer::from(p))
+            );
+        }
+        assert_eq!(polynomial_crt_lift(&residues), f);
+
+        // with too few primes the coefficients are not recovered
+        assert_ne!(polynomial_crt_lift(&residues[0..2]), f);
+    }
+
+    #[test]
+    fn crt_lift_from_modulo() {
+        let f = Polynomial::<Integer>::from_coeffs(vec![-30, 7, 0, 1, -12]);
+        let f5 = f.apply_map(|c| Modulo::<5>::from(c));
+        let f7 = f.apply_map(|c| Modulo::<7>::from(c));
+        let f11 = f.apply_map(|c| Modulo::<11>::from(c));
+        assert_eq!(
+            polynomial_crt_lift(&[
+                (f5.apply_map(|c| c.lift_int()), Natural::from(5u32)),
+                (f7.apply_map(|c| c.lift_int()), Natural::from(7u32)),
+                (f11.apply_map(|c| c.lift_int()), Natural::from(11u32)),
+            ]),
+            f
+        );
+        assert_eq!(polynomial_crt_lift(&[]), Polynomial::zero());
+    }
+}
@@ -0,0 +1,129 @@
+use super::Polynomial;
+use crate::{
+    structure::*,
+    valuation::{Valuation, padic_int_valuation, padic_rat_valuation},
+};
+use algebraeon_structures::*;
+
+impl Polynomial<Rational> {
+    /// The sides of the `p`-adic Newton polygon of `self` as `(slope, horizontal_length)` pairs ordered by increasing slope.
+    ///
+    /// The Newton polygon is the lower convex hull of the points `(i, v_p(a_i))` for the non-zero coefficients `a_i`.
+    pub fn newton_polygon(&self, p: &Natural) -> Vec<(Rational, usize)> {
+        assert!(!self.is_zero());
+        debug_assert!(p.is_irreducible());
+        let points = self
+            .coeffs()
+            .enumerate()
+            .filter_map(|(i, c)| match padic_rat_valuation(p, c.clone()) {
+                Valuation::Infinity => None,
+                Valuation::Finite(v) => Some((i, v)),
+            })
+            .collect::<Vec<_>>();
+
+        // lower convex hull by the monotone chain algorithm
+        let mut hull: Vec<(usize, Integer)> = vec![];
+        for (j, vj) in points {
+            while hull.len() >= 2 {
+                let (h, vh) = &hull[hull.len() - 2];
+                let (i, vi) = &hull[hull.len() - 1];
+                // pop (i, vi) if it is on or above the segment from (h, vh) to (j, vj)
+                if (vi - vh) * Integer::from(j - h) >= (&vj - vh) * Integer::from(i - h) {
+                    hull.pop();
+                } else {
+                    break;
+                }
+            }
+            hull.push((j, vj));
+        }
+
+        hull.windows(2)
+            .map(|w| {
+                let (i, vi) = &w[0];
+                let (j, vj) = &w[1];
+                (
+                    Rational::from_integers(vj - vi, Integer::from(j - i)),
+                    j - i,
+                )
+            })
+            .collect()
+    }
+}
+
+impl Polynomial<Integer> {
+    /// Whether `self` satisfies Eisenstein's criterion at the prime `p`.
+    ///
+    /// That is, `p` does not divide the leading coefficient, `p` divides every other coefficient and `p^2` does not divide the constant coefficient.
+    pub fn is_eisenstein(&self, p: &Natural) -> bool {
+        debug_assert!(p.is_irreducible());
+        let Some(n) = self.degree() else {
+            return false;
+        };
+        if n == 0 {
+            return false;
+        }
+        let zero = Valuation::Finite(Integer::ZERO);
+        let one = Valuation::Finite(Integer::ONE);
+        padic_int_valuation(p, self.leading_coeff().unwrap().clone()) == zero
+            && (0..n).all(|i| padic_int_valuation(p, self.coeff(i).into_owned()) >= one)
+            && padic_int_valuation(p, self.coeff(0).into_owned()) == one
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_newton_polygon() {
+        let x = &Polynomial::<Rational>::var().into_ergonomic();
+        let two = Natural::from(2u32);
+
+        let f = (x.pow(3) + 2 * x.pow(2) + 4 * x + 8).into_verbose();
+        assert_eq!(f.newton_polygon(&two), vec![(Rational::from(-1), 3)]);
+
+        let f = (x.pow(2) + x + 4).into_verbose();
+        assert_eq!(
+            f.newton_polygon(&two),
+            vec![(Rational::from(-2), 1), (Rational::from(0), 1)]
+        );
+
+        let f = (2 * x.pow(2) + 1)
+            .into_verbose()
+            .apply_map(|c| c / Rational::TWO);
+        assert_eq!(f.newton_polygon(&two), vec![(Rational::ONE_HALF, 2)]);
+
+        // missing coefficients are ignored and collinear points give a single side
+        let f = (x.pow(4) + 3 * x.pow(2) + 9).into_verbose();
+        assert_eq!(
+            f.newton_polygon(&Natural::from(3u32)),
+            vec![(-Rational::ONE_HALF, 4)]
+        );
+        assert_eq!(f.newton_polygon(&two), vec![(Rational::from(0), 4)]);
+
+        assert_eq!(Polynomial::<Rational>::one().newton_polygon(&two), vec![]);
+    }
+
+    #[test]
+    fn test_is_eisenstein() {
+        let x = &Polynomial::<Integer>::var().into_ergonomic();
+        let two = Natural::from(2u32);
+        let three = Natural::from(3u32);
+
+        assert!((x.pow(2) - 2).into_verbose().is_eisenstein(&two));
+        assert!(!(x.pow(2) - 2).into_verbose().is_eisenstein(&three));
+        assert!(
+            !(x.pow(3) + 2 * x.pow(2) + 4 * x + 8)
+                .into_verbose()
+                .is_eisenstein(&two)
+        );
+        assert!(
+            (x.pow(4) + 6 * x.pow(2) + 3)
+                .into_verbose()
+                .is_eisenstein(&three)
+        );
+        assert!(!(2 * x.pow(2) + 2).into_verbose().is_eisenstein(&two));
+        assert!(!(x.pow(2) + 1).into_verbose().is_eisenstein(&two));
+        assert!(!Polynomial::<Integer>::from_int(2).is_eisenstein(&two));
+    }
+}
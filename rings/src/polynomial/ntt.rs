@@ -0,0 +1,152 @@
+use super::*;
+use crate::num_theory::modulo::const_naive::Modulo;
+use crate::num_theory::natural_factorization::primes::{is_prime_nat, primitive_root};
+use crate::structure::*;
+use algebraeon_structures::*;
+
+// P < 2^32 so products of residues fit in a u64
+fn mul_mod<const P: usize>(a: usize, b: usize) -> usize {
+    ((a as u64 * b as u64) % P as u64) as usize
+}
+
+/// In place transform of `a` using `w`, a primitive `a.len()`th root of unity modulo `P`.
+///
+/// `a.len()` must be a power of two.
+fn ntt<const P: usize>(a: &mut [usize], w: usize) {
+    let n = a.len();
+    debug_assert!(n.is_power_of_two());
+
+    // bit reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    // powers of w used as twiddle factors
+    let mut twiddles = Vec::with_capacity(n / 2);
+    let mut wk = 1;
+    for _ in 0..n / 2 {
+        twiddles.push(wk);
+        wk = mul_mod::<P>(wk, w);
+    }
+
+    // iterative Cooley-Tukey butterflies
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        let stride = n / len;
+        for start in (0..n).step_by(len) {
+            for k in 0..half {
+                let u = a[start + k];
+                let v = mul_mod::<P>(a[start + k + half], twiddles[k * stride]);
+                a[start + k] = if u + v >= P { u + v - P } else { u + v };
+                a[start + k + half] = if u >= v { u - v } else { u + P - v };
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// Multiply two polynomials over `Z/PZ` using the number theoretic transform.
+///
+/// The transform needs a primitive `2^k`th root of unity modulo `P` for `2^k` at least the number of coefficients of the product, so `P` should be a prime with `P = 1 mod 2^k`, such as `998244353 = 119 * 2^23 + 1`, and less than `2^32`.
+/// When there is no such root of unity, because `P` is not such a prime, this falls back to schoolbook multiplication.
+pub fn mul_ntt<const P: usize>(
+    f: &Polynomial<Modulo<P>>,
+    g: &Polynomial<Modulo<P>>,
+) -> Polynomial<Modulo<P>> {
+    let (Some(f_deg), Some(g_deg)) = (f.degree(), g.degree()) else {
+        return Polynomial::zero();
+    };
+    let n = (f_deg + g_deg + 1).next_power_of_two();
+    if !(3..1 << 32).contains(&P) || !(P - 1).is_multiple_of(n) || !is_prime_nat(&Natural::from(P))
+    {
+        return Polynomial::mul(f, g);
+    }
+
+    let p = Natural::from(P);
+    let w = primitive_root(&p).pow_mod(&Natural::from((P - 1) / n), &p);
+    let w_inv = Integer::modular_inverse(&Integer::from(&w), &p).unwrap();
+    let n_inv = Integer::modular_inverse(&Integer::from(n), &p).unwrap();
+    let [w, w_inv, n_inv] = [w, w_inv, n_inv].map(|x| usize::try_from(x).unwrap());
+
+    let pad = |p: &Polynomial<Modulo<P>>| {
+        let mut a = p
+            .coeffs()
+            .map(|c| usize::from(c.clone()))
+            .collect::<Vec<_>>();
+        a.resize(n, 0);
+        a
+    };
+    let mut a = pad(f);
+    let mut b = pad(g);
+    ntt::<P>(&mut a, w);
+    ntt::<P>(&mut b, w);
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        *x = mul_mod::<P>(*x, *y);
+    }
+    ntt::<P>(&mut a, w_inv);
+    Polynomial::from_coeffs(
+        a.into_iter()
+            .take(f_deg + g_deg + 1)
+            .map(|x| Modulo::<P>::from(mul_mod::<P>(x, n_inv)))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pseudo_random_poly<const P: usize>(deg: usize, seed: usize) -> Polynomial<Modulo<P>> {
+        Polynomial::from_coeffs(
+            (0..=deg)
+                .map(|i| Modulo::<P>::from((i * 7919 + seed * 104729 + i * i * seed) % P))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn ntt_agrees_with_schoolbook() {
+        for f_deg in 0..=64 {
+            for g_deg in [0, 1, f_deg / 2, f_deg, 64] {
+                let f = pseudo_random_poly::<998244353>(f_deg, f_deg + 1);
+                let g = pseudo_random_poly::<998244353>(g_deg, g_deg + 3);
+                assert_eq!(mul_ntt(&f, &g), Polynomial::mul(&f, &g));
+            }
+        }
+        for deg in 0..=64 {
+            let f = pseudo_random_poly::<7681>(deg, 5);
+            let g = pseudo_random_poly::<7681>(64 - deg, 11);
+            assert_eq!(mul_ntt(&f, &g), Polynomial::mul(&f, &g));
+        }
+    }
+
+    #[test]
+    fn ntt_zero_and_fallback() {
+        let f = pseudo_random_poly::<998244353>(10, 1);
+        assert_eq!(mul_ntt(&f, &Polynomial::zero()), Polynomial::zero());
+        assert_eq!(mul_ntt(&Polynomial::zero(), &f), Polynomial::zero());
+
+        // 17 - 1 = 2^4 so products with more than 16 coefficients fall back to schoolbook
+        let f = pseudo_random_poly::<17>(20, 2);
+        let g = pseudo_random_poly::<17>(5, 3);
+        assert_eq!(mul_ntt(&f, &g), Polynomial::mul(&f, &g));
+        let f = pseudo_random_poly::<17>(7, 2);
+        let g = pseudo_random_poly::<17>(5, 3);
+        assert_eq!(mul_ntt(&f, &g), Polynomial::mul(&f, &g));
+
+        // composite moduli always fall back to schoolbook
+        let f = pseudo_random_poly::<15>(6, 2);
+        let g = pseudo_random_poly::<15>(5, 3);
+        assert_eq!(mul_ntt(&f, &g), Polynomial::mul(&f, &g));
+    }
+}
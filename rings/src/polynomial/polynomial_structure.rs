@@ -669,6 +669,63 @@ impl<RS: SemiRingEqSignature, RSB: BorrowedStructure<RS>> PolynomialStructure<RS
         )
     }
 
+    /// The binary form `F(x, y) = sum a_i x^i y^(n-i)` of `p = sum a_i x^i` evaluated at `(x, y)`, where `n = deg(p)`.
+    ///
+    /// Returns zero when `p` is zero.
+    pub fn evaluate_homogeneous(
+        &self,
+        p: &Polynomial<RS::Elem>,
+        x: &RS::Elem,
+        y: &RS::Elem,
+    ) -> RS::Elem {
+        let ring = self.coeff_ring();
+        let Some(n) = self.degree(p) else {
+            return ring.zero();
+        };
+        // F(x, y) = a_0 y^n + x(a_1 y^(n-1) + x(... + x(a_n)))
+        let mut value = ring.zero();
+        let mut y_pow = ring.one();
+        for c in p.coeffs[..=n].iter().rev() {
+            ring.mul_mut(&mut value, x);
+            ring.add_mut(&mut value, &ring.mul(c, &y_pow));
+            ring.mul_mut(&mut y_pow, y);
+        }
+        value
+    }
+
+    /// The homogenization `y^d p(x / y) = sum a_i x^i y^(d-i)` of `p` to total degree `d`, as a polynomial in `y` with coefficients polynomials in `x`.
+    ///
+    /// The coefficient of `y^j` is the monomial `a_(d-j) x^(d-j)`, so the result is a single bivariate polynomial rather than a pair of univariate ones and [`Self::dehomogenize`] recovers `p`.
+    ///
+    /// Panics if `d` is less than the degree of `p`.
+    pub fn homogenize(
+        &self,
+        p: &Polynomial<RS::Elem>,
+        degree: usize,
+    ) -> Polynomial<Polynomial<RS::Elem>> {
+        if let Some(n) = self.degree(p) {
+            assert!(
+                n <= degree,
+                "cannot homogenize a polynomial to a degree less than its own"
+            );
+        }
+        Polynomial::from_coeffs(
+            (0..=degree)
+                .map(|j| {
+                    let i = degree - j;
+                    self.mul_var_pow(&Polynomial::constant(self.coeff(p, i).into_owned()), i)
+                })
+                .collect(),
+        )
+    }
+
+    /// Substitute `y = 1` into a polynomial in `y` with coefficients polynomials in `x`, such as one returned by [`Self::homogenize`].
+    ///
+    /// This is the sum of the coefficients of `h`, which are the polynomials in `x` multiplying each power of `y`.
+    pub fn dehomogenize(&self, h: &Polynomial<Polynomial<RS::Elem>>) -> Polynomial<RS::Elem> {
+        self.sum(&h.coeffs.iter().collect::<Vec<_>>())
+    }
+
     pub fn mul_var_pow(&self, p: &Polynomial<RS::Elem>, n: usize) -> Polynomial<RS::Elem> {
         let mut coeffs = vec![];
         for _i in 0..n {
@@ -919,6 +976,18 @@ impl<RS: IntegralDomainSignature, RSB: BorrowedStructure<RS>> PolynomialStructur
         }
     }
 
+    /// The subresultant polynomial remainder sequence of `a` and `b` computed by Brown's algorithm.
+    ///
+    /// The terms are subresultants of `a` and `b`, so their coefficients are minors of the Sylvester matrix and grow polynomially rather than exponentially as they do for the naive pseudo-remainder sequence.
+    /// The last term is the gcd of `a` and `b` up to scalar multiplication.
+    pub fn subresultant_prs(
+        &self,
+        a: Polynomial<RS::Elem>,
+        b: Polynomial<RS::Elem>,
+    ) -> Vec<Polynomial<RS::Elem>> {
+        self.pseudo_remainder_subresultant_sequence(a, b).0
+    }
+
     // efficiently compute the gcd of a and b up to scalar multipication using pseudo-remainder subresultant sequence
     pub fn subresultant_gcd(
         &self,
@@ -1221,6 +1290,39 @@ where
     }
 }
 
+impl<FS: FieldSignature, FSB: BorrowedStructure<FS>> PolynomialStructure<FS, FSB> {
+    /// Compute `f^k mod g` by repeated squaring, reducing modulo `g` after every multiplication so that intermediate results have degree less than `2 deg(g)`.
+    ///
+    /// Panics if `g` is zero.
+    pub fn power_mod(
+        &self,
+        f: &Polynomial<FS::Elem>,
+        k: &Natural,
+        g: &Polynomial<FS::Elem>,
+    ) -> Polynomial<FS::Elem> {
+        assert!(!self.is_zero(g), "cannot reduce modulo the zero polynomial");
+        let f = self.rem(f, g);
+        let mut ans = self.rem(&self.one(), g);
+        // bits from most to least significant
+        for bit in k.bits().rev() {
+            ans = self.rem(&self.mul(&ans, &ans), g);
+            if bit {
+                ans = self.rem(&self.mul(&ans, &f), g);
+            }
+        }
+        ans
+    }
+}
+
+impl<F: MetaType> Polynomial<F>
+where
+    F::Signature: FieldSignature,
+{
+    pub fn power_mod(&self, k: &Natural, g: &Self) -> Self {
+        Self::structure().power_mod(self, k, g)
+    }
+}
+
 // pub trait InterpolatablePolynomials: ComRS {
 //     fn interpolate(points: &Vec<(Self::ElemT, Self::ElemT)>) -> Option<Polynomial<Self>>;
 // }
@@ -1425,6 +1527,13 @@ where
         )
     }
 
+    /// The rational number `c` such that `self = c q` for a primitive integer polynomial `q`.
+    ///
+    /// `c` is determined up to sign, which is chosen to match [`Polynomial::factor_primitive_fof`].
+    pub fn content_fof(&self) -> Field {
+        self.factor_primitive_fof().0
+    }
+
     pub fn primitive_part_fof(&self) -> Polynomial<Integer> {
         self.factor_primitive_fof().1
     }
@@ -1508,6 +1617,18 @@ where
         Self::structure().evaluate_at_var_pow(self, k)
     }
 
+    pub fn evaluate_homogeneous(&self, x: &R, y: &R) -> R {
+        Self::structure().evaluate_homogeneous(self, x, y)
+    }
+
+    pub fn homogenize(&self, degree: usize) -> Polynomial<Self> {
+        Self::structure().homogenize(self, degree)
+    }
+
+    pub fn dehomogenize(h: &Polynomial<Self>) -> Self {
+        Self::structure().dehomogenize(h)
+    }
+
     pub fn mul_var_pow(&self, n: usize) -> Self {
         Self::structure().mul_var_pow(self, n)
     }
@@ -1564,6 +1685,10 @@ where
         Self::structure().pseudo_remainder_subresultant_sequence(a, b)
     }
 
+    pub fn subresultant_prs(a: &Self, b: &Self) -> Vec<Self> {
+        Self::structure().subresultant_prs(a.clone(), b.clone())
+    }
+
     pub fn subresultant_gcd(a: &Self, b: &Self) -> Self {
         Self::structure().subresultant_gcd(a.clone(), b.clone())
     }
@@ -2039,6 +2164,59 @@ mod tests {
         println!("{:#?}", Polynomial::resultant(&f, &g));
     }
 
+    #[test]
+    fn test_subresultant_prs() {
+        let x = &Polynomial::<Integer>::var().into_ergonomic();
+
+        // Knuth's example
+        let f = (x.pow(8) + x.pow(6) - 3 * x.pow(4) - 3 * x.pow(3) + 8 * x.pow(2) + 2 * x - 5)
+            .into_verbose();
+        let g = (3 * x.pow(6) + 5 * x.pow(4) - 4 * x.pow(2) - 9 * x + 21).into_verbose();
+        let prs = Polynomial::subresultant_prs(&f, &g);
+        assert_eq!(
+            prs,
+            vec![
+                f.clone(),
+                g.clone(),
+                (15 * x.pow(4) - 3 * x.pow(2) + 9).into_verbose(),
+                (65 * x.pow(2) + 125 * x - 245).into_verbose(),
+                (9326 * x - 12300).into_verbose(),
+                Polynomial::constant(Integer::from(260_708)),
+            ]
+        );
+
+        // the naive pseudo-remainder sequence has much larger coefficients
+        let mut naive = vec![f.clone(), g.clone()];
+        loop {
+            let n = naive.len();
+            let r = Polynomial::pseudorem(&naive[n - 2], &naive[n - 1])
+                .unwrap()
+                .unwrap();
+            if r.is_zero() {
+                break;
+            }
+            naive.push(r);
+        }
+        let max_coeff = |p: &Polynomial<Integer>| p.coeffs().map(Abs::abs).max().unwrap();
+        assert_eq!(naive.len(), prs.len());
+        for (s, n) in prs.iter().zip(naive.iter()) {
+            assert!(max_coeff(s) <= max_coeff(n));
+        }
+        assert!(max_coeff(naive.last().unwrap()) > Natural::from(10u32).nat_pow(&30u32.into()));
+
+        // the last term is the gcd up to scalar multiplication
+        let f = ((x + 2).pow(2) * (2 * x - 3).pow(2)).into_verbose();
+        let g = ((3 * x - 1) * (2 * x - 3).pow(2)).into_verbose();
+        assert_eq!(
+            Polynomial::subresultant_prs(&f, &g)
+                .pop()
+                .unwrap()
+                .primitive_part()
+                .unwrap(),
+            (2 * x - 3).pow(2).into_verbose()
+        );
+    }
+
     // #[test]
     // fn test_squarefree_part_by_yuns() {
     //     let x = &Ergonomic::new(Polynomial::<Integer>::var());
@@ -2163,4 +2341,174 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_content_fof() {
+        for f in [
+            Polynomial::from_coeffs(vec![
+                Rational::from_integers(1, 2),
+                Rational::from_integers(1, 3),
+            ]),
+            Polynomial::from_coeffs(vec![
+                Rational::from_integers(4, 1),
+                Rational::from_integers(6, 1),
+            ]),
+            Polynomial::from_coeffs(vec![
+                Rational::from_integers(-3, 4),
+                Rational::ZERO,
+                Rational::from_integers(9, 10),
+            ]),
+        ] {
+            let content = f.content_fof();
+            let prim = f.primitive_part_fof();
+            assert_eq!(prim.content(), Natural::ONE);
+            assert_eq!(
+                Polynomial::mul(
+                    &prim.apply_map(|c| Rational::from(c)),
+                    &Polynomial::constant(content)
+                ),
+                f
+            );
+        }
+        assert_eq!(
+            Polynomial::<Rational>::from_coeffs(vec![
+                Rational::from_integers(4, 1),
+                Rational::from_integers(6, 1),
+            ])
+            .content_fof()
+            .abs(),
+            Rational::TWO
+        );
+        assert_eq!(
+            Polynomial::<Rational>::from_coeffs(vec![
+                Rational::from_integers(-3, 4),
+                Rational::ZERO,
+                Rational::from_integers(9, 10),
+            ])
+            .content_fof()
+            .abs(),
+            Rational::from_integers(3, 20)
+        );
+    }
+
+    #[test]
+    fn test_homogeneous_evaluation() {
+        let x = &Polynomial::<Rational>::var().into_ergonomic();
+        for f in [
+            (2 * x.pow(3) - x + 5).into_verbose(),
+            (x.pow(2) - 4).into_verbose(),
+            (7 * x.pow(0)).into_verbose(),
+            (x.pow(4) * 3 - x.pow(3)).into_verbose(),
+        ] {
+            let n = f.degree().unwrap();
+            for (a, b) in [(3, 2), (-1, 5), (0, 1), (7, -3)] {
+                let (a, b) = (Rational::from(a), Rational::from(b));
+                assert_eq!(
+                    f.evaluate_homogeneous(&a, &b),
+                    b.nat_pow(&Natural::from(n)) * f.evaluate(&(&a / &b))
+                );
+            }
+            // at infinity only the leading coefficient remains
+            assert_eq!(
+                f.evaluate_homogeneous(&Rational::ONE, &Rational::ZERO),
+                f.leading_coeff().unwrap().clone()
+            );
+        }
+        assert_eq!(
+            Polynomial::<Rational>::zero().evaluate_homogeneous(&Rational::ONE, &Rational::TWO),
+            Rational::ZERO
+        );
+    }
+
+    #[test]
+    fn test_power_mod() {
+        use crate::num_theory::modulo::const_naive::Modulo;
+
+        // (x + 1)^8 = x^8 + 1 = 0 mod x^4 + 1 over GF(2)
+        let x = &Polynomial::<Modulo<2>>::var().into_ergonomic();
+        let f = (x + 1).into_verbose();
+        let g = (x.pow(4) + 1).into_verbose();
+        assert_eq!(f.power_mod(&Natural::from(8u32), &g), Polynomial::zero());
+        assert_eq!(
+            f.power_mod(&Natural::from(3u32), &g),
+            (x.pow(3) + x.pow(2) + x + 1).into_verbose()
+        );
+        assert_eq!(f.power_mod(&Natural::ZERO, &g), Polynomial::one());
+
+        // agreement with computing the power and then reducing
+        let x = &Polynomial::<Rational>::var().into_ergonomic();
+        let f = (x.pow(2) - 3 * x + 1).into_verbose();
+        let g = (2 * x.pow(3) + x - 5).into_verbose();
+        for k in 0u32..12 {
+            assert_eq!(
+                f.power_mod(&Natural::from(k), &g),
+                Polynomial::rem(&f.nat_pow(&Natural::from(k)), &g)
+            );
+        }
+        assert_eq!(
+            f.power_mod(&Natural::from(5u32), &Polynomial::one()),
+            Polynomial::zero()
+        );
+
+        // x^(p^k) mod f is the k-fold Frobenius map applied to x mod f
+        let x = &Polynomial::<Modulo<5>>::var().into_ergonomic();
+        let f = (x.pow(6) + 3 * x.pow(2) + x + 2).into_verbose();
+        let p = Natural::from(5u32);
+        let mut frobenius_x = Polynomial::<Modulo<5>>::var();
+        for k in 1u32..8 {
+            frobenius_x = frobenius_x.power_mod(&p, &f);
+            assert_eq!(
+                Polynomial::<Modulo<5>>::var().power_mod(&p.nat_pow(&Natural::from(k)), &f),
+                frobenius_x
+            );
+        }
+        assert_eq!(
+            Polynomial::<Modulo<5>>::var().power_mod(&Natural::from(125u32), &f),
+            Polynomial::rem(&Polynomial::<Modulo<5>>::var_pow(125), &f)
+        );
+    }
+
+    #[test]
+    fn test_homogenize() {
+        let x = &Polynomial::<Integer>::var().into_ergonomic();
+        // (x - 2)(3x + 1) has the projective roots (2 : 1) and (-1 : 3)
+        let f = ((x - 2) * (3 * x + 1)).into_verbose();
+        assert_eq!(
+            f.evaluate_homogeneous(&Integer::from(2), &Integer::from(1)),
+            Integer::ZERO
+        );
+        assert_eq!(
+            f.evaluate_homogeneous(&Integer::from(-1), &Integer::from(3)),
+            Integer::ZERO
+        );
+        assert_eq!(
+            f.evaluate_homogeneous(&Integer::from(-2), &Integer::from(6)),
+            Integer::ZERO
+        );
+        assert_ne!(
+            f.evaluate_homogeneous(&Integer::from(1), &Integer::from(0)),
+            Integer::ZERO
+        );
+
+        // homogenizing to a higher degree adds the root (1 : 0) at infinity
+        for d in [2, 3, 5] {
+            let h = f.homogenize(d);
+            assert_eq!(h.degree(), Some(d));
+            let evaluate_h = |a: i64, b: i64| {
+                Polynomial::evaluate(
+                    &h.apply_map(|c| c.evaluate(&Integer::from(a))),
+                    &Integer::from(b),
+                )
+            };
+            assert_eq!(evaluate_h(2, 1), Integer::ZERO);
+            assert_eq!(evaluate_h(-1, 3), Integer::ZERO);
+            assert_eq!(evaluate_h(1, 0) == Integer::ZERO, d > 2);
+            assert_eq!(
+                evaluate_h(5, 7),
+                f.evaluate_homogeneous(&Integer::from(5), &Integer::from(7))
+                    * Integer::from(7).nat_pow(&Natural::from(d - 2))
+            );
+            assert_eq!(Polynomial::dehomogenize(&h), f);
+        }
+    }
 }
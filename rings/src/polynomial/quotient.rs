@@ -141,6 +141,10 @@ where
     pub fn trace(&self, a: &Polynomial<FS::Elem>) -> FS::Elem {
         self.coefficient_ring_inclusion().trace(a)
     }
+
+    pub fn norm_and_trace(&self, a: &Polynomial<FS::Elem>) -> (FS::Elem, FS::Elem) {
+        self.coefficient_ring_inclusion().norm_and_trace(a)
+    }
 }
 
 impl<
@@ -174,6 +178,18 @@ impl<
 where
     PolynomialStructure<FS, FSB>: SetSignature<Elem = Polynomial<FS::Elem>>,
 {
+    fn generate_random_elements(&self, seed: u64) -> impl Iterator<Item = Self::Elem> {
+        // independent uniform coefficients give a uniform element of the quotient
+        let degree = self.degree();
+        let mut coeffs = self.ring().coeff_ring().generate_random_elements(seed);
+        (0..).map(move |_| {
+            self.from_vec(
+                (0..degree)
+                    .map(|_| coeffs.next().unwrap())
+                    .collect::<Vec<_>>(),
+            )
+        })
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -0,0 +1,256 @@
+use super::*;
+use crate::structure::*;
+use algebraeon_structures::*;
+use std::collections::BTreeMap;
+
+/// A polynomial stored as a map from degree to coefficient, for polynomials with few nonzero terms such as `x^n - 1`.
+#[derive(Debug, Clone)]
+pub struct SparsePolynomial<Set> {
+    // every stored coefficient is nonzero
+    terms: BTreeMap<usize, Set>,
+}
+
+impl<Set> SparsePolynomial<Set> {
+    /// The number of nonzero terms.
+    pub fn num_terms(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// The nonzero terms as pairs `(degree, coefficient)` in increasing order of degree.
+    pub fn terms(&self) -> impl Iterator<Item = (usize, &Set)> {
+        self.terms.iter().map(|(k, c)| (*k, c))
+    }
+}
+
+impl<RS: SemiRingEqSignature, RSB: BorrowedStructure<RS>> PolynomialStructure<RS, RSB> {
+    /// The sparse polynomial with terms `(degree, coefficient)`. Coefficients of repeated degrees are summed.
+    pub fn sparse_from_terms(
+        &self,
+        terms: impl IntoIterator<Item = (usize, RS::Elem)>,
+    ) -> SparsePolynomial<RS::Elem> {
+        let mut sparse = SparsePolynomial {
+            terms: BTreeMap::new(),
+        };
+        for (k, c) in terms {
+            self.sparse_add_term(&mut sparse, k, &c);
+        }
+        sparse
+    }
+
+    fn sparse_add_term(&self, p: &mut SparsePolynomial<RS::Elem>, k: usize, c: &RS::Elem) {
+        let ring = self.coeff_ring();
+        if ring.is_zero(c) {
+            return;
+        }
+        if let Some(existing) = p.terms.get_mut(&k) {
+            ring.add_mut(existing, c);
+            if ring.is_zero(existing) {
+                p.terms.remove(&k);
+            }
+        } else {
+            p.terms.insert(k, c.clone());
+        }
+    }
+
+    pub fn to_sparse(&self, p: &Polynomial<RS::Elem>) -> SparsePolynomial<RS::Elem> {
+        self.sparse_from_terms(self.coeffs(p).cloned().enumerate())
+    }
+
+    pub fn from_sparse(&self, p: &SparsePolynomial<RS::Elem>) -> Polynomial<RS::Elem> {
+        let mut coeffs = match self.sparse_degree(p) {
+            Some(d) => (0..=d)
+                .map(|_| self.coeff_ring().zero())
+                .collect::<Vec<_>>(),
+            None => vec![],
+        };
+        for (k, c) in &p.terms {
+            coeffs[*k] = c.clone();
+        }
+        Polynomial::from_coeffs(coeffs)
+    }
+
+    pub fn sparse_equal(
+        &self,
+        a: &SparsePolynomial<RS::Elem>,
+        b: &SparsePolynomial<RS::Elem>,
+    ) -> bool {
+        a.terms.len() == b.terms.len()
+            && a.terms
+                .iter()
+                .zip(b.terms.iter())
+                .all(|((i, x), (j, y))| i == j && self.coeff_ring().equal(x, y))
+    }
+
+    pub fn sparse_add(
+        &self,
+        a: &SparsePolynomial<RS::Elem>,
+        b: &SparsePolynomial<RS::Elem>,
+    ) -> SparsePolynomial<RS::Elem> {
+        let mut sum = a.clone();
+        for (k, c) in &b.terms {
+            self.sparse_add_term(&mut sum, *k, c);
+        }
+        sum
+    }
+
+    /// Multiply term by term, so that the cost depends on the number of nonzero terms rather than the degrees.
+    pub fn sparse_mul(
+        &self,
+        a: &SparsePolynomial<RS::Elem>,
+        b: &SparsePolynomial<RS::Elem>,
+    ) -> SparsePolynomial<RS::Elem> {
+        let mut product = SparsePolynomial {
+            terms: BTreeMap::new(),
+        };
+        for (i, x) in &a.terms {
+            for (j, y) in &b.terms {
+                self.sparse_add_term(&mut product, i + j, &self.coeff_ring().mul(x, y));
+            }
+        }
+        product
+    }
+
+    pub fn sparse_evaluate(&self, p: &SparsePolynomial<RS::Elem>, x: &RS::Elem) -> RS::Elem {
+        // Horner's method, jumping over runs of zero coefficients with a single power of x
+        let ring = self.coeff_ring();
+        let mut y = ring.zero();
+        let mut prev_k = None;
+        for (k, c) in p.terms.iter().rev() {
+            if let Some(prev_k) = prev_k {
+                ring.mul_mut(&mut y, &ring.nat_pow(x, &Natural::from(prev_k - k)));
+            }
+            ring.add_mut(&mut y, c);
+            prev_k = Some(*k);
+        }
+        if let Some(prev_k) = prev_k {
+            ring.mul_mut(&mut y, &ring.nat_pow(x, &Natural::from(prev_k)));
+        }
+        y
+    }
+
+    pub fn sparse_degree(&self, p: &SparsePolynomial<RS::Elem>) -> Option<usize> {
+        p.terms.keys().next_back().copied()
+    }
+
+    pub fn sparse_leading_coeff<'a>(
+        &self,
+        p: &'a SparsePolynomial<RS::Elem>,
+    ) -> Option<&'a RS::Elem> {
+        p.terms.values().next_back()
+    }
+}
+
+impl<R: MetaType> SparsePolynomial<R>
+where
+    R::Signature: SemiRingEqSignature<Elem = R>,
+{
+    pub fn from_terms(terms: impl IntoIterator<Item = (usize, R)>) -> Self {
+        Polynomial::<R>::structure().sparse_from_terms(terms)
+    }
+
+    pub fn from_polynomial(p: &Polynomial<R>) -> Self {
+        Polynomial::<R>::structure().to_sparse(p)
+    }
+
+    pub fn to_polynomial(&self) -> Polynomial<R> {
+        Polynomial::<R>::structure().from_sparse(self)
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        Polynomial::<R>::structure().sparse_add(self, other)
+    }
+
+    pub fn mul(&self, other: &Self) -> Self {
+        Polynomial::<R>::structure().sparse_mul(self, other)
+    }
+
+    pub fn evaluate(&self, x: &R) -> R {
+        Polynomial::<R>::structure().sparse_evaluate(self, x)
+    }
+
+    pub fn degree(&self) -> Option<usize> {
+        Polynomial::<R>::structure().sparse_degree(self)
+    }
+
+    pub fn leading_coeff(&self) -> Option<&R> {
+        Polynomial::<R>::structure().sparse_leading_coeff(self)
+    }
+}
+
+impl<R: MetaType> PartialEq for SparsePolynomial<R>
+where
+    R::Signature: SemiRingEqSignature<Elem = R>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        Polynomial::<R>::structure().sparse_equal(self, other)
+    }
+}
+
+impl<R: MetaType> Eq for SparsePolynomial<R> where R::Signature: SemiRingEqSignature<Elem = R> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sparse_and_dense_agree() {
+        let x = &Polynomial::<Integer>::var().into_ergonomic();
+        let polys = vec![
+            Polynomial::<Integer>::zero(),
+            Polynomial::<Integer>::one(),
+            (x.pow(7) - 3 * x.pow(2) + 5).into_verbose(),
+            (2 * x.pow(12) + x).into_verbose(),
+            (x.pow(5) - x.pow(5)).into_verbose(),
+            (-x.pow(3) + 4 * x.pow(3) - 3 * x.pow(3) + 1).into_verbose(),
+        ];
+        for p in &polys {
+            let sp = SparsePolynomial::from_polynomial(p);
+            assert_eq!(sp.to_polynomial(), *p);
+            assert_eq!(sp.degree(), p.degree());
+            assert_eq!(sp.leading_coeff(), p.leading_coeff());
+            for v in [-2, 0, 1, 3] {
+                assert_eq!(
+                    sp.evaluate(&Integer::from(v)),
+                    p.evaluate(&Integer::from(v))
+                );
+            }
+            for q in &polys {
+                let sq = SparsePolynomial::from_polynomial(q);
+                assert_eq!(sp.add(&sq).to_polynomial(), Polynomial::add(p, q));
+                assert_eq!(sp.mul(&sq).to_polynomial(), Polynomial::mul(p, q));
+            }
+        }
+
+        // terms cancel and zero terms are not stored
+        let a = SparsePolynomial::from_terms(vec![(3, Integer::from(2)), (0, Integer::ONE)]);
+        let b = SparsePolynomial::from_terms(vec![(3, Integer::from(-2)), (1, Integer::ZERO)]);
+        assert_eq!(a.add(&b).num_terms(), 1);
+        assert_eq!(
+            a.add(&b),
+            SparsePolynomial::from_terms(vec![(0, Integer::ONE)])
+        );
+    }
+
+    #[test]
+    fn sparse_memory_for_large_degree() {
+        // (x - 1) Phi_10007(x) = x^10007 - 1 where Phi_10007 = 1 + x + ... + x^10006 since 10007 is prime
+        let p = 10007;
+        let phi = SparsePolynomial::from_terms((0..p).map(|k| (k, Integer::ONE)));
+        assert_eq!(phi.num_terms(), p);
+        assert_eq!(phi.to_polynomial().num_coeffs(), p);
+
+        let x_minus_1 =
+            SparsePolynomial::from_terms(vec![(1, Integer::ONE), (0, Integer::from(-1))]);
+        let product = phi.mul(&x_minus_1);
+        // the sparse product stores 2 terms where the dense one stores p + 1 coefficients
+        assert_eq!(product.num_terms(), 2);
+        assert_eq!(product.to_polynomial().num_coeffs(), p + 1);
+        assert_eq!(product.degree(), Some(p));
+        assert_eq!(product.leading_coeff(), Some(&Integer::ONE));
+        assert_eq!(
+            product.to_polynomial(),
+            Polynomial::mul(&phi.to_polynomial(), &x_minus_1.to_polynomial())
+        );
+        assert_eq!(product.evaluate(&Integer::from(-1)), Integer::from(-2));
+    }
+}
@@ -1,6 +1,6 @@
 use super::*;
 use crate::matrix::{Matrix, MatrixStructure};
-use crate::polynomial::Polynomial;
+use crate::polynomial::{Polynomial, ToPolynomialSignature};
 use algebraeon_structures::*;
 use std::borrow::Borrow;
 use std::collections::{HashMap, HashSet};
@@ -413,6 +413,26 @@ pub trait FiniteDimensionalFieldExtension<F: FieldSignature, K: FieldSignature>:
     /// The monic minimal polynomial of a
     fn min_poly(&self, a: &K::Elem) -> Polynomial<F::Elem>;
 
+    /// Return `(norm(a), trace(a))`, read off from the characteristic polynomial of multiplication by `a`.
+    ///
+    /// If the characteristic polynomial is `x^n + c_{n-1}x^{n-1} + ... + c_0` then the trace is `-c_{n-1}` and the norm is `(-1)^n c_0`.
+    fn norm_and_trace(&self, a: &K::Elem) -> (F::Elem, F::Elem) {
+        let field = self.domain();
+        let n = self.degree();
+        let char_poly = MatrixStructure::new(field.clone())
+            .characteristic_polynomial(self.col_multiplication_matrix(a))
+            .unwrap();
+        let polys = field.polynomials();
+        debug_assert_eq!(polys.num_coeffs(&char_poly), n + 1);
+        let trace = field.neg(polys.coeff(&char_poly, n - 1).as_ref());
+        let norm = if n % 2 == 0 {
+            polys.coeff(&char_poly, 0).into_owned()
+        } else {
+            field.neg(polys.coeff(&char_poly, 0).as_ref())
+        };
+        (norm, trace)
+    }
+
     fn trace_form_matrix(&self, elems: &[K::Elem]) -> Matrix<F::Elem> {
         let n = self.degree();
         assert_eq!(n, elems.len());
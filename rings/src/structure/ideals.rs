@@ -51,6 +51,25 @@ pub trait IdealsArithmeticSignature<Ring: RingSignature, RingB: BorrowedStructur
     /// Intersection of two ideals
     fn intersect(&self, a: &Self::Elem, b: &Self::Elem) -> Self::Elem;
 
+    /// The least common multiple of two ideals
+    ///
+    /// Divisibility of ideals is reverse containment, so the least common multiple is the largest ideal contained in both, which is their intersection.
+    fn ideal_lcm(&self, a: &Self::Elem, b: &Self::Elem) -> Self::Elem {
+        self.intersect(a, b)
+    }
+
+    /// The greatest common divisor of two ideals
+    ///
+    /// Divisibility of ideals is reverse containment, so the greatest common divisor is the smallest ideal containing both, which is their sum.
+    fn ideal_gcd(&self, a: &Self::Elem, b: &Self::Elem) -> Self::Elem {
+        self.add(a, b)
+    }
+
+    /// Are a and b coprime i.e. is a + b the unit ideal
+    fn are_coprime_ideals(&self, a: &Self::Elem, b: &Self::Elem) -> bool {
+        self.equal(&self.ideal_gcd(a, b), &self.one())
+    }
+
     /// The quotient of ideals (I : J) = {ring elements x such that xJ is a subset of I}
     fn quotient(&self, i: &Self::Elem, j: &Self::Elem) -> Self::Elem;
 }
@@ -226,6 +226,14 @@ pub trait AdditiveMonoidSignature: ZeroSignature + AdditionSignature + TryNegate
         }
         sum
     }
+
+    fn sum_iter(&self, vals: impl Iterator<Item = Self::Elem>) -> Self::Elem {
+        let mut sum = self.zero();
+        for val in vals {
+            self.add_mut(&mut sum, &val);
+        }
+        sum
+    }
 }
 
 #[signature_meta_trait]
@@ -255,6 +263,14 @@ pub trait OneSignature: RinglikeSpecializationSignature {
     fn one(&self) -> Self::Elem;
 }
 
+#[signature_meta_trait]
+pub trait OneEqSignature: OneSignature + EqSignature {
+    fn is_one(&self, a: &Self::Elem) -> bool {
+        self.equal(a, &self.one())
+    }
+}
+impl<R: OneSignature + EqSignature> OneEqSignature for R {}
+
 /// A set with an associative binary opperation `*`.
 #[signature_meta_trait]
 pub trait MultiplicationSignature: RinglikeSpecializationSignature {
@@ -280,6 +296,14 @@ pub trait MultiplicativeMonoidSignature: OneSignature + MultiplicationSignature
         prod
     }
 
+    fn product_iter(&self, vals: impl Iterator<Item = Self::Elem>) -> Self::Elem {
+        let mut prod = self.one();
+        for val in vals {
+            self.mul_mut(&mut prod, &val);
+        }
+        prod
+    }
+
     fn nat_pow(&self, a: &Self::Elem, n: &Natural) -> Self::Elem {
         if *n == Natural::ZERO {
             self.one()
@@ -547,7 +571,33 @@ pub trait RingSignature: SemiRingSignature + AdditiveGroupSignature {
 }
 
 #[signature_meta_trait]
-pub trait RingEqSignature: RingSignature + EqSignature {}
+pub trait RingEqSignature: RingSignature + EqSignature {
+    /// The additive order of `1`, found by repeatedly adding `1` to itself.
+    ///
+    /// Returns `None` if `1` does not have additive order at most `limit`.
+    fn runtime_characteristic_with_limit(&self, limit: &Natural) -> Option<Natural> {
+        let one = self.one();
+        let mut x = one.clone();
+        let mut n = Natural::ONE;
+        while &n <= limit {
+            if self.is_zero(&x) {
+                return Some(n);
+            }
+            self.add_mut(&mut x, &one);
+            n += Natural::ONE;
+        }
+        None
+    }
+
+    /// The characteristic of the ring, computed at runtime from the additive order of `1`.
+    ///
+    /// This returns `0` when `1` has additive order greater than `2^16`, so it should only be relied upon for rings known to have characteristic `0` or small characteristic.
+    /// Where possible prefer [`CharacteristicSignature::characteristic`].
+    fn runtime_characteristic(&self) -> Natural {
+        self.runtime_characteristic_with_limit(&Natural::from(1u32 << 16))
+            .unwrap_or(Natural::ZERO)
+    }
+}
 impl<R: RingSignature + EqSignature> RingEqSignature for R {}
 
 #[signature_meta_trait]
@@ -604,6 +654,10 @@ pub trait OrderedRingSignature: IntegralDomainSignature + OrdSignature {
 pub trait FiniteUnitsSignature: RingSignature {
     fn all_units(&self) -> Vec<Self::Elem>;
 
+    /// Iterate over the units, in the same order as [`FiniteUnitsSignature::all_units`].
+    #[skip_meta]
+    fn units_iter(&self) -> impl Iterator<Item = Self::Elem>;
+
     fn all_units_and_zero(&self) -> Vec<Self::Elem> {
         let mut elems = vec![self.zero()];
         elems.append(&mut self.all_units());
@@ -618,6 +672,10 @@ where
     fn all_units(&self) -> Vec<Self::Elem> {
         self.units().list_all_elements()
     }
+
+    fn units_iter(&self) -> impl Iterator<Item = Self::Elem> {
+        self.units().into_generate_all_elements()
+    }
 }
 
 #[signature_meta_trait]
@@ -870,6 +928,46 @@ pub trait CharZeroFieldSignature: FieldSignature + CharZeroRingSignature {
 pub trait FiniteFieldSignature: FieldSignature + FiniteUnitsSignature + FiniteSetSignature {
     // Return (p, k) where p is a prime and |F| = p^k
     fn characteristic_and_power(&self) -> (Natural, Natural);
+
+    /// The order of a non-zero element `x` in the multiplicative group of the field.
+    ///
+    /// Starting from `|F| - 1`, each prime factor `q` is removed for as long as `x` raised to the remaining exponent divided by `q` is still `1`.
+    fn multiplicative_order(&self, x: &Self::Elem) -> Natural {
+        assert!(!self.is_zero(x));
+        let (p, k) = self.characteristic_and_power();
+        let group_order = p.pow(&k) - Natural::ONE;
+        let mut order = group_order.clone();
+        for q in group_order.factor().into_distinct_irreducibles().unwrap() {
+            while &order % &q == Natural::ZERO
+                && self.equal(&self.nat_pow(x, &(&order / &q)), &self.one())
+            {
+                order = &order / &q;
+            }
+        }
+        order
+    }
+
+    /// Whether `x` generates the multiplicative group of the field.
+    fn is_primitive_element(&self, x: &Self::Elem) -> bool {
+        if self.is_zero(x) {
+            return false;
+        }
+        let (p, k) = self.characteristic_and_power();
+        let group_order = p.pow(&k) - Natural::ONE;
+        group_order
+            .factor()
+            .into_distinct_irreducibles()
+            .unwrap()
+            .into_iter()
+            .all(|q| !self.equal(&self.nat_pow(x, &(&group_order / &q)), &self.one()))
+    }
+
+    /// A generator of the multiplicative group of the field.
+    fn primitive_element(&self) -> Self::Elem {
+        self.generate_all_elements()
+            .find(|x| self.is_primitive_element(x))
+            .unwrap()
+    }
 }
 
 //is a subset of the complex numbers
@@ -992,3 +1090,276 @@ pub trait FreeRingSignature: RingSignature {
         self.free_generators().len()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::num_theory::modulo::const_naive::Modulo;
+    use algebraeon_macros::CanonicalRingStructure;
+    use std::ops::{Add, Mul, Neg};
+
+    // the product ring `R x R`
+    #[derive(Debug, Clone, PartialEq, Eq, CanonicalRingStructure)]
+    #[canonical_structure(eq)]
+    struct Pair<T>(T, T);
+
+    impl<T: Add<Output = T>> Add for Pair<T> {
+        type Output = Self;
+        fn add(self, other: Self) -> Self {
+            Pair(self.0 + other.0, self.1 + other.1)
+        }
+    }
+
+    impl<T: Mul<Output = T>> Mul for Pair<T> {
+        type Output = Self;
+        fn mul(self, other: Self) -> Self {
+            Pair(self.0 * other.0, self.1 * other.1)
+        }
+    }
+
+    impl<T: Neg<Output = T>> Neg for Pair<T> {
+        type Output = Self;
+        fn neg(self) -> Self {
+            Pair(-self.0, -self.1)
+        }
+    }
+
+    impl<T: From<u8> + Clone> From<u8> for Pair<T> {
+        fn from(x: u8) -> Self {
+            Pair(T::from(x), T::from(x))
+        }
+    }
+
+    fn check_integer_conversions<RS: RingEqSignature>(ring: &RS) {
+        assert!(ring.is_zero(&ring.from_int(0)));
+        assert!(ring.is_one(&ring.from_int(1)));
+        assert!(ring.is_one(&ring.from_nat(1u32)));
+        assert!(!ring.is_one(&ring.zero()));
+        assert!(ring.is_zero(&ring.add(&ring.from_int(-1), &ring.one())));
+        for n in -20i32..20 {
+            let x = ring.from_int(n);
+            assert!(ring.equal(&ring.from_int(-n), &ring.neg(&x)));
+            assert!(ring.equal(&ring.from_int(n + 1), &ring.add(&x, &ring.one())));
+            assert!(ring.equal(&ring.from_int(n * 3), &ring.mul(&x, &ring.from_nat(3u32))));
+            if n >= 0 {
+                assert!(ring.equal(&x, &ring.from_nat(Natural::from(n.unsigned_abs()))));
+            }
+        }
+    }
+
+    #[test]
+    fn integer_conversions() {
+        check_integer_conversions(Integer::structure_ref());
+        check_integer_conversions(Rational::structure_ref());
+        check_integer_conversions(&Modulo::<7>::structure());
+        check_integer_conversions(
+            &Polynomial::<Rational>::from_coeffs(vec![-2, 0, 1])
+                .algebraic_number_field()
+                .unwrap(),
+        );
+
+        assert_eq!(Integer::from_int(-5), Integer::from(-5));
+        assert_eq!(Rational::from_nat(5u32), Rational::from(5));
+        assert_eq!(Modulo::<7>::from_int(-1), Modulo::<7>::from(6));
+        assert_eq!(Modulo::<7>::from_int(-15), Modulo::<7>::from(6));
+        assert_eq!(Modulo::<7>::from_nat(100u32), Modulo::<7>::from(2));
+        assert!(Modulo::<7>::from_int(8).is_one());
+        assert!(Modulo::<7>::from_int(14).is_zero());
+        assert!(Rational::ONE.is_one());
+        assert!(!Integer::from(-1).is_one());
+    }
+
+    #[test]
+    fn runtime_characteristic() {
+        assert_eq!(Integer::structure().runtime_characteristic(), Natural::ZERO);
+        assert_eq!(
+            Rational::structure().runtime_characteristic(),
+            Natural::ZERO
+        );
+        assert_eq!(
+            Modulo::<7>::structure().runtime_characteristic(),
+            Modulo::<7>::structure().characteristic()
+        );
+        assert_eq!(
+            Modulo::<65521>::structure().runtime_characteristic(),
+            Modulo::<65521>::structure().characteristic()
+        );
+        assert_eq!(
+            Modulo::<12>::structure().runtime_characteristic(),
+            Natural::from(12u32)
+        );
+        assert_eq!(
+            Polynomial::<Rational>::from_coeffs(vec![-2, 0, 1])
+                .algebraic_number_field()
+                .unwrap()
+                .runtime_characteristic(),
+            Natural::ZERO
+        );
+    }
+
+    #[test]
+    fn runtime_characteristic_with_limit() {
+        let limit = Natural::from(1u32 << 16);
+        assert_eq!(
+            Integer::structure().runtime_characteristic_with_limit(&limit),
+            None
+        );
+        assert_eq!(
+            Rational::structure().runtime_characteristic_with_limit(&limit),
+            None
+        );
+        assert_eq!(
+            Modulo::<7>::structure().runtime_characteristic_with_limit(&limit),
+            Some(Modulo::<7>::structure().characteristic())
+        );
+        assert_eq!(
+            Modulo::<65521>::structure().runtime_characteristic_with_limit(&limit),
+            Some(Modulo::<65521>::structure().characteristic())
+        );
+        // the characteristic may exceed the limit, in which case it is not found
+        assert_eq!(
+            Modulo::<65537>::structure().runtime_characteristic_with_limit(&limit),
+            None
+        );
+        // Z/12Z is not a field but still has a characteristic
+        assert_eq!(
+            Modulo::<12>::structure().runtime_characteristic_with_limit(&limit),
+            Some(Natural::from(12u32))
+        );
+        assert_eq!(
+            Modulo::<1>::structure().runtime_characteristic_with_limit(&limit),
+            Some(Natural::ONE)
+        );
+        assert_eq!(
+            Modulo::<65521>::structure().runtime_characteristic_with_limit(&Natural::from(1000u32)),
+            None
+        );
+        assert_eq!(
+            Modulo::<1000>::structure().runtime_characteristic_with_limit(&Natural::from(1000u32)),
+            Some(Natural::from(1000u32))
+        );
+        assert_eq!(
+            Polynomial::<Rational>::from_coeffs(vec![-2, 0, 1])
+                .algebraic_number_field()
+                .unwrap()
+                .runtime_characteristic_with_limit(&Natural::from(100u32)),
+            None
+        );
+    }
+
+    #[test]
+    fn sums_and_products() {
+        let ring = Integer::structure();
+        assert_eq!(
+            ring.sum_iter((1..=100).map(Integer::from)),
+            Integer::from(5050)
+        );
+        assert_eq!(
+            ring.product_iter((1..=10).map(Integer::from)),
+            Integer::from(3628800)
+        );
+        assert_eq!(ring.sum_iter(std::iter::empty()), Integer::ZERO);
+        assert_eq!(ring.product_iter(std::iter::empty()), Integer::ONE);
+        assert_eq!(
+            Integer::sum_iter((1..=4).map(|n| Integer::from(n * n))),
+            Integer::sum(&[1, 4, 9, 16].map(Integer::from))
+        );
+
+        // 1/1 + 1/2 + ... + 1/6 = 49/20
+        assert_eq!(
+            Rational::sum_iter((1..=6).map(|n| Rational::from_integers(1, n))),
+            Rational::from_integers(49, 20)
+        );
+
+        // (p - 1)! = -1 mod p
+        assert_eq!(
+            Modulo::<13>::product_iter((1..13usize).map(Modulo::from)),
+            Modulo::<13>::from_int(-1)
+        );
+
+        assert_eq!(
+            Rational::from(2).try_int_pow(&Integer::from(-3)),
+            Some(Rational::from_integers(1, 8))
+        );
+        assert_eq!(Integer::from(2).try_int_pow(&Integer::from(-3)), None);
+        assert_eq!(
+            Integer::from(-1).try_int_pow(&Integer::from(-3)),
+            Some(Integer::from(-1))
+        );
+        assert_eq!(
+            Integer::from(3).try_int_pow(&Integer::from(4)),
+            Some(Integer::from(81))
+        );
+    }
+
+    #[test]
+    fn units() {
+        let z = Integer::structure();
+        assert!(z.is_unit(&Integer::ONE));
+        assert!(z.is_unit(&Integer::from(-1)));
+        assert!(!z.is_unit(&Integer::from(2)));
+        assert!(!z.is_unit(&Integer::from(-7)));
+        assert!(!z.is_unit(&Integer::ZERO));
+        assert_eq!(
+            z.units_iter().collect::<Vec<_>>(),
+            vec![Integer::ONE, Integer::from(-1)]
+        );
+
+        // every non-zero element of a field is a unit
+        for n in -10..10 {
+            assert_eq!(Rational::from(n).is_unit(), n != 0);
+            assert!(Rational::from_integers(1, n * n + 1).is_unit());
+            assert_eq!(Modulo::<7>::from(n).is_unit(), n % 7 != 0);
+        }
+        let f7 = Modulo::<7>::structure();
+        assert_eq!(f7.units_iter().count(), 6);
+        assert!(f7.units_iter().all(|u| f7.is_unit(&u)));
+        assert_eq!(f7.units_iter().collect::<Vec<_>>(), f7.all_units());
+
+        // the units of Z/12Z are 1, 5, 7 and 11
+        assert_eq!(
+            (0usize..12)
+                .filter(|n| Modulo::<12>::from(*n).is_unit())
+                .collect::<Vec<_>>(),
+            vec![1, 5, 7, 11]
+        );
+    }
+
+    #[test]
+    fn canonical_ring_structure() {
+        let ring = Pair::<Integer>::structure();
+        assert_eq!(ring.zero(), Pair(Integer::ZERO, Integer::ZERO));
+        assert_eq!(ring.one(), Pair(Integer::ONE, Integer::ONE));
+        assert_eq!(
+            ring.from_int(-7),
+            Pair(Integer::from(-7), Integer::from(-7))
+        );
+
+        let a = Pair(Integer::from(2), Integer::from(-3));
+        let b = Pair(Integer::from(5), Integer::from(4));
+        assert_eq!(ring.add(&a, &b), Pair(Integer::from(7), Integer::from(1)));
+        assert_eq!(ring.sub(&a, &b), Pair(Integer::from(-3), Integer::from(-7)));
+        assert_eq!(
+            ring.mul(&a, &b),
+            Pair(Integer::from(10), Integer::from(-12))
+        );
+        assert_eq!(
+            Pair::nat_pow(&a, &Natural::from(3u8)),
+            Pair(Integer::from(8), Integer::from(-27))
+        );
+        assert!(ring.is_zero(&ring.add(&a, &ring.neg(&a))));
+        assert!(ring.equal(&ring.bracket(&a, &b), &ring.zero()));
+
+        // (1, 0) is a zero divisor
+        let e = Pair(Integer::ONE, Integer::ZERO);
+        assert_eq!(ring.mul(&e, &e), e);
+        assert!(ring.is_zero(&ring.mul(&e, &ring.sub(&ring.one(), &e))));
+
+        // and the same construction works over other rings
+        let ring = Pair::<Rational>::structure();
+        assert_eq!(
+            ring.sum(&[ring.one(), ring.one(), ring.from_int(-1)]),
+            ring.one()
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use algebraeon_structures::*;
+
+/// The binomial coefficient `n! / (k! (n-k)!)`, which is `0` when `k > n`.
+pub fn binomial(n: usize, k: usize) -> Natural {
+    choose(Natural::from(n), Natural::from(k))
+}
+
+/// The multinomial coefficient `n! / (k_1! k_2! ... k_r!)` where `parts = [k_1, k_2, ..., k_r]`.
+///
+/// Returns `None` if the parts do not sum to `n`.
+pub fn multinomial(n: usize, parts: &[usize]) -> Option<Natural> {
+    if parts.iter().sum::<usize>() != n {
+        return None;
+    }
+    // n! / (k_1! ... k_r!) = C(k_1, k_1) C(k_1 + k_2, k_2) ... C(n, k_r)
+    let mut m = Natural::ONE;
+    let mut total = 0;
+    for &k in parts {
+        total += k;
+        m *= binomial(total, k);
+    }
+    Some(m)
+}
+
+/// The Bernoulli number `B_n` with the convention `B_1 = -1/2`.
+///
+/// Computed using the recurrence `sum_{k=0}^{m} C(m+1, k) B_k = 0` for `m >= 1`.
+pub fn bernoulli_number(n: usize) -> Rational {
+    let mut bs = vec![Rational::ONE];
+    for m in 1..=n {
+        let mut s = Rational::ZERO;
+        for (k, b) in bs.iter().enumerate() {
+            s += Rational::from(binomial(m + 1, k)) * b;
+        }
+        bs.push(-s / Rational::from(m + 1));
+    }
+    bs.pop().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn binomial_values() {
+        assert_eq!(binomial(0, 0), Natural::ONE);
+        assert_eq!(binomial(5, 0), Natural::ONE);
+        assert_eq!(binomial(5, 2), Natural::from(10u32));
+        assert_eq!(binomial(5, 5), Natural::ONE);
+        assert_eq!(binomial(5, 6), Natural::ZERO);
+        assert_eq!(
+            binomial(100, 50),
+            Natural::from_str("100891344545564193334812497256").unwrap()
+        );
+
+        // Pascal's rule
+        for n in 1..20 {
+            for k in 1..=n {
+                assert_eq!(binomial(n, k), binomial(n - 1, k - 1) + binomial(n - 1, k));
+            }
+        }
+    }
+
+    #[test]
+    fn multinomial_values() {
+        assert_eq!(multinomial(0, &[]), Some(Natural::ONE));
+        assert_eq!(multinomial(4, &[2, 2]), Some(Natural::from(6u32)));
+        assert_eq!(multinomial(10, &[2, 3, 5]), Some(Natural::from(2520u32)));
+        assert_eq!(multinomial(10, &[2, 3, 4]), None);
+        assert_eq!(multinomial(7, &[7]), Some(Natural::ONE));
+
+        // n! / (k_1! ... k_r!)
+        let parts = [3, 0, 4, 1, 2];
+        assert_eq!(
+            multinomial(10, &parts).unwrap(),
+            Natural::from(10u32).factorial()
+                / parts
+                    .iter()
+                    .map(|k| Natural::from(*k).factorial())
+                    .product::<Natural>()
+        );
+    }
+
+    #[test]
+    fn bernoulli_numbers() {
+        let expected = [
+            Rational::from(1),
+            Rational::from_integers(-1, 2),
+            Rational::from_integers(1, 6),
+            Rational::from(0),
+            Rational::from_integers(-1, 30),
+            Rational::from(0),
+            Rational::from_integers(1, 42),
+            Rational::from(0),
+            Rational::from_integers(-1, 30),
+            Rational::from(0),
+        ];
+        for (n, b) in expected.into_iter().enumerate() {
+            assert_eq!(bernoulli_number(n), b);
+        }
+        assert_eq!(bernoulli_number(12), Rational::from_integers(-691, 2730));
+    }
+}
@@ -1,12 +1,17 @@
 //! Contains combinatorial counting and enumeration algorithms.
 
+mod binomial_coefficients;
 mod number_compositions;
 mod number_partitions;
+mod posets;
 mod set_partitions;
 mod stirling_numbers;
 mod subsets;
 mod twelvefold_way;
 
+pub use binomial_coefficients::bernoulli_number;
+pub use binomial_coefficients::binomial;
+pub use binomial_coefficients::multinomial;
 pub use number_compositions::compositions;
 pub use number_compositions::compositions_sized;
 pub use number_compositions::compositions_sized_zero;
@@ -17,6 +22,8 @@ pub use number_partitions::num_partitions_sized;
 pub use number_partitions::num_partitions_sized_predicated;
 pub use number_partitions::num_partitions_sized_zero;
 pub use number_partitions::num_partitions_sized_zero_predicated;
+pub use posets::AntiChain;
+pub use posets::Poset;
 pub use set_partitions::Partition;
 pub use set_partitions::set_compositions_eq;
 pub use set_partitions::set_partitions_eq;
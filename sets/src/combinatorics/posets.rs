@@ -0,0 +1,355 @@
+use algebraeon_structures::*;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// A finite partially ordered set.
+#[derive(Debug, Clone)]
+pub struct Poset<T: Eq + Hash> {
+    elements: Vec<T>,
+    // (i, j) is present when elements[i] < elements[j]
+    less_than: HashSet<(usize, usize)>,
+}
+
+/// A set of pairwise incomparable elements of a [`Poset`].
+#[derive(Debug, Clone)]
+pub struct AntiChain<'a, T: Eq + Hash> {
+    poset: &'a Poset<T>,
+    elements: Vec<usize>,
+}
+
+impl<T: Eq + Hash> Poset<T> {
+    /// `less_than` contains `(i, j)` when `elements[i] < elements[j]`.
+    pub fn new_unchecked(elements: Vec<T>, less_than: HashSet<(usize, usize)>) -> Self {
+        let poset = Self {
+            elements,
+            less_than,
+        };
+        debug_assert!(poset.is_valid());
+        poset
+    }
+
+    /// The poset on `elements` where `x <= y` whenever `le(x, y)`.
+    ///
+    /// `le` should be reflexive, antisymmetric and transitive.
+    pub fn from_relation(elements: Vec<T>, le: impl Fn(&T, &T) -> bool) -> Self {
+        let n = elements.len();
+        let mut less_than = HashSet::new();
+        for i in 0..n {
+            for j in 0..n {
+                if i != j && le(&elements[i], &elements[j]) {
+                    less_than.insert((i, j));
+                }
+            }
+        }
+        Self::new_unchecked(elements, less_than)
+    }
+
+    /// Whether the relation is irreflexive, antisymmetric and transitive, so that it is the strict order of a partial order.
+    pub fn is_valid(&self) -> bool {
+        let n = self.elements.len();
+        for &(i, j) in &self.less_than {
+            if i >= n || j >= n || i == j || self.less_than.contains(&(j, i)) {
+                return false;
+            }
+        }
+        for &(i, j) in &self.less_than {
+            for k in 0..n {
+                if self.less_than.contains(&(j, k)) && !self.less_than.contains(&(i, k)) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    pub fn elements(&self) -> impl Iterator<Item = &T> {
+        self.elements.iter()
+    }
+
+    fn index_of(&self, x: &T) -> usize {
+        self.elements
+            .iter()
+            .position(|e| e == x)
+            .expect("element not in the poset")
+    }
+
+    fn le_idx(&self, i: usize, j: usize) -> bool {
+        i == j || self.less_than.contains(&(i, j))
+    }
+
+    /// Whether `x < y`. Panics if either is not an element of the poset.
+    pub fn less_than(&self, x: &T, y: &T) -> bool {
+        self.less_than
+            .contains(&(self.index_of(x), self.index_of(y)))
+    }
+
+    /// Whether `x <= y`. Panics if either is not an element of the poset.
+    pub fn less_than_or_equal(&self, x: &T, y: &T) -> bool {
+        self.le_idx(self.index_of(x), self.index_of(y))
+    }
+
+    /// The pairs `(x, y)` such that `y` covers `x`, meaning `x < y` with nothing strictly between them. These are the edges of the Hasse diagram.
+    pub fn covers(&self) -> Vec<(&T, &T)> {
+        let n = self.elements.len();
+        self.less_than
+            .iter()
+            .filter(|(i, j)| {
+                !(0..n)
+                    .any(|k| self.less_than.contains(&(*i, k)) && self.less_than.contains(&(k, *j)))
+            })
+            .map(|(i, j)| (&self.elements[*i], &self.elements[*j]))
+            .collect()
+    }
+
+    /// The elements with nothing above them.
+    pub fn maximal_elements(&self) -> AntiChain<'_, T> {
+        AntiChain {
+            poset: self,
+            elements: (0..self.elements.len())
+                .filter(|i| !self.less_than.iter().any(|(a, _)| a == i))
+                .collect(),
+        }
+    }
+
+    /// The elements with nothing below them.
+    pub fn minimal_elements(&self) -> AntiChain<'_, T> {
+        AntiChain {
+            poset: self,
+            elements: (0..self.elements.len())
+                .filter(|i| !self.less_than.iter().any(|(_, b)| b == i))
+                .collect(),
+        }
+    }
+
+    /// Return the elements as an antichain if they are pairwise incomparable. Panics if any is not an element of the poset.
+    pub fn antichain<'a>(&'a self, elements: Vec<&T>) -> Option<AntiChain<'a, T>> {
+        let mut idxs = elements
+            .into_iter()
+            .map(|x| self.index_of(x))
+            .collect::<Vec<_>>();
+        idxs.sort_unstable();
+        idxs.dedup();
+        for &i in &idxs {
+            for &j in &idxs {
+                if self.less_than.contains(&(i, j)) {
+                    return None;
+                }
+            }
+        }
+        Some(AntiChain {
+            poset: self,
+            elements: idxs,
+        })
+    }
+
+    /// The matrix `Z` with `Z[i][j] = 1` when `elements[i] <= elements[j]` and `0` otherwise, as a list of rows.
+    pub fn zeta_matrix(&self) -> Vec<Vec<Integer>> {
+        let n = self.elements.len();
+        (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| {
+                        if self.le_idx(i, j) {
+                            Integer::ONE
+                        } else {
+                            Integer::ZERO
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The Möbius function `mu(x, y)`, defined by `mu(x, x) = 1`, `mu(x, y) = -sum_{x <= z < y} mu(x, z)` for `x < y`, and `mu(x, y) = 0` otherwise.
+    ///
+    /// Panics if either is not an element of the poset.
+    pub fn mobius_function(&self, x: &T, y: &T) -> Integer {
+        let (x, y) = (self.index_of(x), self.index_of(y));
+        if !self.le_idx(x, y) {
+            return Integer::ZERO;
+        }
+        // the interval [x, y] ordered by the number of elements below, which is a linear extension
+        let mut interval = (0..self.elements.len())
+            .filter(|z| self.le_idx(x, *z) && self.le_idx(*z, y))
+            .collect::<Vec<_>>();
+        interval.sort_by_key(|z| self.less_than.iter().filter(|(_, b)| b == z).count());
+        let mut mu: Vec<(usize, Integer)> = vec![];
+        for &z in &interval {
+            let value = if z == x {
+                Integer::ONE
+            } else {
+                -mu.iter()
+                    .filter(|(w, _)| self.less_than.contains(&(*w, z)))
+                    .map(|(_, m)| m.clone())
+                    .sum::<Integer>()
+            };
+            if z == y {
+                return value;
+            }
+            mu.push((z, value));
+        }
+        unreachable!()
+    }
+}
+
+impl<T: Eq + Hash> AntiChain<'_, T> {
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    pub fn elements(&self) -> impl Iterator<Item = &T> {
+        self.elements.iter().map(|i| &self.poset.elements[*i])
+    }
+
+    pub fn contains(&self, x: &T) -> bool {
+        self.elements.iter().any(|i| &self.poset.elements[*i] == x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number_theoretic_mobius(mut n: usize) -> Integer {
+        let mut mu = Integer::ONE;
+        let mut p = 2;
+        while p * p <= n {
+            if n.is_multiple_of(p) {
+                n /= p;
+                if n.is_multiple_of(p) {
+                    return Integer::ZERO;
+                }
+                mu = -mu;
+            }
+            p += 1;
+        }
+        if n > 1 {
+            mu = -mu;
+        }
+        mu
+    }
+
+    #[test]
+    fn divisor_lattice() {
+        let n = 360usize;
+        let divisors = (1..=n).filter(|d| n.is_multiple_of(*d)).collect::<Vec<_>>();
+        let poset = Poset::from_relation(divisors.clone(), |a, b| b.is_multiple_of(*a));
+        assert!(poset.is_valid());
+        assert_eq!(poset.len(), 24);
+        assert_eq!(
+            poset.minimal_elements().elements().collect::<Vec<_>>(),
+            vec![&1]
+        );
+        assert_eq!(
+            poset.maximal_elements().elements().collect::<Vec<_>>(),
+            vec![&n]
+        );
+
+        // mu(a, b) is the number theoretic Mobius function of b / a
+        for a in &divisors {
+            for b in &divisors {
+                let expected = if b.is_multiple_of(*a) {
+                    number_theoretic_mobius(b / a)
+                } else {
+                    Integer::ZERO
+                };
+                assert_eq!(poset.mobius_function(a, b), expected);
+            }
+        }
+
+        // y covers x exactly when y / x is prime
+        for (x, y) in poset.covers() {
+            assert!(y.is_multiple_of(*x));
+            let q = y / x;
+            assert!((2..q).all(|d| !q.is_multiple_of(d)));
+        }
+        // 360 = 2^3 3^2 5 has 24 divisors and each prime power p^e contributes 24 e / (e + 1) edges to the Hasse diagram
+        assert_eq!(poset.covers().len(), 18 + 16 + 12);
+
+        assert!(poset.antichain(vec![&8, &9, &5]).is_some());
+        assert!(poset.antichain(vec![&8, &4]).is_none());
+    }
+
+    #[test]
+    fn face_lattice_of_simplex() {
+        // the faces of the simplex with vertices 0, ..., n - 1 including the empty face are the subsets, ordered by inclusion
+        for n in 0..5usize {
+            let faces = (0..(1u32 << n)).collect::<Vec<_>>();
+            let poset = Poset::from_relation(faces.clone(), |a, b| a & b == *a);
+            assert!(poset.is_valid());
+            assert_eq!(poset.len(), 1 << n);
+
+            let minimal = poset.minimal_elements();
+            assert_eq!(minimal.len(), 1);
+            assert!(minimal.contains(&0));
+            let maximal = poset.maximal_elements();
+            assert_eq!(maximal.len(), 1);
+            assert!(maximal.contains(&((1 << n) - 1)));
+
+            // each face of dimension k - 1 is covered by n - k faces
+            assert_eq!(poset.covers().len(), n * (1 << n) / 2);
+            for (a, b) in poset.covers() {
+                assert_eq!(b.count_ones(), a.count_ones() + 1);
+            }
+
+            // the zeta matrix counts the pairs of nested faces
+            let zeta = poset.zeta_matrix();
+            let ones = zeta
+                .iter()
+                .flatten()
+                .filter(|z| **z == Integer::ONE)
+                .count();
+            assert_eq!(ones, 3usize.pow(n as u32));
+
+            for a in &faces {
+                for b in &faces {
+                    let expected = if a & b == *a {
+                        if (b ^ a).count_ones() % 2 == 0 {
+                            Integer::ONE
+                        } else {
+                            -Integer::ONE
+                        }
+                    } else {
+                        Integer::ZERO
+                    };
+                    assert_eq!(poset.mobius_function(a, b), expected);
+                }
+            }
+
+            // the vertices form an antichain
+            let vertices = (0..n).map(|i| 1u32 << i).collect::<Vec<_>>();
+            assert_eq!(poset.antichain(vertices.iter().collect()).unwrap().len(), n);
+        }
+    }
+
+    #[test]
+    fn invalid_relations() {
+        let poset = Poset {
+            elements: vec![0, 1, 2],
+            less_than: HashSet::from([(0, 1), (1, 2)]),
+        };
+        assert!(!poset.is_valid());
+        let poset = Poset {
+            elements: vec![0, 1],
+            less_than: HashSet::from([(0, 1), (1, 0)]),
+        };
+        assert!(!poset.is_valid());
+        let poset = Poset {
+            elements: vec![0, 1],
+            less_than: HashSet::from([(0, 0)]),
+        };
+        assert!(!poset.is_valid());
+    }
+}
@@ -302,6 +302,162 @@ impl Integer {
             self.abs().is_square()
         }
     }
+
+    /// The representation in base `base`, using the digits `0-9` followed by `a-z` and a leading `-` when negative.
+    ///
+    /// Panics unless `2 <= base <= 36`.
+    pub fn to_string_base(&self, base: u32) -> String {
+        use malachite::base::num::conversion::traits::ToStringBase;
+        assert!((2..=36).contains(&base), "base out of range");
+        self.0.to_string_base(base as u8)
+    }
+
+    /// The number of digits of the absolute value in base `base`, taking zero to have one digit.
+    ///
+    /// Panics if `base < 2`.
+    pub fn digit_count(&self, base: u32) -> usize {
+        self.abs().digit_count(base)
+    }
+
+    /// Return `(d, x, y)` such that `a*x + b*y = d` where `d >= 0` is the greatest common divisor of `a` and `b`.
+    pub fn extended_gcd(a: &Integer, b: &Integer) -> (Integer, Integer, Integer) {
+        let (mut r0, mut r1) = (a.clone(), b.clone());
+        let (mut x0, mut x1) = (Integer::ONE, Integer::ZERO);
+        let (mut y0, mut y1) = (Integer::ZERO, Integer::ONE);
+        while r1 != Integer::ZERO {
+            let q = &r0 / &r1;
+            (r0, r1) = (r1.clone(), &r0 - &q * &r1);
+            (x0, x1) = (x1.clone(), &x0 - &q * &x1);
+            (y0, y1) = (y1.clone(), &y0 - &q * &y1);
+        }
+        if r0 < Integer::ZERO {
+            (-r0, -x0, -y0)
+        } else {
+            (r0, x0, y0)
+        }
+    }
+
+    /// Compute `self^exp mod modulus`, returning a value in `[0, modulus)`.
+    ///
+    /// This reduces `self` into `[0, modulus)` and then applies [`ModPow::mod_pow`].
+    ///
+    /// # Panics
+    ///
+    /// If `modulus` is zero.
+    pub fn pow_mod(&self, exp: &Natural, modulus: &Natural) -> Natural {
+        assert_ne!(*modulus, Natural::ZERO, "modulus must be non-zero");
+        (self % modulus).mod_pow(exp, modulus)
+    }
+
+    /// The inverse of `a` modulo `n` as a value in `[0, n)`, or `None` if `a` and `n` are not coprime.
+    ///
+    /// This reduces `a` into `[0, n)` and then applies [`ModInv::mod_inv`].
+    /// ```
+    /// use algebraeon_structures::{Integer, Natural};
+    /// assert_eq!(
+    ///     Integer::modular_inverse(&Integer::from(-3), &Natural::from(7u32)),
+    ///     Some(Natural::from(2u32)),
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `n` is zero.
+    pub fn modular_inverse(a: &Integer, n: &Natural) -> Option<Natural> {
+        assert_ne!(*n, Natural::ZERO, "modulus must be non-zero");
+        let a = a % n;
+        // mod_inv requires a non-zero argument, and 0 is invertible only modulo 1
+        if a == Natural::ZERO {
+            return (*n == Natural::ONE).then_some(Natural::ZERO);
+        }
+        a.mod_inv(n)
+    }
+
+    /// The non-negative greatest common divisor of `a` and `b`
+    pub fn gcd(a: &Integer, b: &Integer) -> Integer {
+        Integer::from(gcd(a.abs(), b.abs()))
+    }
+
+    /// The non-negative least common multiple of `a` and `b`
+    pub fn lcm(a: &Integer, b: &Integer) -> Integer {
+        Integer::from(lcm(a.abs(), b.abs()))
+    }
+
+    /// The non-negative greatest common divisor of all of `items`, which is `0` if `items` is empty
+    pub fn gcd_list(items: &[Integer]) -> Integer {
+        items.iter().fold(Integer::ZERO, |g, x| Integer::gcd(&g, x))
+    }
+
+    /// The non-negative least common multiple of all of `items`, which is `1` if `items` is empty
+    pub fn lcm_list(items: &[Integer]) -> Integer {
+        items.iter().fold(Integer::ONE, |l, x| Integer::lcm(&l, x))
+    }
+
+    /// Solve `x = a mod m` and `x = b mod n` where the moduli need not be coprime.
+    ///
+    /// Returns `(x, l)` where `l` is the least common multiple of `m` and `n` and the solutions are exactly the integers congruent to `x` modulo `l`, or `None` if there are no solutions.
+    /// When `l` is non-zero `x` satisfies `0 <= x < l`.
+    pub fn two_residues_crt(
+        a: &Integer,
+        m: &Natural,
+        b: &Integer,
+        n: &Natural,
+    ) -> Option<(Integer, Natural)> {
+        // a zero modulus determines x exactly, so it only remains to check the other congruence
+        let congruent = |x: &Integer, y: &Integer, k: &Natural| {
+            if *k == Natural::ZERO {
+                x == y
+            } else {
+                (x - y) % k == Natural::ZERO
+            }
+        };
+        if *m == Natural::ZERO {
+            return congruent(a, b, n).then(|| (a.clone(), Natural::ZERO));
+        }
+        if *n == Natural::ZERO {
+            return congruent(b, a, m).then(|| (b.clone(), Natural::ZERO));
+        }
+        let m = Integer::from(m);
+        let n = Integer::from(n);
+        // u m + v n = g
+        let (g, u, _v) = Integer::extended_gcd(&m, &n);
+        let d = b - a;
+        if &d % &g != Integer::ZERO {
+            return None;
+        }
+        // x = a + m k where m k = b - a mod n, that is, (m/g) k = (b - a)/g mod n/g
+        let n_g = &n / &g;
+        let k = (d / &g * u) % &n_g;
+        let l = &m * &n_g;
+        let mut x = a + m * k;
+        if l != Integer::ZERO {
+            x = x % &l;
+        }
+        Some((x, l.abs()))
+    }
+
+    /// Solve the simultaneous congruences `x = a_i mod m_i` for `(a_i, m_i)` in `residues` where the moduli need not be coprime.
+    ///
+    /// Returns `(x, l)` where `l` is the least common multiple of the `m_i` and the solutions are exactly the integers congruent to `x` modulo `l`, or `None` if there are no solutions.
+    /// When `l` is non-zero `x` satisfies `0 <= x < l`.
+    /// ```
+    /// use algebraeon_structures::{Integer, Natural};
+    /// assert_eq!(
+    ///     Integer::chinese_remainder(&[
+    ///         (Integer::from(2), Natural::from(3u32)),
+    ///         (Integer::from(3), Natural::from(5u32)),
+    ///         (Integer::from(2), Natural::from(7u32)),
+    ///     ]),
+    ///     Some((Integer::from(23), Natural::from(105u32))),
+    /// );
+    /// ```
+    pub fn chinese_remainder(residues: &[(Integer, Natural)]) -> Option<(Integer, Natural)> {
+        residues
+            .iter()
+            .try_fold((Integer::ZERO, Natural::ONE), |(x, l), (a, m)| {
+                Integer::two_residues_crt(&x, &l, a, m)
+            })
+    }
 }
 
 impl PartialEq<Natural> for Integer {
@@ -675,6 +831,177 @@ impl CountableSetSignature for IntegerCanonicalStructure {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_pow_mod_and_modular_inverse() {
+        let m = Natural::from(101u32);
+        assert_eq!(
+            Integer::from(2).pow_mod(&Natural::from(100u32), &m),
+            Natural::ONE
+        );
+        assert_eq!(
+            Integer::from(-2).pow_mod(&Natural::from(3u32), &m),
+            Natural::from(93u32)
+        );
+        assert_eq!(
+            Integer::from(3).pow_mod(&Natural::ZERO, &Natural::from(5u32)),
+            Natural::ONE
+        );
+
+        for n in 1u32..30 {
+            let n_nat = Natural::from(n);
+            for a in -40i32..40 {
+                let a = Integer::from(a);
+                match Integer::modular_inverse(&a, &n_nat) {
+                    Some(inv) => {
+                        assert!(inv < n_nat);
+                        assert_eq!((&a * Integer::from(inv)) % &n_nat, Natural::ONE % &n_nat);
+                    }
+                    None => {
+                        assert_ne!(Integer::gcd(&a, &Integer::from(&n_nat)), Integer::ONE);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_chinese_remainder() {
+        assert_eq!(
+            Integer::chinese_remainder(&[
+                (Integer::from(2), Natural::from(3u32)),
+                (Integer::from(3), Natural::from(5u32)),
+                (Integer::from(2), Natural::from(7u32)),
+            ]),
+            Some((Integer::from(23), Natural::from(105u32)))
+        );
+        assert_eq!(
+            Integer::chinese_remainder(&[]),
+            Some((Integer::ZERO, Natural::ONE))
+        );
+        // negative residues and residues larger than the modulus
+        assert_eq!(
+            Integer::chinese_remainder(&[
+                (Integer::from(-1), Natural::from(4u32)),
+                (Integer::from(13), Natural::from(9u32)),
+            ]),
+            Some((Integer::from(31), Natural::from(36u32)))
+        );
+        // moduli which are not coprime
+        assert_eq!(
+            Integer::chinese_remainder(&[
+                (Integer::from(3), Natural::from(4u32)),
+                (Integer::from(5), Natural::from(6u32)),
+            ]),
+            Some((Integer::from(11), Natural::from(12u32)))
+        );
+        assert_eq!(
+            Integer::chinese_remainder(&[
+                (Integer::from(1), Natural::from(4u32)),
+                (Integer::from(2), Natural::from(6u32)),
+            ]),
+            None
+        );
+        // zero moduli
+        assert_eq!(
+            Integer::chinese_remainder(&[(Integer::from(5), Natural::ZERO)]),
+            Some((Integer::from(5), Natural::ZERO))
+        );
+        assert_eq!(
+            Integer::chinese_remainder(&[
+                (Integer::from(2), Natural::from(3u32)),
+                (Integer::from(-7), Natural::ZERO),
+                (Integer::from(1), Natural::from(4u32)),
+            ]),
+            Some((Integer::from(-7), Natural::ZERO))
+        );
+        assert_eq!(
+            Integer::chinese_remainder(&[
+                (Integer::from(-7), Natural::ZERO),
+                (Integer::from(1), Natural::from(3u32)),
+            ]),
+            None
+        );
+        assert_eq!(
+            Integer::two_residues_crt(
+                &Integer::from(4),
+                &Natural::ZERO,
+                &Integer::from(4),
+                &Natural::ZERO
+            ),
+            Some((Integer::from(4), Natural::ZERO))
+        );
+        assert_eq!(
+            Integer::two_residues_crt(
+                &Integer::from(4),
+                &Natural::ZERO,
+                &Integer::from(5),
+                &Natural::ZERO
+            ),
+            None
+        );
+        assert_eq!(
+            Integer::two_residues_crt(
+                &Integer::from(7),
+                &Natural::from(10u32),
+                &Integer::from(17),
+                &Natural::from(10u32)
+            ),
+            Some((Integer::from(7), Natural::from(10u32)))
+        );
+        // a zero modulus fixes the value exactly
+        assert_eq!(
+            Integer::two_residues_crt(
+                &Integer::from(-8),
+                &Natural::ZERO,
+                &Integer::from(1),
+                &Natural::from(3u32)
+            ),
+            Some((Integer::from(-8), Natural::ZERO))
+        );
+        assert_eq!(
+            Integer::two_residues_crt(
+                &Integer::from(-8),
+                &Natural::ZERO,
+                &Integer::from(0),
+                &Natural::from(3u32)
+            ),
+            None
+        );
+        assert_eq!(
+            Integer::two_residues_crt(
+                &Integer::from(5),
+                &Natural::ZERO,
+                &Integer::from(5),
+                &Natural::ZERO
+            ),
+            Some((Integer::from(5), Natural::ZERO))
+        );
+
+        // exhaustive check against brute force for small moduli
+        for m in 1u32..13 {
+            for n in 1u32..13 {
+                for a in 0..m {
+                    for b in 0..n {
+                        let brute = (0..m * n).find(|x| x % m == a && x % n == b);
+                        let crt = Integer::two_residues_crt(
+                            &Integer::from(a),
+                            &Natural::from(m),
+                            &Integer::from(b),
+                            &Natural::from(n),
+                        );
+                        match brute {
+                            Some(x) => assert_eq!(
+                                crt,
+                                Some((Integer::from(x), Natural::lcm(&m.into(), &n.into())))
+                            ),
+                            None => assert_eq!(crt, None),
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_latex_and_typst() {
         assert_eq!(Integer::ZERO.latex(), "0");
@@ -866,4 +1193,60 @@ mod tests {
             Err(())
         );
     }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b, d) in [
+            (240, 46, 2),
+            (-240, 46, 2),
+            (240, -46, 2),
+            (-240, -46, 2),
+            (17, 5, 1),
+            (0, 7, 7),
+            (7, 0, 7),
+            (0, -7, 7),
+            (0, 0, 0),
+            (12, 12, 12),
+        ] {
+            let (a, b, d) = (Integer::from(a), Integer::from(b), Integer::from(d));
+            let (g, x, y) = Integer::extended_gcd(&a, &b);
+            assert_eq!(g, d);
+            assert_eq!(&a * &x + &b * &y, g);
+            assert_eq!(Integer::gcd(&a, &b), d);
+        }
+    }
+
+    #[test]
+    fn test_gcd_and_lcm_list() {
+        assert_eq!(
+            Integer::lcm(&Integer::from(-4), &Integer::from(6)),
+            Integer::from(12)
+        );
+        assert_eq!(
+            Integer::lcm(&Integer::from(0), &Integer::from(6)),
+            Integer::from(0)
+        );
+
+        let items = [12, -18, 30, 42].map(Integer::from);
+        assert_eq!(Integer::gcd_list(&items), Integer::from(6));
+        assert_eq!(
+            Integer::gcd_list(&items),
+            Integer::gcd(
+                &Integer::gcd(&Integer::gcd(&items[0], &items[1]), &items[2]),
+                &items[3]
+            )
+        );
+        assert_eq!(Integer::lcm_list(&items), Integer::from(1260));
+        assert_eq!(Integer::gcd_list(&[]), Integer::from(0));
+        assert_eq!(Integer::lcm_list(&[]), Integer::from(1));
+    }
+
+    #[test]
+    fn test_string_base() {
+        assert_eq!(Integer::from(-255).to_string_base(16), "-ff");
+        assert_eq!(Integer::from(10).to_string_base(3), "101");
+        assert_eq!(Integer::from(-255).digit_count(16), 2);
+        assert_eq!(Integer::from(-1000).digit_count(10), 4);
+        assert_eq!(Integer::ZERO.digit_count(2), 1);
+    }
 }
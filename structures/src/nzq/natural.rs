@@ -887,6 +887,43 @@ impl Natural {
                 .checked_sub(other.to_malachite_ref())?,
         ))
     }
+
+    /// The representation in base `base`, using the digits `0-9` followed by `a-z`.
+    /// ```
+    /// use algebraeon_structures::Natural;
+    /// assert_eq!(Natural::from(255u32).to_string_base(16), "ff");
+    /// ```
+    /// Panics unless `2 <= base <= 36`.
+    pub fn to_string_base(&self, base: u32) -> String {
+        use malachite::base::num::conversion::traits::ToStringBase;
+        assert!((2..=36).contains(&base), "base out of range");
+        self.0.to_string_base(base as u8)
+    }
+
+    /// Parse a representation in base `base` as produced by [`Natural::to_string_base`]. Upper case digits are also accepted.
+    ///
+    /// Panics unless `2 <= base <= 36`.
+    #[allow(clippy::result_unit_err)]
+    pub fn from_string_base(s: &str, base: u32) -> Result<Self, ()> {
+        use malachite::base::num::conversion::traits::FromStringBase;
+        assert!((2..=36).contains(&base), "base out of range");
+        Ok(Self(
+            malachite::natural::Natural::from_string_base(base as u8, s).ok_or(())?,
+        ))
+    }
+
+    /// The number of digits in base `base`, taking zero to have one digit.
+    ///
+    /// Panics if `base < 2`.
+    pub fn digit_count(&self, base: u32) -> usize {
+        use malachite::base::num::arithmetic::traits::FloorLogBase;
+        assert!(base >= 2, "base out of range");
+        if self == &Self::ZERO {
+            1
+        } else {
+            (&self.0).floor_log_base(&malachite::natural::Natural::from(base)) as usize + 1
+        }
+    }
 }
 
 impl CountableSetSignature for NaturalCanonicalStructure {
@@ -1079,4 +1116,41 @@ mod tests {
         );
         assert!(Natural::from(2u16).try_sub(&Natural::from(3u16)).is_none());
     }
+
+    #[test]
+    fn test_string_base() {
+        assert_eq!(Natural::from(255u32).to_string_base(16), "ff");
+        assert_eq!(Natural::from(255u32).to_string_base(2), "11111111");
+        assert_eq!(Natural::ZERO.to_string_base(7), "0");
+        assert_eq!(
+            Natural::from_string_base("FF", 16),
+            Ok(Natural::from(255u32))
+        );
+        assert_eq!(Natural::from_string_base("z", 36), Ok(Natural::from(35u32)));
+        assert_eq!(Natural::from_string_base("12", 2), Err(()));
+        assert_eq!(Natural::from_string_base("", 10), Err(()));
+        assert_eq!(Natural::from_string_base("-1", 10), Err(()));
+
+        assert_eq!(Natural::from(256u32).bitcount(), 9);
+        assert_eq!(Natural::from(255u32).bitcount(), 8);
+        assert_eq!(Natural::from(255u32).digit_count(16), 2);
+        assert_eq!(Natural::from(256u32).digit_count(16), 3);
+        assert_eq!(Natural::from(999u32).digit_count(10), 3);
+        assert_eq!(Natural::from(1000u32).digit_count(10), 4);
+        assert_eq!(Natural::ZERO.digit_count(10), 1);
+
+        let mut seed = 12345u64;
+        for _ in 0..100 {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let n = Natural::from(seed) * Natural::from(seed >> 7);
+            let s = n.to_string_base(3);
+            assert!(s.chars().all(|c| matches!(c, '0' | '1' | '2')));
+            assert_eq!(s.len(), n.digit_count(3));
+            assert_eq!(Natural::from_string_base(&s, 3), Ok(n.clone()));
+            assert_eq!(n.to_string_base(2).len(), n.bitcount());
+            assert_eq!(n.to_string_base(10), format!("{}", n));
+        }
+    }
 }
@@ -6,6 +6,35 @@ pub fn primes() -> impl Iterator<Item = usize> {
     usize::primes()
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IsPowerTestResult {
+    Zero,
+    One,
+    Power(Natural, usize),
+    No,
+}
+
+/// Returns n=a^p where a is as large as possible and p is prime
+pub fn is_power_test(n: &Natural) -> IsPowerTestResult {
+    if *n == Natural::ZERO {
+        IsPowerTestResult::Zero
+    } else if *n == Natural::ONE {
+        IsPowerTestResult::One
+    } else {
+        // The largest power n can possibly be is when n is a power of 2 and n = 2^{bitcount(n)+1}
+        // So we only need to check n isn't a kth power up to k = bitcount(n)+1
+        // We also only need to check for prime k
+        let max_k = n.bitcount() + 1;
+        for k in primes().take_while(|&k| k <= max_k) {
+            let a = n.nth_root_floor(&k.into());
+            if *n == a.pow(&k.into()) {
+                return IsPowerTestResult::Power(a, k);
+            }
+        }
+        IsPowerTestResult::No
+    }
+}
+
 impl Natural {
     /// Factorial
     /// ```
@@ -79,8 +108,11 @@ impl Natural {
         } else if x == &Natural::ZERO {
             Natural::ZERO
         } else {
+            // x < 2^bits so the root is less than 2^ceil(bits/n)
+            let bits = Natural::from(x.bitcount());
+            let e: usize = ((bits + n - Natural::ONE) / n).try_into().unwrap();
             let mut a = Natural::ONE;
-            let mut b = x.clone();
+            let mut b = Natural::power_of_2(e as u64);
             while &a + &Natural::ONE < b {
                 let m = (&a + &b) / Natural::TWO;
                 if m.pow(n) <= *x {
@@ -168,6 +200,28 @@ impl Natural {
     pub fn is_square(&self) -> bool {
         self.sqrt_if_square().is_some()
     }
+
+    /// If `self` is `b^k` for some `k >= 2` return `(b, k)` with `k` as large as possible.
+    /// `0` and `1` are not considered to be perfect powers.
+    /// ```
+    /// use algebraeon_structures::Natural;
+    /// assert_eq!(
+    ///     Natural::from(8u32).is_perfect_power(),
+    ///     Some((Natural::from(2u32), 3)),
+    /// );
+    /// assert_eq!(Natural::from(12u32).is_perfect_power(), None);
+    /// ```
+    pub fn is_perfect_power(&self) -> Option<(Natural, u32)> {
+        // if self = b^k with b not a perfect power then is_power_test finds b^(k/p) for the smallest prime p dividing k
+        // so taking prime roots until there are none left finds b and multiplies the primes up to k
+        let mut b = self.clone();
+        let mut k = 1u32;
+        while let IsPowerTestResult::Power(a, p) = is_power_test(&b) {
+            b = a;
+            k *= u32::try_from(p).unwrap();
+        }
+        (k >= 2).then_some((b, k))
+    }
 }
 
 pub fn choose(a: impl Borrow<Natural>, b: impl Borrow<Natural>) -> Natural {
@@ -195,10 +249,68 @@ pub fn gcd(mut x: Natural, mut y: Natural) -> Natural {
 
 /// The least common multiple of `x` and `y`
 pub fn lcm(x: Natural, y: Natural) -> Natural {
+    if x == Natural::ZERO || y == Natural::ZERO {
+        return Natural::ZERO;
+    }
     let g = gcd(x.clone(), y.clone());
     x * (y / g)
 }
 
+impl Natural {
+    /// Compute `self^exp mod modulus`, returning a value in `[0, modulus)`.
+    ///
+    /// This is [`ModPow::mod_pow`] taking its arguments by reference.
+    /// ```
+    /// use algebraeon_structures::Natural;
+    /// assert_eq!(
+    ///     Natural::from(3u32).pow_mod(&Natural::from(4u32), &Natural::from(5u32)),
+    ///     Natural::from(1u32),
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `modulus` is zero.
+    pub fn pow_mod(&self, exp: &Natural, modulus: &Natural) -> Natural {
+        assert_ne!(*modulus, Natural::ZERO, "modulus must be non-zero");
+        self.mod_pow(exp, modulus)
+    }
+
+    /// The greatest common divisor of `a` and `b`
+    pub fn gcd(a: &Natural, b: &Natural) -> Natural {
+        gcd(a.clone(), b.clone())
+    }
+
+    /// The least common multiple of `a` and `b`
+    pub fn lcm(a: &Natural, b: &Natural) -> Natural {
+        lcm(a.clone(), b.clone())
+    }
+
+    /// The greatest common divisor of all of `items`, which is `0` if `items` is empty
+    /// ```
+    /// use algebraeon_structures::Natural;
+    /// assert_eq!(
+    ///     Natural::gcd_list(&[Natural::from(12u32), Natural::from(18u32), Natural::from(30u32)]),
+    ///     Natural::from(6u32),
+    /// );
+    /// ```
+    pub fn gcd_list(items: &[Natural]) -> Natural {
+        items.iter().fold(Natural::ZERO, |g, x| gcd(g, x.clone()))
+    }
+
+    /// The least common multiple of all of `items`, which is `1` if `items` is empty
+    /// ```
+    /// use algebraeon_structures::Natural;
+    /// assert_eq!(
+    ///     Natural::lcm_list(&[Natural::from(4u32), Natural::from(6u32), Natural::from(10u32)]),
+    ///     Natural::from(60u32),
+    /// );
+    /// ```
+    pub fn lcm_list(items: &[Natural]) -> Natural {
+        items.iter().fold(Natural::ONE, |l, x| lcm(l, x.clone()))
+    }
+}
+
 // fn stirling_partition_number(n: &Natural, x: &Natural) -> Natural {
 //     todo!()
 // }
@@ -211,6 +323,153 @@ pub fn lcm(x: Natural, y: Natural) -> Natural {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_is_power_test() {
+        assert_eq!(
+            is_power_test(&Natural::from(0usize)),
+            IsPowerTestResult::Zero
+        );
+        assert_eq!(
+            is_power_test(&Natural::from(1usize)),
+            IsPowerTestResult::One
+        );
+        assert_eq!(is_power_test(&Natural::from(2usize)), IsPowerTestResult::No);
+        assert_eq!(is_power_test(&Natural::from(3usize)), IsPowerTestResult::No);
+        assert_eq!(
+            is_power_test(&Natural::from(4usize)),
+            IsPowerTestResult::Power(Natural::from(2usize), 2)
+        );
+        assert_eq!(is_power_test(&Natural::from(5usize)), IsPowerTestResult::No);
+        assert_eq!(is_power_test(&Natural::from(6usize)), IsPowerTestResult::No);
+        assert_eq!(is_power_test(&Natural::from(7usize)), IsPowerTestResult::No);
+        assert_eq!(
+            is_power_test(&Natural::from(8usize)),
+            IsPowerTestResult::Power(Natural::from(2usize), 3)
+        );
+        assert_eq!(
+            is_power_test(&Natural::from(9usize)),
+            IsPowerTestResult::Power(Natural::from(3usize), 2)
+        );
+        assert_eq!(
+            is_power_test(&Natural::from(10usize)),
+            IsPowerTestResult::No
+        );
+
+        println!("{:?}", is_power_test(&Natural::from(0usize)));
+    }
+
+    #[test]
+    fn test_roots() {
+        assert_eq!(Natural::from(8u32).sqrt_floor(), Natural::from(2u32));
+        assert_eq!(Natural::from(9u32).sqrt_floor(), Natural::from(3u32));
+        assert_eq!(Natural::from(0u32).sqrt_floor(), Natural::from(0u32));
+        assert_eq!(Natural::from(1u32).sqrt_floor(), Natural::from(1u32));
+        assert_eq!(Natural::from(3u32).sqrt_floor(), Natural::from(1u32));
+        assert!(Natural::from(9u32).is_square());
+        assert!(Natural::from(0u32).is_square());
+        assert!(!Natural::from(8u32).is_square());
+
+        for n in 0u32..300 {
+            let n = Natural::from(n);
+            for k in 1u32..6 {
+                let k = Natural::from(k);
+                let r = n.nth_root_floor(&k);
+                assert!(r.pow(&k) <= n);
+                assert!((&r + Natural::ONE).pow(&k) > n);
+            }
+        }
+
+        // large values around a perfect power
+        let x = Natural::from(1234567891u64).pow(&Natural::from(7u32));
+        let k = Natural::from(7u32);
+        assert_eq!(x.nth_root_floor(&k), Natural::from(1234567891u64));
+        assert_eq!(
+            (&x - Natural::ONE).nth_root_floor(&k),
+            Natural::from(1234567890u64)
+        );
+        assert_eq!(
+            (&x + Natural::ONE).nth_root_floor(&k),
+            Natural::from(1234567891u64)
+        );
+    }
+
+    #[test]
+    fn test_is_perfect_power() {
+        assert_eq!(
+            Natural::from(8u32).is_perfect_power(),
+            Some((Natural::from(2u32), 3))
+        );
+        assert_eq!(
+            Natural::from(64u32).is_perfect_power(),
+            Some((Natural::from(2u32), 6))
+        );
+        assert_eq!(
+            Natural::from(4u32).is_perfect_power(),
+            Some((Natural::from(2u32), 2))
+        );
+        assert_eq!(
+            Natural::from(36u32).is_perfect_power(),
+            Some((Natural::from(6u32), 2))
+        );
+        assert_eq!(Natural::from(0u32).is_perfect_power(), None);
+        assert_eq!(Natural::from(1u32).is_perfect_power(), None);
+        assert_eq!(Natural::from(2u32).is_perfect_power(), None);
+        assert_eq!(Natural::from(72u32).is_perfect_power(), None);
+        let x = Natural::from(6u32).pow(&Natural::from(60u32));
+        assert_eq!(x.is_perfect_power(), Some((Natural::from(6u32), 60)));
+        assert_eq!((x + Natural::ONE).is_perfect_power(), None);
+    }
+
+    #[test]
+    fn test_gcd_and_lcm_lists() {
+        let n = |x: u32| Natural::from(x);
+        assert_eq!(Natural::gcd_list(&[n(12), n(18), n(30)]), n(6));
+        assert_eq!(Natural::lcm_list(&[n(4), n(6), n(10)]), n(60));
+        assert_eq!(Natural::gcd_list(&[]), n(0));
+        assert_eq!(Natural::lcm_list(&[]), n(1));
+        assert_eq!(Natural::gcd_list(&[n(0), n(0)]), n(0));
+        assert_eq!(Natural::gcd_list(&[n(0), n(15)]), n(15));
+        assert_eq!(Natural::lcm_list(&[n(7), n(0)]), n(0));
+        assert_eq!(
+            Integer::gcd_list(&[Integer::from(-12), Integer::from(18), Integer::from(30)]),
+            Integer::from(6)
+        );
+        assert_eq!(
+            Integer::lcm_list(&[Integer::from(4), Integer::from(-6), Integer::from(10)]),
+            Integer::from(60)
+        );
+        assert_eq!(Integer::gcd_list(&[]), Integer::ZERO);
+        assert_eq!(Integer::lcm_list(&[]), Integer::ONE);
+    }
+
+    #[test]
+    fn test_pow_mod() {
+        let n = |x: u32| Natural::from(x);
+        // Fermat's little theorem
+        assert_eq!(n(2).pow_mod(&n(100), &n(101)), n(1));
+        assert_eq!(n(3).pow_mod(&n(0), &n(5)), n(1));
+        assert_eq!(n(3).pow_mod(&n(0), &n(1)), n(0));
+        assert_eq!(n(0).pow_mod(&n(0), &n(7)), n(1));
+        assert_eq!(n(0).pow_mod(&n(3), &n(7)), n(0));
+        for base in 0u32..20 {
+            for exp in 0u32..20 {
+                for modulus in 1u32..20 {
+                    assert_eq!(
+                        n(base).pow_mod(&n(exp), &n(modulus)),
+                        n(base).pow(&n(exp)) % n(modulus)
+                    );
+                }
+            }
+        }
+        // 2^(2^64) mod a 128 bit modulus
+        let m = Natural::from(340_282_366_920_938_463_463_374_607_431_768_211_297u128);
+        let mut x = n(2);
+        for _ in 0..64 {
+            x = (&x * &x) % &m;
+        }
+        assert_eq!(n(2).pow_mod(&Natural::power_of_2(64), &m), x);
+    }
+
     #[test]
     fn test_choose() {
         assert_eq!(
@@ -427,4 +686,24 @@ mod tests {
             Natural::from(3usize)
         );
     }
+
+    #[test]
+    fn test_natural_gcd_and_lcm() {
+        assert_eq!(
+            Natural::gcd(&Natural::from(12usize), &Natural::from(18usize)),
+            Natural::from(6usize)
+        );
+        assert_eq!(
+            Natural::lcm(&Natural::from(12usize), &Natural::from(18usize)),
+            Natural::from(36usize)
+        );
+        assert_eq!(
+            Natural::lcm(&Natural::from(0usize), &Natural::from(18usize)),
+            Natural::from(0usize)
+        );
+        assert_eq!(
+            Natural::lcm(&Natural::from(0usize), &Natural::from(0usize)),
+            Natural::from(0usize)
+        );
+    }
 }
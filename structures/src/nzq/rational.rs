@@ -176,18 +176,7 @@ impl TryFrom<f32> for Rational {
 
 impl From<&Rational> for f64 {
     fn from(val: &Rational) -> Self {
-        if val == &Rational::ZERO {
-            return 0.0;
-        }
-        let sign = val < &Rational::ZERO;
-        let (m, e, _) = val
-            .to_malachite_ref()
-            .sci_mantissa_and_exponent_round_ref::<f64>(
-                malachite::base::rounding_modes::RoundingMode::Nearest,
-            )
-            .unwrap();
-        let f: f64 = m * 2.0.pow(e);
-        if sign { -f } else { f }
+        val.to_f64()
     }
 }
 
@@ -666,6 +655,24 @@ impl Rational {
         malachite::rational::exhaustive::exhaustive_rationals().map(Rational)
     }
 
+    /// The nearest `f64` to `self`, rounding ties to even.
+    /// Values too large in absolute value become infinite and values too small become zero.
+    pub fn to_f64(&self) -> f64 {
+        use malachite::base::num::conversion::traits::RoundingFrom;
+        use malachite::base::rounding_modes::RoundingMode;
+        f64::rounding_from(self.to_malachite_ref(), RoundingMode::Nearest).0
+    }
+
+    /// The exact value of `x`, which is a dyadic rational `m * 2^e`, or `None` if `x` is NaN or infinite.
+    pub fn from_f64(x: f64) -> Option<Self> {
+        Self::try_from(x).ok()
+    }
+
+    /// The closest rational to `x` with denominator at most `max_denominator`, or `None` if `x` is NaN or infinite.
+    pub fn approx_f64(x: f64, max_denominator: &Natural) -> Option<Self> {
+        Some(Self::from_f64(x)?.approximate(max_denominator))
+    }
+
     #[allow(clippy::result_unit_err, clippy::missing_errors_doc)]
     pub fn try_from_float_simplest(x: f64) -> Result<Self, ()> {
         match malachite::Rational::try_from_float_simplest(x) {
@@ -981,4 +988,109 @@ mod tests {
         let r4 = Rational::from_integers(0, 1);
         assert_eq!(r4.height(), Natural::from(1u32));
     }
+
+    #[test]
+    fn test_f64_round_trip() {
+        let mut values = vec![
+            0.0,
+            -0.0,
+            1.0,
+            -1.5,
+            0.1,
+            1.0 / 3.0,
+            std::f64::consts::PI,
+            f64::MAX,
+            f64::MIN,
+            f64::MIN_POSITIVE,
+            f64::MIN_POSITIVE / 3.0,
+            f64::from_bits(1),
+            f64::EPSILON,
+            1e300,
+            -1e-300,
+        ];
+        let mut seed = 1u64;
+        for _ in 0..1000 {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let x = f64::from_bits(seed);
+            if x.is_finite() {
+                values.push(x);
+            }
+        }
+        for x in values {
+            let r = Rational::from_f64(x).unwrap();
+            assert_eq!(r.to_f64(), x);
+            assert_eq!(f64::from(&r), x);
+            // the denominator is a power of two
+            assert_eq!(r.denominator().bits().filter(|b| *b).count(), 1);
+        }
+        assert_eq!(Rational::from_f64(f64::NAN), None);
+        assert_eq!(Rational::from_f64(f64::INFINITY), None);
+        assert_eq!(Rational::from_f64(f64::NEG_INFINITY), None);
+        assert_eq!(
+            Rational::from_f64(0.375),
+            Some(Rational::from_integers(3, 8))
+        );
+        assert_eq!(
+            Rational::from_f64(0.1),
+            Some(Rational::from_integers(
+                Integer::from(3602879701896397u64),
+                Integer::from(36028797018963968u64)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_to_f64_rounding() {
+        // to_f64 is within half an ulp
+        let mut seed = 7u64;
+        for _ in 0..1000 {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let n = Integer::from((seed >> 11) as i64 - (1i64 << 52));
+            let d = Integer::from((seed & 0xffff) + 1);
+            let r = Rational::from_integers(n, d);
+            let x = r.to_f64();
+            let x_rat = Rational::from_f64(x).unwrap();
+            let ulp = Rational::from_f64(f64::from_bits(x.abs().to_bits() + 1)).unwrap()
+                - Rational::from_f64(x.abs()).unwrap();
+            assert!(Rational::from(2) * Abs::abs(&(x_rat - &r)) <= ulp);
+        }
+        assert_eq!(Rational::from_integers(1, 3).to_f64(), 1.0 / 3.0);
+        assert_eq!(Rational::from_integers(-2, 3).to_f64(), -2.0 / 3.0);
+        assert_eq!(Rational::ZERO.to_f64(), 0.0);
+        // subnormals are rounded once
+        let tiny = Rational::from_f64(f64::from_bits(1)).unwrap();
+        assert_eq!(
+            (&tiny * Rational::from_integers(3, 2)).to_f64(),
+            f64::from_bits(2)
+        );
+        assert_eq!((&tiny * Rational::from_integers(1, 3)).to_f64(), 0.0);
+        let huge = Rational::from_f64(f64::MAX).unwrap() * Rational::from(2);
+        assert_eq!(huge.to_f64(), f64::INFINITY);
+        assert_eq!((-huge).to_f64(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_approx_f64() {
+        assert_eq!(
+            Rational::approx_f64(std::f64::consts::PI, &Natural::from(1000u32)),
+            Some(Rational::from_integers(355, 113))
+        );
+        assert_eq!(
+            Rational::approx_f64(std::f64::consts::PI, &Natural::from(10u32)),
+            Some(Rational::from_integers(22, 7))
+        );
+        assert_eq!(
+            Rational::approx_f64(-0.333333, &Natural::from(100u32)),
+            Some(Rational::from_integers(-1, 3))
+        );
+        assert_eq!(
+            Rational::approx_f64(2.0, &Natural::ONE),
+            Some(Rational::from(2))
+        );
+        assert_eq!(Rational::approx_f64(f64::NAN, &Natural::ONE), None);
+    }
 }
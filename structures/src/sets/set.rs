@@ -166,6 +166,57 @@ mod tests {
         println!("{}", A::structure().to_string(&a));
     }
 
+    #[test]
+    fn generic_canonical_structure() {
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, CanonicalStructure)]
+        #[canonical_structure(eq, partial_ord, ord)]
+        pub struct Wrapper<T: Debug + Clone>(T);
+
+        #[derive(Debug, Clone, CanonicalStructure)]
+        pub struct Labelled<'a, T, const N: usize>
+        where
+            T: Send + Sync,
+        {
+            label: &'a str,
+            values: [T; N],
+        }
+
+        assert!(Wrapper::<i32>::structure().equal(&Wrapper(3), &Wrapper(3)));
+        assert!(!Wrapper::<i32>::structure().equal(&Wrapper(3), &Wrapper(4)));
+        assert_eq!(
+            Wrapper::<i32>::structure().cmp(&Wrapper(3), &Wrapper(4)),
+            std::cmp::Ordering::Less
+        );
+        assert_eq!(
+            Wrapper::<String>::structure().sort(vec![
+                Wrapper("b".to_string()),
+                Wrapper("c".to_string()),
+                Wrapper("a".to_string())
+            ]),
+            vec![
+                Wrapper("a".to_string()),
+                Wrapper("b".to_string()),
+                Wrapper("c".to_string())
+            ]
+        );
+        assert_eq!(
+            Wrapper::<i32>::structure(),
+            WrapperCanonicalStructure::<i32>::new()
+        );
+        assert_eq!(
+            format!("{:?}", Wrapper::<i32>::structure()),
+            "WrapperCanonicalStructure"
+        );
+
+        let x = Labelled {
+            label: "x",
+            values: [1u8, 2, 3],
+        };
+        assert!(Labelled::structure().is_element(&x));
+        assert_eq!(x.label, "x");
+        assert_eq!(x.values.len(), 3);
+    }
+
     #[test]
     fn to_string_structure_impl() {
         #[allow(dead_code)]